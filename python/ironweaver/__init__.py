@@ -1,7 +1,8 @@
 # Import the Rust extension module classes
 from typing import Callable, Iterable
 
-from ._ironweaver import Vertex, Node, Edge, Path, ObservedDictionary
+from ._ironweaver import Vertex, Node, Edge, Path, ObservedDictionary, BfsFrontiers
+from ._ironweaver import GraphError, NodeNotFoundError, EdgeNotFoundError, CancelledError
 
 # Import the Python LGF parser
 from .lgf_parser import parse_lgf, parse_lgf_file
@@ -372,7 +373,8 @@ def _node_traverse(self, depth=None, filter=None, edge_filter=None):
         Maximum traversal depth.
     filter : dict or callable, optional
         If a dict, only edges whose ``attr`` matches every key/value pair are
-        followed.  If a callable, it receives an :class:`EdgeView` and must
+        followed; a list, tuple or set value matches any of its elements.
+        If a callable, it receives an :class:`EdgeView` and must
         return ``True`` for edges that should be followed.
     edge_filter : callable, optional
         Explicit callable edge filter (same semantics as a callable *filter*).
@@ -449,14 +451,74 @@ def _node_bfs_search(self, target_id, depth=None, filter=None, edge_filter=None)
     return self._original_bfs_search(target_id, depth=depth, filter=dict_filter, edge_filter=ef)
 
 
+def _node_path_to(self, target_id, depth=None, filter=None, edge_filter=None):
+    """Shortest hop path from this node to a target, found with BFS.
+
+    Parameters
+    ----------
+    target_id : str
+        ID of the node to reach.
+    depth : int, optional
+        Maximum number of hops.
+    filter : dict or callable, optional
+        Dict for attribute matching or callable receiving :class:`EdgeView`.
+    edge_filter : callable, optional
+        Explicit callable edge filter.
+    """
+    dict_filter = None
+    callable_filter = edge_filter
+
+    if filter is not None:
+        if callable(filter):
+            if edge_filter is not None:
+                raise ValueError("Cannot pass both a callable 'filter' and 'edge_filter'")
+            callable_filter = filter
+        else:
+            dict_filter = filter
+
+    ef = _wrap_edge_filter(callable_filter) if callable_filter is not None else None
+    return self._original_path_to(target_id, depth=depth, filter=dict_filter, edge_filter=ef)
+
+
+def _node_bfs_layers(self, depth=None, filter=None, edge_filter=None):
+    """BFS from this node, returning node IDs grouped by distance.
+
+    Parameters
+    ----------
+    depth : int, optional
+        Maximum traversal depth.
+    filter : dict or callable, optional
+        Dict for attribute matching or callable receiving :class:`EdgeView`.
+    edge_filter : callable, optional
+        Explicit callable edge filter.
+    """
+    dict_filter = None
+    callable_filter = edge_filter
+
+    if filter is not None:
+        if callable(filter):
+            if edge_filter is not None:
+                raise ValueError("Cannot pass both a callable 'filter' and 'edge_filter'")
+            callable_filter = filter
+        else:
+            dict_filter = filter
+
+    ef = _wrap_edge_filter(callable_filter) if callable_filter is not None else None
+    return self._original_bfs_layers(depth=depth, filter=dict_filter, edge_filter=ef)
+
+
 def _setup_traversal_methods():
     Node._original_traverse = Node.traverse
     Node._original_bfs = Node.bfs
     Node._original_bfs_search = Node.bfs_search
+    Node._original_path_to = Node.path_to
+    Node._original_bfs_layers = Node.bfs_layers
 
     Node.traverse = _node_traverse
     Node.bfs = _node_bfs
     Node.bfs_search = _node_bfs_search
+    Node.path_to = _node_path_to
+    Node.bfs_layers = _node_bfs_layers
 
 _setup_traversal_methods()
 
@@ -470,6 +532,11 @@ __all__ = [
     "Edge",
     "Path",
     "ObservedDictionary",
+    "BfsFrontiers",
+    "GraphError",
+    "NodeNotFoundError",
+    "EdgeNotFoundError",
+    "CancelledError",
     "parse_lgf",
     "parse_lgf_file",
 ]
@@ -3,14 +3,17 @@ Public type stubs for the ironweaver package.
 
 This file describes every symbol available after::
 
-    from ironweaver import Vertex, Node, Edge, Path
+    from ironweaver import Vertex, Node, Edge, Path, BfsFrontiers
+    from ironweaver import GraphError, NodeNotFoundError, EdgeNotFoundError, CancelledError
     from ironweaver import NodeView, EdgeView
     from ironweaver import parse_lgf, parse_lgf_file
 """
 
 from __future__ import annotations
 
-from typing import Any, Callable, Iterator, final
+import re
+
+from typing import Any, Callable, Iterator, final, overload
 
 # ---------------------------------------------------------------------------
 # NodeView — proxy passed to Vertex.filter predicates
@@ -132,6 +135,48 @@ class EdgeView:
         """Return True if attribute *key* exists on this edge."""
         ...
 
+# ---------------------------------------------------------------------------
+# Exceptions
+# ---------------------------------------------------------------------------
+
+class GraphError(ValueError):
+    """Base class for errors about the graph itself.
+
+    Raised directly for an unreachable target, a negative edge weight in a
+    shortest-path search or ``expand_weighted``, and a duplicate node id in
+    ``add_node``. Subclasses ``ValueError``, so existing
+    ``except ValueError`` handlers keep working.
+    """
+
+class NodeNotFoundError(GraphError, KeyError):
+    """A node id does not exist in the graph.
+
+    Raised by ``Vertex.get_node``, ``vertex[id]``, ``add_edge`` and every
+    algorithm that takes a node id. Catchable as ``KeyError`` too.
+
+    Example::
+
+        try:
+            v["missing"]
+        except NodeNotFoundError as e:
+            print(e)   # Node with id 'missing' not found
+    """
+
+class EdgeNotFoundError(GraphError, KeyError):
+    """A requested edge does not exist in the graph.
+
+    Raised by ``Vertex.remove_edge`` when ``edge_id`` matches no edge between
+    the two nodes.
+    """
+
+class CancelledError(KeyboardInterrupt):
+    """A long-running algorithm was stopped by its ``should_cancel`` callback.
+
+    Subclasses ``KeyboardInterrupt`` rather than ``Exception``, so a broad
+    ``except Exception`` does not hide the cancellation. The graph is left
+    unchanged.
+    """
+
 # ---------------------------------------------------------------------------
 # ObservedDictionary  (PyO3 extension class — cannot be subclassed)
 # ---------------------------------------------------------------------------
@@ -189,7 +234,14 @@ class Edge:
     ) -> Edge: ...
     def __repr__(self) -> str: ...
     def toJSON(self) -> dict[str, Any]:
-        """Return the attr dict as a plain Python dict."""
+        """Same as :meth:`to_dict`."""
+        ...
+    def to_dict(self) -> dict[str, Any]:
+        """Return ``{"id", "from_id", "to_id", "attr", "meta"}``.
+
+        ``attr`` and ``meta`` are shallow copies, and the endpoints are enough to
+        rebuild the edge with :meth:`Vertex.add_edge`.
+        """
         ...
     def attr_set(self, key: str, value: Any) -> None:
         """Set attr[key] = value and fire on_update_callbacks if the value changed."""
@@ -197,6 +249,41 @@ class Edge:
     def attr_get(self, key: str) -> Any | None:
         """Return attr[key], or None if the key does not exist."""
         ...
+    def attr_get_str(self, key: str) -> str | None:
+        """Return attr[key] as a str, or None if the key does not exist.
+
+        Raises TypeError if the value is not a string. The ``int``,
+        ``float`` and ``bool`` variants below work the same way.
+
+        Example::
+
+            name = node.attr_get_str("name")
+        """
+        ...
+    def attr_get_int(self, key: str) -> int | None:
+        """Return attr[key] as an int; a bool raises TypeError."""
+        ...
+    def attr_get_float(self, key: str) -> float | None:
+        """Return attr[key] as a float, converting ints; a bool raises TypeError."""
+        ...
+    def attr_get_bool(self, key: str) -> bool | None:
+        """Return attr[key] as a bool; ``0`` and ``1`` raise TypeError."""
+        ...
+    def attr_increment(self, key: str, delta: int | float = 1) -> int | float:
+        """Add *delta* to the numeric value at attr[key] and return the new value.
+
+        A missing key is created with value *delta*. ``on_update_callbacks``
+        fire once, exactly as for :meth:`attr_set`.
+
+        Raises:
+            TypeError: If the existing value or *delta* is not an int or float.
+
+        Example::
+
+            edge.attr_increment("count")        # 1
+            edge.attr_increment("count", 2)     # 3
+        """
+        ...
 
 # ---------------------------------------------------------------------------
 # Node  (PyO3 extension class — cannot be subclassed)
@@ -219,7 +306,7 @@ class Node:
     attr: dict[str, Any]
     """Node attributes, e.g. {"type": "Person", "age": 30}."""
     edges: list[Edge]
-    """Outgoing edges."""
+    """Outgoing edges. Assigning a new list keeps ``edge_count`` of the vertex the node was added to in step."""
     inverse_edges: list[Edge]
     """Incoming edges."""
     meta: dict[str, Any]
@@ -250,7 +337,8 @@ class Node:
             Maximum traversal depth. None means unlimited.
         filter:
             If a dict, only edges whose ``attr`` contains every key/value pair
-            are followed (e.g. ``{"type": "broader"}``).
+            are followed (e.g. ``{"type": "broader"}``). A list, tuple or set
+            value matches any of its elements (``{"type": ["broader", "related"]}``).
             If a callable, it receives an :class:`EdgeView` and must return True
             for edges that should be followed. Cannot be combined with edge_filter.
         edge_filter:
@@ -281,15 +369,212 @@ class Node:
     ) -> Node | None:
         """Search for *target_id* using BFS. Returns the Node if found, None otherwise."""
         ...
+    def path_to(
+        self,
+        target_id: str,
+        depth: int | None = ...,
+        filter: dict[str, Any] | Callable[[EdgeView], bool] | None = ...,
+        edge_filter: Callable[[EdgeView], bool] | None = ...,
+    ) -> Path | None:
+        """Shortest hop path from this node to *target_id*, found with BFS.
+
+        Like :meth:`bfs_search`, but returns the whole route as a :class:`Path`
+        (starting at this node, ending at the target) instead of only the target.
+        Returns None if the target is unreachable within *depth* hops.
+
+        Example::
+
+            graph["a"].path_to("d").toJSON()   # ["a", "b", "c", "d"]
+        """
+        ...
+    def bfs_layers(
+        self,
+        depth: int | None = ...,
+        filter: dict[str, Any] | Callable[[EdgeView], bool] | None = ...,
+        edge_filter: Callable[[EdgeView], bool] | None = ...,
+    ) -> list[list[str]]:
+        """BFS from this node, returning node IDs grouped by distance.
+
+        Index 0 is ``[self.id]``, index 1 the nodes one hop away, and so on.
+
+        Example::
+
+            graph["a"].bfs_layers(depth=2)   # [["a"], ["b", "c"], ["d"]]
+        """
+        ...
+    def walk(
+        self,
+        order: str = "bfs",
+        on_visit: Callable[[Node], Any] | None = None,
+        on_edge: Callable[[Edge], Any] | None = None,
+        depth: int | None = None,
+    ) -> None:
+        """Traverse from this node, calling back instead of building a Vertex.
+
+        *order* is ``"bfs"`` or ``"dfs"`` (preorder). ``on_visit(node)`` runs
+        once per node when it is first reached, starting with this node;
+        returning ``False`` prunes that branch. ``on_edge(edge)`` runs for
+        every edge followed out of a visited node, before its target is
+        visited. *depth* limits the number of hops. Raises ValueError for an
+        unknown *order*.
+
+        Example::
+
+            seen = []
+            graph["a"].walk(on_visit=lambda n: seen.append(n.id))
+            graph["a"].walk("dfs", on_visit=lambda n: n.attr.get("kind") != "leaf")
+        """
+        ...
     def attr_get(self, key: str) -> Any | None:
         """Return attr[key], or None if the key does not exist."""
         ...
+    def attr_get_str(self, key: str) -> str | None:
+        """Return attr[key] as a str, or None if the key does not exist.
+
+        Raises TypeError if the value is not a string. The ``int``,
+        ``float`` and ``bool`` variants below work the same way.
+
+        Example::
+
+            name = node.attr_get_str("name")
+        """
+        ...
+    def attr_get_int(self, key: str) -> int | None:
+        """Return attr[key] as an int; a bool raises TypeError."""
+        ...
+    def attr_get_float(self, key: str) -> float | None:
+        """Return attr[key] as a float, converting ints; a bool raises TypeError."""
+        ...
+    def attr_get_bool(self, key: str) -> bool | None:
+        """Return attr[key] as a bool; ``0`` and ``1`` raise TypeError."""
+        ...
     def attr_set(self, key: str, value: Any) -> None:
         """Set attr[key] = value and fire on_update_callbacks if the value changed."""
         ...
+    def attr_increment(self, key: str, delta: int | float = 1) -> int | float:
+        """Add *delta* to the numeric value at attr[key] and return the new value.
+
+        A missing key is created with value *delta*. ``on_update_callbacks``
+        fire once, exactly as for :meth:`attr_set`.
+
+        Raises:
+            TypeError: If the existing value or *delta* is not an int or float.
+
+        Example::
+
+            node.attr_increment("visits")        # 1
+            node.attr_increment("visits", 2)     # 3
+        """
+        ...
     def attr_list_append(self, key: str, value: Any) -> None:
         """Append *value* to the list stored at attr[key], creating it if missing."""
         ...
+    def add_tag(self, tag: str) -> None:
+        """Add *tag* to this node. Tags live apart from ``attr`` and are saved with the graph.
+
+        Example::
+
+            node.add_tag("vip")
+            node.has_tag("vip")          # True
+            graph.nodes_with_tag("vip")  # [node]
+        """
+        ...
+    def has_tag(self, tag: str) -> bool:
+        """Return True if this node carries *tag*."""
+        ...
+    def tags(self) -> list[str]:
+        """Return this node's tags, sorted."""
+        ...
+    def set_subgraph(self, vertex: Vertex | None) -> None:
+        """Attach a nested graph this node expands into; ``None`` detaches it.
+
+        Subgraphs are saved recursively with the graph. See :meth:`Vertex.flatten`.
+
+        Example::
+
+            graph["build"].set_subgraph(build_steps)
+        """
+        ...
+    def get_subgraph(self) -> Vertex | None:
+        """Return the nested graph attached with :meth:`set_subgraph`, or None."""
+        ...
+    def common_neighbors(self, other_node: Node) -> list[str]:
+        """Return ids that are outgoing neighbours of both this node and *other_node*.
+
+        Computed directly from the two ``edges`` lists, so no :class:`Vertex`
+        is needed. Each id appears once, in the order of this node's edges.
+
+        Example::
+
+            v.add_edge("a", "c", {})
+            v.add_edge("b", "c", {})
+            v["a"].common_neighbors(v["b"])   # ["c"]
+        """
+        ...
+    def all_edges(self) -> list[Edge]:
+        """Return ``edges`` followed by ``inverse_edges``.
+
+        Useful when direction does not matter. A self-loop sits in both lists
+        but is returned once.
+        """
+        ...
+    def is_connected_to(self, other_id: str) -> bool:
+        """True if an edge links this node and *other_id* in either direction.
+
+        Example::
+
+            v.add_edge("a", "b", {})
+            v["b"].is_connected_to("a")   # True
+        """
+        ...
+    def aggregate_neighbors(
+        self,
+        attr_key: str,
+        op: str = "sum",
+        direction: str = "out",
+    ) -> float | int | None:
+        """Aggregate the numeric attribute *attr_key* over neighbouring nodes.
+
+        *direction* is ``"out"`` (``edges``), ``"in"`` (``inverse_edges``) or
+        ``"both"``; a neighbour is read once per connecting edge. *op* is
+        ``"sum"``, ``"mean"``, ``"min"``, ``"max"`` or ``"count"`` (number of
+        numeric values). Missing and non-numeric values, including bools, are
+        skipped. ``"sum"`` of nothing is 0.0; ``"mean"``, ``"min"`` and
+        ``"max"`` of nothing are None.
+
+        Raises:
+            ValueError: For an unknown *op* or *direction*.
+
+        Example::
+
+            node.aggregate_neighbors("value", op="mean")   # 2.5
+        """
+        ...
+    def top_neighbors(
+        self, k: int, weight_field: str = "weight", direction: str = "out"
+    ) -> list[tuple[str, float]]:
+        """Return the *k* most strongly connected neighbours as ``(id, weight)``.
+
+        Sums *weight_field* (1.0 when missing) over the edges to each
+        neighbour in *direction* (``"out"``, ``"in"`` or ``"both"``), skipping
+        self-loops, and sorts by weight descending, then by id.
+
+        Raises:
+            ValueError: For an unknown *direction*.
+            TypeError: If a weight is not numeric.
+
+        Example::
+
+            node.top_neighbors(2)   # [("b", 5.0), ("c", 3.0)]
+        """
+        ...
+    def to_dict(self) -> dict[str, Any]:
+        """Return ``{"id", "attr", "meta", "edges", "inverse_edges"}``.
+
+        ``edges`` holds ``{"id", "to_id"}`` per outgoing edge and
+        ``inverse_edges`` holds ``{"id", "from_id"}`` per incoming edge.
+        """
+        ...
 
 # ---------------------------------------------------------------------------
 # Path  (PyO3 extension class — cannot be subclassed)
@@ -300,10 +585,10 @@ class Path:
     """An ordered sequence of nodes.
 
     .. note::
-        No current public API method returns a ``Path`` object directly.
+        ``Vertex.batch_shortest_paths`` returns ``Path`` objects.
         ``shortest_path_bfs`` and the traversal methods return a
         :class:`Vertex` subgraph; use ``result.meta["nodelist"]`` for the
-        ordered node-ID list. ``Path`` is reserved for future use.
+        ordered node-ID list.
     """
 
     nodes: list[Node]
@@ -314,6 +599,17 @@ class Path:
         """Return the list of node IDs along this path."""
         ...
 
+@final
+class BfsFrontiers:
+    """Lazy iterator over BFS levels, returned by :meth:`Vertex.bfs_frontiers`.
+
+    Each step yields the node IDs of one level; the next level is only
+    computed when requested.
+    """
+
+    def __iter__(self) -> BfsFrontiers: ...
+    def __next__(self) -> list[str]: ...
+
 # ---------------------------------------------------------------------------
 # Vertex — main graph class  (PyO3 extension class — cannot be subclassed)
 # ---------------------------------------------------------------------------
@@ -345,6 +641,8 @@ class Vertex:
     on_edge_add_callbacks   – ``(vertex: Vertex, edge: Edge) -> bool``
     on_node_update_callbacks – ``(vertex, node, key, new_val, old_val) -> bool``
     on_edge_update_callbacks – ``(vertex, edge, key, new_val, old_val) -> bool``
+    on_vertex_meta_change_callbacks – ``(vertex, key, new_val, old_val) -> bool``
+    on_node_remove_callbacks – ``(vertex: Vertex, node: Node) -> bool``
 
     Return ``False`` from any callback to stop further callbacks in that chain.
     The node or edge is **always added** regardless of the return value —
@@ -359,10 +657,26 @@ class Vertex:
     on_edge_add_callbacks: list[Callable[[Vertex, Edge], bool]]
     on_node_update_callbacks: list[Callable[[Vertex | None, Node, str, Any, Any | None], bool]]
     on_edge_update_callbacks: list[Callable[[Vertex | None, Edge, str, Any, Any | None], bool]]
+    on_vertex_meta_change_callbacks: list[Callable[[Vertex, str, Any, Any | None], bool]]
+    """Fires when meta_set/meta_update changes a meta value."""
+    on_node_remove_callbacks: list[Callable[[Vertex, Node], bool]]
+    """Fires after a node is removed, e.g. by remove_isolated_nodes or clear."""
 
     def __new__(cls) -> Vertex: ...
+    @overload
     def __getitem__(self, key: str, /) -> Node:
-        """Return the node with the given ID. Raises KeyError if not found."""
+        """Return the node with the given ID. Raises NodeNotFoundError (a KeyError) if not found."""
+        ...
+    @overload
+    def __getitem__(self, key: list[str], /) -> Vertex:
+        """Return the subgraph induced by the given IDs, like ``filter(ids=...)``.
+
+        Raises NodeNotFoundError naming every missing ID.
+
+        Example::
+
+            sub = graph[["a", "b", "c"]]
+        """
         ...
     def __iter__(self) -> Iterator[Node]:
         """Iterate over all nodes (values) in the graph."""
@@ -378,9 +692,30 @@ class Vertex:
             if "alice" in graph: ...
         """
         ...
-    def __repr__(self) -> str: ...
-    def keys(self) -> list[str]:
-        """Return all node IDs."""
+    def __repr__(self) -> str:
+        """``Vertex(id1, id2, ...)`` with the IDs sorted; beyond 10 nodes only the
+        first 10 IDs are shown, followed by ``... (+N more)`` and the total node count."""
+        ...
+    def __str__(self) -> str:
+        """Multi-line summary for ``print(graph)``.
+
+        Example::
+
+            Vertex with 1000 nodes and 2400 edges
+              nodes: a, b, c, d, e, ...
+              edge types: knows (1800), likes (600)
+        """
+        ...
+    def keys(self, ordered: bool = False) -> list[str]:
+        """Return all node IDs.
+
+        Nodes live in a hash map, so the default order is arbitrary and changes
+        between runs. Pass ``ordered=True`` to get the ids sorted.
+
+        Example::
+
+            graph.keys(ordered=True)   # ["a", "b", "c"]
+        """
         ...
     def toJSON(self) -> dict[str, Any]: ...
 
@@ -389,156 +724,995 @@ class Vertex:
     # ------------------------------------------------------------------
 
     def has_node(self, id: str) -> bool: ...
+    def nodes_with_tag(self, tag: str) -> list[Node]:
+        """Return every node carrying *tag* (see :meth:`Node.add_tag`), sorted by ID."""
+        ...
     def node_count(self) -> int: ...
-    def get_metadata(self) -> dict[str, Any]:
+    def get_metadata(self, undirected: bool = ...) -> dict[str, Any]:
         """Return summary metadata about the graph.
 
         Returned keys:
 
-        ==================  =================================================
-        ``node_count``      Number of nodes (int)
-        ``edge_count``      Number of edges (int)
-        ``average_degree``  Mean number of outgoing edges per node (float)
-        ``node_ids``        List of all node ID strings
-        ==================  =================================================
+        =========================  =================================================
+        ``node_count``             Number of nodes (int)
+        ``edge_count``             Number of edges (int)
+        ``reciprocal_edge_count``  Node pairs linked in both directions (int)
+        ``average_degree``         Mean number of outgoing edges per node (float)
+        ``node_ids``               List of all node ID strings
+        =========================  =================================================
+
+        With ``undirected=True``, ``edge_count`` counts each unordered node pair
+        joined by at least one edge once (so ``a -> b`` plus ``b -> a`` is one
+        edge) and ``average_degree`` is ``2 * edge_count / node_count``.
         """
         ...
+    def degree_distribution(self, direction: str = ...) -> dict[int, int]:
+        """Map each degree value to the number of nodes with that degree.
 
-    # ------------------------------------------------------------------
-    # Mutation
-    # ------------------------------------------------------------------
+        *direction* is ``"out"`` (default), ``"in"`` or ``"total"``.
 
-    def add_node(self, id: str, attr: dict[str, Any] | None) -> Node:
-        """Add a node and return it. Raises ValueError if *id* already exists."""
-        ...
-    def add_edge(self, from_id: str, to_id: str, attr: dict[str, Any] | None) -> Edge:
-        """Add a directed edge and return it. Raises ValueError if either node is missing."""
-        ...
-    def get_node(self, id: str) -> Node:
-        """Return the node. Raises KeyError if not found."""
-        ...
-    def prune(self) -> int:
-        """Remove dangling edges (edges pointing to nodes not in this vertex).
+        Example::
 
-        Returns the number of edges removed. Useful after filtering or subsetting.
+            dist = graph.degree_distribution("total")
+            # plot sorted(dist.items()) on log-log axes to check for a power law
         """
         ...
+    def edge_attr_histogram(self, field: str) -> dict[Any, int]:
+        """Count the edges carrying each distinct value of edge attribute *field*.
 
-    # ------------------------------------------------------------------
-    # Persistence
-    # ------------------------------------------------------------------
+        Edges without *field* are skipped. Unhashable values (lists, dicts)
+        are keyed by ``str(value)``.
 
-    def save_to_json(self, file_path: str | None = ...) -> str | None:
-        """Serialize to JSON.
+        Example::
 
-        If *file_path* is given, writes to that path and returns None.
-        If *file_path* is None, returns the JSON string.
+            graph.edge_attr_histogram("type")   # {"knows": 12, "works_at": 5}
         """
         ...
-    def save_to_binary(self, file_path: str) -> None:
-        """Serialize to a compact binary format."""
-        ...
-    def save_to_binary_f16(self, file_path: str) -> None:
-        """Like save_to_binary but stores floats as f16 to reduce file size."""
-        ...
-    @staticmethod
-    def load_from_json(source: str | dict[str, Any]) -> Vertex:
-        """Load from a file path, a raw JSON string, or a plain dict.
+    def infer_schema(self) -> dict[str, dict[str, dict[str, Any]]]:
+        """Report the observed value types of every node and edge attribute.
+
+        Values are classified like the serializer does: ``"string"``, ``"int"``,
+        ``"float"``, ``"half"``, ``"bool"``, ``"none"``, ``"list"`` or ``"dict"``.
 
         Example::
 
-            loaded = Vertex.load_from_json("my_graph.json")   # file path
-            loaded = Vertex.load_from_json(json_string)        # raw JSON string
-            loaded = Vertex.load_from_json({"nodes": {...}})   # plain dict
+            schema = graph.infer_schema()
+            schema["nodes"]["age"]
+            # {"types": ["int", "string"], "mixed": True, "count": 3, "required": True}
+
+        ``required`` is True when every node (or every edge) carries the key.
         """
         ...
-    @staticmethod
-    def load_from_binary(file_path: str) -> Vertex: ...
-    @staticmethod
-    def from_nodes(nodes: dict[str, Node]) -> Vertex:
-        """Construct a Vertex directly from an existing node mapping."""
-        ...
-    @staticmethod
-    def from_nodes_with_path(nodes: dict[str, Node], nodelist: list[str]) -> Vertex:
-        """Like from_nodes but also stores *nodelist* in ``meta["nodelist"]``."""
+    def nodes_to_columns(self, fields: list[str]) -> dict[str, list[Any]]:
+        """Export node attributes as columns aligned with an ``id`` column.
+
+        Rows follow the sorted node ids; nodes without a field get ``None``.
+        Raises ValueError if a field is named ``id``.
+
+        Example::
+
+            import pandas as pd
+            df = pd.DataFrame(graph.nodes_to_columns(["age", "city"]))
+        """
         ...
+    def edges_to_columns(self, fields: list[str]) -> dict[str, list[Any]]:
+        """Export edge attributes as columns aligned with ``from_id``/``to_id``.
 
-    # ------------------------------------------------------------------
-    # Conversion
-    # ------------------------------------------------------------------
+        Rows follow the sorted source ids, then each node's edge order; edges
+        without a field get ``None``. Raises ValueError if a field is named
+        ``from_id`` or ``to_id``.
 
-    def to_networkx(self) -> Any:
-        """Convert to a ``networkx.DiGraph``. Requires networkx to be installed."""
+        Example::
+
+            cols = graph.edges_to_columns(["weight"])
+            # {"from_id": ["a", "a"], "to_id": ["b", "c"], "weight": [2.0, None]}
+        """
         ...
 
     # ------------------------------------------------------------------
-    # Algorithms
+    # Mutation
     # ------------------------------------------------------------------
 
-    def shortest_path_bfs(
-        self,
-        root_node_id: str,
-        target_node_id: str,
-        max_depth: int | None = ...,
-    ) -> Vertex:
-        """Return a new Vertex containing only the nodes on the shortest BFS path.
+    def add_node(self, id: str, attr: dict[str, Any] | None) -> Node:
+        """Add a node and return it. Raises GraphError if *id* already exists."""
+        ...
+    def add_edge(self, from_id: str, to_id: str, attr: dict[str, Any] | None) -> Edge:
+        """Add a directed edge and return it. Raises NodeNotFoundError if either node is missing."""
+        ...
+    def remove_edge(self, from_id: str, to_id: str, edge_id: str | None = None) -> int:
+        """Remove the edges from *from_id* to *to_id* and return how many were removed.
 
-        The ordered sequence of node IDs is in ``result.meta["nodelist"]``.
-        Raises ValueError if either node is missing or the target is unreachable.
+        With *edge_id* only the edge carrying that ID is removed. The edge is
+        dropped from both ``from_node.edges`` and ``to_node.inverse_edges``.
+        Raises NodeNotFoundError if either node is missing, and
+        EdgeNotFoundError if *edge_id* matches no edge between them.
         """
         ...
-    def expand(self, source_vertex: Vertex, depth: int | None = ...) -> Vertex:
-        """Expand this subgraph by pulling neighbour nodes from *source_vertex*.
-
-        *depth* defaults to 1 (one hop).
+    def set_default_attr(self, key: str, value: Any) -> int:
+        """Set ``attr[key] = value`` on every node that lacks *key*.
 
-        Only **outgoing** edges are followed during expansion; nodes that point
-        *into* the seed nodes are not included.
+        Nodes that already have *key* keep their value. Writes go through
+        ``Node.attr_set``, so ``on_node_update_callbacks`` fire once per
+        changed node. Every node gets the same *value* object. Returns the
+        number of nodes changed.
 
         Example::
 
-            seed = graph.filter(id="ckd")
-            expanded = seed.expand(graph, depth=1)
-            # expanded now contains ckd + all nodes ckd has outgoing edges to
+            graph.set_default_attr("score", 0)
         """
         ...
-    def filter(
-        self,
-        predicate: Callable[[NodeView], bool] | None = ...,
-        *,
-        ids: list[str] | None = ...,
-        id: str | None = ...,
-        **kwargs: Any,
-    ) -> Vertex:
-        """Return a new Vertex containing only matching nodes and their shared edges.
+    def set_default_edge_attr(self, key: str, value: Any) -> int:
+        """Set ``attr[key] = value`` on every edge that lacks *key*.
 
-        Exactly one filtering mode must be used:
+        The edge analogue of :meth:`set_default_attr`, firing
+        ``on_edge_update_callbacks`` once per changed edge. Returns the number
+        of edges changed.
 
-        **Predicate (lambda) mode** — most expressive::
+        Example::
 
-            result = g.filter(lambda n: (
-                n.id.startswith("user_")
-                and n.type == "Person"
-                and n.attr("age", 0) >= 18
-                and not n.has_attr("deleted")
-                and n.has_edge_to("org_1")
-            ))
+            graph.set_default_edge_attr("weight", 1.0)
+        """
+        ...
+    def rename_attr(self, old_key: str, new_key: str, on: str = "nodes") -> int:
+        """Rename attribute *old_key* to *new_key* on every node (or edge) having it.
 
-        **ID list mode**::
+        Values are kept. The new key is written with ``attr_set``, so update
+        callbacks fire once per renamed item; items without *old_key* are
+        skipped. *on* is ``"nodes"`` or ``"edges"``. Raises GraphError, before
+        changing anything, if an item already has *new_key*. Returns the
+        number of items renamed.
 
-            result = g.filter(ids=["alice", "bob"])
-            result = g.filter(id="alice")
+        Example::
 
-        **Attribute equality mode** (keyword arguments)::
+            graph.rename_attr("name", "label")
+            graph.rename_attr("w", "weight", on="edges")
+        """
+        ...
+    def relabel(self, mapping: dict[str, str]) -> None:
+        """Rename the nodes in *mapping* (old ID → new ID) in one step.
 
-            result = g.filter(type="Person")
-            result = g.filter(status="active", role="admin")  # multiple kwargs are ANDed
+        Every rename is validated before any is applied, so swaps work and a
+        failing call leaves the graph untouched. Raises NodeNotFoundError for
+        an unknown old ID and GraphError if two nodes would share a new ID or
+        a new ID belongs to a node that is not renamed. Edges follow their
+        nodes; no callbacks fire.
 
-        The predicate receives a :class:`NodeView` which exposes:
+        Example::
 
-        ==================  =====================================================
-        ``n.id``            node ID (str)
-        ``n.type``          shortcut for ``n.attr("type")``
+            graph.relabel({node_id: f"v1:{node_id}" for node_id in graph.nodes})
+        """
+        ...
+    def reverse_in_place(self) -> None:
+        """Flip the direction of every edge without copying the graph.
+
+        Each edge swaps ``from_node`` and ``to_node`` and each node swaps its
+        ``edges`` and ``inverse_edges``; the ``Edge`` objects themselves are
+        kept. No callbacks fire. Call :meth:`prune` first if edges point at
+        nodes outside the graph.
+
+        Example::
+
+            graph.reverse_in_place()   # dependencies now point at dependents
+        """
+        ...
+    def remove_isolated_nodes(self) -> int:
+        """Remove every node with no outgoing and no incoming edges.
+
+        Fires ``on_node_remove_callbacks`` with ``(vertex, node)`` for each
+        removed node, in node ID order. Returns the number of nodes removed.
+
+        Example::
+
+            graph.add_node("lonely")
+            graph.remove_isolated_nodes()   # 1
+        """
+        ...
+    def clear(self, fire: bool = True) -> None:
+        """Remove every node and edge and reset ``meta`` to an empty dict.
+
+        With *fire* (the default) ``on_node_remove_callbacks`` runs once per
+        removed node, in node ID order, after everything is gone. Pass
+        ``fire=False`` to skip them. The callback lists themselves are kept.
+        """
+        ...
+    def clear_edges(self) -> int:
+        """Remove every edge but keep the nodes. Returns the number of edges removed."""
+        ...
+    def edge_count(self) -> int:
+        """Return the number of edges in O(1).
+
+        The count is cached and updated by :meth:`add_edge`, :meth:`remove_edge`
+        and :meth:`prune`. Assigning ``node.edges`` directly bypasses the cache;
+        call :meth:`repair` afterwards.
+        """
+        ...
+    def validate(self) -> list[str]:
+        """Compare cached values (the edge count) against a full recount.
+
+        Returns a description of each inconsistency, or an empty list.
+        """
+        ...
+    def repair(self) -> list[str]:
+        """Recompute cached values and return the inconsistencies that were fixed.
+
+        Example::
+
+            loaded = Vertex.load_from_binary("graph.bin")
+            loaded["a"].edges = []  # no owning vertex to update, bypasses the cache
+            loaded.repair()         # ["edge_count is 3 but the nodes hold 1 edges"]
+        """
+        ...
+    def meta_set(self, key: str, value: Any) -> None:
+        """Set meta[key] = value and fire on_vertex_meta_change_callbacks if it changed."""
+        ...
+    def meta_get(self, key: str, default: Any = ...) -> Any:
+        """Return meta[key], or *default* (None) if the key does not exist."""
+        ...
+    def meta_update(self, values: dict[str, Any]) -> None:
+        """Call :meth:`meta_set` for every item of *values*."""
+        ...
+    def has_callbacks(self) -> dict[str, int]:
+        """Return the number of callbacks registered in each callback list.
+
+        Callbacks are never serialized, so a loaded graph reports 0 everywhere.
+
+        Example::
+
+            g = Vertex.load_from_binary("graph.bin")
+            if not g.has_callbacks()["on_node_add_callbacks"]:
+                g.on_node_add_callbacks.append(on_add)
+        """
+        ...
+    def get_node(self, id: str) -> Node:
+        """Return the node. Raises NodeNotFoundError (a KeyError) if not found."""
+        ...
+    def filter_by_id(
+        self,
+        prefix: str | None = ...,
+        pattern: str | re.Pattern[str] | None = ...,
+    ) -> Vertex:
+        """Return the subgraph of nodes whose id starts with *prefix* and/or
+        contains a match of the regex *pattern*.
+
+        *pattern* is searched (not anchored) with Rust ``regex`` syntax, which
+        has no lookarounds or backreferences. A compiled ``re.Pattern`` keeps
+        its IGNORECASE, MULTILINE, DOTALL and VERBOSE flags as inline
+        ``(?imsx)`` groups. Raises ValueError if neither argument is given,
+        the pattern is invalid, or it carries any other flag.
+
+        Example::
+
+            people = graph.filter_by_id(prefix="person:")
+            numbered = graph.filter_by_id(pattern=re.compile(r"^person:\d+$"))
+            any_case = graph.filter_by_id(pattern=re.compile("^person:", re.I))
+        """
+        ...
+    def partition(
+        self,
+        keep_cross_edges: bool = ...,
+        **kwargs: Any,
+    ) -> tuple[Vertex, Vertex]:
+        """Split into ``(matching, non_matching)`` subgraphs in one pass.
+
+        *kwargs* are attribute filters as in ``filter(**kwargs)``. Edges
+        crossing the boundary are dropped unless ``keep_cross_edges=True``,
+        which keeps them on their source node pointing into the other vertex.
+        Raises ValueError if no filters are given.
+
+        Example::
+
+            people, rest = graph.partition(type="Person")
+        """
+        ...
+    def flatten(self, separator: str = "/") -> Vertex:
+        """Inline nested subgraphs (see :meth:`Node.set_subgraph`) into one flat graph.
+
+        Top-level ids are kept; node ``c`` nested in node ``p`` becomes
+        ``p/c``, recursively. Container nodes remain and edges are copied within
+        their own level. Raises GraphError if a subgraph contains one of its
+        ancestors and ValueError if a namespaced id collides with an existing one.
+
+        Example::
+
+            flat = graph.flatten()
+            flat["build/compile"]
+        """
+        ...
+    def prune(self) -> int:
+        """Remove dangling edges (edges pointing to nodes not in this vertex).
+
+        Returns the number of edges removed. Useful after filtering or subsetting.
+        """
+        ...
+    def merge(
+        self,
+        other: Vertex,
+        on_conflict: Callable[[str, Node, Node], dict[str, Any]] | None = None,
+    ) -> Vertex:
+        """Combine this graph and *other* into a new graph.
+
+        Nodes of both graphs are kept. For a node ID present in both,
+        *on_conflict* is called as ``(node_id, self_node, other_node)`` and
+        must return the merged attribute dict; without it this graph's
+        attributes win. Tags of a shared node are unioned. Edges are unioned: an edge of *other* is dropped when
+        the same endpoints already have an edge with the same ID and equal
+        attributes. Neither input is modified.
+
+        Example::
+
+            combined = g1.merge(
+                g2, on_conflict=lambda nid, a, b: {**a.attr, **b.attr}
+            )
+        """
+        ...
+    def diff(self, other: Vertex) -> dict[str, Any]:
+        """Describe the changes from this graph to the snapshot *other*.
+
+        Returns a dict with ``added_nodes`` (``{id: attr}``),
+        ``removed_nodes`` (sorted IDs), ``added_edges`` / ``removed_edges``
+        (lists of ``{"from_id", "to_id", "type", "attr"}``) and
+        ``changed_attrs`` (``{id: {key: {"old": ..., "new": ...}}}``, leaving
+        out ``"old"`` for an added key and ``"new"`` for a removed one). Nodes
+        match by ID. Edges with the same endpoints and ``type`` are paired one
+        to one by equal attributes, so an edge whose other attributes changed
+        appears in ``removed_edges`` with the old and ``added_edges`` with the
+        new attributes.
+
+        Example::
+
+            delta = yesterday.diff(today)
+            delta["added_nodes"]     # {"n42": {"name": "new"}}
+            delta["changed_attrs"]   # {"n7": {"score": {"old": 1, "new": 2}}}
+        """
+        ...
+    def apply_patch(self, patch: dict[str, Any]) -> None:
+        """Apply a :meth:`diff` result to this graph in place.
+
+        The whole patch is validated first: removed nodes, nodes with changed
+        attributes and endpoints of added edges must exist
+        (``NodeNotFoundError``), added nodes must be new (``GraphError``) and
+        removed edges present (``EdgeNotFoundError``; an entry with ``attr``
+        needs an edge with equal attributes). Edges are removed first (the
+        last of any parallel matches), then nodes with their
+        remaining edges, firing
+        ``on_node_remove_callbacks``. Additions go through ``add_node`` /
+        ``add_edge`` and value changes through ``Node.attr_set``, so their
+        callbacks fire too; removed keys are dropped silently. Missing
+        sections count as empty.
+
+        Example::
+
+            replica.apply_patch(yesterday.diff(today))
+        """
+        ...
+    def structurally_equal(self, other: Vertex, ignore_attrs: list[str] | None = None) -> bool:
+        """Return True if *other* has the same topology and attributes.
+
+        Both graphs need the same node IDs with equal attributes, the same
+        edges between every pair of nodes (parallel edges matched one to one
+        by their attributes) and equal ``meta``. Keys in *ignore_attrs* are
+        skipped in node and edge attributes and in ``meta``. Tags, edge IDs
+        and the ``meta`` of single nodes and edges are not compared.
+
+        Example::
+
+            loaded = Vertex.load_from_json(graph.save_to_json())
+            graph.structurally_equal(loaded, ignore_attrs=["updated_at"])   # True
+        """
+        ...
+    def content_hash(self) -> str:
+        """Return a stable SHA-256 hex digest of the graph content.
+
+        The hash covers what ``structurally_equal`` compares: node IDs,
+        node attributes, each node's outgoing edges with their attributes,
+        and ``meta``. Keys are sorted and edges ordered canonically, so two
+        equal graphs built in different orders hash the same. Tags, edge IDs,
+        the ``meta`` of single nodes and edges, and timestamps are left out.
+        Values are hashed in serialized form, so ``1`` and ``1.0`` differ.
+
+        Example::
+
+            if graph.content_hash() != cache.get("hash"):
+                cache["hash"] = graph.content_hash()
+                rebuild_index(graph)
+        """
+        ...
+    def edge_set_hash(self) -> int:
+        """Return an order-independent 64-bit fingerprint of the edge set.
+
+        The XOR of one hash per edge over its source and target IDs. It is
+        much cheaper than :meth:`content_hash` because attributes are
+        ignored: different values prove the topologies differ, equal values
+        call for a full comparison. Parallel edges count individually, and
+        isolated nodes do not affect the value. An edgeless graph gives 0.
+
+        Example::
+
+            if first.edge_set_hash() != second.edge_set_hash():
+                print("topology changed")
+            elif not first.structurally_equal(second):
+                print("attributes changed")
+        """
+        ...
+
+    # ------------------------------------------------------------------
+    # Persistence
+    # ------------------------------------------------------------------
+
+    def save_to_json(self, file_path: str | None = ..., include_timestamp: bool = True) -> str | None:
+        """Serialize to JSON.
+
+        If *file_path* is given, writes to that path and returns None.
+        If *file_path* is None, returns the JSON string. Nodes, edges and
+        attribute keys are written in sorted order; with
+        ``include_timestamp=False`` ``metadata`` has no save time either, so
+        saving the same graph twice gives identical output. The other
+        ``save_to_*`` methods take the same flag.
+        """
+        ...
+    def save_to_binary(self, file_path: str, include_timestamp: bool = True) -> None:
+        """Serialize to a compact binary format."""
+        ...
+    def save_to_binary_f16(self, file_path: str, include_timestamp: bool = True) -> None:
+        """Like save_to_binary but stores floats as f16 to reduce file size.
+
+        Floats keep about three significant decimal digits and magnitudes above
+        65504 become infinity. The header records the downcast, so
+        :meth:`load_from_binary` and :meth:`load_from_binary_f16` both return
+        regular floats.
+        """
+        ...
+    def save_to_binary_zstd(self, file_path: str, level: int = 3, include_timestamp: bool = True) -> None:
+        """Like save_to_binary but compresses the payload with zstd.
+
+        The header marks the file as compressed, so both :meth:`load_from_binary`
+        and :meth:`load_from_binary_zstd` read it. Compressed files cannot be
+        extended with :meth:`append_to_binary`.
+
+        Args:
+            file_path: Destination path.
+            level: zstd compression level (higher is smaller and slower).
+
+        Raises:
+            ValueError: If *level* is outside the range zstd supports.
+
+        Example::
+
+            v.save_to_binary_zstd("graph.bin.zst", level=9)
+            v2 = Vertex.load_from_binary_zstd("graph.bin.zst")
+        """
+        ...
+    def append_to_binary(self, file_path: str) -> None:
+        """Append this graph's nodes and edges to an existing binary file as a delta.
+
+        The delta is written length-prefixed after the current contents, so the
+        file is never rewritten. :meth:`load_from_binary` merges the base and all
+        deltas. Creates the file if missing.
+
+        A graph that last saved, loaded or appended this file writes only what
+        changed since (new or changed nodes and edges, and the graph meta);
+        changed records replace the stored ones, and an unchanged graph writes
+        nothing. Removals cannot be appended and raise RuntimeError, as does a
+        file another writer changed since.
+
+        Any other graph is appended whole and all of its edges are added, even
+        ones equal to an edge already in the file. Shared node ids keep edges
+        from both sides and take the delta's attribute values on conflicting
+        keys. Files written with an older binary format version must be loaded
+        and saved again before appending.
+
+        Example::
+
+            base.save_to_binary("graph.bin")
+            base.add_node("carol", {})
+            base.append_to_binary("graph.bin")     # writes only carol
+
+            delta = Vertex()
+            delta.add_node("alice", {})            # existing node, attrs unchanged
+            delta.add_node("dave", {"age": 41})
+            delta.add_edge("alice", "dave", {"type": "knows"})
+            delta.append_to_binary("graph.bin")
+            merged = Vertex.load_from_binary("graph.bin")
+        """
+        ...
+    @staticmethod
+    def load_from_json(source: str | dict[str, Any], coerce_numeric: bool = False) -> Vertex:
+        """Load from a file path, a raw JSON string, or a plain dict.
+
+        With *coerce_numeric*, string attribute values of nodes and edges that
+        parse as a number (``"42"``, ``"3.14"``) become int or float, which
+        helps with CSV-derived JSON. Other strings are left unchanged.
+
+        Example::
+
+            loaded = Vertex.load_from_json("my_graph.json")   # file path
+            loaded = Vertex.load_from_json(json_string)        # raw JSON string
+            loaded = Vertex.load_from_json({"nodes": {...}})   # plain dict
+            loaded = Vertex.load_from_json("from_csv.json", coerce_numeric=True)
+        """
+        ...
+    def save_to_jsonl(self, file_path: str, include_timestamp: bool = True) -> None:
+        """Serialize to JSON Lines: a metadata line, then one line per node and per edge.
+
+        The format is append-friendly and streams into line-oriented tools.
+        """
+        ...
+    @staticmethod
+    def load_from_jsonl(file_path: str) -> Vertex:
+        """Load a graph written by :meth:`save_to_jsonl`, parsing one line at a time."""
+        ...
+    @staticmethod
+    def load_from_binary(file_path: str) -> Vertex:
+        """Load a binary graph, merging any deltas written by :meth:`append_to_binary`.
+
+        The file must start with the Ironweaver magic bytes and a format version.
+
+        Raises:
+            RuntimeError: If the header is missing (e.g. a legacy header-less
+                file) or the format version is newer than this build supports.
+        """
+        ...
+    @staticmethod
+    def load_from_binary_f16(file_path: str) -> Vertex:
+        """Load a file written by :meth:`save_to_binary_f16`.
+
+        Floats come back as Python floats carrying the f16 rounding.
+
+        Raises:
+            RuntimeError: If the file was not saved with f16 floats.
+
+        Example::
+
+            v.save_to_binary_f16("graph_f16.bin")
+            v2 = Vertex.load_from_binary_f16("graph_f16.bin")
+            v2["a"].attr["score"]  # 0.1 -> 0.0999755859375
+        """
+        ...
+    @staticmethod
+    def load_from_binary_zstd(file_path: str) -> Vertex:
+        """Load a file written by :meth:`save_to_binary_zstd`.
+
+        Raises:
+            RuntimeError: If the file is not zstd-compressed.
+        """
+        ...
+    @staticmethod
+    def from_adjacency(
+        adj: dict[str, list[str]] | dict[str, dict[str, dict[str, Any] | None]],
+    ) -> Vertex:
+        """Build a graph from an adjacency dict.
+
+        Each value is either a list of target ids or a dict mapping target ids
+        to edge attributes (``None`` for none). Targets that never appear as
+        keys are created with empty attributes.
+
+        Raises:
+            TypeError: If an entry is neither a list of ids nor a dict.
+
+        Example::
+
+            v = Vertex.from_adjacency({"a": ["b", "c"], "b": ["c"]})
+            v = Vertex.from_adjacency({"a": {"b": {"type": "knows"}}})
+        """
+        ...
+    @staticmethod
+    def from_pandas_edgelist(
+        df: Any,
+        source: str = "source",
+        target: str = "target",
+        edge_attr: str | list[str] | bool | None = None,
+    ) -> Vertex:
+        """Build a graph from a pandas DataFrame edge list, like networkx's function.
+
+        Every row becomes one edge from its *source* to its *target* column
+        value, and nodes are created automatically with empty attributes.
+        *edge_attr* names the columns copied into the edge attributes: one
+        name, a list of names, or ``True`` for every other column. Each column
+        is read once with ``tolist()``, so numpy scalars arrive as plain
+        Python values.
+
+        Raises:
+            KeyError: If a requested column does not exist.
+            TypeError: If a node id is not a string (use ``.astype(str)``).
+
+        Example::
+
+            df = pandas.DataFrame({"src": ["a", "b"], "dst": ["b", "c"], "w": [1.0, 2.5]})
+            v = Vertex.from_pandas_edgelist(df, "src", "dst", edge_attr="w")
+        """
+        ...
+    def to_adjacency_dict(self, with_attrs: bool = False) -> dict[str, Any]:
+        """Export the graph as an adjacency dict, the inverse of :meth:`from_adjacency`.
+
+        Every node is a key. With *with_attrs* each target maps to a copy of
+        the edge attributes; parallel edges then keep the last edge's attributes.
+
+        Example::
+
+            v.to_adjacency_dict()                 # {"a": ["b", "c"], "b": ["c"], "c": []}
+            v.to_adjacency_dict(with_attrs=True)  # {"a": {"b": {...}, "c": {...}}, ...}
+        """
+        ...
+    @staticmethod
+    def from_nodes(nodes: dict[str, Node]) -> Vertex:
+        """Construct a Vertex directly from an existing node mapping."""
+        ...
+    @staticmethod
+    def from_nodes_with_path(nodes: dict[str, Node], nodelist: list[str]) -> Vertex:
+        """Like from_nodes but also stores *nodelist* in ``meta["nodelist"]``."""
+        ...
+
+    # ------------------------------------------------------------------
+    # Conversion
+    # ------------------------------------------------------------------
+
+    def to_networkx(self) -> Any:
+        """Convert to a ``networkx.DiGraph``. Requires networkx to be installed.
+
+        Nodes and edges carry their attributes (values are shared, not
+        copied). Parallel edges collapse into one DiGraph edge whose
+        attributes are merged in edge order, later edges winning. The graph
+        is filled with one ``add_nodes_from`` and one ``add_edges_from``
+        call, so conversion time is dominated by networkx itself.
+        """
+        ...
+    def to_dot(self, color_by: str | None = None, size_by: str | None = None) -> str:
+        """Render the graph as Graphviz DOT source.
+
+        Args:
+            color_by: Node attribute whose distinct values (compared by ``str()``)
+                each get their own ``color``/``fillcolor``.
+            size_by: Numeric node attribute scaled linearly onto the node
+                ``width``, from 0.5 (smallest value) to 2.0 (largest).
+
+        Nodes missing an attribute keep the Graphviz defaults.
+
+        Raises:
+            TypeError: If a *size_by* value is not numeric.
+
+        Example::
+
+            with open("graph.dot", "w") as f:
+                f.write(graph.to_dot(color_by="community", size_by="pagerank"))
+        """
+        ...
+
+    # ------------------------------------------------------------------
+    # Algorithms
+    # ------------------------------------------------------------------
+
+    def shortest_path_bfs(
+        self,
+        root_node_id: str,
+        target_node_id: str,
+        max_depth: int | None = ...,
+        exclude_nodes: set[str] | None = ...,
+        exclude_edges: set[tuple[str, str]] | None = ...,
+        edge_filter: dict[str, Any] | None = ...,
+    ) -> Vertex:
+        """Return a new Vertex containing only the nodes on the shortest BFS path.
+
+        The ordered sequence of node IDs is in ``result.meta["nodelist"]``.
+        Raises ValueError if either node is missing or the target is unreachable.
+
+        *exclude_nodes* are never visited and edges listed in *exclude_edges*
+        (as ``(from_id, to_id)`` pairs) are never followed, which lets you
+        route around failed hubs or banned relationships::
+
+            detour = g.shortest_path_bfs("a", "d", exclude_nodes={"hub"})
+
+        *edge_filter* restricts the search to edges whose ``attr`` matches
+        every key/value pair, e.g. ``edge_filter={"type": "is_a"}``. A list,
+        tuple or set value matches any of its elements, so
+        ``edge_filter={"type": ["is_a", "part_of"]}`` follows both relations.
+        """
+        ...
+    def is_reachable(
+        self,
+        root_node_id: str,
+        target_node_id: str,
+        max_depth: int | None = None,
+        edge_filter: dict[str, Any] | None = None,
+    ) -> bool:
+        """Return whether *target_node_id* can be reached from *root_node_id*.
+
+        Same BFS as :meth:`shortest_path_bfs`, but returns False instead of
+        raising when there is no path within *max_depth* hops. *edge_filter*
+        restricts the edges followed as in :meth:`shortest_path_bfs`. A node
+        always reaches itself. Missing nodes still raise NodeNotFoundError.
+
+        Example::
+
+            if graph.is_reachable("a", "z", max_depth=3):
+                route = graph.shortest_path_bfs("a", "z")
+        """
+        ...
+    def batch_shortest_paths(
+        self,
+        pairs: list[tuple[str, str]],
+        weight_field: str | None = ...,
+        parallel: bool = ...,
+        progress: Callable[[int, int], None] | None = None,
+        should_cancel: Callable[[], bool] | None = None,
+        max_cost: float | None = None,
+        max_depth: int | None = None,
+        edge_filter: dict[str, Any] | None = None,
+    ) -> list[Path | None]:
+        """Compute one shortest :class:`Path` per ``(source, target)`` pair.
+
+        Pairs sharing a source reuse a single shortest-path run, and runs are
+        spread across threads when *parallel* is True. Without *weight_field*
+        paths minimise hop count; with it they minimise the summed (non-negative)
+        edge weight, missing weights counting as 1.0. Unreachable pairs yield None.
+        *progress* is called as ``(completed, total)`` distinct sources after
+        each batch of runs.
+        *should_cancel* is polled before each batch; returning True raises
+        :class:`CancelledError`.
+
+        *max_cost* (summed weight, or hops without *weight_field*) and
+        *max_depth* (hops) must both hold on the same path: the result is the
+        cheapest path within the hop budget, so a cheap but long route can be
+        replaced by a dearer, shorter one, or the pair yields None.
+        *edge_filter* restricts the edges followed as in :meth:`shortest_path_bfs`.
+
+        Example::
+
+            paths = g.batch_shortest_paths([("a", "d"), ("a", "c"), ("b", "d")])
+            [n.id for n in paths[0].nodes]   # ["a", "b", "c", "d"]
+            few_hops = g.batch_shortest_paths([("a", "d")], weight_field="cost", max_depth=2)
+        """
+        ...
+    def parallel_bfs(self, start_node_id: str, depth: int | None = None) -> Vertex:
+        """Breadth-first search from *start_node_id* with the GIL released.
+
+        The topology is snapshotted once and the search runs in Rust without
+        the GIL, so other Python threads keep running alongside it. Follows outgoing edges up to *depth* hops (all if None). The
+        result holds the reached nodes with the visit order in
+        ``meta["nodelist"]``, identical to an unfiltered :meth:`Node.bfs`.
+
+        Raises:
+            ValueError: If *start_node_id* does not exist.
+
+        Example::
+
+            reached = g.parallel_bfs("a", depth=2)
+            reached.meta["nodelist"]   # ["a", "b", "c"]
+        """
+        ...
+    def bfs_frontiers(self, root_node_id: str, max_depth: int | None = None) -> BfsFrontiers:
+        """Iterate over BFS levels from *root_node_id* lazily.
+
+        Yields one ``list[str]`` per level (the first is ``[root_node_id]``),
+        following outgoing edges up to *max_depth* hops (all if None). Only the
+        current frontier is kept and each level is computed on demand, so
+        stopping early avoids traversing the rest of a huge graph.
+
+        Raises:
+            ValueError: If *root_node_id* does not exist.
+
+        Example::
+
+            for level, ids in enumerate(g.bfs_frontiers("a")):
+                if "target" in ids:
+                    break
+        """
+        ...
+    def shortest_path_tree(self, root_node_id: str, weight_field: str | None = None) -> Vertex:
+        """Return the tree of shortest paths from *root_node_id* to every reachable node.
+
+        Each reachable node keeps only a copy of the edge from its predecessor
+        (BFS tree without *weight_field*, Dijkstra tree with it). Nodes and
+        edges keep their original attributes; ``meta["distances"]`` maps each
+        node ID to its distance from the root.
+
+        Raises:
+            ValueError: If the root does not exist or a weight is negative.
+
+        Example::
+
+            tree = g.shortest_path_tree("a")
+            tree.meta["distances"]   # {"a": 0.0, "b": 1.0, "c": 2.0}
+        """
+        ...
+    def bellman_ford(self, root_node_id: str, weight_field: str = "weight") -> dict[str, float]:
+        """Distances from *root_node_id* with negative edge weights allowed.
+
+        Bellman-Ford over the summed *weight_field* (1.0 per edge without
+        it). Only reachable nodes are returned. A negative cycle reachable
+        from the root raises GraphError naming a node on it.
+
+        Example::
+
+            graph.bellman_ford("start", weight_field="delta")   # {"start": 0.0, "b": -2.0, ...}
+        """
+        ...
+    def floyd_warshall(
+        self,
+        weight_field: str = "weight",
+        progress: Callable[[int, int], None] | None = None,
+        should_cancel: Callable[[], bool] | None = None,
+    ) -> tuple[list[str], list[list[float]]]:
+        """All-pairs shortest distances as a dense matrix (Floyd-Warshall).
+
+        Returns ``(ids, matrix)`` with *ids* sorted and ``matrix[i][j]`` the
+        cheapest summed *weight_field* from ``ids[i]`` to ``ids[j]`` (1.0 per
+        edge without it), ``inf`` if unreachable. Negative weights are
+        allowed; a negative cycle raises GraphError. O(n³), so meant for small
+        or dense graphs.
+
+        *progress* is called as ``(completed, total)`` after each of the n
+        pivot passes. *should_cancel* is polled before each pass; returning
+        True raises :class:`CancelledError`.
+
+        Example::
+
+            ids, dist = graph.floyd_warshall()
+            dist[ids.index("a")][ids.index("d")]   # 4.0
+        """
+        ...
+    def steiner_tree(self, terminals: list[str], weight_field: str = "weight") -> Vertex:
+        """Return an approximate minimum-weight tree connecting *terminals*.
+
+        Uses the metric-closure MST heuristic (at most twice the optimal
+        weight) with edge direction ignored. Intermediate nodes are pulled in
+        as needed; edges are copies of the originals in their original
+        direction. ``meta["total_weight"]`` holds the summed weight.
+
+        Raises:
+            NodeNotFoundError: If a terminal does not exist.
+            GraphError: If the terminals are not connected or a weight is negative.
+
+        Example::
+
+            tree = g.steiner_tree(["warehouse", "store_a", "store_b"], weight_field="km")
+            tree.meta["total_weight"]
+        """
+        ...
+    def path_neighborhood(
+        self,
+        root_node_id: str,
+        target_node_id: str,
+        padding: int = 1,
+        weight_field: str | None = None,
+        max_cost: float | None = None,
+        max_depth: int | None = None,
+        edge_filter: dict[str, Any] | None = None,
+    ) -> Vertex:
+        """Shortest path from *root_node_id* to *target_node_id* plus its surroundings.
+
+        Every node within *padding* outgoing hops of a path node is added, along
+        with the edges among the selected nodes. The path minimises hops, or the
+        summed *weight_field* when given. ``meta["nodelist"]`` holds the path.
+        *max_cost* and *max_depth* bound the path's summed weight and hop count
+        together, as in :meth:`batch_shortest_paths`. *edge_filter* restricts the
+        edges followed, for the path and the padding, as in :meth:`shortest_path_bfs`.
+
+        Raises:
+            ValueError: If a node does not exist, the target is unreachable
+                within the limits, or a weight or *max_cost* is negative.
+
+        Example::
+
+            view = g.path_neighborhood("a", "c", padding=1)
+            view.meta["nodelist"]   # ["a", "b", "c"]
+        """
+        ...
+    def expand(
+        self, source_vertex: Vertex, depth: int | None = ..., annotate_distance: bool = False
+    ) -> Vertex:
+        """Expand this subgraph by pulling neighbour nodes from *source_vertex*.
+
+        *depth* defaults to 1 (one hop). With *annotate_distance* every result
+        node records its hop distance from the nearest seed node in
+        ``meta["distance"]`` (0 for the seeds).
+
+        Only **outgoing** edges are followed during expansion; nodes that point
+        *into* the seed nodes are not included.
+
+        Example::
+
+            seed = graph.filter(id="ckd")
+            expanded = seed.expand(graph, depth=1)
+            # expanded now contains ckd + all nodes ckd has outgoing edges to
+            ranked = seed.expand(graph, depth=2, annotate_distance=True)
+            ranked["ckd"].meta["distance"]   # 0
+        """
+        ...
+    def expand_weighted(
+        self,
+        source_vertex: Vertex,
+        max_cost: float,
+        weight_field: str = ...,
+        annotate_distance: bool = False,
+        max_depth: int | None = None,
+    ) -> Vertex:
+        """Like :meth:`expand`, but bounded by cumulative edge weight instead of hops.
+
+        A node from *source_vertex* is included when its cheapest outgoing-edge
+        path from any node of this vertex costs at most *max_cost*. Edges
+        without *weight_field* (default ``"weight"``) cost 1.0. With
+        *annotate_distance* that cheapest cost is stored in each result node's
+        ``meta["distance"]``. Raises ValueError for a negative budget and
+        GraphError for a negative edge weight.
+
+        *max_depth* adds a hop budget: a node is only included when a single
+        path stays within both *max_cost* and *max_depth* hops, so a cheap
+        but long chain can be cut off by the hop limit.
+
+        Example::
+
+            seed = graph.filter(id="depot")
+            nearby = seed.expand_weighted(graph, max_cost=12.5, weight_field="km")
+            few_calls = seed.expand_weighted(graph, max_cost=12.5, max_depth=3)
+        """
+        ...
+    def budget_subgraph(
+        self, seed_ids: list[str], max_nodes: int, scoring: str = "pagerank"
+    ) -> Vertex:
+        """Grow a subgraph of at most *max_nodes* nodes around *seed_ids*.
+
+        Starting from the seeds, repeatedly adds the highest-scoring node
+        reached by an outgoing edge of the current set, so the budget is spent
+        on the most relevant neighbors (e.g. a fixed-size graph-RAG context).
+        *scoring* is ``"pagerank"`` or ``"degree"`` (in plus out edges); scores
+        are computed once on the whole graph and ties go to the smaller ID.
+        The result holds every edge among the chosen nodes and has fewer than
+        *max_nodes* nodes only when nothing more is reachable.
+
+        Raises NodeNotFoundError for an unknown seed, and ValueError when there
+        are more seeds than *max_nodes* or *scoring* is unknown.
+
+        Example::
+
+            context = graph.budget_subgraph(["ckd", "diabetes"], 20)
+            by_degree = graph.budget_subgraph(["ckd"], 10, scoring="degree")
+        """
+        ...
+    def filter(
+        self,
+        predicate: Callable[[NodeView], bool] | None = ...,
+        *,
+        ids: list[str] | None = ...,
+        id: str | None = ...,
+        strict: bool = ...,
+        **kwargs: Any,
+    ) -> Vertex:
+        """Return a new Vertex containing only matching nodes and their shared edges.
+
+        Exactly one filtering mode must be used:
+
+        **Predicate (lambda) mode** — most expressive::
+
+            result = g.filter(lambda n: (
+                n.id.startswith("user_")
+                and n.type == "Person"
+                and n.attr("age", 0) >= 18
+                and not n.has_attr("deleted")
+                and n.has_edge_to("org_1")
+            ))
+
+        **ID list mode**::
+
+            result = g.filter(ids=["alice", "bob"])
+            result = g.filter(id="alice")
+
+        Unknown ids raise :exc:`ValueError`. Pass ``strict=False`` to skip
+        them instead; the result then carries its own copy of ``meta`` with
+        the set of ids that were found under ``"found_ids"``::
+
+            result = g.filter(ids=stale_ids, strict=False)
+            result.meta["found_ids"]   # {"alice"}
+
+        **Attribute equality mode** (keyword arguments)::
+
+            result = g.filter(type="Person")
+            result = g.filter(status="active", role="admin")  # multiple kwargs are ANDed
+
+        The predicate receives a :class:`NodeView` which exposes:
+
+        ==================  =====================================================
+        ``n.id``            node ID (str)
+        ``n.type``          shortcut for ``n.attr("type")``
         ``n.attr(key)``     attribute value, or None
         ``n.attr(key, d)``  attribute value with default *d*
         ``n.has_attr(key)`` True if key present
@@ -568,6 +1742,9 @@ class Vertex:
         include_edge_types: bool | None = ...,
         edge_type_field: str | None = ...,
         stratified: bool | None = ...,
+        include_edge_ids: bool | None = ...,
+        stop_prob: float | None = ...,
+        seed: int | None = ...,
     ) -> list[list[str]]:
         """Perform random walks from *start_node_id*.
 
@@ -596,6 +1773,18 @@ class Vertex:
             by ``1 / (1 + times_visited)``, steering walks towards the
             least-visited nodes. Visit counts persist across all attempts of
             one call. Defaults to False.
+        include_edge_ids:
+            If True, each walk alternates between node IDs and the ``id`` of
+            the traversed edge, so parallel edges can be told apart. Edges
+            whose ``id`` is None are recorded as ``"from->to"``. Cannot be
+            combined with *include_edge_types*. Defaults to False.
+        stop_prob:
+            Probability of ending the walk after each visited node, so walk
+            lengths follow a geometric distribution (about ``1 / stop_prob``
+            nodes on average) capped at *max_length*, like a browsing session.
+            *min_length* still filters the results. Defaults to 0.
+        seed:
+            Seed for reproducible walks. Defaults to OS entropy.
 
         Returns a list of walks; each walk is a list of strings.
 
@@ -603,7 +1792,605 @@ class Vertex:
 
             walks = graph.random_walks("node1", 5, 20)
             walks = graph.random_walks("node1", 5, 20, include_edge_types=True)
+            walks = graph.random_walks("node1", 5, 20, include_edge_ids=True)
             walks = graph.random_walks(None, 5, 50, stratified=True)
+            walks = graph.random_walks("node1", 50, 100, stop_prob=0.2, seed=7)
+        """
+        ...
+    def sample_nodes(
+        self,
+        k: int,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+        replace: bool = ...,
+    ) -> list[str]:
+        """Draw *k* random node IDs.
+
+        With *weight_field*, each node is drawn with probability proportional
+        to that numeric attribute (missing → 1.0, zero → never drawn). Sampling
+        is without replacement unless ``replace=True``; the same *seed* always
+        yields the same sample. Raises ValueError if *k* exceeds the drawable
+        population without replacement.
+
+        Example::
+
+            train = graph.sample_nodes(800, seed=42)
+            hubs = graph.sample_nodes(10, weight_field="degree", seed=42)
+        """
+        ...
+    def sample_by_pagerank(self, k: int, seed: int | None = None) -> list[str]:
+        """Draw *k* distinct node IDs with probability proportional to PageRank.
+
+        PageRank (damping 0.85, unweighted edges) is recomputed on each call,
+        so well-linked nodes are over-represented. Every node has a positive
+        rank, so any *k* up to the node count works; larger *k* raises
+        ValueError. The same *seed* always yields the same sample.
+
+        Example::
+
+            important = graph.sample_by_pagerank(200, seed=42)
+        """
+        ...
+    def sample_edges(
+        self,
+        k: int,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+        replace: bool = ...,
+    ) -> list[Edge]:
+        """Draw *k* random edges; same options as :meth:`sample_nodes`."""
+        ...
+    def sample_subgraph(
+        self,
+        n: int,
+        method: str = ...,
+        seed: int | None = ...,
+        burn_prob: float = ...,
+    ) -> Vertex:
+        """Extract a random subgraph with at most *n* nodes.
+
+        ``method="induced"`` (default) keeps the subgraph induced by *n*
+        uniformly drawn nodes. ``method="forest_fire"`` starts at a random node
+        and burns a geometric number of neighbours (mean
+        ``burn_prob / (1 - burn_prob)``, edges followed in both directions) in
+        BFS order, which preserves community structure better and yields a
+        connected sample on a connected graph.
+
+        Example::
+
+            sample = graph.sample_subgraph(500, method="forest_fire", seed=1)
+        """
+        ...
+    def sparsify(
+        self,
+        keep_fraction: float,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+    ) -> Vertex:
+        """Copy the graph keeping all nodes but only a random share of the edges.
+
+        ``round(keep_fraction * edge_count())`` edges are drawn without
+        replacement. With *weight_field*, heavier edges are more likely to be
+        kept; edges missing the attribute weigh 1.0 and zero-weight edges are
+        always dropped.
+
+        Raises:
+            ValueError: If *keep_fraction* is outside ``[0, 1]``.
+
+        Example::
+
+            sparse = graph.sparsify(0.1, weight_field="weight", seed=1)
+        """
+        ...
+    def greedy_coloring(
+        self,
+        strategy: str = ...,
+        seed: int | None = ...,
+        return_num_colors: bool = ...,
+    ) -> dict[str, int] | tuple[dict[str, int], int]:
+        """Assign colors 0, 1, ... so that no two adjacent nodes share a color.
+
+        Edges are treated as undirected. *strategy* picks the coloring order:
+        ``"largest_first"`` (default), ``"smallest_last"``,
+        ``"random_sequential"`` (uses *seed*) or ``"saturation_largest_first"``
+        (DSatur). With ``return_num_colors=True`` returns
+        ``(coloring, num_colors)``.
+
+        Example::
+
+            slots, n = tasks.greedy_coloring(return_num_colors=True)
+            # tasks sharing an edge (a conflict) never get the same slot
+        """
+        ...
+    def is_dag(self) -> bool:
+        """Return True if the graph has no directed cycle (self-loops are cycles)."""
+        ...
+    def condensation(self) -> tuple[Vertex, dict[str, int]]:
+        """Contract each strongly connected component into one super-node.
+
+        Returns ``(dag, mapping)``: *dag* has nodes ``"0"``, ``"1"``, ... in
+        topological order, each with ``members`` (sorted original IDs) and
+        ``size`` attributes; *mapping* maps every original node ID to its
+        component ID. Intra-component edges are dropped and parallel edges
+        merged.
+
+        Example::
+
+            dag, mapping = graph.condensation()
+            dag[str(mapping["a"])].attr["members"]   # ["a", "b", "c"]
+        """
+        ...
+    def largest_scc(self) -> Vertex:
+        """Return the induced subgraph of the largest strongly connected component.
+
+        Useful before analyses that assume every node reaches every other.
+        Ties go to the component containing the smallest node ID; an empty
+        graph gives an empty Vertex.
+
+        Example::
+
+            core = graph.largest_scc()
+            core.is_strongly_connected()   # True
+        """
+        ...
+    def coarsen(self, matching: list[tuple[str, str]]) -> Vertex:
+        """Contract every node pair in *matching* at once into a coarser graph.
+
+        Pairs sharing a node are unioned into one super-node, named after its
+        smallest member ID, with ``members`` (sorted original IDs) and
+        ``size`` attributes. Unmatched nodes become one-member super-nodes.
+        Edges inside a super-node are dropped; parallel edges are merged with
+        ``count`` holding how many they replace. ``meta["coarse_to_original"]``
+        maps each super-node ID to its members. Raises ValueError for an
+        unknown node ID.
+
+        Example::
+
+            coarse = graph.coarsen([("a", "b"), ("c", "d")])
+            coarse.meta["coarse_to_original"]["a"]   # ["a", "b"]
+        """
+        ...
+    def quotient_by(
+        self, attr_key: str, weight_field: str | None = None, self_loops: bool = True
+    ) -> Vertex:
+        """Merge all nodes sharing a value of *attr_key* into one super-node.
+
+        Builds the quotient (blockmodel) graph used to summarize a large graph
+        by category. Each super-node is named ``str(value)`` and carries
+        *attr_key*, ``members`` (sorted original IDs) and ``size``; nodes
+        without the attribute are left out. Edges between two groups merge
+        into one edge with ``count`` and ``weight`` (the summed
+        *weight_field*, 1.0 per edge without it). Edges inside a group become
+        a self-loop, or are dropped with ``self_loops=False``.
+        ``meta["group_members"]`` maps each super-node ID to its members.
+
+        Example::
+
+            summary = graph.quotient_by("category")
+            summary["fruit"].attr["size"]          # 3
+            summary["fruit"].edges[0].attr          # {"count": 2, "weight": 2.0}
+        """
+        ...
+    def collapse_multi_edges(self, weight_field: str = "weight") -> Vertex:
+        """Turn a multigraph into a weighted simple graph.
+
+        Parallel edges with the same source and target merge into one edge
+        that keeps the first edge's attributes and ID, with *weight_field* set
+        to their summed weight (1.0 per edge without it) and
+        ``multiplicity`` to how many were merged. Edge direction is kept, so
+        ``a -> b`` and ``b -> a`` stay separate.
+
+        Example::
+
+            simple = graph.collapse_multi_edges()
+            simple["a"].edges[0].attr   # {"weight": 3.0, "multiplicity": 3}
+        """
+        ...
+    def match_pattern(
+        self, pattern: Vertex, max_matches: int | None = None
+    ) -> list[dict[str, str]]:
+        """Find every occurrence of the motif *pattern* (subgraph isomorphism).
+
+        Each pattern node maps to a distinct graph node whose ``attr``
+        contains the pattern node's attributes with equal values. Each pattern
+        edge needs a graph edge in the same direction whose ``attr`` contains
+        the pattern edge's attributes, e.g. ``{"type": "knows"}``; extra graph
+        edges are fine. Patterns are limited to 10 nodes (ValueError beyond).
+
+        Returns one ``{pattern_id: graph_id}`` dict per match, sorted by the
+        assigned graph IDs, stopping after *max_matches* if given.
+
+        Example::
+
+            motif = Vertex()
+            motif.add_node("p", {"kind": "person"})
+            motif.add_node("c", {"kind": "company"})
+            motif.add_edge("p", "c", {"type": "works_at"})
+            graph.match_pattern(motif)   # [{"p": "bob", "c": "acme"}, ...]
+        """
+        ...
+    def is_weakly_connected(self) -> bool:
+        """Return True if the graph is one component when edge direction is ignored.
+
+        An empty graph returns False.
+        """
+        ...
+    def is_strongly_connected(self) -> bool:
+        """Return True if every node reaches every other node along edge directions.
+
+        An empty graph returns False.
+        """
+        ...
+    def common_reachable(self, source_ids: list[str]) -> set[str]:
+        """Return the nodes reachable from *every* source in *source_ids*.
+
+        Each source's descendants are found by BFS along edge directions and
+        intersected. A source only counts as its own descendant when it lies
+        on a cycle; an empty *source_ids* gives an empty set. Raises
+        NodeNotFoundError for an unknown source.
+
+        Example::
+
+            impacted = graph.common_reachable(["lib_a", "lib_b"])
+        """
+        ...
+    def probable_reachable(
+        self,
+        source_id: str,
+        min_prob: float = 0.1,
+        prob_field: str = "probability",
+    ) -> dict[str, float]:
+        """Return the best path probability to each node reachable from *source_id*.
+
+        A path's probability is the product of the *prob_field* values of its
+        edges (missing values count as 1.0). A best-first search keeps the
+        highest product per node and prunes paths below *min_prob*; the
+        source maps to 1.0. Raises NodeNotFoundError for an unknown source and
+        ValueError if *min_prob* or an edge probability lies outside [0, 1].
+
+        Example::
+
+            likely = graph.probable_reachable("patient_zero", min_prob=0.2)
+        """
+        ...
+    def approximate_diameter(self, iterations: int = 2) -> int:
+        """Estimate the diameter in hops with the double-sweep BFS heuristic.
+
+        Edge direction is ignored. Each component gets *iterations* BFS sweeps,
+        each starting from the farthest node of the previous one. The result is
+        exact for trees but only a lower bound on general graphs. Disconnected
+        graphs report the largest component value; an empty graph returns 0.
+
+        Raises:
+            ValueError: If *iterations* is 0.
+
+        Example::
+
+            graph.approximate_diameter()   # 4 for the path a-b-c-d-e
+        """
+        ...
+    def eccentricity(
+        self,
+        weight_field: str | None = None,
+        per_component: bool = False,
+    ) -> dict[str, float]:
+        """Return each node's largest shortest-path distance to any other node.
+
+        Edge direction is ignored; distances are hop counts unless
+        *weight_field* names a non-negative edge weight. A disconnected graph
+        raises GraphError unless ``per_component=True``, which measures each
+        node within its own weakly connected component.
+
+        Example::
+
+            graph.eccentricity()   # {"a": 4.0, "b": 3.0, "c": 2.0, ...} on the path a-b-c-d-e
+        """
+        ...
+    def center(self, weight_field: str | None = None, per_component: bool = False) -> list[str]:
+        """Return the sorted IDs of the nodes with minimum eccentricity.
+
+        With ``per_component=True`` the center of every component is included.
+
+        Example::
+
+            graph.center()   # ["c"] for the path a-b-c-d-e
+        """
+        ...
+    def periphery(self, weight_field: str | None = None, per_component: bool = False) -> list[str]:
+        """Return the sorted IDs of the nodes with maximum eccentricity.
+
+        With ``per_component=True`` the periphery of every component is included.
+
+        Example::
+
+            graph.periphery()   # ["a", "e"] for the path a-b-c-d-e
+        """
+        ...
+    def closeness_centrality(
+        self,
+        weight_field: str | None = None,
+        wf_improved: bool = True,
+        progress: Callable[[int, int], None] | None = None,
+        should_cancel: Callable[[], bool] | None = None,
+    ) -> dict[str, float]:
+        """Return closeness centrality per node ID, from incoming distances.
+
+        For a node reached by ``r`` other nodes with total distance ``d``:
+
+        * ``wf_improved=False``: ``r / d``
+        * ``wf_improved=True`` (default, Wasserman-Faust): ``(r / d) * (r / (n - 1))``
+
+        The correction scales by the fraction of the graph that can reach the
+        node, giving sensible values on disconnected graphs (networkx's
+        default). Distances are hop counts, or summed *weight_field* values
+        (non-negative, missing counts as 1.0). Unreached nodes score 0.0.
+        *progress* is called as ``(completed, total)`` nodes after each batch.
+        *should_cancel* is polled before each batch; returning True raises
+        :class:`CancelledError`.
+
+        Example::
+
+            graph.closeness_centrality()                   # {"a": 0.33, ...}
+            graph.closeness_centrality(wf_improved=False)
+        """
+        ...
+    def harmonic_centrality(
+        self,
+        weight_field: str | None = None,
+        progress: Callable[[int, int], None] | None = None,
+        should_cancel: Callable[[], bool] | None = None,
+    ) -> dict[str, float]:
+        """Return harmonic centrality per node ID.
+
+        Sums ``1 / d`` over the shortest-path distances from every other node
+        to the node; unreachable nodes contribute 0, so disconnected graphs
+        need no correction. Distances are hop counts, or summed *weight_field*
+        values (non-negative, missing counts as 1.0). *progress* is called as
+        ``(completed, total)`` nodes after each batch.
+        *should_cancel* is polled before each batch; returning True raises
+        :class:`CancelledError`.
+
+        Example::
+
+            graph.harmonic_centrality()   # {"a": 0.0, "b": 1.0, "c": 1.5}
+        """
+        ...
+    def betweenness_centrality_sampled(
+        self,
+        num_samples: int,
+        seed: int | None = None,
+        weight_field: str | None = None,
+        progress: Callable[[int, int], None] | None = None,
+        should_cancel: Callable[[], bool] | None = None,
+    ) -> dict[str, float]:
+        """Estimate betweenness centrality from *num_samples* random sources.
+
+        Brandes' accumulation from distinct sources drawn with *seed*, scaled
+        by ``n / num_samples``; sampling every node gives the exact value.
+        Scores are unnormalized and directed: the share of shortest paths
+        between other ordered node pairs that pass through the node.
+        Distances are hop counts, or summed *weight_field* values
+        (non-negative, missing counts as 1.0). *progress* and
+        *should_cancel* work as in :meth:`harmonic_centrality`, counting
+        sources.
+
+        Raises:
+            ValueError: If *num_samples* is not between 1 and the node count.
+
+        Example::
+
+            graph.betweenness_centrality_sampled(100, seed=0)
+        """
+        ...
+    def modularity(
+        self,
+        communities: dict[str, int],
+        weight_field: str | None = None,
+    ) -> float:
+        """Return the modularity of *communities* on the undirected weighted view.
+
+        ``Q = (1/2m) * sum_ij (A_ij - k_i k_j / 2m) * delta(c_i, c_j)``, where
+        each directed edge is an undirected edge of its *weight_field* value
+        (1.0 if unset or missing) and ``m`` is the total weight. Every node
+        needs a community. A graph without edges scores 0.0.
+
+        Raises:
+            ValueError: If a node has no community, a key is not a node, or a
+                weight is negative.
+
+        Example::
+
+            graph.modularity({"a": 0, "b": 0, "x": 1, "y": 1})
+        """
+        ...
+    def cut_size(
+        self,
+        partition: dict[str, int],
+        weight_field: str | None = None,
+    ) -> float:
+        """Count the edges crossing between parts of *partition*.
+
+        The graph partitioning objective. Every directed edge whose endpoints
+        have different part labels counts once; self-loops never cross. With
+        *weight_field* the crossing weights are summed instead (missing
+        weights count as 1.0).
+
+        Raises:
+            ValueError: If a node has no part or a key is not a node.
+            TypeError: If a weight attribute is not numeric.
+
+        Example::
+
+            halves = {n: 0 if n.startswith("l") else 1 for n in graph.keys()}
+            graph.cut_size(halves)   # 1.0 for a barbell split at the bridge
+        """
+        ...
+    def degree_assortativity(self, weight_field: str | None = None) -> float:
+        """Return the degree assortativity coefficient.
+
+        The Pearson correlation of the degrees at both ends of every edge,
+        with edge direction ignored: positive when hubs link to hubs, negative
+        when hubs link to leaves. With *weight_field* each node's degree is
+        the sum of its incident edge weights (missing weights count as 1.0).
+        Returns NaN for a graph without edges or when every edge joins nodes
+        of equal degree. Raises TypeError for a non-numeric weight.
+
+        Example::
+
+            graph.degree_assortativity()   # about -1.0 for a star
+        """
+        ...
+    def transitivity(self) -> float:
+        """Global transitivity: ``3 * triangles / connected triples``.
+
+        The graph-level clustering coefficient on the undirected view, where a
+        connected triple is a path of two edges. It weighs every triple
+        equally, unlike the average of local clustering coefficients.
+        Parallel edges and edges in both directions count once; self-loops
+        are ignored. Returns 0.0 when there are no triples.
+
+        Example::
+
+            graph.transitivity()   # 1.0 for a complete graph
+        """
+        ...
+    def to_laplacian(
+        self, normalized: bool = False, weight_field: str | None = None
+    ) -> tuple[Any, list[str]]:
+        """Return the graph Laplacian as a numpy array plus the node ID of each row.
+
+        Edge direction is ignored: each edge adds its *weight_field* value
+        (1.0 if unset or missing) to both symmetric entries of ``A``, and
+        self-loops are skipped. The result is ``D - A``, or
+        ``I - D^-1/2 A D^-1/2`` with ``normalized=True`` (isolated nodes get
+        an all-zero row). Rows and columns follow the sorted node IDs.
+
+        Raises:
+            RuntimeError: If NumPy is not installed.
+            GraphError: If a weight is negative.
+
+        Example::
+
+            laplacian, ids = graph.to_laplacian(weight_field="weight")
+            laplacian.sum(axis=1)   # all zeros
+        """
+        ...
+    def fiedler_vector(self, weight_field: str | None = None) -> dict[str, float]:
+        """Return the Fiedler vector: a 1-D spectral ordering of the nodes.
+
+        The eigenvector of the second-smallest eigenvalue of the Laplacian
+        from :meth:`to_laplacian`, computed by power iteration in Rust (no
+        NumPy needed). It has unit length, sums to 0 and its largest entry is
+        positive. Splitting the nodes by sign approximates the sparsest cut.
+        Graphs with fewer than two nodes or no edges map every node to 0.0.
+
+        Raises:
+            GraphError: If a weight is negative.
+
+        Example::
+
+            fiedler = graph.fiedler_vector()
+            left = [nid for nid, x in fiedler.items() if x < 0]
+        """
+        ...
+    def spring_layout(
+        self,
+        iterations: int = 50,
+        seed: int | None = None,
+        weight_field: str | None = None,
+    ) -> dict[str, tuple[float, float]]:
+        """Compute 2-D positions with a Fruchterman-Reingold force layout.
+
+        All node pairs repel and edges attract their endpoints (direction
+        ignored, scaled by ``weight_field`` when given) while the step size
+        cools over ``iterations``. The simulation runs in Rust; ``seed``
+        makes it reproducible. Positions are centered on the origin and
+        scaled into ``[-1, 1]``.
+
+        Raises:
+            GraphError: If a weight is negative.
+
+        Example::
+
+            pos = graph.spring_layout(seed=42)
+            networkx.draw(graph.to_networkx(), pos=pos)
+        """
+        ...
+    def normalize_edge_weights(self, weight_field: str = "weight", mode: str = "outgoing") -> None:
+        """Rescale edge weights in place, firing ``on_edge_update_callbacks``.
+
+        ``mode="outgoing"`` makes each node's outgoing weights sum to 1
+        (transition probabilities for random walks / PageRank); nodes without
+        outgoing edges are left unchanged. ``mode="global"`` min-max scales all
+        weights onto ``[0, 1]``. A missing weight counts as 1.0.
+
+        Raises:
+            ValueError: For an unknown *mode*.
+            GraphError: If ``mode="outgoing"`` meets a negative weight.
+
+        Example::
+
+            graph.normalize_edge_weights("weight")
+            sum(e.attr["weight"] for e in graph["a"].edges)   # 1.0
+        """
+        ...
+    def derive_edge_weight(self, out_field: str, expression: Callable[[Edge], float]) -> None:
+        """Store ``expression(edge)`` at ``out_field`` on every edge.
+
+        One Rust-driven pass over all edges. Every value is computed before
+        any is written, so a failing *expression* leaves the graph unchanged.
+        Values are written with ``Edge.attr_set``, firing
+        ``on_edge_update_callbacks`` for each one that changes.
+
+        Raises:
+            TypeError: If *expression* returns something other than an int or float.
+
+        Example::
+
+            graph.derive_edge_weight("cost", lambda e: 1 / e.attr["confidence"])
+        """
+        ...
+    def map_node_attrs(self, func: Callable[[dict[str, Any]], dict[str, Any]], fire: bool = True) -> None:
+        """Replace every node's ``attr`` with ``func(attr)`` in one pass.
+
+        *func* receives a copy of each attribute dict and returns the new
+        one. All results are computed before any is written, so a failing
+        *func* leaves the graph unchanged. With *fire* (the default) changed
+        values go through ``attr_set`` and fire ``on_node_update_callbacks``;
+        dropped keys are removed without callbacks. ``fire=False`` swaps the
+        dicts in silently. Raises TypeError if *func* returns a non-dict.
+
+        Example::
+
+            graph.map_node_attrs(lambda a: {k: v.lower() if isinstance(v, str) else v
+                                            for k, v in a.items()})
+        """
+        ...
+    def map_edge_attrs(self, func: Callable[[dict[str, Any]], dict[str, Any]], fire: bool = True) -> None:
+        """Edge counterpart of :meth:`map_node_attrs`, firing ``on_edge_update_callbacks``."""
+        ...
+    def propagate(
+        self,
+        attr_key: str,
+        out_key: str,
+        op: str = "mean",
+        iterations: int = 1,
+        direction: str = "out",
+    ) -> None:
+        """Write each node's neighbour aggregate of *attr_key* into *out_key*.
+
+        A batched :meth:`Node.aggregate_neighbors` (same *op* and *direction*
+        values). Each iteration reads a consistent snapshot of the previous
+        one: the first reads *attr_key*, later ones the previous results.
+        Nodes whose aggregate is None keep their current *out_key*. Values are
+        written directly, without update callbacks.
+
+        Raises:
+            ValueError: For an unknown *op* or *direction*, or *iterations* of 0.
+
+        Example::
+
+            graph.propagate("label_score", "smoothed", op="mean", iterations=3)
         """
         ...
 
@@ -673,6 +2460,11 @@ __all__ = [
     "Edge",
     "Path",
     "ObservedDictionary",
+    "BfsFrontiers",
+    "GraphError",
+    "NodeNotFoundError",
+    "EdgeNotFoundError",
+    "CancelledError",
     "parse_lgf",
     "parse_lgf_file",
 ]
@@ -1,17 +1,31 @@
 """
 Type stubs for the ironweaver Rust extension module (_ironweaver.so).
 
-These stubs mirror the exact PyO3-generated signatures. All five classes are
+These stubs mirror the exact PyO3-generated signatures. All six classes are
 @final (PyO3 extension types cannot be subclassed). Constructors use __new__
 because that is the slot PyO3 populates; at runtime __init__ takes no args.
 
-Note: Vertex.filter, Node.traverse, Node.bfs, and Node.bfs_search reflect the
+Note: Vertex.filter, Node.traverse, Node.bfs, Node.bfs_search, Node.path_to, and Node.bfs_layers reflect the
 Python-level wrappers applied in ironweaver/__init__.py at import time.
 """
 
 from __future__ import annotations
 
-from typing import Any, Callable, Iterator, final
+import re
+
+from typing import Any, Callable, Iterator, final, overload
+
+class GraphError(ValueError):
+    """Base class for errors about the graph itself."""
+
+class NodeNotFoundError(GraphError, KeyError):
+    """A node id does not exist in the graph."""
+
+class EdgeNotFoundError(GraphError, KeyError):
+    """A requested edge does not exist in the graph."""
+
+class CancelledError(KeyboardInterrupt):
+    """A long-running algorithm was stopped by its should_cancel callback."""
 
 @final
 class ObservedDictionary:
@@ -48,12 +62,20 @@ class Edge:
     ) -> Edge: ...
     def __repr__(self) -> str: ...
     def toJSON(self) -> dict[str, Any]: ...
+    def to_dict(self) -> dict[str, Any]: ...
     def attr_set(self, key: str, value: Any) -> None:
         """Set attr[key] = value and fire on_update_callbacks if the value changed."""
         ...
     def attr_get(self, key: str) -> Any | None:
         """Return attr[key], or None if the key does not exist."""
         ...
+    def attr_get_str(self, key: str) -> str | None: ...
+    def attr_get_int(self, key: str) -> int | None: ...
+    def attr_get_float(self, key: str) -> float | None: ...
+    def attr_get_bool(self, key: str) -> bool | None: ...
+    def attr_increment(self, key: str, delta: int | float = 1) -> int | float:
+        """Add *delta* to the numeric attr[key] (created as *delta*) and return it."""
+        ...
 
 @final
 class Node:
@@ -100,9 +122,56 @@ class Node:
     ) -> Node | None:
         """BFS search for target_id. Returns the Node if found, None otherwise."""
         ...
+    def path_to(
+        self,
+        target_id: str,
+        depth: int | None = ...,
+        filter: dict[str, Any] | Callable[[Any], bool] | None = ...,
+        edge_filter: Callable[[Any], bool] | None = ...,
+    ) -> Path | None:
+        """BFS route from this node to target_id, or None if unreachable."""
+        ...
+    def bfs_layers(
+        self,
+        depth: int | None = ...,
+        filter: dict[str, Any] | Callable[[Any], bool] | None = ...,
+        edge_filter: Callable[[Any], bool] | None = ...,
+    ) -> list[list[str]]:
+        """Node IDs grouped by BFS distance. Wrapped in Python like bfs."""
+        ...
+    def walk(
+        self,
+        order: str = ...,
+        on_visit: Callable[[Node], Any] | None = ...,
+        on_edge: Callable[[Edge], Any] | None = ...,
+        depth: int | None = ...,
+    ) -> None: ...
     def attr_get(self, key: str) -> Any | None: ...
+    def attr_get_str(self, key: str) -> str | None: ...
+    def attr_get_int(self, key: str) -> int | None: ...
+    def attr_get_float(self, key: str) -> float | None: ...
+    def attr_get_bool(self, key: str) -> bool | None: ...
     def attr_set(self, key: str, value: Any) -> None: ...
+    def attr_increment(self, key: str, delta: int | float = 1) -> int | float: ...
     def attr_list_append(self, key: str, value: Any) -> None: ...
+    def add_tag(self, tag: str) -> None: ...
+    def has_tag(self, tag: str) -> bool: ...
+    def tags(self) -> list[str]: ...
+    def set_subgraph(self, vertex: Vertex | None) -> None: ...
+    def get_subgraph(self) -> Vertex | None: ...
+    def common_neighbors(self, other_node: Node) -> list[str]: ...
+    def all_edges(self) -> list[Edge]: ...
+    def is_connected_to(self, other_id: str) -> bool: ...
+    def aggregate_neighbors(
+        self,
+        attr_key: str,
+        op: str = ...,
+        direction: str = ...,
+    ) -> float | int | None: ...
+    def top_neighbors(
+        self, k: int, weight_field: str = ..., direction: str = ...
+    ) -> list[tuple[str, float]]: ...
+    def to_dict(self) -> dict[str, Any]: ...
 
 @final
 class Path:
@@ -114,6 +183,13 @@ class Path:
     def __repr__(self) -> str: ...
     def toJSON(self) -> list[str]: ...
 
+@final
+class BfsFrontiers:
+    """Lazy iterator yielding the node IDs of one BFS level per step."""
+
+    def __iter__(self) -> BfsFrontiers: ...
+    def __next__(self) -> list[str]: ...
+
 @final
 class Vertex:
     """A directed property graph backed by a Rust HashMap."""
@@ -124,57 +200,176 @@ class Vertex:
     on_edge_add_callbacks: list[Callable[[Vertex, Edge], bool]]
     on_node_update_callbacks: list[Callable[[Vertex | None, Node, str, Any, Any | None], bool]]
     on_edge_update_callbacks: list[Callable[[Vertex | None, Edge, str, Any, Any | None], bool]]
+    on_vertex_meta_change_callbacks: list[Callable[[Vertex, str, Any, Any | None], bool]]
+    on_node_remove_callbacks: list[Callable[[Vertex, Node], bool]]
 
     def __new__(cls) -> Vertex: ...
+    @overload
     def __getitem__(self, key: str, /) -> Node: ...
+    @overload
+    def __getitem__(self, key: list[str], /) -> Vertex: ...
     def __iter__(self) -> Iterator[Node]: ...
     def __len__(self) -> int: ...
     def __contains__(self, key: str | Node, /) -> bool:
         """True if the node ID (or Node) exists. Added by the Python wrapper."""
         ...
     def __repr__(self) -> str: ...
-    def keys(self) -> list[str]: ...
+    def __str__(self) -> str: ...
+    def keys(self, ordered: bool = ...) -> list[str]: ...
     def toJSON(self) -> dict[str, Any]: ...
     def has_node(self, id: str) -> bool: ...
+    def nodes_with_tag(self, tag: str) -> list[Node]: ...
     def node_count(self) -> int: ...
     def add_node(self, id: str, attr: dict[str, Any] | None) -> Node: ...
     def add_edge(self, from_id: str, to_id: str, attr: dict[str, Any] | None) -> Edge: ...
+    def remove_edge(self, from_id: str, to_id: str, edge_id: str | None = ...) -> int: ...
+    def set_default_attr(self, key: str, value: Any) -> int: ...
+    def set_default_edge_attr(self, key: str, value: Any) -> int: ...
+    def rename_attr(self, old_key: str, new_key: str, on: str = ...) -> int: ...
+    def relabel(self, mapping: dict[str, str]) -> None: ...
+    def reverse_in_place(self) -> None: ...
+    def remove_isolated_nodes(self) -> int: ...
+    def clear(self, fire: bool = ...) -> None: ...
+    def clear_edges(self) -> int: ...
+    def edge_count(self) -> int: ...
+    def validate(self) -> list[str]: ...
+    def repair(self) -> list[str]: ...
     def get_node(self, id: str) -> Node: ...
-    def save_to_json(self, file_path: str | None = ...) -> str | None: ...
-    def save_to_binary(self, file_path: str) -> None: ...
-    def save_to_binary_f16(self, file_path: str) -> None: ...
+    def meta_set(self, key: str, value: Any) -> None: ...
+    def meta_get(self, key: str, default: Any = ...) -> Any: ...
+    def meta_update(self, values: dict[str, Any]) -> None: ...
+    def has_callbacks(self) -> dict[str, int]: ...
+    def save_to_json(self, file_path: str | None = ..., include_timestamp: bool = ...) -> str | None: ...
+    def save_to_binary(self, file_path: str, include_timestamp: bool = ...) -> None: ...
+    def save_to_binary_f16(self, file_path: str, include_timestamp: bool = ...) -> None: ...
+    def save_to_binary_zstd(self, file_path: str, level: int = ..., include_timestamp: bool = ...) -> None: ...
+    def append_to_binary(self, file_path: str) -> None: ...
     @staticmethod
-    def load_from_json(source: str | dict[str, Any]) -> Vertex:
+    def load_from_json(source: str | dict[str, Any], coerce_numeric: bool = ...) -> Vertex:
         """Load from a file path, a raw JSON string, or a plain dict."""
         ...
+    def save_to_jsonl(self, file_path: str, include_timestamp: bool = ...) -> None: ...
+    @staticmethod
+    def load_from_jsonl(file_path: str) -> Vertex: ...
     @staticmethod
     def load_from_binary(file_path: str) -> Vertex: ...
     @staticmethod
+    def load_from_binary_f16(file_path: str) -> Vertex: ...
+    @staticmethod
+    def load_from_binary_zstd(file_path: str) -> Vertex: ...
+    @staticmethod
+    def from_adjacency(
+        adj: dict[str, list[str]] | dict[str, dict[str, dict[str, Any] | None]],
+    ) -> Vertex: ...
+    @staticmethod
+    def from_pandas_edgelist(
+        df: Any,
+        source: str = ...,
+        target: str = ...,
+        edge_attr: str | list[str] | bool | None = ...,
+    ) -> Vertex: ...
+    def to_adjacency_dict(self, with_attrs: bool = ...) -> dict[str, Any]: ...
+    @staticmethod
     def from_nodes(nodes: dict[str, Node]) -> Vertex: ...
     @staticmethod
     def from_nodes_with_path(nodes: dict[str, Node], nodelist: list[str]) -> Vertex: ...
-    def get_metadata(self) -> dict[str, Any]: ...
+    def get_metadata(self, undirected: bool = ...) -> dict[str, Any]: ...
+    def degree_distribution(self, direction: str = ...) -> dict[int, int]: ...
+    def edge_attr_histogram(self, field: str) -> dict[Any, int]: ...
+    def infer_schema(self) -> dict[str, dict[str, dict[str, Any]]]: ...
+    def nodes_to_columns(self, fields: list[str]) -> dict[str, list[Any]]: ...
+    def edges_to_columns(self, fields: list[str]) -> dict[str, list[Any]]: ...
     def to_networkx(self) -> Any: ...
+    def to_dot(self, color_by: str | None = ..., size_by: str | None = ...) -> str: ...
     def shortest_path_bfs(
         self,
         root_node_id: str,
         target_node_id: str,
         max_depth: int | None = ...,
+        exclude_nodes: set[str] | None = ...,
+        exclude_edges: set[tuple[str, str]] | None = ...,
+        edge_filter: dict[str, Any] | None = ...,
     ) -> Vertex:
         """Ordered path is in ``result.meta["nodelist"]``. Raises ValueError if unreachable."""
         ...
-    def expand(self, source_vertex: Vertex, depth: int | None = ...) -> Vertex: ...
+    def is_reachable(
+        self,
+        root_node_id: str,
+        target_node_id: str,
+        max_depth: int | None = ...,
+        edge_filter: dict[str, Any] | None = ...,
+    ) -> bool: ...
+    def batch_shortest_paths(
+        self,
+        pairs: list[tuple[str, str]],
+        weight_field: str | None = ...,
+        parallel: bool = ...,
+        progress: Callable[[int, int], None] | None = ...,
+        should_cancel: Callable[[], bool] | None = ...,
+        max_cost: float | None = ...,
+        max_depth: int | None = ...,
+        edge_filter: dict[str, Any] | None = ...,
+    ) -> list[Path | None]: ...
+    def parallel_bfs(self, start_node_id: str, depth: int | None = ...) -> Vertex: ...
+    def bfs_frontiers(self, root_node_id: str, max_depth: int | None = ...) -> BfsFrontiers: ...
+    def shortest_path_tree(self, root_node_id: str, weight_field: str | None = ...) -> Vertex: ...
+    def bellman_ford(self, root_node_id: str, weight_field: str = ...) -> dict[str, float]: ...
+    def floyd_warshall(
+        self,
+        weight_field: str = ...,
+        progress: Callable[[int, int], None] | None = ...,
+        should_cancel: Callable[[], bool] | None = ...,
+    ) -> tuple[list[str], list[list[float]]]: ...
+    def steiner_tree(self, terminals: list[str], weight_field: str = ...) -> Vertex: ...
+    def path_neighborhood(
+        self,
+        root_node_id: str,
+        target_node_id: str,
+        padding: int = ...,
+        weight_field: str | None = ...,
+        max_cost: float | None = ...,
+        max_depth: int | None = ...,
+        edge_filter: dict[str, Any] | None = ...,
+    ) -> Vertex: ...
+    def expand(
+        self, source_vertex: Vertex, depth: int | None = ..., annotate_distance: bool = ...
+    ) -> Vertex: ...
+    def expand_weighted(
+        self,
+        source_vertex: Vertex,
+        max_cost: float,
+        weight_field: str = ...,
+        annotate_distance: bool = ...,
+        max_depth: int | None = ...,
+    ) -> Vertex: ...
+    def budget_subgraph(
+        self, seed_ids: list[str], max_nodes: int, scoring: str = ...
+    ) -> Vertex: ...
     def filter(
         self,
         predicate: Callable[[Any], bool] | None = ...,
         *,
         ids: list[str] | None = ...,
         id: str | None = ...,
+        strict: bool = ...,
         **kwargs: Any,
     ) -> Vertex:
         """Patched at import time by ironweaver/__init__.py to accept a predicate callable."""
         ...
+    def filter_by_id(self, prefix: str | None = ..., pattern: str | re.Pattern[str] | None = ...) -> Vertex: ...
+    def partition(self, keep_cross_edges: bool = ..., **kwargs: Any) -> tuple[Vertex, Vertex]: ...
+    def flatten(self, separator: str = ...) -> Vertex: ...
     def prune(self) -> int: ...
+    def merge(
+        self,
+        other: Vertex,
+        on_conflict: Callable[[str, Node, Node], dict[str, Any]] | None = ...,
+    ) -> Vertex: ...
+    def diff(self, other: Vertex) -> dict[str, Any]: ...
+    def apply_patch(self, patch: dict[str, Any]) -> None: ...
+    def structurally_equal(self, other: Vertex, ignore_attrs: list[str] | None = ...) -> bool: ...
+    def content_hash(self) -> str: ...
+    def edge_set_hash(self) -> int: ...
     def random_walks(
         self,
         start_node_id: str | None,
@@ -185,6 +380,133 @@ class Vertex:
         include_edge_types: bool | None = ...,
         edge_type_field: str | None = ...,
         stratified: bool | None = ...,
+        include_edge_ids: bool | None = ...,
+        stop_prob: float | None = ...,
+        seed: int | None = ...,
     ) -> list[list[str]]: ...
+    def sample_nodes(
+        self,
+        k: int,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+        replace: bool = ...,
+    ) -> list[str]: ...
+    def sample_by_pagerank(self, k: int, seed: int | None = ...) -> list[str]: ...
+    def sample_edges(
+        self,
+        k: int,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+        replace: bool = ...,
+    ) -> list[Edge]: ...
+    def sample_subgraph(
+        self,
+        n: int,
+        method: str = ...,
+        seed: int | None = ...,
+        burn_prob: float = ...,
+    ) -> Vertex: ...
+    def sparsify(
+        self,
+        keep_fraction: float,
+        weight_field: str | None = ...,
+        seed: int | None = ...,
+    ) -> Vertex: ...
+    def greedy_coloring(
+        self,
+        strategy: str = ...,
+        seed: int | None = ...,
+        return_num_colors: bool = ...,
+    ) -> dict[str, int] | tuple[dict[str, int], int]: ...
+    def is_dag(self) -> bool: ...
+    def condensation(self) -> tuple[Vertex, dict[str, int]]: ...
+    def largest_scc(self) -> Vertex: ...
+    def coarsen(self, matching: list[tuple[str, str]]) -> Vertex: ...
+    def quotient_by(
+        self, attr_key: str, weight_field: str | None = ..., self_loops: bool = ...
+    ) -> Vertex: ...
+    def collapse_multi_edges(self, weight_field: str = ...) -> Vertex: ...
+    def match_pattern(
+        self, pattern: Vertex, max_matches: int | None = ...
+    ) -> list[dict[str, str]]: ...
+    def is_weakly_connected(self) -> bool: ...
+    def approximate_diameter(self, iterations: int = ...) -> int: ...
+    def eccentricity(self, weight_field: str | None = ..., per_component: bool = ...) -> dict[str, float]: ...
+    def center(self, weight_field: str | None = ..., per_component: bool = ...) -> list[str]: ...
+    def periphery(self, weight_field: str | None = ..., per_component: bool = ...) -> list[str]: ...
+    def closeness_centrality(
+        self,
+        weight_field: str | None = ...,
+        wf_improved: bool = ...,
+        progress: Callable[[int, int], None] | None = ...,
+        should_cancel: Callable[[], bool] | None = ...,
+    ) -> dict[str, float]: ...
+    def harmonic_centrality(
+        self,
+        weight_field: str | None = ...,
+        progress: Callable[[int, int], None] | None = ...,
+        should_cancel: Callable[[], bool] | None = ...,
+    ) -> dict[str, float]: ...
+    def betweenness_centrality_sampled(
+        self,
+        num_samples: int,
+        seed: int | None = ...,
+        weight_field: str | None = ...,
+        progress: Callable[[int, int], None] | None = ...,
+        should_cancel: Callable[[], bool] | None = ...,
+    ) -> dict[str, float]: ...
+    def modularity(
+        self,
+        communities: dict[str, int],
+        weight_field: str | None = ...,
+    ) -> float: ...
+    def cut_size(
+        self,
+        partition: dict[str, int],
+        weight_field: str | None = ...,
+    ) -> float: ...
+    def degree_assortativity(self, weight_field: str | None = ...) -> float: ...
+    def transitivity(self) -> float: ...
+    def to_laplacian(
+        self, normalized: bool = ..., weight_field: str | None = ...
+    ) -> tuple[Any, list[str]]: ...
+    def fiedler_vector(self, weight_field: str | None = ...) -> dict[str, float]: ...
+    def spring_layout(
+        self,
+        iterations: int = ...,
+        seed: int | None = ...,
+        weight_field: str | None = ...,
+    ) -> dict[str, tuple[float, float]]: ...
+    def normalize_edge_weights(self, weight_field: str = ..., mode: str = ...) -> None: ...
+    def derive_edge_weight(self, out_field: str, expression: Callable[[Edge], float]) -> None: ...
+    def map_node_attrs(self, func: Callable[[dict[str, Any]], dict[str, Any]], fire: bool = ...) -> None: ...
+    def map_edge_attrs(self, func: Callable[[dict[str, Any]], dict[str, Any]], fire: bool = ...) -> None: ...
+    def propagate(
+        self,
+        attr_key: str,
+        out_key: str,
+        op: str = ...,
+        iterations: int = ...,
+        direction: str = ...,
+    ) -> None: ...
+    def is_strongly_connected(self) -> bool: ...
+    def common_reachable(self, source_ids: list[str]) -> set[str]: ...
+    def probable_reachable(
+        self,
+        source_id: str,
+        min_prob: float = ...,
+        prob_field: str = ...,
+    ) -> dict[str, float]: ...
 
-__all__ = ["ObservedDictionary", "Edge", "Node", "Path", "Vertex"]
+__all__ = [
+    "GraphError",
+    "NodeNotFoundError",
+    "EdgeNotFoundError",
+    "CancelledError",
+    "ObservedDictionary",
+    "Edge",
+    "Node",
+    "Path",
+    "BfsFrontiers",
+    "Vertex",
+]
@@ -5,8 +5,21 @@ use pyo3::types::{PyAny, PyDict, PyList};
 use pyo3::class::basic::CompareOp;
 use std::collections::HashMap;
 use crate::Node;
+use crate::node::{incremented_value, typed_attr, AttrType};
 
 
+/// Copy an attribute map into a fresh Python dict.
+pub(crate) fn map_to_dict<'py>(
+    py: Python<'py>,
+    map: &HashMap<String, Py<PyAny>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, value) in map {
+        dict.set_item(key, value)?;
+    }
+    Ok(dict)
+}
+
 #[pyclass]
 pub struct Edge {
     #[pyo3(get, set)]
@@ -65,17 +78,26 @@ impl Edge {
         Ok(format!("{}: {} --> {}", typ, from_id, to_id))
     }
 
-    fn toJSON(&self, py: Python<'_>) -> Py<PyAny> {
+    /// Same as ``to_dict``.
+    fn toJSON(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        self.to_dict(py)
+    }
+
+    /// Return ``{"id", "from_id", "to_id", "attr", "meta"}`` with shallow copies
+    /// of ``attr`` and ``meta``, enough to rebuild the edge with ``add_edge``.
+    pub fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         let dict = PyDict::new(py);
-        for (k, v) in &self.attr {
-            dict.set_item(k, v).unwrap();
-        }
-        dict.into()
+        dict.set_item("id", &self.id)?;
+        dict.set_item("from_id", &self.from_node.borrow(py).id)?;
+        dict.set_item("to_id", &self.to_node.borrow(py).id)?;
+        dict.set_item("attr", map_to_dict(py, &self.attr)?)?;
+        dict.set_item("meta", map_to_dict(py, &self.meta)?)?;
+        Ok(dict.into())
     }
 
     /// Set a value in ``attr`` under ``key``.
     /// Fires ``on_update_callbacks`` if the value actually changed.
-    fn attr_set(slf: PyRefMut<'_, Self>, py: Python<'_>, key: String, value: Py<PyAny>) -> PyResult<()> {
+    pub(crate) fn attr_set(slf: PyRefMut<'_, Self>, py: Python<'_>, key: String, value: Py<PyAny>) -> PyResult<()> {
         let old_value = slf.attr.get(&key).map(|v| v.clone_ref(py));
 
         // Check whether the value actually changed
@@ -126,10 +148,53 @@ impl Edge {
         Ok(())
     }
 
+    /// Add ``delta`` to the numeric value at ``attr[key]`` (starting from ``delta``
+    /// if the key is missing) and return the new value.
+    /// Fires ``on_update_callbacks`` once, like ``attr_set``.
+    #[pyo3(signature = (key, delta=None))]
+    fn attr_increment(
+        slf: PyRefMut<'_, Self>,
+        py: Python<'_>,
+        key: String,
+        delta: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let new_value = incremented_value(py, slf.attr.get(&key), delta, &key)?;
+        Edge::attr_set(slf, py, key, new_value.clone_ref(py))?;
+        Ok(new_value)
+    }
+
     /// Retrieve a value from ``attr`` by key.
     /// Returns ``None`` if the key does not exist.
     fn attr_get<'py>(&self, py: Python<'py>, key: String) -> Option<Py<PyAny>> {
         self.attr.get(&key).map(|v| v.clone_ref(py))
     }
+
+    /// Retrieve ``attr[key]`` as a ``str``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not a string.
+    fn attr_get_str(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Str)
+    }
+
+    /// Retrieve ``attr[key]`` as an ``int``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not an int (``bool`` is rejected).
+    fn attr_get_int(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Int)
+    }
+
+    /// Retrieve ``attr[key]`` as a ``float``, converting ints.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not an int or float (``bool`` is rejected).
+    fn attr_get_float(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Float)
+    }
+
+    /// Retrieve ``attr[key]`` as a ``bool``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not a bool (``0`` and ``1`` are rejected).
+    fn attr_get_bool(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Bool)
+    }
 }
 
@@ -0,0 +1,86 @@
+// errors.rs
+//
+// Exception classes raised across the crate. `GraphError` derives from
+// `ValueError`, and the not-found errors additionally derive from `KeyError`,
+// so code catching the built-in exceptions keeps working. `CancelledError`
+// derives from `KeyboardInterrupt` so a generic `except Exception` does not
+// swallow a user's request to stop.
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyKeyError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+
+create_exception!(
+    ironweaver,
+    GraphError,
+    pyo3::exceptions::PyValueError,
+    "Base class for errors about the graph itself, such as an unreachable target or a negative edge weight."
+);
+
+create_exception!(
+    ironweaver,
+    CancelledError,
+    pyo3::exceptions::PyKeyboardInterrupt,
+    "A long-running algorithm was stopped because its should_cancel callback returned True."
+);
+
+static NODE_NOT_FOUND_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static EDGE_NOT_FOUND_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+/// Build a subclass of both `GraphError` and `KeyError`.
+///
+/// `create_exception!` only supports a single base, so the class is created
+/// with `type(name, bases, namespace)`. `__str__` is taken from `Exception` so
+/// messages are not quoted the way `KeyError` quotes its key.
+fn lookup_error_type(py: Python<'_>, name: &str, doc: &str) -> PyResult<Py<PyType>> {
+    let bases = PyTuple::new(py, [py.get_type::<GraphError>(), py.get_type::<PyKeyError>()])?;
+    let namespace = PyDict::new(py);
+    namespace.set_item("__module__", "ironweaver")?;
+    namespace.set_item("__doc__", doc)?;
+    namespace.set_item("__str__", py.get_type::<PyException>().getattr("__str__")?)?;
+    let class = py.get_type::<PyType>().call1((name, bases, namespace))?;
+    Ok(class.downcast_into::<PyType>()?.unbind())
+}
+
+/// The `NodeNotFoundError` class.
+pub fn node_not_found_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    NODE_NOT_FOUND_ERROR
+        .get_or_try_init(py, || {
+            lookup_error_type(py, "NodeNotFoundError", "A node id does not exist in the graph.")
+        })
+        .map(|class| class.bind(py))
+}
+
+/// The `EdgeNotFoundError` class.
+pub fn edge_not_found_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    EDGE_NOT_FOUND_ERROR
+        .get_or_try_init(py, || {
+            lookup_error_type(py, "EdgeNotFoundError", "A requested edge does not exist in the graph.")
+        })
+        .map(|class| class.bind(py))
+}
+
+/// `NodeNotFoundError` with `msg`.
+pub fn node_not_found(msg: impl Into<String>) -> PyErr {
+    let msg = msg.into();
+    Python::with_gil(|py| match node_not_found_type(py) {
+        Ok(class) => PyErr::from_type(class.clone(), msg),
+        Err(err) => err,
+    })
+}
+
+/// `EdgeNotFoundError` with `msg`.
+pub fn edge_not_found(msg: impl Into<String>) -> PyErr {
+    let msg = msg.into();
+    Python::with_gil(|py| match edge_not_found_type(py) {
+        Ok(class) => PyErr::from_type(class.clone(), msg),
+        Err(err) => err,
+    })
+}
+
+/// `NodeNotFoundError` with the standard message for `id`.
+pub fn missing_node(id: &str) -> PyErr {
+    node_not_found(format!("Node with id '{}' not found", id))
+}
@@ -5,7 +5,9 @@ mod observed_dictionary;
 mod path;
 mod vertex;
 pub mod serialization;
+mod errors;
 pub use vertex::Vertex;
+pub use vertex::BfsFrontiers;
 pub use path::Path;
 pub use node::Node;
 pub use edge::Edge;
@@ -15,12 +17,17 @@ use pyo3::prelude::*;
 use pyo3::types::PyModule;
 
 #[pymodule]
-fn _ironweaver(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
+fn _ironweaver(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ObservedDictionary>()?;
     m.add_class::<Edge>()?;
     m.add_class::<Node>()?;
     m.add_class::<Path>()?;
     m.add_class::<Vertex>()?;
+    m.add_class::<BfsFrontiers>()?;
+    m.add("GraphError", py.get_type::<errors::GraphError>())?;
+    m.add("NodeNotFoundError", errors::node_not_found_type(py)?)?;
+    m.add("EdgeNotFoundError", errors::edge_not_found_type(py)?)?;
+    m.add("CancelledError", py.get_type::<errors::CancelledError>())?;
     Ok(())
 }
 
@@ -1,9 +1,12 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyList};
+use pyo3::types::{PyAny, PyBool, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
 use std::collections::{HashMap, HashSet};
 use pyo3::class::basic::CompareOp;
 use crate::Edge;
+use crate::edge::map_to_dict;
 use crate::Vertex;
+use crate::vertex::attr_weight;
+use crate::Path;
 
 #[pyclass]
 pub struct Node {
@@ -11,7 +14,7 @@ pub struct Node {
     pub id: String,
     #[pyo3(get, set)]
     pub attr: HashMap<String, Py<PyAny>>,
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub edges: Vec<Py<Edge>>,
     #[pyo3(get, set)]
     pub inverse_edges: Vec<Py<Edge>>,
@@ -26,6 +29,10 @@ pub struct Node {
     /// Back-reference to the owning Vertex (set during ``add_node``).
     #[pyo3(get)]
     pub vertex: Option<Py<PyAny>>,
+    /// Lightweight labels kept apart from ``attr``; see ``add_tag``.
+    pub tags: HashSet<String>,
+    /// Nested graph this node expands into; see ``set_subgraph``.
+    pub subgraph: Option<Py<Vertex>>,
 }
 
 #[pymethods]
@@ -46,6 +53,8 @@ impl Node {
             on_edge_add_callbacks: Vec::new(),
             on_update_callbacks: PyList::empty(py).into(),
             vertex: None,
+            tags: HashSet::new(),
+            subgraph: None,
         }
     }
 
@@ -58,9 +67,182 @@ impl Node {
         &self.id
     }
 
+    /// Replace the outgoing edges, keeping the owning vertex's edge count in step.
+    #[setter]
+    fn set_edges(mut slf: PyRefMut<'_, Self>, py: Python<'_>, edges: Vec<Py<Edge>>) {
+        let (old, new) = (slf.edges.len(), edges.len());
+        slf.edges = edges;
+        let owner = slf.vertex.as_ref().map(|vertex| vertex.clone_ref(py));
+        drop(slf);
+        let Some(owner) = owner else { return };
+        // A vertex busy in another call leaves the cache to `Vertex.repair`
+        if let Ok(vertex) = owner.bind(py).downcast::<Vertex>() {
+            if let Ok(mut vertex) = vertex.try_borrow_mut() {
+                vertex.edge_count = (vertex.edge_count + new).saturating_sub(old);
+            }
+        }
+    }
+
+    /// Return ``{"id", "attr", "meta", "edges", "inverse_edges"}``.
+    /// ``edges`` lists ``{"id", "to_id"}`` per outgoing edge and
+    /// ``inverse_edges`` lists ``{"id", "from_id"}`` per incoming edge.
+    fn to_dict(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let edges = PyList::empty(py);
+        for edge in &self.edges {
+            let edge_ref = edge.borrow(py);
+            let entry = PyDict::new(py);
+            entry.set_item("id", &edge_ref.id)?;
+            entry.set_item("to_id", &edge_ref.to_node.borrow(py).id)?;
+            edges.append(entry)?;
+        }
+
+        let inverse_edges = PyList::empty(py);
+        for edge in &self.inverse_edges {
+            let edge_ref = edge.borrow(py);
+            let entry = PyDict::new(py);
+            entry.set_item("id", &edge_ref.id)?;
+            entry.set_item("from_id", &edge_ref.from_node.borrow(py).id)?;
+            inverse_edges.append(entry)?;
+        }
+
+        let dict = PyDict::new(py);
+        dict.set_item("id", &self.id)?;
+        dict.set_item("attr", map_to_dict(py, &self.attr)?)?;
+        dict.set_item("meta", map_to_dict(py, &self.meta)?)?;
+        dict.set_item("edges", edges)?;
+        dict.set_item("inverse_edges", inverse_edges)?;
+        Ok(dict.into())
+    }
+
+    /// Return ids that are successors of both this node and ``other_node``.
+    /// Computed from the two ``edges`` lists alone, so no parent Vertex is needed.
+    /// Ids appear once each, in the order of this node's outgoing edges.
+    fn common_neighbors(&self, py: Python<'_>, other_node: PyRef<'_, Node>) -> Vec<String> {
+        let other_successors: HashSet<String> = other_node
+            .edges
+            .iter()
+            .map(|edge| edge.borrow(py).to_node.borrow(py).id.clone())
+            .collect();
+
+        let mut seen = HashSet::new();
+        let mut common = Vec::new();
+        for edge in &self.edges {
+            let to_id = edge.borrow(py).to_node.borrow(py).id.clone();
+            if other_successors.contains(&to_id) && seen.insert(to_id.clone()) {
+                common.push(to_id);
+            }
+        }
+        common
+    }
+
+    /// Return ``edges`` followed by ``inverse_edges``, for when direction does not matter.
+    /// A self-loop sits in both lists but is returned once.
+    fn all_edges(&self, py: Python<'_>) -> Vec<Py<Edge>> {
+        let mut all: Vec<Py<Edge>> = self.edges.iter().map(|edge| edge.clone_ref(py)).collect();
+        for edge in &self.inverse_edges {
+            if edge.borrow(py).from_node.borrow(py).id != self.id {
+                all.push(edge.clone_ref(py));
+            }
+        }
+        all
+    }
+
+    /// True if an edge links this node and ``other_id`` in either direction.
+    fn is_connected_to(&self, py: Python<'_>, other_id: &str) -> bool {
+        self.edges
+            .iter()
+            .any(|edge| edge.borrow(py).to_node.borrow(py).id == other_id)
+            || self
+                .inverse_edges
+                .iter()
+                .any(|edge| edge.borrow(py).from_node.borrow(py).id == other_id)
+    }
+
+    /// Aggregate a numeric attribute over this node's neighbours.
+    /// direction: "out" follows ``edges``, "in" ``inverse_edges``, "both" both lists;
+    /// a neighbour is read once per connecting edge.
+    /// op: "sum", "mean", "min", "max" or "count" (number of numeric values).
+    /// Missing and non-numeric values (including bools) are skipped; "mean", "min"
+    /// and "max" return None when nothing is left, "sum" returns 0.0.
+    #[pyo3(signature = (attr_key, op="sum", direction="out"))]
+    fn aggregate_neighbors(
+        &self,
+        py: Python<'_>,
+        attr_key: &str,
+        op: &str,
+        direction: &str,
+    ) -> PyResult<Py<PyAny>> {
+        let (outgoing, incoming) = neighbor_direction(direction)?;
+
+        let mut neighbors = Vec::new();
+        if outgoing {
+            neighbors.extend(self.edges.iter().map(|edge| edge.borrow(py).to_node.clone_ref(py)));
+        }
+        if incoming {
+            neighbors.extend(self.inverse_edges.iter().map(|edge| edge.borrow(py).from_node.clone_ref(py)));
+        }
+
+        let mut values = Vec::new();
+        for neighbor in &neighbors {
+            if let Some(value) = neighbor.borrow(py).attr.get(attr_key) {
+                values.extend(numeric_value(py, value)?);
+            }
+        }
+
+        let result = aggregate_values(&values, op)?;
+        if op == "count" {
+            return Ok(values.len().into_pyobject(py)?.into_any().unbind());
+        }
+        Ok(result.into_pyobject(py)?.into_any().unbind())
+    }
+
+    /// Return the ``k`` neighbours with the highest total edge weight as
+    /// ``(id, weight)`` pairs, strongest first.
+    /// direction: "out", "in" or "both", as in ``aggregate_neighbors``. Weights of
+    /// parallel edges to the same neighbour are summed; self-loops are skipped.
+    /// A missing ``weight_field`` counts as 1.0, a non-numeric one raises
+    /// ``TypeError``. Ties are broken by id.
+    #[pyo3(signature = (k, weight_field="weight", direction="out"))]
+    fn top_neighbors(
+        &self,
+        py: Python<'_>,
+        k: usize,
+        weight_field: &str,
+        direction: &str,
+    ) -> PyResult<Vec<(String, f64)>> {
+        let (outgoing, incoming) = neighbor_direction(direction)?;
+
+        let mut totals: HashMap<String, f64> = HashMap::new();
+        let mut add = |edge: &Py<Edge>, outward: bool| -> PyResult<()> {
+            let edge_ref = edge.borrow(py);
+            let other = if outward { &edge_ref.to_node } else { &edge_ref.from_node };
+            let other_id = other.borrow(py).id.clone();
+            if other_id != self.id {
+                *totals.entry(other_id).or_default() += attr_weight(py, &edge_ref.attr, Some(weight_field), "Edge")?;
+            }
+            Ok(())
+        };
+        if outgoing {
+            for edge in &self.edges {
+                add(edge, true)?;
+            }
+        }
+        if incoming {
+            for edge in &self.inverse_edges {
+                add(edge, false)?;
+            }
+        }
+
+        let mut ranked: Vec<(String, f64)> = totals.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(k);
+        Ok(ranked)
+    }
+
     /// Traverse reachable nodes, returning Vertex
     /// If depth is None, traverses all.
-    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"})
+    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"});
+    ///   a list, tuple or set value matches any of its elements
     /// edge_filter: Optional Python callable that receives an Edge and returns bool
     /// Returns a Vertex (dict of id:Node) with traversal path in meta["nodelist"]
     fn traverse<'py>(
@@ -82,7 +264,8 @@ impl Node {
 
     /// Breadth-First Search traversal of reachable nodes
     /// If depth is None, traverses all nodes.
-    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"})
+    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"});
+    ///   a list, tuple or set value matches any of its elements
     /// edge_filter: Optional Python callable that receives an Edge and returns bool
     /// Returns a Vertex (dict of id:Node) in BFS order with traversal path in meta["nodelist"]
     fn bfs<'py>(
@@ -96,14 +279,47 @@ impl Node {
 
         let mut found = HashMap::<String, Py<Node>>::new();
         let mut visited = HashSet::<String>::new();
-        let mut nodelist = Vec::<String>::new();
-        bfs_iterative(py, self_handle, depth, &mut found, &mut visited, &mut nodelist, &filter, &edge_filter)?;
+        let mut visits = Vec::<(String, usize)>::new();
+        bfs_iterative(py, self_handle, depth, &mut found, &mut visited, &mut visits, &filter, &edge_filter)?;
 
+        let nodelist = visits.into_iter().map(|(id, _)| id).collect();
         Py::new(py, Vertex::from_nodes_with_path(py, found, nodelist)?)
     }
 
+    /// Breadth-First Search grouped by distance from this node
+    /// If depth is None, traverses all nodes.
+    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"});
+    ///   a list, tuple or set value matches any of its elements
+    /// edge_filter: Optional Python callable that receives an Edge and returns bool
+    /// Returns a list of layers: index 0 is [self.id], index 1 the nodes one hop away, etc.
+    fn bfs_layers<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        depth: Option<usize>,
+        filter: Option<HashMap<String, Py<PyAny>>>,
+        edge_filter: Option<Py<PyAny>>,
+    ) -> PyResult<Vec<Vec<String>>> {
+        let self_handle: Py<Node> = slf.into();
+
+        let mut found = HashMap::<String, Py<Node>>::new();
+        let mut visited = HashSet::<String>::new();
+        let mut visits = Vec::<(String, usize)>::new();
+        bfs_iterative(py, self_handle, depth, &mut found, &mut visited, &mut visits, &filter, &edge_filter)?;
+
+        // BFS visits nodes in non-decreasing depth, so layers fill in order
+        let mut layers: Vec<Vec<String>> = Vec::new();
+        for (id, level) in visits {
+            if level == layers.len() {
+                layers.push(Vec::new());
+            }
+            layers[level].push(id);
+        }
+        Ok(layers)
+    }
+
     /// Search for a specific node by ID using BFS
-    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"})
+    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"});
+    ///   a list, tuple or set value matches any of its elements
     /// edge_filter: Optional Python callable that receives an Edge and returns bool
     /// Returns the node if found, None otherwise
     fn bfs_search<'py>(
@@ -115,7 +331,75 @@ impl Node {
         edge_filter: Option<Py<PyAny>>,
     ) -> PyResult<Option<Py<Node>>> {
         let self_handle: Py<Node> = slf.into();
-        bfs_search_iterative(py, self_handle, target_id, depth, &filter, &edge_filter)
+        let path = bfs_search_iterative(py, self_handle, target_id, depth, &filter, &edge_filter)?;
+        Ok(path.and_then(|mut nodes| nodes.pop()))
+    }
+
+    /// Shortest hop path from this node to ``target_id`` using BFS
+    /// filter: Optional HashMap of edge attribute filters (e.g., {"type": "broader"});
+    ///   a list, tuple or set value matches any of its elements
+    /// edge_filter: Optional Python callable that receives an Edge and returns bool
+    /// Returns a Path starting at this node and ending at the target,
+    /// or None if the target is not reachable within ``depth`` hops
+    #[pyo3(signature = (target_id, depth=None, filter=None, edge_filter=None))]
+    fn path_to<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        target_id: String,
+        depth: Option<usize>,
+        filter: Option<HashMap<String, Py<PyAny>>>,
+        edge_filter: Option<Py<PyAny>>,
+    ) -> PyResult<Option<Py<Path>>> {
+        let self_handle: Py<Node> = slf.into();
+        match bfs_search_iterative(py, self_handle, target_id, depth, &filter, &edge_filter)? {
+            Some(nodes) => Ok(Some(Py::new(py, Path { nodes })?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Generic BFS or DFS from this node that calls back instead of building a Vertex
+    /// order: "bfs" (default) or "dfs" (preorder, following edges in order)
+    /// on_visit: Optional callable receiving each Node when it is first reached, starting
+    ///   with this node. Returning False prunes the branch: its edges are not followed
+    /// on_edge: Optional callable receiving every Edge followed out of a visited node,
+    ///   before its target is visited (the target may already have been visited)
+    /// depth: Optional maximum number of hops from this node
+    #[pyo3(signature = (order="bfs", on_visit=None, on_edge=None, depth=None))]
+    fn walk<'py>(
+        slf: PyRef<'py, Self>,
+        py: Python<'py>,
+        order: &str,
+        on_visit: Option<Py<PyAny>>,
+        on_edge: Option<Py<PyAny>>,
+        depth: Option<usize>,
+    ) -> PyResult<()> {
+        let depth_first = match order {
+            "bfs" => false,
+            "dfs" => true,
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Unknown order '{}'; expected 'bfs' or 'dfs'",
+                    other
+                )))
+            }
+        };
+        let self_handle: Py<Node> = slf.into();
+        walk_iterative(py, self_handle, depth_first, &on_visit, &on_edge, depth)
+    }
+
+    /// Add ``delta`` to the numeric value at ``attr[key]`` (starting from ``delta``
+    /// if the key is missing) and return the new value.
+    /// Fires ``on_update_callbacks`` once, like ``attr_set``.
+    #[pyo3(signature = (key, delta=None))]
+    fn attr_increment(
+        slf: PyRefMut<'_, Self>,
+        py: Python<'_>,
+        key: String,
+        delta: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let new_value = incremented_value(py, slf.attr.get(&key), delta, &key)?;
+        Node::attr_set(slf, py, key, new_value.clone_ref(py))?;
+        Ok(new_value)
     }
 
     /// Retrieve a value from ``attr`` by key.
@@ -124,9 +408,37 @@ impl Node {
         self.attr.get(&key).map(|v| v.clone_ref(py))
     }
 
+    /// Retrieve ``attr[key]`` as a ``str``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not a string.
+    fn attr_get_str(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Str)
+    }
+
+    /// Retrieve ``attr[key]`` as an ``int``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not an int (``bool`` is rejected).
+    fn attr_get_int(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Int)
+    }
+
+    /// Retrieve ``attr[key]`` as a ``float``, converting ints.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not an int or float (``bool`` is rejected).
+    fn attr_get_float(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Float)
+    }
+
+    /// Retrieve ``attr[key]`` as a ``bool``.
+    /// Returns ``None`` if the key does not exist; raises ``TypeError`` if the
+    /// value is not a bool (``0`` and ``1`` are rejected).
+    fn attr_get_bool(&self, py: Python<'_>, key: &str) -> PyResult<Option<Py<PyAny>>> {
+        typed_attr(py, &self.attr, key, AttrType::Bool)
+    }
+
     /// Set a value in ``attr`` under ``key``.
     /// Fires ``on_update_callbacks`` if the value actually changed.
-    fn attr_set(slf: PyRefMut<'_, Self>, py: Python<'_>, key: String, value: Py<PyAny>) -> PyResult<()> {
+    pub(crate) fn attr_set(slf: PyRefMut<'_, Self>, py: Python<'_>, key: String, value: Py<PyAny>) -> PyResult<()> {
         let old_value = slf.attr.get(&key).map(|v| v.clone_ref(py));
 
         // Check whether the value actually changed
@@ -178,6 +490,35 @@ impl Node {
         Ok(())
     }
 
+    /// Add ``tag`` to this node's tags (a no-op if it is already present).
+    fn add_tag(&mut self, tag: String) {
+        self.tags.insert(tag);
+    }
+
+    /// Return ``True`` if this node carries ``tag``.
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Return this node's tags, sorted.
+    fn tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self.tags.iter().cloned().collect();
+        tags.sort();
+        tags
+    }
+
+    /// Attach a nested graph this node expands into, e.g. a sub-process.
+    /// Pass ``None`` to detach it. ``Vertex.flatten`` inlines nested graphs.
+    #[pyo3(signature = (vertex))]
+    fn set_subgraph(&mut self, vertex: Option<Py<Vertex>>) {
+        self.subgraph = vertex;
+    }
+
+    /// Return the nested graph attached with ``set_subgraph``, or ``None``.
+    fn get_subgraph(&self, py: Python<'_>) -> Option<Py<Vertex>> {
+        self.subgraph.as_ref().map(|vertex| vertex.clone_ref(py))
+    }
+
     /// Append ``value`` to a list stored at ``key`` in ``attr``.
     /// If the list does not exist, it will be created.
     #[pyo3(signature = (key, value))]
@@ -196,7 +537,7 @@ impl Node {
 }
 
 // Helper function to check if an edge matches the filter criteria
-fn edge_matches_filter(
+pub(crate) fn edge_matches_filter(
     py: Python<'_>,
     edge: &Py<Edge>,
     filter: &Option<HashMap<String, Py<PyAny>>>,
@@ -211,10 +552,7 @@ fn edge_matches_filter(
         for (filter_key, filter_value) in filter_map {
             if let Some(edge_value) = edge_attr.get(filter_key) {
                 // Compare the values by converting to Python objects and using Python's equality
-                let edge_py_obj = edge_value.bind(py);
-                let filter_py_obj = filter_value.bind(py);
-                
-                if !edge_py_obj.eq(filter_py_obj)? {
+                if !filter_value_matches(edge_value.bind(py), filter_value.bind(py))? {
                     return Ok(false);
                 }
             } else {
@@ -236,6 +574,26 @@ fn edge_matches_filter(
     Ok(true)
 }
 
+/// A filter value matches an attribute equal to it or, for a list, tuple or
+/// set, an attribute equal to any of its elements.
+fn filter_value_matches(value: &Bound<'_, PyAny>, filter_value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    if value.eq(filter_value)? {
+        return Ok(true);
+    }
+    let is_choice = filter_value.is_instance_of::<PyList>()
+        || filter_value.is_instance_of::<PyTuple>()
+        || filter_value.is_instance_of::<PySet>()
+        || filter_value.is_instance_of::<PyFrozenSet>();
+    if is_choice {
+        for choice in filter_value.try_iter()? {
+            if value.eq(choice?)? {
+                return Ok(true);
+            }
+        }
+    }
+    Ok(false)
+}
+
 // Helper is Rust-only, not a #[pymethods]
 fn traverse_recursive(
     py: Python<'_>,
@@ -284,7 +642,7 @@ fn bfs_iterative(
     depth: Option<usize>,
     found: &mut HashMap<String, Py<Node>>,
     visited: &mut HashSet<String>,
-    nodelist: &mut Vec<String>,
+    visits: &mut Vec<(String, usize)>,
     filter: &Option<HashMap<String, Py<PyAny>>>,
     edge_filter: &Option<Py<PyAny>>,
 ) -> PyResult<()> {
@@ -300,7 +658,7 @@ fn bfs_iterative(
     // Mark starting node and add to queue
     visited.insert(start_id.clone());
     found.insert(start_id.clone(), start_node.clone_ref(py));
-    nodelist.push(start_id);
+    visits.push((start_id, 0));
     queue.push_back((start_node, 0));
     
     while let Some((current_node, current_depth)) = queue.pop_front() {
@@ -327,7 +685,7 @@ fn bfs_iterative(
                 if !visited.contains(&to_id) {
                     visited.insert(to_id.clone());
                     found.insert(to_id.clone(), to_node.clone_ref(py));
-                    nodelist.push(to_id);
+                    visits.push((to_id, current_depth + 1));
                     queue.push_back((to_node, current_depth + 1));
                 }
             }
@@ -337,20 +695,140 @@ fn bfs_iterative(
     Ok(())
 }
 
-// BFS search helper function that stops when target is found
-fn bfs_search_iterative(
+/// True for Python ints and floats, excluding ``bool``.
+fn is_number(value: &Bound<'_, PyAny>) -> bool {
+    (value.is_instance_of::<PyInt>() || value.is_instance_of::<PyFloat>())
+        && !value.is_instance_of::<PyBool>()
+}
+
+/// The value as f64 if it is an int or float (not a bool), else None.
+pub(crate) fn numeric_value(py: Python<'_>, value: &Py<PyAny>) -> PyResult<Option<f64>> {
+    let value = value.bind(py);
+    if is_number(value) {
+        Ok(Some(value.extract::<f64>()?))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Parse an ``aggregate_neighbors`` direction into (follow edges, follow inverse_edges).
+pub(crate) fn neighbor_direction(direction: &str) -> PyResult<(bool, bool)> {
+    match direction {
+        "out" => Ok((true, false)),
+        "in" => Ok((false, true)),
+        "both" => Ok((true, true)),
+        other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown direction '{}'; expected 'out', 'in' or 'both'",
+            other
+        ))),
+    }
+}
+
+/// Combine `values` with `op` ("sum", "mean", "min", "max" or "count").
+/// "mean", "min" and "max" of no values give None; "count" gives the length.
+pub(crate) fn aggregate_values(values: &[f64], op: &str) -> PyResult<Option<f64>> {
+    match op {
+        "sum" => Ok(Some(values.iter().sum())),
+        "mean" if values.is_empty() => Ok(None),
+        "mean" => Ok(Some(values.iter().sum::<f64>() / values.len() as f64)),
+        "min" => Ok(values.iter().copied().reduce(f64::min)),
+        "max" => Ok(values.iter().copied().reduce(f64::max)),
+        "count" => Ok(Some(values.len() as f64)),
+        other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown op '{}'; expected 'sum', 'mean', 'min', 'max' or 'count'",
+            other
+        ))),
+    }
+}
+
+/// Compute ``old + delta`` for ``attr_increment``; ``delta`` defaults to 1.
+/// Raises ``TypeError`` if either value is not an int or float.
+pub(crate) fn incremented_value(
+    py: Python<'_>,
+    old: Option<&Py<PyAny>>,
+    delta: Option<Py<PyAny>>,
+    key: &str,
+) -> PyResult<Py<PyAny>> {
+    let delta = match delta {
+        Some(delta) => delta,
+        None => 1i64.into_pyobject(py)?.into_any().unbind(),
+    };
+    if !is_number(delta.bind(py)) {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Increment for '{}' must be an int or float",
+            key
+        )));
+    }
+
+    match old {
+        None => Ok(delta),
+        Some(old) if is_number(old.bind(py)) => Ok(old.bind(py).add(delta.bind(py))?.unbind()),
+        Some(old) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Cannot increment attribute '{}': existing value of type '{}' is not numeric",
+            key,
+            old.bind(py).get_type().name()?
+        ))),
+    }
+}
+
+/// Value type checked by the ``attr_get_*`` accessors of ``Node`` and ``Edge``.
+#[derive(Clone, Copy)]
+pub(crate) enum AttrType {
+    Str,
+    Int,
+    Float,
+    Bool,
+}
+
+/// ``attr[key]`` if it has the `expected` type, ``None`` if the key is missing.
+///
+/// Ints are widened to float for ``Float``; bools never pass as numbers.
+/// Raises ``TypeError`` on any other mismatch.
+pub(crate) fn typed_attr(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    key: &str,
+    expected: AttrType,
+) -> PyResult<Option<Py<PyAny>>> {
+    let Some(value) = attr.get(key) else { return Ok(None) };
+    let bound = value.bind(py);
+    let (matches, name) = match expected {
+        AttrType::Str => (bound.is_instance_of::<PyString>(), "str"),
+        AttrType::Int => (bound.is_instance_of::<PyInt>() && !bound.is_instance_of::<PyBool>(), "int"),
+        AttrType::Float => (is_number(bound), "float"),
+        AttrType::Bool => (bound.is_instance_of::<PyBool>(), "bool"),
+    };
+    if !matches {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "Attribute '{}' must be {}, got '{}'",
+            key,
+            name,
+            bound.get_type().name()?
+        )));
+    }
+    match expected {
+        AttrType::Float => Ok(Some(PyFloat::new(py, bound.extract::<f64>()?).into_any().unbind())),
+        _ => Ok(Some(value.clone_ref(py))),
+    }
+}
+
+// BFS search helper function that stops when target is found.
+// Returns the nodes from start to target, rebuilt from the predecessor map.
+pub(crate) fn bfs_search_iterative(
     py: Python<'_>,
     start_node: Py<Node>,
     target_id: String,
     depth: Option<usize>,
     filter: &Option<HashMap<String, Py<PyAny>>>,
     edge_filter: &Option<Py<PyAny>>,
-) -> PyResult<Option<Py<Node>>> {
+) -> PyResult<Option<Vec<Py<Node>>>> {
     use std::collections::VecDeque;
     
     // Queue stores (node, current_depth)
     let mut queue = VecDeque::new();
     let mut visited = HashSet::<String>::new();
+    // Maps each discovered node id to the node it was reached from
+    let mut predecessors = HashMap::<String, Py<Node>>::new();
     
     // Get starting node ID
     let start_node_ref = start_node.bind(py);
@@ -358,7 +836,7 @@ fn bfs_search_iterative(
     
     // Check if start node is the target
     if start_id == target_id {
-        return Ok(Some(start_node));
+        return Ok(Some(vec![start_node]));
     }
     
     // Mark starting node and add to queue
@@ -385,14 +863,22 @@ fn bfs_search_iterative(
                 let to_node_ref = to_node.bind(py);
                 let to_id = to_node_ref.getattr("id")?.extract::<String>()?;
                 
-                // If this is our target, return it
+                // If this is our target, walk the predecessors back to the start
                 if to_id == target_id {
-                    return Ok(Some(to_node));
+                    let mut path = vec![to_node, current_node.clone_ref(py)];
+                    let mut id = current_ref.getattr("id")?.extract::<String>()?;
+                    while let Some(prev) = predecessors.get(&id) {
+                        id = prev.bind(py).getattr("id")?.extract::<String>()?;
+                        path.push(prev.clone_ref(py));
+                    }
+                    path.reverse();
+                    return Ok(Some(path));
                 }
                 
                 // If not visited, mark and enqueue
                 if !visited.contains(&to_id) {
-                    visited.insert(to_id);
+                    visited.insert(to_id.clone());
+                    predecessors.insert(to_id, current_node.clone_ref(py));
                     queue.push_back((to_node, current_depth + 1));
                 }
             }
@@ -402,3 +888,70 @@ fn bfs_search_iterative(
     // Target not found
     Ok(None)
 }
+
+// Outgoing edges of a node being expanded by `walk_iterative`
+struct WalkFrame {
+    edges: Vec<Py<Edge>>,
+    next: usize,
+    level: usize,
+}
+
+// Shared BFS/DFS loop behind `walk`: BFS expands the oldest frame, DFS the newest
+fn walk_iterative(
+    py: Python<'_>,
+    start_node: Py<Node>,
+    depth_first: bool,
+    on_visit: &Option<Py<PyAny>>,
+    on_edge: &Option<Py<PyAny>>,
+    depth: Option<usize>,
+) -> PyResult<()> {
+    use std::collections::VecDeque;
+
+    let frame_for = |node: &Py<Node>, level: usize| -> Option<WalkFrame> {
+        if depth.is_some_and(|d| level >= d) {
+            return None;
+        }
+        let edges = node.borrow(py).edges.iter().map(|e| e.clone_ref(py)).collect();
+        Some(WalkFrame { edges, next: 0, level })
+    };
+    // `on_visit` prunes by returning False; any other result keeps going
+    let visit = |node: &Py<Node>| -> PyResult<bool> {
+        let Some(callback) = on_visit else { return Ok(true) };
+        let result = callback.call1(py, (node.clone_ref(py),))?;
+        Ok(!result.bind(py).downcast::<PyBool>().is_ok_and(|b| !b.is_true()))
+    };
+
+    let mut visited = HashSet::<String>::new();
+    visited.insert(start_node.borrow(py).id.clone());
+    let mut frames = VecDeque::new();
+    if visit(&start_node)? {
+        frames.extend(frame_for(&start_node, 0));
+    }
+
+    loop {
+        let frame = if depth_first { frames.back_mut() } else { frames.front_mut() };
+        let Some(frame) = frame else { break };
+        let Some(edge) = frame.edges.get(frame.next).map(|e| e.clone_ref(py)) else {
+            if depth_first {
+                frames.pop_back();
+            } else {
+                frames.pop_front();
+            }
+            continue;
+        };
+        frame.next += 1;
+        let level = frame.level + 1;
+
+        if let Some(callback) = on_edge {
+            callback.call1(py, (edge.clone_ref(py),))?;
+        }
+        let to_node = edge.borrow(py).to_node.clone_ref(py);
+        if !visited.insert(to_node.borrow(py).id.clone()) {
+            continue;
+        }
+        if visit(&to_node)? {
+            frames.extend(frame_for(&to_node, level));
+        }
+    }
+    Ok(())
+}
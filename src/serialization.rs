@@ -5,20 +5,214 @@ use serde::{Deserialize, Serialize};
 use half::f16;
 use serde::ser::{SerializeStruct, Serializer as _};
 use bincode::Options;
-use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, BufWriter};
-use std::path::Path;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{File, OpenOptions};
+use std::hash::{Hash, Hasher};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 use crate::{Node, Edge, Vertex};
+use crate::errors::{node_not_found, GraphError};
+
+/// Leading bytes of every binary graph file.
+pub const BINARY_MAGIC: &[u8; 8] = b"IRONWVR\0";
+/// Version of the outer binary layout written by `save_to_binary`.
+///
+/// This guards the file format itself; `metadata["version"]` lives inside the
+/// payload and cannot be read until the layout is known. Version 1 headers
+/// carry no flags; version 2 adds a `u32` flag word; version 3 adds a `tags`
+/// list to every node in the payload; version 4 adds an optional nested
+/// `subgraph` to every node.
+pub const BINARY_FORMAT_VERSION: u32 = 4;
+/// Oldest format version whose payload nodes carry `tags`.
+const BINARY_TAGS_VERSION: u32 = 3;
+/// Oldest format version whose payload nodes carry `subgraph`.
+const BINARY_SUBGRAPH_VERSION: u32 = 4;
+/// Header flag: the payload after the header is a single zstd frame.
+pub const BINARY_FLAG_ZSTD: u32 = 1;
+/// Header flag: float values in the base payload were downcast to f16.
+pub const BINARY_FLAG_F16: u32 = 2;
+
+/// Parsed header of a binary graph file.
+#[derive(Debug, Clone, Copy)]
+pub struct BinaryHeader {
+    pub version: u32,
+    pub flags: u32,
+}
+
+impl BinaryHeader {
+    pub fn has(&self, flag: u32) -> bool {
+        self.flags & flag == flag
+    }
+}
+
+/// Human-readable names of the bits set in `flags`, e.g. `"zstd"`.
+fn describe_binary_flags(flags: u32) -> String {
+    let mut names = Vec::new();
+    if flags & BINARY_FLAG_ZSTD != 0 {
+        names.push("zstd");
+    }
+    if flags & BINARY_FLAG_F16 != 0 {
+        names.push("f16");
+    }
+    if names.is_empty() {
+        "plain".to_string()
+    } else {
+        names.join("+")
+    }
+}
+
+/// Write the magic bytes, the current format version and `flags`.
+fn write_binary_header<W: Write>(writer: &mut W, flags: u32) -> std::io::Result<()> {
+    writer.write_all(BINARY_MAGIC)?;
+    writer.write_all(&BINARY_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&flags.to_le_bytes())
+}
+
+/// Check the magic bytes and read the header, migrating older layouts.
+fn read_binary_header<R: Read>(reader: &mut R) -> Result<BinaryHeader, Box<dyn std::error::Error>> {
+    let mut header = [0u8; 12];
+    let mut filled = 0;
+    while filled < header.len() {
+        match reader.read(&mut header[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    if filled < header.len() || &header[..8] != BINARY_MAGIC {
+        return Err("missing Ironweaver binary header; the file is not an Ironweaver graph or \
+                    is a legacy header-less file from before format versioning. Load it with \
+                    the release that wrote it and save it again (or export to JSON)"
+            .into());
+    }
+
+    let version = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+    if version > BINARY_FORMAT_VERSION {
+        return Err(format!(
+            "binary format version {} is newer than the supported version {}; upgrade ironweaver",
+            version, BINARY_FORMAT_VERSION
+        )
+        .into());
+    }
+
+    let flags = match version {
+        // Version 1 headers end after the version and never set any flags.
+        1 => 0,
+        _ => {
+            let mut flag_buf = [0u8; 4];
+            reader.read_exact(&mut flag_buf)?;
+            u32::from_le_bytes(flag_buf)
+        }
+    };
+    Ok(BinaryHeader { version, flags })
+}
+
+/// The entries of `map` in key order.
+fn sorted(map: &HashMap<String, SerializableValue>) -> BTreeMap<&String, &SerializableValue> {
+    map.iter().collect()
+}
+
+/// Serialize a map with its keys in sorted order, so that saving the same
+/// graph twice gives identical bytes.
+fn sorted_map<S: serde::Serializer>(
+    map: &HashMap<String, SerializableValue>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    sorted(map).serialize(serializer)
+}
 
 /// Serializable representation of a node that avoids circular references
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SerializableNode {
     pub id: String,
+    #[serde(serialize_with = "sorted_map")]
     pub attr: HashMap<String, SerializableValue>,
+    #[serde(serialize_with = "sorted_map")]
     pub meta: HashMap<String, SerializableValue>,
     pub edge_ids: Vec<String>, // Store edge IDs instead of actual edges
     pub inverse_edge_ids: Vec<String>, // Store inverse edge IDs
+    /// Sorted node tags; missing in files written before tags existed.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Nested graph attached with `Node.set_subgraph`, serialized recursively.
+    #[serde(default)]
+    pub subgraph: Option<Box<SerializableGraph>>,
+}
+
+/// Node layout of binary format versions 1 and 2, which had no `tags`.
+#[derive(Deserialize)]
+struct SerializableNodeV2 {
+    id: String,
+    attr: HashMap<String, SerializableValue>,
+    meta: HashMap<String, SerializableValue>,
+    edge_ids: Vec<String>,
+    inverse_edge_ids: Vec<String>,
+}
+
+impl From<SerializableNodeV2> for SerializableNode {
+    fn from(node: SerializableNodeV2) -> Self {
+        SerializableNode {
+            id: node.id,
+            attr: node.attr,
+            meta: node.meta,
+            edge_ids: node.edge_ids,
+            inverse_edge_ids: node.inverse_edge_ids,
+            tags: Vec::new(),
+            subgraph: None,
+        }
+    }
+}
+
+/// Node layout of binary format version 3, which had no `subgraph`.
+#[derive(Deserialize)]
+struct SerializableNodeV3 {
+    id: String,
+    attr: HashMap<String, SerializableValue>,
+    meta: HashMap<String, SerializableValue>,
+    edge_ids: Vec<String>,
+    inverse_edge_ids: Vec<String>,
+    tags: Vec<String>,
+}
+
+impl From<SerializableNodeV3> for SerializableNode {
+    fn from(node: SerializableNodeV3) -> Self {
+        SerializableNode {
+            id: node.id,
+            attr: node.attr,
+            meta: node.meta,
+            edge_ids: node.edge_ids,
+            inverse_edge_ids: node.inverse_edge_ids,
+            tags: node.tags,
+            subgraph: None,
+        }
+    }
+}
+
+/// Graph layout of binary format versions before 4, with the node layout `N`
+/// of that version.
+#[derive(Deserialize)]
+struct LegacySerializableGraph<N> {
+    nodes: BTreeMap<String, N>,
+    edges: BTreeMap<String, SerializableEdge>,
+    meta: HashMap<String, SerializableValue>,
+    metadata: HashMap<String, SerializableValue>,
+}
+
+impl<N: Into<SerializableNode>> From<LegacySerializableGraph<N>> for SerializableGraph {
+    fn from(legacy: LegacySerializableGraph<N>) -> Self {
+        let nodes = legacy
+            .nodes
+            .into_iter()
+            .map(|(id, node)| (id, node.into()))
+            .collect();
+        SerializableGraph {
+            nodes,
+            edges: legacy.edges,
+            meta: legacy.meta,
+            metadata: legacy.metadata,
+        }
+    }
 }
 
 /// Serializable representation of an edge
@@ -27,7 +221,9 @@ pub struct SerializableEdge {
     pub id: String, // Unique edge identifier
     pub from_id: String,
     pub to_id: String,
+    #[serde(serialize_with = "sorted_map")]
     pub attr: HashMap<String, SerializableValue>,
+    #[serde(serialize_with = "sorted_map")]
     pub meta: HashMap<String, SerializableValue>,
 }
 
@@ -41,15 +237,80 @@ pub enum SerializableValue {
     Bool(bool),
     None,
     List(Vec<SerializableValue>),
+    #[serde(serialize_with = "sorted_map")]
     Dict(HashMap<String, SerializableValue>),
 }
 
+/// Structural equality, so values can key a hash map. Floats compare by bit
+/// pattern, which makes `NaN` equal to itself and `0.0` differ from `-0.0`.
+impl PartialEq for SerializableValue {
+    fn eq(&self, other: &Self) -> bool {
+        use SerializableValue::*;
+        match (self, other) {
+            (String(a), String(b)) => a == b,
+            (Int(a), Int(b)) => a == b,
+            (Float(a), Float(b)) => a.to_bits() == b.to_bits(),
+            (Half(a), Half(b)) => a.to_bits() == b.to_bits(),
+            (Bool(a), Bool(b)) => a == b,
+            (None, None) => true,
+            (List(a), List(b)) => a == b,
+            (Dict(a), Dict(b)) => a.len() == b.len() && a.iter().all(|(k, v)| b.get(k) == Some(v)),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for SerializableValue {}
+
+impl std::hash::Hash for SerializableValue {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            SerializableValue::String(s) => s.hash(state),
+            SerializableValue::Int(i) => i.hash(state),
+            SerializableValue::Float(f) => f.to_bits().hash(state),
+            SerializableValue::Half(h) => h.to_bits().hash(state),
+            SerializableValue::Bool(b) => b.hash(state),
+            SerializableValue::None => {}
+            SerializableValue::List(list) => list.hash(state),
+            SerializableValue::Dict(dict) => {
+                // Hash entries in key order so equal dicts hash alike
+                let mut entries: Vec<_> = dict.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                entries.hash(state);
+            }
+        }
+    }
+}
+
+/// One line of a JSON Lines graph file.
+///
+/// A file starts with a single `Metadata` record followed by one record per
+/// node and one per edge, so it can be streamed and appended to line by line.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "record", rename_all = "lowercase")]
+pub enum JsonlRecord {
+    Metadata {
+        #[serde(serialize_with = "sorted_map")]
+        meta: HashMap<String, SerializableValue>,
+        #[serde(serialize_with = "sorted_map")]
+        metadata: HashMap<String, SerializableValue>,
+    },
+    Node(SerializableNode),
+    Edge(SerializableEdge),
+}
+
 /// Complete graph representation for serialization
+///
+/// Nodes and edges are kept sorted by id, and attribute maps are written in
+/// key order, so that output is reproducible.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SerializableGraph {
-    pub nodes: HashMap<String, SerializableNode>,
-    pub edges: HashMap<String, SerializableEdge>,
+    pub nodes: BTreeMap<String, SerializableNode>,
+    pub edges: BTreeMap<String, SerializableEdge>,
+    #[serde(serialize_with = "sorted_map")]
     pub meta: HashMap<String, SerializableValue>,
+    #[serde(serialize_with = "sorted_map")]
     pub metadata: HashMap<String, SerializableValue>,
 }
 
@@ -111,6 +372,70 @@ impl SerializableValue {
         }
     }
 
+    /// Recursively convert Half variants back to Float
+    ///
+    /// The widened values keep the f16 rounding: about three significant
+    /// decimal digits, with magnitudes above 65504 stored as infinity.
+    pub fn widen_f16(&mut self) {
+        match self {
+            SerializableValue::Half(h) => {
+                *self = SerializableValue::Float(h.to_f64());
+            }
+            SerializableValue::List(list) => {
+                for item in list {
+                    item.widen_f16();
+                }
+            }
+            SerializableValue::Dict(dict) => {
+                for value in dict.values_mut() {
+                    value.widen_f16();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Recursively parse numeric strings such as `"42"` or `"3.14"` into Int
+    /// or Float. Strings that are not a plain finite number stay unchanged.
+    pub fn coerce_numeric(&mut self) {
+        match self {
+            SerializableValue::String(s) => {
+                if let Ok(i) = s.parse::<i64>() {
+                    *self = SerializableValue::Int(i);
+                } else if let Ok(f) = s.parse::<f64>() {
+                    if f.is_finite() {
+                        *self = SerializableValue::Float(f);
+                    }
+                }
+            }
+            SerializableValue::List(list) => {
+                for item in list {
+                    item.coerce_numeric();
+                }
+            }
+            SerializableValue::Dict(dict) => {
+                for value in dict.values_mut() {
+                    value.coerce_numeric();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Lowercase name of the variant, used when reporting attribute schemas
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            SerializableValue::String(_) => "string",
+            SerializableValue::Int(_) => "int",
+            SerializableValue::Float(_) => "float",
+            SerializableValue::Half(_) => "half",
+            SerializableValue::Bool(_) => "bool",
+            SerializableValue::None => "none",
+            SerializableValue::List(_) => "list",
+            SerializableValue::Dict(_) => "dict",
+        }
+    }
+
     /// Convert SerializableValue back to Python object
     pub fn to_python(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         match self {
@@ -141,15 +466,87 @@ impl SerializableValue {
     }
 }
 
+/// Order `edges` by the position of their id in `saved_ids`.
+fn sort_by_saved_order(py: Python<'_>, edges: &mut [Py<Edge>], saved_ids: &[String]) {
+    let position: HashMap<&str, usize> = saved_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+    edges.sort_by_cached_key(|edge| {
+        edge.borrow(py)
+            .id
+            .as_deref()
+            .and_then(|id| position.get(id).copied())
+            .unwrap_or(usize::MAX)
+    });
+}
+
 impl SerializableGraph {
     /// Create a SerializableGraph from a Vertex (collection of nodes)
-    pub fn from_vertex(py: Python<'_>, vertex: &Vertex) -> PyResult<Self> {
-        let mut serializable_nodes = HashMap::new();
-        let mut serializable_edges = HashMap::new();
+    ///
+    /// With `include_timestamp` the top-level `metadata` records the current
+    /// time. Without it the result only depends on the graph, so repeated
+    /// serializations are byte-identical.
+    pub fn from_vertex(py: Python<'_>, vertex: &Vertex, include_timestamp: bool) -> PyResult<Self> {
+        Self::from_top_level_vertex(py, vertex, include_timestamp, None)
+    }
+
+    /// `from_vertex`, also returning each edge with the id it was serialized
+    /// under.
+    pub fn from_vertex_with_edges(
+        py: Python<'_>,
+        vertex: &Vertex,
+        include_timestamp: bool,
+    ) -> PyResult<(Self, EdgeIds)> {
+        let mut edges = Vec::new();
+        let graph = Self::from_top_level_vertex(py, vertex, include_timestamp, Some(&mut edges))?;
+        Ok((graph, edges))
+    }
+
+    fn from_top_level_vertex(
+        py: Python<'_>,
+        vertex: &Vertex,
+        include_timestamp: bool,
+        edge_ids: Option<&mut EdgeIds>,
+    ) -> PyResult<Self> {
+        let mut graph = Self::from_nested_vertex(py, vertex, &mut Vec::new(), edge_ids)?;
+        if include_timestamp {
+            graph.metadata.insert(
+                "timestamp".to_string(),
+                SerializableValue::String(chrono::Utc::now().to_rfc3339()),
+            );
+        }
+        Ok(graph)
+    }
+
+    /// `from_vertex` for a graph nested below `ancestors`, which guard
+    /// against a subgraph containing itself. Serialized edges are pushed to
+    /// `edge_ids` if given.
+    fn from_nested_vertex(
+        py: Python<'_>,
+        vertex: &Vertex,
+        ancestors: &mut Vec<*const Vertex>,
+        mut edge_ids: Option<&mut EdgeIds>,
+    ) -> PyResult<Self> {
+        if ancestors.contains(&(vertex as *const Vertex)) {
+            return Err(GraphError::new_err(
+                "Cannot serialize a nested subgraph that contains one of its own ancestors",
+            ));
+        }
+        ancestors.push(vertex as *const Vertex);
+        let mut serializable_nodes = BTreeMap::new();
+        let mut serializable_edges = BTreeMap::new();
         let mut edge_counter = 0u64;
 
+        // Visit nodes in id order so generated edge ids do not depend on
+        // HashMap iteration order
+        let mut node_ids: Vec<&String> = vertex.nodes.keys().collect();
+        node_ids.sort();
+
         // First pass: collect all nodes and their basic info
-        for (node_id, node_py) in &vertex.nodes {
+        for node_id in &node_ids {
+            let node_py = &vertex.nodes[*node_id];
             let node_ref = node_py.bind(py);
             
             // Extract node attributes
@@ -166,21 +563,31 @@ impl SerializableGraph {
                 serializable_meta.insert(key, SerializableValue::from_python(py, &value)?);
             }
 
+            let mut tags: Vec<String> = node_py.borrow(py).tags.iter().cloned().collect();
+            tags.sort();
+
+            let subgraph = match node_py.borrow(py).subgraph.as_ref() {
+                Some(nested) => Some(Box::new(Self::from_nested_vertex(py, &nested.borrow(py), ancestors, None)?)),
+                None => None,
+            };
+
             // We'll fill in edge_ids and inverse_edge_ids in the second pass
             let serializable_node = SerializableNode {
-                id: node_id.clone(),
+                id: node_id.to_string(),
                 attr: serializable_attr,
                 meta: serializable_meta,
                 edge_ids: Vec::new(),
                 inverse_edge_ids: Vec::new(),
+                tags,
+                subgraph,
             };
             
-            serializable_nodes.insert(node_id.clone(), serializable_node);
+            serializable_nodes.insert(node_id.to_string(), serializable_node);
         }
 
         // Second pass: collect all edges and update node edge references
-        for (_node_id, node_py) in &vertex.nodes {
-            let node_ref = node_py.bind(py);
+        for node_id in &node_ids {
+            let node_ref = vertex.nodes[*node_id].bind(py);
             let edges: Vec<Py<Edge>> = node_ref.getattr("edges")?.extract()?;
             
             for edge_py in edges {
@@ -195,6 +602,9 @@ impl SerializableGraph {
                 // Generate unique edge ID
                 let edge_id = format!("edge_{}_{}_to_{}", edge_counter, from_id, to_id);
                 edge_counter += 1;
+                if let Some(edge_ids) = edge_ids.as_mut() {
+                    edge_ids.push((edge_py.clone_ref(py), edge_id.clone()));
+                }
                 
                 // Extract edge attributes
                 let attr_py: HashMap<String, Py<PyAny>> = edge_ref.getattr("attr")?.extract()?;
@@ -246,10 +656,8 @@ impl SerializableGraph {
         metadata.insert("version".to_string(), SerializableValue::String("1.0".to_string()));
         metadata.insert("node_count".to_string(), SerializableValue::Int(serializable_nodes.len() as i64));
         metadata.insert("edge_count".to_string(), SerializableValue::Int(serializable_edges.len() as i64));
-        metadata.insert("timestamp".to_string(), SerializableValue::String(
-            chrono::Utc::now().to_rfc3339()
-        ));
 
+        ancestors.pop();
         Ok(SerializableGraph {
             nodes: serializable_nodes,
             edges: serializable_edges,
@@ -287,6 +695,11 @@ impl SerializableGraph {
                 on_edge_add_callbacks: Vec::new(),
                 on_update_callbacks: PyList::empty(py).into(),
                 vertex: None,
+                tags: serializable_node.tags.iter().cloned().collect(),
+                subgraph: match &serializable_node.subgraph {
+                    Some(nested) => Some(Py::new(py, nested.to_vertex(py)?)?),
+                    None => None,
+                },
             })?;
             
             python_nodes.insert(node_id.clone(), node.clone_ref(py));
@@ -299,11 +712,11 @@ impl SerializableGraph {
         
         for serializable_edge in self.edges.values() {
             let from_node = python_nodes.get(&serializable_edge.from_id)
-                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                .ok_or_else(|| node_not_found(
                     format!("From node {} not found", serializable_edge.from_id)
                 ))?;
             let to_node = python_nodes.get(&serializable_edge.to_id)
-                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                .ok_or_else(|| node_not_found(
                     format!("To node {} not found", serializable_edge.to_id)
                 ))?;
             
@@ -342,16 +755,19 @@ impl SerializableGraph {
                 .push(edge);
         }
         
-        // Third pass: update nodes with their edges and inverse_edges
-        for (node_id, edges) in node_edges {
+        // Third pass: update nodes with their edges and inverse_edges, restoring
+        // the saved order (edges missing from the id lists go last)
+        for (node_id, mut edges) in node_edges {
             if let Some(node_py) = python_nodes.get(&node_id) {
+                sort_by_saved_order(py, &mut edges, &self.nodes[&node_id].edge_ids);
                 let mut node_ref = node_py.bind(py).borrow_mut();
                 node_ref.edges = edges;
             }
         }
         
-        for (node_id, inverse_edges) in node_inverse_edges {
+        for (node_id, mut inverse_edges) in node_inverse_edges {
             if let Some(node_py) = python_nodes.get(&node_id) {
+                sort_by_saved_order(py, &mut inverse_edges, &self.nodes[&node_id].inverse_edge_ids);
                 let mut node_ref = node_py.bind(py).borrow_mut();
                 node_ref.inverse_edges = inverse_edges;
             }
@@ -376,8 +792,8 @@ impl SerializableGraph {
         let mut st = serializer.serialize_struct("SerializableGraph", 4)?;
         st.serialize_field("nodes", &self.nodes)?;
         st.serialize_field("edges", &self.edges)?;
-        st.serialize_field("meta", &self.meta)?;
-        st.serialize_field("metadata", &self.metadata)?;
+        st.serialize_field("meta", &sorted(&self.meta))?;
+        st.serialize_field("metadata", &sorted(&self.metadata))?;
         st.end()?;
         Ok(())
     }
@@ -402,61 +818,598 @@ impl SerializableGraph {
         Ok(graph)
     }
 
+    /// Save graph to a JSON Lines file: a metadata line, then one line per node and edge
+    pub fn save_to_jsonl<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+
+        let header = JsonlRecord::Metadata {
+            meta: self.meta.clone(),
+            metadata: self.metadata.clone(),
+        };
+        serde_json::to_writer(&mut writer, &header)?;
+        writer.write_all(b"\n")?;
+
+        let mut node_ids: Vec<&String> = self.nodes.keys().collect();
+        node_ids.sort();
+        for node_id in node_ids {
+            serde_json::to_writer(&mut writer, &JsonlRecord::Node(self.nodes[node_id].clone()))?;
+            writer.write_all(b"\n")?;
+        }
+
+        let mut edge_ids: Vec<&String> = self.edges.keys().collect();
+        edge_ids.sort();
+        for edge_id in edge_ids {
+            serde_json::to_writer(&mut writer, &JsonlRecord::Edge(self.edges[edge_id].clone()))?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load graph from a JSON Lines file, parsing one record at a time
+    pub fn load_from_jsonl<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+        let mut graph = SerializableGraph {
+            nodes: BTreeMap::new(),
+            edges: BTreeMap::new(),
+            meta: HashMap::new(),
+            metadata: HashMap::new(),
+        };
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: JsonlRecord = serde_json::from_str(&line)
+                .map_err(|e| format!("line {}: {}", line_number + 1, e))?;
+            match record {
+                JsonlRecord::Metadata { meta, metadata } => {
+                    graph.meta.extend(meta);
+                    graph.metadata.extend(metadata);
+                }
+                JsonlRecord::Node(node) => {
+                    graph.nodes.insert(node.id.clone(), node);
+                }
+                JsonlRecord::Edge(edge) => {
+                    graph.edges.insert(edge.id.clone(), edge);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
     /// Save graph to binary file (more efficient for large graphs)
     pub fn save_to_binary<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        self.write_binary_file(path, 0)
+    }
+
+    /// Write the header with `flags` followed by the uncompressed payload.
+    fn write_binary_file<P: AsRef<Path>>(&self, path: P, flags: u32) -> Result<(), Box<dyn std::error::Error>> {
         let file = File::create(path)?;
-        let writer = BufWriter::new(file);
+        let mut writer = BufWriter::new(file);
+        write_binary_header(&mut writer, flags)?;
+        self.write_binary_payload(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Save graph to binary file with the payload compressed by zstd at `level`
+    pub fn save_to_binary_zstd<P: AsRef<Path>>(&self, path: P, level: i32) -> Result<(), Box<dyn std::error::Error>> {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        write_binary_header(&mut writer, BINARY_FLAG_ZSTD)?;
+        let mut encoder = zstd::stream::write::Encoder::new(writer, level)?;
+        self.write_binary_payload(&mut encoder)?;
+        encoder.finish()?.flush()?;
+        Ok(())
+    }
+
+    /// Stream the bincode encoding of this graph into `writer`.
+    fn write_binary_payload<W: Write>(&self, writer: W) -> Result<(), Box<dyn std::error::Error>> {
         let options = bincode::DefaultOptions::new().with_fixint_encoding();
         let mut serializer = bincode::Serializer::new(writer, options);
         let mut st = serializer.serialize_struct("SerializableGraph", 4)?;
         st.serialize_field("nodes", &self.nodes)?;
         st.serialize_field("edges", &self.edges)?;
-        st.serialize_field("meta", &self.meta)?;
-        st.serialize_field("metadata", &self.metadata)?;
+        st.serialize_field("meta", &sorted(&self.meta))?;
+        st.serialize_field("metadata", &sorted(&self.metadata))?;
         st.end()?;
         Ok(())
     }
 
-    /// Load graph from binary file
+    /// Decode one bincode payload written with format `version`.
+    fn read_binary_payload<R: Read>(reader: R, version: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        let options = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .allow_trailing_bytes();
+        if version >= BINARY_SUBGRAPH_VERSION {
+            Ok(options.deserialize_from(reader)?)
+        } else if version >= BINARY_TAGS_VERSION {
+            let legacy: LegacySerializableGraph<SerializableNodeV3> = options.deserialize_from(reader)?;
+            Ok(legacy.into())
+        } else {
+            let legacy: LegacySerializableGraph<SerializableNodeV2> = options.deserialize_from(reader)?;
+            Ok(legacy.into())
+        }
+    }
+
+    /// Append this graph to an existing binary file as a delta.
+    ///
+    /// Each delta is stored after the base payload as a little-endian `u64`
+    /// byte length followed by the bincode-encoded graph, so the existing
+    /// contents never need rewriting. `edges` pairs this graph's edges with
+    /// their ids, as returned by `from_vertex_with_edges`.
+    ///
+    /// If `snapshot` describes this file, only the nodes, edges and meta that
+    /// changed since are written, and nothing at all if none did. Otherwise
+    /// the whole graph is appended and all of its edges are added on load.
+    /// Creates a plain binary file if `path` does not exist yet; an existing
+    /// file must carry a valid header and must not be zstd-compressed.
+    ///
+    /// Returns the snapshot describing the file afterwards, or `None` after
+    /// appending a whole graph, whose edge ids are only settled on load.
+    pub fn append_to_binary<P: AsRef<Path>>(
+        self,
+        path: P,
+        edges: EdgeIds,
+        snapshot: Option<&BinarySnapshot>,
+    ) -> Result<Option<BinarySnapshot>, Box<dyn std::error::Error>> {
+        let path = path.as_ref();
+        if !path.exists() {
+            self.save_to_binary(path)?;
+            return Ok(Some(BinarySnapshot::capture(path, &self, edges)?));
+        }
+        let header = read_binary_header(&mut File::open(path)?)?;
+        if header.has(BINARY_FLAG_ZSTD) {
+            return Err("cannot append to a zstd-compressed binary file".into());
+        }
+        if header.version != BINARY_FORMAT_VERSION {
+            return Err(format!(
+                "cannot append to a version {} binary file; load it and save it again first",
+                header.version
+            )
+            .into());
+        }
+
+        let snapshot = match snapshot {
+            Some(snapshot) if snapshot.path == path.canonicalize()? => snapshot,
+            _ => {
+                self.write_binary_delta(path)?;
+                return Ok(None);
+            }
+        };
+        if snapshot.stamp != FileStamp::of(path)? {
+            return Err("the file changed since this graph last saved, loaded or appended it; \
+                        load it again before appending"
+                .into());
+        }
+        let (delta, graph, edges) = snapshot.changes(self, edges)?;
+        if let Some(delta) = delta {
+            delta.write_binary_delta(path)?;
+        }
+        Ok(Some(BinarySnapshot::capture(path, &graph, edges)?))
+    }
+
+    /// Write this graph as one length-prefixed delta at the end of `path`.
+    fn write_binary_delta(&self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let payload = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialize(self)?;
+        let file = OpenOptions::new().append(true).open(path)?;
+        let mut writer = BufWriter::new(file);
+        writer.write_all(&(payload.len() as u64).to_le_bytes())?;
+        writer.write_all(&payload)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Load graph from binary file, merging any deltas written by `append_to_binary`
+    ///
+    /// Compressed and f16 payloads are detected from the header; f16 values
+    /// are widened back to full-precision floats.
     pub fn load_from_binary<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::load_from_binary_requiring(path, 0)
+    }
+
+    /// Like `load_from_binary`, but fail unless the header sets every bit of `required_flags`.
+    pub fn load_from_binary_requiring<P: AsRef<Path>>(
+        path: P,
+        required_flags: u32,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let graph = bincode::deserialize_from(reader)?;
+        let mut reader = BufReader::new(file);
+        let header = read_binary_header(&mut reader)?;
+        if !header.has(required_flags) {
+            return Err(format!(
+                "binary file is {}, expected {}",
+                describe_binary_flags(header.flags),
+                describe_binary_flags(required_flags)
+            )
+            .into());
+        }
+        let mut graph = if header.has(BINARY_FLAG_ZSTD) {
+            let decoder = zstd::stream::read::Decoder::with_buffer(reader)?.single_frame();
+            Self::read_binary_payload(decoder, header.version)?
+        } else {
+            let mut graph = Self::read_binary_payload(&mut reader, header.version)?;
+            while !reader.fill_buf()?.is_empty() {
+                let mut len_buf = [0u8; 8];
+                reader.read_exact(&mut len_buf)?;
+                let mut payload = vec![0u8; u64::from_le_bytes(len_buf) as usize];
+                reader.read_exact(&mut payload)?;
+                let delta = Self::read_binary_payload(payload.as_slice(), header.version)?;
+                graph.merge(delta);
+            }
+            graph
+        };
+
+        if header.has(BINARY_FLAG_F16) {
+            graph.convert_f16_to_floats();
+        }
         Ok(graph)
     }
 
-    /// Convert all Float values to Half (f16)
-    pub fn convert_floats_to_f16(&mut self) {
-        for node in self.nodes.values_mut() {
-            for value in node.attr.values_mut() {
-                value.to_f16();
+    /// Apply a delta written by `append_to_binary`.
+    ///
+    /// Records are matched by id only, never by content, so a delta edge is
+    /// either a new edge or, in a delta from a graph that tracked this file
+    /// (see `BinarySnapshot`), a new version of the stored edge with that id.
+    pub fn merge(&mut self, delta: SerializableGraph) {
+        if delta.metadata.contains_key(TRACKED_DELTA_KEY) {
+            self.merge_tracked(delta);
+        } else {
+            self.merge_whole(delta);
+        }
+        self.metadata.insert("node_count".to_string(), SerializableValue::Int(self.nodes.len() as i64));
+        self.metadata.insert("edge_count".to_string(), SerializableValue::Int(self.edges.len() as i64));
+    }
+
+    /// Union a whole appended graph into this one.
+    ///
+    /// Every delta edge is added, even one equal to an existing edge, since
+    /// parallel edges may carry the same attributes; ids that collide with
+    /// existing ones are renamed, as every serialization numbers its edges
+    /// from zero. Nodes present in both keep their edges from each side and
+    /// take attribute and meta values from `delta` on conflicting keys.
+    fn merge_whole(&mut self, delta: SerializableGraph) {
+        let mut renamed = HashMap::new();
+        for (edge_id, mut edge) in delta.edges {
+            let mut new_id = edge_id.clone();
+            let mut counter = self.edges.len();
+            while self.edges.contains_key(&new_id) {
+                new_id = format!("edge_{}_{}_to_{}", counter, edge.from_id, edge.to_id);
+                counter += 1;
+            }
+            edge.id = new_id.clone();
+            self.link_base_endpoints(&edge, &delta.nodes);
+            renamed.insert(edge_id, new_id.clone());
+            self.edges.insert(new_id, edge);
+        }
+
+        for (node_id, mut node) in delta.nodes {
+            for edge_id in node.edge_ids.iter_mut().chain(node.inverse_edge_ids.iter_mut()) {
+                if let Some(new_id) = renamed.get(edge_id) {
+                    *edge_id = new_id.clone();
+                }
             }
-            for value in node.meta.values_mut() {
-                value.to_f16();
+            match self.nodes.get_mut(&node_id) {
+                Some(existing) => {
+                    existing.attr.extend(node.attr);
+                    existing.meta.extend(node.meta);
+                    existing.edge_ids.extend(node.edge_ids);
+                    existing.inverse_edge_ids.extend(node.inverse_edge_ids);
+                    existing.tags.extend(node.tags);
+                    existing.tags.sort();
+                    existing.tags.dedup();
+                    if node.subgraph.is_some() {
+                        existing.subgraph = node.subgraph;
+                    }
+                }
+                None => {
+                    self.nodes.insert(node_id, node);
+                }
             }
         }
-        for edge in self.edges.values_mut() {
-            for value in edge.attr.values_mut() {
-                value.to_f16();
+
+        self.meta.extend(delta.meta);
+    }
+
+    /// Apply the changes a tracking graph appended: delta edges replace the
+    /// stored edge with the same id or are added, and delta nodes and meta
+    /// replace the stored ones outright, so removed keys stay removed.
+    fn merge_tracked(&mut self, delta: SerializableGraph) {
+        for (edge_id, edge) in delta.edges {
+            if !self.edges.contains_key(&edge_id) {
+                self.link_base_endpoints(&edge, &delta.nodes);
+            }
+            self.edges.insert(edge_id, edge);
+        }
+        self.nodes.extend(delta.nodes);
+        self.meta = delta.meta;
+    }
+
+    /// List a newly added `edge` on those endpoints that only exist in this
+    /// graph; endpoints in `delta_nodes` already list it.
+    fn link_base_endpoints(&mut self, edge: &SerializableEdge, delta_nodes: &BTreeMap<String, SerializableNode>) {
+        if !delta_nodes.contains_key(&edge.from_id) {
+            if let Some(node) = self.nodes.get_mut(&edge.from_id) {
+                node.edge_ids.push(edge.id.clone());
             }
-            for value in edge.meta.values_mut() {
-                value.to_f16();
+        }
+        if !delta_nodes.contains_key(&edge.to_id) {
+            if let Some(node) = self.nodes.get_mut(&edge.to_id) {
+                node.inverse_edge_ids.push(edge.id.clone());
+            }
+        }
+    }
+
+    /// Apply `f` to every attribute, meta and metadata value.
+    fn for_each_value_mut(&mut self, f: &dyn Fn(&mut SerializableValue)) {
+        for node in self.nodes.values_mut() {
+            node.attr.values_mut().for_each(f);
+            node.meta.values_mut().for_each(f);
+            if let Some(subgraph) = node.subgraph.as_mut() {
+                subgraph.for_each_value_mut(f);
             }
         }
-        for value in self.meta.values_mut() {
-            value.to_f16();
+        for edge in self.edges.values_mut() {
+            edge.attr.values_mut().for_each(f);
+            edge.meta.values_mut().for_each(f);
+        }
+        self.meta.values_mut().for_each(f);
+        self.metadata.values_mut().for_each(f);
+    }
+
+    /// Parse numeric strings in every node and edge `attr`, including nested
+    /// subgraphs. Meta and metadata are left alone.
+    pub fn coerce_numeric_attrs(&mut self) {
+        for node in self.nodes.values_mut() {
+            node.attr.values_mut().for_each(SerializableValue::coerce_numeric);
+            if let Some(subgraph) = node.subgraph.as_mut() {
+                subgraph.coerce_numeric_attrs();
+            }
         }
-        for value in self.metadata.values_mut() {
-            value.to_f16();
+        for edge in self.edges.values_mut() {
+            edge.attr.values_mut().for_each(SerializableValue::coerce_numeric);
         }
     }
 
+    /// Convert all Float values to Half (f16)
+    pub fn convert_floats_to_f16(&mut self) {
+        self.for_each_value_mut(&SerializableValue::to_f16);
+    }
+
+    /// Convert all Half values back to Float, keeping the f16 rounding
+    pub fn convert_f16_to_floats(&mut self) {
+        self.for_each_value_mut(&SerializableValue::widen_f16);
+    }
+
     /// Save graph to binary using f16 for floats
+    ///
+    /// The header carries `BINARY_FLAG_F16` so loaders know the floats were
+    /// downcast.
     pub fn save_to_binary_f16<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
         let mut graph = self.clone();
         graph.convert_floats_to_f16();
-        graph.save_to_binary(path)
+        graph.write_binary_file(path, BINARY_FLAG_F16)
+    }
+}
+
+/// Edges paired with the id they are serialized under.
+pub type EdgeIds = Vec<(Py<Edge>, String)>;
+
+/// `metadata` key marking a delta appended by a graph that tracked the file
+/// through a `BinarySnapshot`; `merge` then matches its edges by id and lets
+/// its nodes and meta replace the stored ones.
+const TRACKED_DELTA_KEY: &str = "tracked_delta";
+
+/// Length and modification time of a file, to notice other writers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl FileStamp {
+    fn of(path: &Path) -> std::io::Result<Self> {
+        let metadata = std::fs::metadata(path)?;
+        Ok(FileStamp { len: metadata.len(), modified: metadata.modified().ok() })
+    }
+}
+
+/// What a binary file holds, as a `Vertex` last saved, loaded or appended it.
+///
+/// `append_to_binary` compares the graph against this to write only what
+/// changed. Nodes are known by id and edges by object, each with the id it is
+/// stored under, so parallel edges with equal attributes stay distinct. Only
+/// content hashes are kept, not the records themselves.
+pub struct BinarySnapshot {
+    path: PathBuf,
+    stamp: FileStamp,
+    nodes: HashMap<String, u64>,
+    edges: HashMap<usize, StoredEdge>,
+    meta: u64,
+}
+
+struct StoredEdge {
+    /// Held so the address keying the snapshot cannot be reused.
+    edge: Py<Edge>,
+    id: String,
+    from_id: String,
+    to_id: String,
+    digest: u64,
+}
+
+impl BinarySnapshot {
+    /// Record that the file at `path` now holds `graph`, whose records `edges`
+    /// are stored under the paired ids.
+    pub fn capture(path: &Path, graph: &SerializableGraph, edges: EdgeIds) -> std::io::Result<Self> {
+        let edges = edges
+            .into_iter()
+            .filter_map(|(edge, id)| {
+                let record = graph.edges.get(&id)?;
+                let stored = StoredEdge {
+                    digest: edge_digest(record),
+                    from_id: record.from_id.clone(),
+                    to_id: record.to_id.clone(),
+                    edge,
+                    id,
+                };
+                Some((stored.edge.as_ptr() as usize, stored))
+            })
+            .collect();
+        Ok(BinarySnapshot {
+            path: path.canonicalize()?,
+            stamp: FileStamp::of(path)?,
+            nodes: graph.nodes.iter().map(|(id, node)| (id.clone(), node_digest(node))).collect(),
+            edges,
+            meta: meta_digest(&graph.meta),
+        })
     }
+
+    /// Compare `graph` (with `edges` from `from_vertex_with_edges`) against
+    /// the file. Returns the delta to append, `None` if nothing changed, plus
+    /// `graph` and `edges` renamed to the ids the file knows them by; new
+    /// edges get fresh ids. Fails if a stored node or edge is gone, since a
+    /// delta cannot remove anything.
+    fn changes(
+        &self,
+        mut graph: SerializableGraph,
+        edges: EdgeIds,
+    ) -> Result<(Option<SerializableGraph>, SerializableGraph, EdgeIds), Box<dyn std::error::Error>> {
+        const REMOVED: &str = "since the file was written; appending cannot remove anything, save the graph instead";
+        if let Some(id) = self.nodes.keys().find(|id| !graph.nodes.contains_key(*id)) {
+            return Err(format!("node '{}' was removed {}", id, REMOVED).into());
+        }
+
+        let mut used: HashSet<String> = self.edges.values().map(|stored| stored.id.clone()).collect();
+        let mut counter = used.len();
+        let mut seen = HashSet::new();
+        let mut changed = HashSet::new();
+        let mut renamed = HashMap::new();
+        for (edge, serialized_id) in &edges {
+            let record = &graph.edges[serialized_id];
+            let key = edge.as_ptr() as usize;
+            let id = match self.edges.get(&key).filter(|stored| stored.edge.is(edge)) {
+                Some(stored) if stored.from_id == record.from_id && stored.to_id == record.to_id => {
+                    seen.insert(key);
+                    if stored.digest != edge_digest(record) {
+                        changed.insert(stored.id.clone());
+                    }
+                    stored.id.clone()
+                }
+                _ => {
+                    let mut id = serialized_id.clone();
+                    while used.contains(&id) {
+                        id = format!("edge_{}_{}_to_{}", counter, record.from_id, record.to_id);
+                        counter += 1;
+                    }
+                    used.insert(id.clone());
+                    changed.insert(id.clone());
+                    id
+                }
+            };
+            renamed.insert(serialized_id.clone(), id);
+        }
+        if let Some(stored) = self.edges.iter().find(|(key, _)| !seen.contains(*key)).map(|(_, stored)| stored) {
+            return Err(format!(
+                "the edge from '{}' to '{}' was removed or moved {}",
+                stored.from_id, stored.to_id, REMOVED
+            )
+            .into());
+        }
+
+        graph.edges = std::mem::take(&mut graph.edges)
+            .into_values()
+            .map(|mut edge| {
+                edge.id = renamed[&edge.id].clone();
+                (edge.id.clone(), edge)
+            })
+            .collect();
+        for node in graph.nodes.values_mut() {
+            for id in node.edge_ids.iter_mut().chain(node.inverse_edge_ids.iter_mut()) {
+                *id = renamed[id.as_str()].clone();
+            }
+        }
+        let edges = edges
+            .into_iter()
+            .map(|(edge, serialized_id)| (edge, renamed[&serialized_id].clone()))
+            .collect();
+
+        let nodes: BTreeMap<String, SerializableNode> = graph
+            .nodes
+            .iter()
+            .filter(|(id, node)| self.nodes.get(*id) != Some(&node_digest(node)))
+            .map(|(id, node)| (id.clone(), node.clone()))
+            .collect();
+        let changed_edges: BTreeMap<String, SerializableEdge> = graph
+            .edges
+            .iter()
+            .filter(|(id, _)| changed.contains(*id))
+            .map(|(id, edge)| (id.clone(), edge.clone()))
+            .collect();
+        if nodes.is_empty() && changed_edges.is_empty() && meta_digest(&graph.meta) == self.meta {
+            return Ok((None, graph, edges));
+        }
+        let mut metadata = graph.metadata.clone();
+        metadata.insert(TRACKED_DELTA_KEY.to_string(), SerializableValue::Bool(true));
+        let delta = SerializableGraph { nodes, edges: changed_edges, meta: graph.meta.clone(), metadata };
+        Ok((Some(delta), graph, edges))
+    }
+}
+
+/// Hash of a node's content, leaving out the ids of its edges.
+fn node_digest(node: &SerializableNode) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hash_node_content(node, &mut hasher);
+    hasher.finish()
+}
+
+fn hash_node_content<H: Hasher>(node: &SerializableNode, state: &mut H) {
+    sorted(&node.attr).hash(state);
+    sorted(&node.meta).hash(state);
+    node.tags.hash(state);
+    match &node.subgraph {
+        Some(subgraph) => hash_graph(subgraph, state),
+        None => 0u8.hash(state),
+    }
+}
+
+/// Hash of a whole nested graph, edge ids included.
+fn hash_graph<H: Hasher>(graph: &SerializableGraph, state: &mut H) {
+    1u8.hash(state);
+    for (id, node) in &graph.nodes {
+        id.hash(state);
+        hash_node_content(node, state);
+        node.edge_ids.hash(state);
+        node.inverse_edge_ids.hash(state);
+    }
+    for (id, edge) in &graph.edges {
+        id.hash(state);
+        edge_digest(edge).hash(state);
+    }
+    sorted(&graph.meta).hash(state);
+}
+
+/// Hash of an edge's endpoints and content, leaving out its id.
+fn edge_digest(edge: &SerializableEdge) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    edge.from_id.hash(&mut hasher);
+    edge.to_id.hash(&mut hasher);
+    sorted(&edge.attr).hash(&mut hasher);
+    sorted(&edge.meta).hash(&mut hasher);
+    hasher.finish()
+}
+
+fn meta_digest(meta: &HashMap<String, SerializableValue>) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    sorted(meta).hash(&mut hasher);
+    hasher.finish()
 }
 
 // Add chrono for timestamps
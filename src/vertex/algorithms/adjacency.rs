@@ -0,0 +1,168 @@
+// vertex/algorithms/adjacency.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::Edge;
+use crate::node::edge_matches_filter;
+use crate::errors::{missing_node, GraphError};
+use super::super::core::Vertex;
+
+/// Pure-Rust snapshot of a vertex's topology.
+///
+/// Nodes are addressed by their position in `ids`, which is sorted so that
+/// algorithms built on top of the snapshot break ties reproducibly. Outgoing
+/// edges keep the order of the node's `edges` list. Building the snapshot is
+/// the only step that needs the GIL.
+pub struct Adjacency {
+    pub ids: Vec<String>,
+    pub index: HashMap<String, usize>,
+    /// Outgoing edges per node as `(target, weight)`
+    pub out: Vec<Vec<(usize, f64)>>,
+}
+
+impl Adjacency {
+    /// Snapshot `vertex`, reading edge weights from `weight_field` when given.
+    ///
+    /// Edges pointing at nodes outside the vertex (e.g. left over after
+    /// `from_nodes`) are ignored.
+    pub fn from_vertex(vertex: &Vertex, py: Python<'_>, weight_field: Option<&str>) -> PyResult<Self> {
+        Self::from_vertex_filtered(vertex, py, weight_field, &None)
+    }
+
+    /// Like `from_vertex`, keeping only edges that match `edge_filter` (see
+    /// `Vertex.shortest_path_bfs`).
+    pub fn from_vertex_filtered(
+        vertex: &Vertex,
+        py: Python<'_>,
+        weight_field: Option<&str>,
+        edge_filter: &Option<HashMap<String, Py<PyAny>>>,
+    ) -> PyResult<Self> {
+        let mut ids: Vec<String> = vertex.nodes.keys().cloned().collect();
+        ids.sort();
+        let index: HashMap<String, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(i, id)| (id.clone(), i))
+            .collect();
+
+        let mut out = vec![Vec::new(); ids.len()];
+        for (from_idx, id) in ids.iter().enumerate() {
+            let node_ref = vertex.nodes[id].bind(py).borrow();
+            for edge in &node_ref.edges {
+                if !edge_matches_filter(py, edge, edge_filter, &None)? {
+                    continue;
+                }
+                let edge_ref = edge.bind(py).borrow();
+                let to_id = edge_ref.to_node.bind(py).borrow().id.clone();
+                if let Some(&to_idx) = index.get(&to_id) {
+                    let weight = edge_weight(py, &edge_ref, weight_field)?;
+                    out[from_idx].push((to_idx, weight));
+                }
+            }
+        }
+
+        Ok(Adjacency { ids, index, out })
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Look up the index of a node, raising ``NodeNotFoundError`` if it is missing.
+    pub fn require(&self, id: &str) -> PyResult<usize> {
+        self.index.get(id).copied().ok_or_else(|| missing_node(id))
+    }
+
+    /// The same snapshot with every edge reversed, keeping its weight.
+    pub fn reversed(&self) -> Adjacency {
+        let mut out = vec![Vec::new(); self.len()];
+        for (from_idx, edges) in self.out.iter().enumerate() {
+            for &(to_idx, weight) in edges {
+                out[to_idx].push((from_idx, weight));
+            }
+        }
+        Adjacency {
+            ids: self.ids.clone(),
+            index: self.index.clone(),
+            out,
+        }
+    }
+
+    /// The same snapshot with every edge also usable against its direction,
+    /// keeping its weight both ways.
+    pub fn undirected(&self) -> Adjacency {
+        let mut out = self.reversed().out;
+        for (list, forward) in out.iter_mut().zip(&self.out) {
+            list.extend(forward.iter().copied());
+        }
+        Adjacency {
+            ids: self.ids.clone(),
+            index: self.index.clone(),
+            out,
+        }
+    }
+
+    /// Neighbours of each node with edge direction ignored.
+    ///
+    /// Lists are sorted and deduplicated; self-loops are dropped.
+    pub fn undirected_neighbors(&self) -> Vec<Vec<usize>> {
+        let mut neighbors = vec![Vec::new(); self.len()];
+        for (from_idx, edges) in self.out.iter().enumerate() {
+            for &(to_idx, _) in edges {
+                if from_idx != to_idx {
+                    neighbors[from_idx].push(to_idx);
+                    neighbors[to_idx].push(from_idx);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+            list.dedup();
+        }
+        neighbors
+    }
+
+    /// Raise ``GraphError`` if any edge weight is negative.
+    pub fn ensure_non_negative(&self) -> PyResult<()> {
+        for (from_idx, edges) in self.out.iter().enumerate() {
+            if let Some((to_idx, w)) = edges.iter().find(|(_, w)| *w < 0.0) {
+                return Err(GraphError::new_err(format!(
+                    "Negative edge weight {} on edge '{}' -> '{}'",
+                    w, self.ids[from_idx], self.ids[*to_idx]
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read the numeric weight of `edge`.
+///
+/// Without a `weight_field` every edge weighs 1.0; a missing attribute also
+/// counts as 1.0, while a non-numeric value raises ``TypeError``.
+pub fn edge_weight(py: Python<'_>, edge: &Edge, weight_field: Option<&str>) -> PyResult<f64> {
+    attr_weight(py, &edge.attr, weight_field, "Edge")
+}
+
+/// Read a numeric weight from an attribute map, following the rules of
+/// `edge_weight`. `owner` names the item kind in the error message.
+pub fn attr_weight(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    weight_field: Option<&str>,
+    owner: &str,
+) -> PyResult<f64> {
+    let field = match weight_field {
+        Some(field) => field,
+        None => return Ok(1.0),
+    };
+    match attr.get(field) {
+        Some(value) => value.extract::<f64>(py).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "{} attribute '{}' must be numeric to be used as a weight",
+                owner, field
+            ))
+        }),
+        None => Ok(1.0),
+    }
+}
@@ -0,0 +1,61 @@
+// vertex/algorithms/batch_shortest_paths.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use std::collections::HashMap;
+use crate::Path;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::progress::{run_monitored, Monitor};
+use super::sssp::{single_source_within, PathLimits, ShortestPaths};
+
+#[allow(clippy::too_many_arguments)]
+pub fn batch_shortest_paths(
+    vertex: &Vertex,
+    py: Python<'_>,
+    pairs: Vec<(String, String)>,
+    weight_field: Option<String>,
+    limits: PathLimits,
+    parallel: bool,
+    monitor: Monitor<'_, '_>,
+    edge_filter: Option<HashMap<String, Py<PyAny>>>,
+) -> PyResult<Py<PyList>> {
+    let adj = Adjacency::from_vertex_filtered(vertex, py, weight_field.as_deref(), &edge_filter)?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+
+    // Resolve every pair up front so a bad id fails before any work is done
+    let mut index_pairs = Vec::with_capacity(pairs.len());
+    for (source, target) in &pairs {
+        index_pairs.push((adj.require(source)?, adj.require(target)?));
+    }
+
+    // One SSSP run per distinct source, shared by all pairs starting there
+    let mut sources: Vec<usize> = index_pairs.iter().map(|(s, _)| *s).collect();
+    sources.sort_unstable();
+    sources.dedup();
+
+    let runs: Vec<ShortestPaths> = run_monitored(py, sources.len(), parallel, monitor, |i| {
+        single_source_within(&adj, sources[i], weighted, limits)
+    })?;
+    let by_source: HashMap<usize, &ShortestPaths> =
+        runs.iter().map(|run| (run.source, run)).collect();
+
+    let result = PyList::empty(py);
+    for (source, target) in index_pairs {
+        match by_source[&source].path_to(target) {
+            Some(path) => {
+                let nodes = path
+                    .iter()
+                    .map(|&i| vertex.nodes[&adj.ids[i]].clone_ref(py))
+                    .collect();
+                result.append(Py::new(py, Path { nodes })?)?;
+            }
+            None => result.append(py.None())?,
+        }
+    }
+
+    Ok(result.into())
+}
@@ -0,0 +1,71 @@
+// vertex/algorithms/bellman_ford.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+
+/// Single-source distances that allow negative edge weights.
+///
+/// Bellman-Ford over the summed `weight_field` (1.0 per edge without it),
+/// stopping early once a round changes nothing. Only nodes reachable from
+/// `root_node_id` are returned. A negative cycle reachable from the root
+/// raises `GraphError` naming a node on that cycle.
+pub fn bellman_ford(
+    vertex: &Vertex,
+    py: Python<'_>,
+    root_node_id: &str,
+    weight_field: &str,
+) -> PyResult<HashMap<String, f64>> {
+    let adj = Adjacency::from_vertex(vertex, py, Some(weight_field))?;
+    let root = adj.require(root_node_id)?;
+
+    let (dist, cycle_node) = py.allow_threads(|| relax(&adj, root));
+    if let Some(idx) = cycle_node {
+        return Err(GraphError::new_err(format!(
+            "Negative cycle through node '{}' is reachable from '{}'",
+            adj.ids[idx], root_node_id
+        )));
+    }
+    Ok(adj
+        .ids
+        .iter()
+        .zip(dist)
+        .filter_map(|(id, d)| d.map(|d| (id.clone(), d)))
+        .collect())
+}
+
+/// Distances from `root`, plus a node on a reachable negative cycle if any.
+fn relax(adj: &Adjacency, root: usize) -> (Vec<Option<f64>>, Option<usize>) {
+    let n = adj.len();
+    let mut dist: Vec<Option<f64>> = vec![None; n];
+    let mut pred: Vec<Option<usize>> = vec![None; n];
+    dist[root] = Some(0.0);
+
+    // n - 1 rounds settle every shortest path; a change in round n means a cycle
+    for round in 0..n {
+        let mut changed = None;
+        for from_idx in 0..n {
+            let Some(base) = dist[from_idx] else { continue };
+            for &(to_idx, weight) in &adj.out[from_idx] {
+                let candidate = base + weight;
+                if dist[to_idx].is_none_or(|known| candidate < known) {
+                    dist[to_idx] = Some(candidate);
+                    pred[to_idx] = Some(from_idx);
+                    changed = Some(to_idx);
+                }
+            }
+        }
+        let Some(last) = changed else { return (dist, None) };
+        if round == n - 1 {
+            // Walking n predecessors back from a node still improving lands on the cycle
+            let mut idx = last;
+            for _ in 0..n {
+                idx = pred[idx].unwrap_or(idx);
+            }
+            return (dist, Some(idx));
+        }
+    }
+    (dist, None)
+}
@@ -0,0 +1,79 @@
+// vertex/algorithms/bfs_frontiers.rs
+
+use pyo3::prelude::*;
+use std::collections::HashSet;
+use crate::Node;
+use crate::errors::missing_node;
+use super::super::core::Vertex;
+
+/// Lazy BFS over live nodes, yielding the node ids of one level per step.
+///
+/// Only the current frontier and the visited set are kept, and the next level
+/// is computed when it is requested, so abandoning the iterator early skips
+/// the rest of the traversal.
+#[pyclass]
+pub struct BfsFrontiers {
+    frontier: Vec<Py<Node>>,
+    visited: HashSet<String>,
+    depth: usize,
+    max_depth: Option<usize>,
+    started: bool,
+}
+
+impl BfsFrontiers {
+    /// Replace the frontier with the unvisited successors of its nodes.
+    fn advance(&mut self, py: Python<'_>) {
+        let mut next = Vec::new();
+        for node in &self.frontier {
+            for edge in &node.borrow(py).edges {
+                let to_node = &edge.borrow(py).to_node;
+                let to_id = to_node.borrow(py).id.clone();
+                if self.visited.insert(to_id) {
+                    next.push(to_node.clone_ref(py));
+                }
+            }
+        }
+        self.frontier = next;
+        self.depth += 1;
+    }
+}
+
+#[pymethods]
+impl BfsFrontiers {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<Vec<String>> {
+        if self.started {
+            if self.max_depth.is_some_and(|max| self.depth >= max) {
+                self.frontier.clear();
+            } else {
+                self.advance(py);
+            }
+        }
+        self.started = true;
+
+        if self.frontier.is_empty() {
+            return None;
+        }
+        Some(self.frontier.iter().map(|node| node.borrow(py).id.clone()).collect())
+    }
+}
+
+/// Start a lazy BFS from `root_node_id`, see `BfsFrontiers`.
+pub fn bfs_frontiers(
+    vertex: &Vertex,
+    py: Python<'_>,
+    root_node_id: String,
+    max_depth: Option<usize>,
+) -> PyResult<BfsFrontiers> {
+    let root = vertex.nodes.get(&root_node_id).ok_or_else(|| missing_node(&root_node_id))?;
+    Ok(BfsFrontiers {
+        frontier: vec![root.clone_ref(py)],
+        visited: HashSet::from([root_node_id]),
+        depth: 0,
+        max_depth,
+        started: false,
+    })
+}
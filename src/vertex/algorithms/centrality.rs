@@ -0,0 +1,240 @@
+// vertex/algorithms/centrality.rs
+
+use pyo3::prelude::*;
+use rand::seq::index;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::progress::{run_monitored, Monitor};
+use super::sampling::make_rng;
+use super::sssp::{single_source, HeapEntry};
+
+/// Closeness centrality from incoming shortest-path distances.
+///
+/// For a node `u` that `r` other nodes can reach with total distance `d`, the
+/// plain score is `r / d`. With `wf_improved` it is scaled by `r / (n - 1)`
+/// (Wasserman-Faust), so nodes reachable only inside a small component no
+/// longer score as high as well-connected ones. Nodes nobody reaches score 0.
+pub fn closeness_centrality(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<String>,
+    wf_improved: bool,
+    monitor: Monitor<'_, '_>,
+) -> PyResult<HashMap<String, f64>> {
+    let n = vertex.nodes.len();
+    score_incoming(vertex, py, weight_field, monitor, |distances| {
+        let (reached, total) = distances
+            .fold((0usize, 0.0), |(count, sum), d| (count + 1, sum + d));
+        if total <= 0.0 || n <= 1 {
+            return 0.0;
+        }
+        let closeness = reached as f64 / total;
+        if wf_improved {
+            closeness * reached as f64 / (n - 1) as f64
+        } else {
+            closeness
+        }
+    })
+}
+
+/// Harmonic centrality: the sum of `1 / d` over the incoming shortest-path
+/// distances of every other node. Unreachable nodes contribute 0.
+pub fn harmonic_centrality(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<String>,
+    monitor: Monitor<'_, '_>,
+) -> PyResult<HashMap<String, f64>> {
+    score_incoming(vertex, py, weight_field, monitor, |distances| {
+        distances.filter(|&d| d > 0.0).map(|d| 1.0 / d).sum()
+    })
+}
+
+/// Score every node from the distances of the other nodes that reach it.
+///
+/// One SSSP run per node over the reversed snapshot, spread across threads
+/// without the GIL and reported to `monitor` per batch of nodes. `score`
+/// receives the finite distances, excluding the node itself.
+fn score_incoming<F>(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<String>,
+    monitor: Monitor<'_, '_>,
+    score: F,
+) -> PyResult<HashMap<String, f64>>
+where
+    F: Fn(&mut dyn Iterator<Item = f64>) -> f64 + Sync,
+{
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+    let reversed = adj.reversed();
+
+    let scores = run_monitored(py, reversed.len(), true, monitor, |idx| {
+        let run = single_source(&reversed, idx, weighted);
+        let mut distances = run
+            .dist
+            .iter()
+            .enumerate()
+            .filter(|&(other, _)| other != idx)
+            .filter_map(|(_, d)| *d);
+        score(&mut distances)
+    })?;
+
+    Ok(adj.ids.into_iter().zip(scores).collect())
+}
+
+/// Betweenness centrality estimated from `num_samples` random sources.
+///
+/// Runs Brandes' dependency accumulation from sources drawn without
+/// replacement and scales the summed dependencies by `n / num_samples`, so
+/// sampling every node gives the exact, unnormalized directed betweenness:
+/// the number of shortest paths between other ordered node pairs through a
+/// node, each pair split evenly over its shortest paths. Parallel edges count
+/// as distinct paths.
+pub fn betweenness_centrality_sampled(
+    vertex: &Vertex,
+    py: Python<'_>,
+    num_samples: usize,
+    seed: Option<u64>,
+    weight_field: Option<String>,
+    monitor: Monitor<'_, '_>,
+) -> PyResult<HashMap<String, f64>> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+    let n = adj.len();
+    if n == 0 {
+        return Ok(HashMap::new());
+    }
+    if num_samples == 0 || num_samples > n {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "num_samples must be between 1 and the node count ({}), got {}",
+            n, num_samples
+        )));
+    }
+
+    let mut sources = index::sample(&mut make_rng(seed), n, num_samples).into_vec();
+    sources.sort_unstable();
+    let runs = run_monitored(py, sources.len(), true, monitor, |i| {
+        dependencies(&adj, sources[i], weighted)
+    })?;
+
+    let mut scores = vec![0.0; n];
+    for run in runs {
+        for (idx, delta) in run {
+            scores[idx] += delta;
+        }
+    }
+    let scale = n as f64 / num_samples as f64;
+    Ok(adj.ids.into_iter().zip(scores.into_iter().map(|s| s * scale)).collect())
+}
+
+/// Brandes dependencies of `source` on every other node it reaches, as
+/// `(node, dependency)` pairs with a non-zero dependency.
+fn dependencies(adj: &Adjacency, source: usize, weighted: bool) -> Vec<(usize, f64)> {
+    let n = adj.len();
+    let mut dist = vec![f64::INFINITY; n];
+    let mut sigma = vec![0.0f64; n];
+    let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+    // Nodes in order of non-decreasing distance
+    let mut order = Vec::new();
+    dist[source] = 0.0;
+    sigma[source] = 1.0;
+
+    if weighted {
+        let mut settled = vec![false; n];
+        let mut heap = BinaryHeap::new();
+        heap.push(HeapEntry { cost: 0.0, node: source });
+        while let Some(HeapEntry { cost, node }) = heap.pop() {
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            order.push(node);
+            for &(next, weight) in &adj.out[node] {
+                let candidate = cost + weight;
+                if candidate < dist[next] {
+                    dist[next] = candidate;
+                    sigma[next] = sigma[node];
+                    preds[next] = vec![node];
+                    heap.push(HeapEntry { cost: candidate, node: next });
+                } else if candidate == dist[next] && !settled[next] {
+                    sigma[next] += sigma[node];
+                    preds[next].push(node);
+                }
+            }
+        }
+    } else {
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &(next, _) in &adj.out[node] {
+                if dist[next].is_infinite() {
+                    dist[next] = dist[node] + 1.0;
+                    queue.push_back(next);
+                }
+                if dist[next] == dist[node] + 1.0 {
+                    sigma[next] += sigma[node];
+                    preds[next].push(node);
+                }
+            }
+        }
+    }
+
+    let mut delta = vec![0.0f64; n];
+    let mut result = Vec::new();
+    for &node in order.iter().rev() {
+        for &pred in &preds[node] {
+            delta[pred] += sigma[pred] / sigma[node] * (1.0 + delta[node]);
+        }
+        if node != source && delta[node] != 0.0 {
+            result.push((node, delta[node]));
+        }
+    }
+    result
+}
+
+/// Probability of following an edge rather than teleporting in `pagerank`.
+const DAMPING: f64 = 0.85;
+/// `pagerank` stops once the scores change by less than this in total.
+const PAGERANK_TOLERANCE: f64 = 1e-10;
+const PAGERANK_MAX_ITERATIONS: usize = 100;
+
+/// PageRank of every node by power iteration, summing to 1.
+///
+/// Rank flows along outgoing edges in proportion to their weight, so parallel
+/// edges count once each. Nodes without outgoing edges spread their rank
+/// evenly over all nodes.
+pub(crate) fn pagerank(adj: &Adjacency) -> Vec<f64> {
+    let n = adj.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let totals: Vec<f64> = adj.out.iter().map(|edges| edges.iter().map(|&(_, w)| w).sum()).collect();
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..PAGERANK_MAX_ITERATIONS {
+        let dangling: f64 = (0..n).filter(|&u| totals[u] <= 0.0).map(|u| rank[u]).sum();
+        let base = (1.0 - DAMPING + DAMPING * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (u, edges) in adj.out.iter().enumerate() {
+            if totals[u] <= 0.0 {
+                continue;
+            }
+            for &(v, w) in edges {
+                next[v] += DAMPING * rank[u] * w / totals[u];
+            }
+        }
+        let change: f64 = rank.iter().zip(&next).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if change < PAGERANK_TOLERANCE {
+            break;
+        }
+    }
+    rank
+}
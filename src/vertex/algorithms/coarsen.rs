@@ -0,0 +1,87 @@
+// vertex/algorithms/coarsen.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::{BTreeMap, HashMap};
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::Adjacency;
+
+/// Contract every pair in `matching` at once.
+///
+/// Pairs sharing a node are unioned, so each connected group of pairs becomes
+/// a single super-node. A super-node is named after its smallest member id and
+/// carries `members` (sorted original ids) and `size`; untouched nodes become
+/// one-member super-nodes under their own id. Edges inside a super-node are
+/// dropped and parallel edges between two super-nodes are merged, with `count`
+/// holding how many original edges they replace. The result's
+/// `meta["coarse_to_original"]` maps every super-node id to its members.
+pub fn coarsen(
+    vertex: &Vertex,
+    py: Python<'_>,
+    matching: Vec<(String, String)>,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+
+    let mut parent: Vec<usize> = (0..adj.len()).collect();
+    for (a, b) in &matching {
+        let (a, b) = (adj.require(a)?, adj.require(b)?);
+        let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+        // Keep the smaller index as root so it names the group (ids are sorted)
+        if root_a < root_b {
+            parent[root_b] = root_a;
+        } else {
+            parent[root_a] = root_b;
+        }
+    }
+
+    let root_of: Vec<usize> = (0..adj.len()).map(|idx| find(&mut parent, idx)).collect();
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (idx, &root) in root_of.iter().enumerate() {
+        groups.entry(root).or_default().push(idx);
+    }
+
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let coarse_to_original = PyDict::new(py);
+    for (&root, members) in &groups {
+        let member_ids: Vec<&String> = members.iter().map(|&idx| &adj.ids[idx]).collect();
+        let mut attr = HashMap::new();
+        attr.insert("members".to_string(), member_ids.clone().into_pyobject(py)?.into_any().unbind());
+        attr.insert("size".to_string(), members.len().into_pyobject(py)?.into_any().unbind());
+        manipulation::add_node(&mut result, py, adj.ids[root].clone(), Some(attr))?;
+        coarse_to_original.set_item(&adj.ids[root], member_ids)?;
+    }
+
+    let mut links: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, _) in edges {
+            let (from_root, to_root) = (root_of[from_idx], root_of[to_idx]);
+            if from_root != to_root {
+                *links.entry((from_root, to_root)).or_default() += 1;
+            }
+        }
+    }
+    for ((from_root, to_root), count) in links {
+        let mut attr = HashMap::new();
+        attr.insert("count".to_string(), count.into_pyobject(py)?.into_any().unbind());
+        manipulation::add_edge(
+            &mut result,
+            py,
+            adj.ids[from_root].clone(),
+            adj.ids[to_root].clone(),
+            Some(attr),
+        )?;
+    }
+
+    result.meta.bind(py).set_item("coarse_to_original", coarse_to_original)?;
+    Py::new(py, result)
+}
+
+/// Union-find root lookup with path halving.
+fn find(parent: &mut [usize], mut idx: usize) -> usize {
+    while parent[idx] != idx {
+        parent[idx] = parent[parent[idx]];
+        idx = parent[idx];
+    }
+    idx
+}
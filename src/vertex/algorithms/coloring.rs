@@ -0,0 +1,120 @@
+// vertex/algorithms/coloring.rs
+
+use pyo3::prelude::*;
+use rand::seq::SliceRandom;
+use std::collections::{BTreeSet, HashMap};
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::sampling::make_rng;
+
+/// Order nodes by descending degree, ties broken by id.
+fn largest_first(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..neighbors.len()).collect();
+    order.sort_by_key(|&idx| std::cmp::Reverse(neighbors[idx].len()));
+    order
+}
+
+/// Repeatedly remove a node of minimum remaining degree; color in reverse.
+fn smallest_last(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut degree: Vec<usize> = neighbors.iter().map(|list| list.len()).collect();
+    let mut queue: BTreeSet<(usize, usize)> = degree.iter().enumerate().map(|(idx, &d)| (d, idx)).collect();
+    let mut removed = vec![false; neighbors.len()];
+    let mut order = Vec::with_capacity(neighbors.len());
+
+    while let Some((_, idx)) = queue.pop_first() {
+        removed[idx] = true;
+        order.push(idx);
+        for &nb in &neighbors[idx] {
+            if !removed[nb] {
+                queue.remove(&(degree[nb], nb));
+                degree[nb] -= 1;
+                queue.insert((degree[nb], nb));
+            }
+        }
+    }
+
+    order.reverse();
+    order
+}
+
+/// Smallest color not used by an already colored neighbour.
+fn first_free_color(neighbors: &[usize], colors: &[Option<usize>]) -> usize {
+    let mut used: Vec<usize> = neighbors.iter().filter_map(|&nb| colors[nb]).collect();
+    used.sort_unstable();
+    used.dedup();
+    used.iter().enumerate().find(|(i, c)| *i != **c).map_or(used.len(), |(i, _)| i)
+}
+
+/// DSatur: always color the node whose neighbours already use the most
+/// distinct colors, ties broken by degree and then id.
+fn saturation_largest_first(neighbors: &[Vec<usize>]) -> Vec<Option<usize>> {
+    let n = neighbors.len();
+    let mut colors: Vec<Option<usize>> = vec![None; n];
+    let mut seen: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+
+    for _ in 0..n {
+        let idx = (0..n)
+            .filter(|&idx| colors[idx].is_none())
+            .max_by(|&a, &b| {
+                seen[a].len()
+                    .cmp(&seen[b].len())
+                    .then(neighbors[a].len().cmp(&neighbors[b].len()))
+                    .then(b.cmp(&a))
+            })
+            .expect("an uncolored node remains");
+        let color = first_free_color(&neighbors[idx], &colors);
+        colors[idx] = Some(color);
+        for &nb in &neighbors[idx] {
+            seen[nb].insert(color);
+        }
+    }
+
+    colors
+}
+
+/// Greedy node coloring of the undirected view of `vertex`.
+///
+/// Returns the color of every node; adjacent nodes never share a color.
+pub fn greedy_coloring(
+    vertex: &Vertex,
+    py: Python<'_>,
+    strategy: &str,
+    seed: Option<u64>,
+) -> PyResult<HashMap<String, usize>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let neighbors = adj.undirected_neighbors();
+
+    let colors = match strategy {
+        "saturation_largest_first" | "dsatur" => saturation_largest_first(&neighbors),
+        _ => {
+            let order = match strategy {
+                "largest_first" => largest_first(&neighbors),
+                "smallest_last" => smallest_last(&neighbors),
+                "random_sequential" => {
+                    let mut order: Vec<usize> = (0..adj.len()).collect();
+                    order.shuffle(&mut make_rng(seed));
+                    order
+                }
+                other => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        "Unknown coloring strategy '{}', expected one of 'largest_first', \
+                         'smallest_last', 'random_sequential', 'saturation_largest_first'",
+                        other
+                    )))
+                }
+            };
+            let mut colors = vec![None; adj.len()];
+            for idx in order {
+                colors[idx] = Some(first_free_color(&neighbors[idx], &colors));
+            }
+            colors
+        }
+    };
+
+    Ok(adj
+        .ids
+        .into_iter()
+        .zip(colors)
+        .map(|(id, color)| (id, color.expect("every node is colored")))
+        .collect())
+}
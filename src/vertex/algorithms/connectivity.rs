@@ -0,0 +1,147 @@
+// vertex/algorithms/connectivity.rs
+
+use pyo3::prelude::*;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::scc::strongly_connected_components;
+use super::sssp::HeapEntry;
+
+/// True if ignoring edge direction leaves a single component.
+/// An empty graph is not connected.
+pub fn is_weakly_connected(vertex: &Vertex, py: Python<'_>) -> PyResult<bool> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    if adj.len() == 0 {
+        return Ok(false);
+    }
+
+    let neighbors = adj.undirected_neighbors();
+    let mut seen = vec![false; adj.len()];
+    let mut stack = vec![0];
+    seen[0] = true;
+    let mut reached = 1;
+    while let Some(idx) = stack.pop() {
+        for &nb in &neighbors[idx] {
+            if !seen[nb] {
+                seen[nb] = true;
+                reached += 1;
+                stack.push(nb);
+            }
+        }
+    }
+
+    Ok(reached == adj.len())
+}
+
+/// True if every node reaches every other node along edge directions.
+/// An empty graph is not connected.
+pub fn is_strongly_connected(vertex: &Vertex, py: Python<'_>) -> PyResult<bool> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    if adj.len() == 0 {
+        return Ok(false);
+    }
+    Ok(strongly_connected_components(&adj).len() == 1)
+}
+
+/// Nodes reachable from every source in `source_ids`.
+///
+/// Each source's descendants are the nodes reached along at least one edge,
+/// so a source only counts as its own descendant when it lies on a cycle.
+/// No sources give an empty set.
+pub fn common_reachable(
+    vertex: &Vertex,
+    py: Python<'_>,
+    source_ids: &[String],
+) -> PyResult<HashSet<String>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let sources = source_ids
+        .iter()
+        .map(|id| adj.require(id))
+        .collect::<PyResult<Vec<usize>>>()?;
+    if sources.is_empty() {
+        return Ok(HashSet::new());
+    }
+
+    let common = py.allow_threads(|| {
+        // Number of sources reaching each node
+        let mut hits = vec![0usize; adj.len()];
+        let mut seen = vec![usize::MAX; adj.len()];
+        for (round, &source) in sources.iter().enumerate() {
+            let mut queue: VecDeque<usize> = VecDeque::from([source]);
+            while let Some(idx) = queue.pop_front() {
+                for &(to_idx, _) in &adj.out[idx] {
+                    if seen[to_idx] != round {
+                        seen[to_idx] = round;
+                        hits[to_idx] += 1;
+                        queue.push_back(to_idx);
+                    }
+                }
+            }
+        }
+        hits.iter()
+            .enumerate()
+            .filter(|&(_, &count)| count == sources.len())
+            .map(|(idx, _)| idx)
+            .collect::<Vec<usize>>()
+    });
+
+    Ok(common.into_iter().map(|idx| adj.ids[idx].clone()).collect())
+}
+
+/// Highest path probability from `source_id` to every node it reaches with
+/// at least `min_prob`.
+///
+/// A path's probability is the product of its edges' `prob_field` values
+/// (missing values count as 1.0). Since products only shrink along a path,
+/// a best-first search settles each node at its best probability and drops
+/// any branch that falls below `min_prob`. The source maps to 1.0.
+pub fn probable_reachable(
+    vertex: &Vertex,
+    py: Python<'_>,
+    source_id: &str,
+    min_prob: f64,
+    prob_field: &str,
+) -> PyResult<HashMap<String, f64>> {
+    if !(0.0..=1.0).contains(&min_prob) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "min_prob must be in [0, 1], got {}",
+            min_prob
+        )));
+    }
+    let adj = Adjacency::from_vertex(vertex, py, Some(prob_field))?;
+    let source = adj.require(source_id)?;
+    if let Some(&(_, p)) = adj.out.iter().flatten().find(|&&(_, p)| !(0.0..=1.0).contains(&p)) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Edge attribute '{}' must be a probability in [0, 1], got {}",
+            prob_field, p
+        )));
+    }
+
+    let best = py.allow_threads(|| {
+        let mut best: Vec<Option<f64>> = vec![None; adj.len()];
+        let mut settled = vec![false; adj.len()];
+        best[source] = Some(1.0);
+        // Costs are negated probabilities, so the min-heap pops the likeliest node
+        let mut heap = BinaryHeap::from([HeapEntry { cost: -1.0, node: source }]);
+        while let Some(HeapEntry { cost, node }) = heap.pop() {
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+            for &(next, p) in &adj.out[node] {
+                let candidate = -cost * p;
+                if candidate >= min_prob && best[next].is_none_or(|b| candidate > b) {
+                    best[next] = Some(candidate);
+                    heap.push(HeapEntry { cost: -candidate, node: next });
+                }
+            }
+        }
+        best
+    });
+
+    Ok(best
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, p)| p.map(|p| (adj.ids[idx].clone(), p)))
+        .collect())
+}
@@ -0,0 +1,75 @@
+// vertex/algorithms/diameter.rs
+
+use pyo3::prelude::*;
+use std::collections::VecDeque;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+
+/// Lower bound on the diameter using repeated BFS sweeps, ignoring edge direction.
+///
+/// Each component is swept `iterations` times: BFS from a start node, then from
+/// the farthest node found, and so on. With two sweeps (double sweep) the result
+/// is exact for trees; on general graphs it can underestimate. The largest
+/// eccentricity seen in any component is returned, 0 for an empty graph.
+pub fn approximate_diameter(vertex: &Vertex, py: Python<'_>, iterations: usize) -> PyResult<usize> {
+    if iterations == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "iterations must be at least 1",
+        ));
+    }
+
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let neighbors = adj.undirected_neighbors();
+    let mut covered = vec![false; adj.len()];
+    let mut dist = vec![usize::MAX; adj.len()];
+    let mut best = 0;
+
+    for root in 0..adj.len() {
+        if covered[root] {
+            continue;
+        }
+        let mut start = root;
+        for _ in 0..iterations {
+            let (farthest, eccentricity) = sweep(&neighbors, start, &mut dist, &mut covered);
+            best = best.max(eccentricity);
+            start = farthest;
+        }
+    }
+
+    Ok(best)
+}
+
+/// BFS from `start`, returning the farthest node and its distance.
+/// Ties go to the lowest index so results are deterministic.
+fn sweep(
+    neighbors: &[Vec<usize>],
+    start: usize,
+    dist: &mut [usize],
+    covered: &mut [bool],
+) -> (usize, usize) {
+    let mut reached = vec![start];
+    let mut queue = VecDeque::from([start]);
+    dist[start] = 0;
+    let (mut farthest, mut max_dist) = (start, 0);
+
+    while let Some(idx) = queue.pop_front() {
+        covered[idx] = true;
+        let d = dist[idx];
+        if d > max_dist || (d == max_dist && idx < farthest) {
+            farthest = idx;
+            max_dist = d;
+        }
+        for &nb in &neighbors[idx] {
+            if dist[nb] == usize::MAX {
+                dist[nb] = d + 1;
+                reached.push(nb);
+                queue.push_back(nb);
+            }
+        }
+    }
+
+    for idx in reached {
+        dist[idx] = usize::MAX;
+    }
+    (farthest, max_dist)
+}
@@ -0,0 +1,534 @@
+// vertex/algorithms/diff.rs
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use crate::Edge;
+use crate::errors::{edge_not_found, missing_node, GraphError};
+use crate::serialization::SerializableValue;
+use super::super::callbacks;
+use super::super::core::Vertex;
+use super::super::manipulation;
+
+/// Identity of an edge for diffing: endpoints plus its `type` attribute.
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct EdgeKey {
+    from_id: String,
+    to_id: String,
+    edge_type: Option<SerializableValue>,
+}
+
+/// `type` attribute of an edge for matching; `None` counts as missing.
+fn edge_type(py: Python<'_>, value: Option<&Py<PyAny>>) -> PyResult<Option<SerializableValue>> {
+    match value {
+        Some(value) if !value.is_none(py) => Ok(Some(SerializableValue::from_python(py, value)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Edges of a graph grouped by `EdgeKey`.
+///
+/// Each group keeps its edges so that surplus ones can be reported with
+/// their attributes.
+struct EdgeGroups {
+    /// Keys in order of their first edge, taking nodes by sorted id
+    order: Vec<EdgeKey>,
+    groups: HashMap<EdgeKey, Vec<Py<Edge>>>,
+}
+
+fn edges_by_key(vertex: &Vertex, py: Python<'_>) -> PyResult<EdgeGroups> {
+    let mut order: Vec<EdgeKey> = Vec::new();
+    let mut groups: HashMap<EdgeKey, Vec<Py<Edge>>> = HashMap::new();
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    for id in ids {
+        for edge in &vertex.nodes[id].borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let key = EdgeKey {
+                from_id: id.clone(),
+                to_id: edge_ref.to_node.borrow(py).id.clone(),
+                edge_type: edge_type(py, edge_ref.attr.get("type"))?,
+            };
+            groups
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    order.push(key);
+                    Vec::new()
+                })
+                .push(edge.clone_ref(py));
+        }
+    }
+    Ok(EdgeGroups { order, groups })
+}
+
+fn copy_attr<'py>(py: Python<'py>, attr: &HashMap<String, Py<PyAny>>) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, value) in attr {
+        dict.set_item(key, value)?;
+    }
+    Ok(dict)
+}
+
+/// One edge of a diff: `{"from_id", "to_id", "type", "attr"}`.
+fn edge_entry<'py>(py: Python<'py>, edge: &Py<Edge>) -> PyResult<Bound<'py, PyDict>> {
+    let edge_ref = edge.borrow(py);
+    let entry = PyDict::new(py);
+    entry.set_item("from_id", &edge_ref.from_node.borrow(py).id)?;
+    entry.set_item("to_id", &edge_ref.to_node.borrow(py).id)?;
+    entry.set_item("type", edge_ref.attr.get("type").map(|t| t.clone_ref(py)))?;
+    entry.set_item("attr", copy_attr(py, &edge_ref.attr)?)?;
+    Ok(entry)
+}
+
+/// Describe how to get from `vertex` to `other`, matching nodes by id.
+///
+/// Returns a dict with
+/// - `added_nodes`: `{id: attr}` for nodes only in `other`
+/// - `removed_nodes`: sorted ids of nodes only in `vertex`
+/// - `added_edges` / `removed_edges`: edge entries (see `edge_entry`). Edges
+///   with the same source, target and `type` attribute are paired one to one
+///   by equal attributes; an edge whose other attributes changed shows up as
+///   a removed entry with the old attributes and an added one with the new.
+/// - `changed_attrs`: `{id: {key: {"old": ..., "new": ...}}}` for nodes in
+///   both graphs, comparing values with `==`. `"old"` is left out for an
+///   added key and `"new"` for a removed one.
+pub fn diff(vertex: &Vertex, py: Python<'_>, other: &Vertex) -> PyResult<Py<PyAny>> {
+    let ids: BTreeSet<&String> = vertex.nodes.keys().collect();
+    let other_ids: BTreeSet<&String> = other.nodes.keys().collect();
+
+    let added_nodes = PyDict::new(py);
+    for id in other_ids.difference(&ids) {
+        added_nodes.set_item(id, copy_attr(py, &other.nodes[*id].borrow(py).attr)?)?;
+    }
+    let removed_nodes: Vec<&String> = ids.difference(&other_ids).copied().collect();
+
+    let changed_attrs = PyDict::new(py);
+    for id in ids.intersection(&other_ids) {
+        let (old_attr, new_attr) = (&vertex.nodes[*id].borrow(py).attr, &other.nodes[*id].borrow(py).attr);
+        let keys: BTreeSet<&String> = old_attr.keys().chain(new_attr.keys()).collect();
+        let changes = PyDict::new(py);
+        for key in keys {
+            let (old, new) = (old_attr.get(key), new_attr.get(key));
+            if let (Some(old), Some(new)) = (old, new) {
+                if old.bind(py).eq(new.bind(py))? {
+                    continue;
+                }
+            }
+            let change = PyDict::new(py);
+            if let Some(old) = old {
+                change.set_item("old", old)?;
+            }
+            if let Some(new) = new {
+                change.set_item("new", new)?;
+            }
+            changes.set_item(key, change)?;
+        }
+        if !changes.is_empty() {
+            changed_attrs.set_item(id, changes)?;
+        }
+    }
+
+    let edges = edges_by_key(vertex, py)?;
+    let other_edges = edges_by_key(other, py)?;
+    let added_edges = PyList::empty(py);
+    for key in &other_edges.order {
+        let existing = edges.groups.get(key).map_or(&[][..], Vec::as_slice);
+        for edge in unpaired(py, &other_edges.groups[key], existing)? {
+            added_edges.append(edge_entry(py, edge)?)?;
+        }
+    }
+    let removed_edges = PyList::empty(py);
+    for key in &edges.order {
+        let remaining = other_edges.groups.get(key).map_or(&[][..], Vec::as_slice);
+        for edge in unpaired(py, &edges.groups[key], remaining)? {
+            removed_edges.append(edge_entry(py, edge)?)?;
+        }
+    }
+
+    let result = PyDict::new(py);
+    result.set_item("added_nodes", added_nodes)?;
+    result.set_item("removed_nodes", removed_nodes)?;
+    result.set_item("added_edges", added_edges)?;
+    result.set_item("removed_edges", removed_edges)?;
+    result.set_item("changed_attrs", changed_attrs)?;
+    Ok(result.into_any().unbind())
+}
+
+/// The edges of `edges` left over after pairing each with an unused edge of
+/// `others` that has equal attributes.
+fn unpaired<'a>(py: Python<'_>, edges: &'a [Py<Edge>], others: &[Py<Edge>]) -> PyResult<Vec<&'a Py<Edge>>> {
+    let mut available: Vec<&Py<Edge>> = others.iter().collect();
+    let mut left = Vec::new();
+    for edge in edges {
+        let edge_ref = edge.borrow(py);
+        let mut found = None;
+        for (pos, other) in available.iter().enumerate() {
+            if attrs_equal(py, &edge_ref.attr, &other.borrow(py).attr, &HashSet::new())? {
+                found = Some(pos);
+                break;
+            }
+        }
+        match found {
+            Some(pos) => {
+                available.swap_remove(pos);
+            }
+            None => left.push(edge),
+        }
+    }
+    Ok(left)
+}
+
+/// An `added_edges` / `removed_edges` entry of a patch.
+struct PatchEdge {
+    key: EdgeKey,
+    /// Missing in hand-written entries; a removal then takes any matching edge
+    attr: Option<HashMap<String, Py<PyAny>>>,
+}
+
+/// The `changed_attrs` of one node; a value of `None` removes the key.
+struct AttrChanges {
+    id: String,
+    changes: Vec<(String, Option<Py<PyAny>>)>,
+}
+
+fn patch_edges(py: Python<'_>, patch: &Bound<'_, PyDict>, section: &str) -> PyResult<Vec<PatchEdge>> {
+    let Some(entries) = patch.get_item(section)? else { return Ok(Vec::new()) };
+    let mut edges = Vec::new();
+    for entry in entries.try_iter()? {
+        let entry = entry?;
+        let entry = entry.downcast::<PyDict>()?;
+        let required = |name: &str| -> PyResult<String> {
+            entry
+                .get_item(name)?
+                .ok_or_else(|| GraphError::new_err(format!("Patch edge in '{}' has no '{}'", section, name)))?
+                .extract()
+        };
+        let type_value = entry.get_item("type")?.map(|t| t.unbind());
+        let attr: Option<HashMap<String, Py<PyAny>>> = match entry.get_item("attr")? {
+            Some(attr) => Some(attr.extract()?),
+            None => None,
+        };
+        edges.push(PatchEdge {
+            key: EdgeKey {
+                from_id: required("from_id")?,
+                to_id: required("to_id")?,
+                edge_type: edge_type(py, type_value.as_ref())?,
+            },
+            attr,
+        });
+    }
+    Ok(edges)
+}
+
+/// Apply a `diff` result to the vertex behind `vertex_handle` in place.
+///
+/// The whole patch is checked before anything changes: removed nodes and
+/// nodes with changed attributes must exist (`NodeNotFoundError`), added
+/// edges must end at nodes that exist afterwards, added nodes must not
+/// already exist (`GraphError`) and every removed edge must be present
+/// (`EdgeNotFoundError`); a removed entry with `attr` only matches an edge
+/// with equal attributes.
+/// Edges are removed first (the last matching parallel edges go), then
+/// nodes along with any remaining incident edges, firing
+/// `on_node_remove_callbacks`. Nodes and edges are added through
+/// `Vertex.add_node` / `Vertex.add_edge` and values set through
+/// `Node.attr_set`, so their callbacks fire as usual; removed keys are
+/// dropped without a callback.
+pub fn apply_patch(vertex_handle: &Py<Vertex>, py: Python<'_>, patch: &Bound<'_, PyDict>) -> PyResult<()> {
+    let removed_nodes: Vec<String> = match patch.get_item("removed_nodes")? {
+        Some(ids) => ids.extract()?,
+        None => Vec::new(),
+    };
+    let added_nodes: Vec<(String, HashMap<String, Py<PyAny>>)> = match patch.get_item("added_nodes")? {
+        Some(nodes) => {
+            let nodes: HashMap<String, HashMap<String, Py<PyAny>>> = nodes.extract()?;
+            let mut nodes: Vec<(String, HashMap<String, Py<PyAny>>)> = nodes.into_iter().collect();
+            nodes.sort_by(|a, b| a.0.cmp(&b.0));
+            nodes
+        }
+        None => Vec::new(),
+    };
+    let removed_edges = patch_edges(py, patch, "removed_edges")?;
+    let added_edges = patch_edges(py, patch, "added_edges")?;
+    let mut changed_attrs: Vec<AttrChanges> = Vec::new();
+    if let Some(changed) = patch.get_item("changed_attrs")? {
+        let changed = changed.downcast::<PyDict>()?;
+        for (id, changes) in changed.iter() {
+            let mut node_changes = AttrChanges { id: id.extract()?, changes: Vec::new() };
+            for (key, change) in changes.downcast::<PyDict>()?.iter() {
+                let new = change.downcast::<PyDict>()?.get_item("new")?.map(|v| v.unbind());
+                node_changes.changes.push((key.extract()?, new));
+            }
+            changed_attrs.push(node_changes);
+        }
+        changed_attrs.sort_by(|a, b| a.id.cmp(&b.id));
+    }
+
+    // Validate, then detach the removed edges and nodes
+    let removed = {
+        let mut vertex = vertex_handle.borrow_mut(py);
+        let removed_ids: HashSet<&str> = removed_nodes.iter().map(String::as_str).collect();
+        let added_ids: HashSet<&str> = added_nodes.iter().map(|(id, _)| id.as_str()).collect();
+        for id in &removed_nodes {
+            if !vertex.nodes.contains_key(id) {
+                return Err(missing_node(id));
+            }
+        }
+        for (id, _) in &added_nodes {
+            if vertex.nodes.contains_key(id) && !removed_ids.contains(id.as_str()) {
+                return Err(GraphError::new_err(format!("Patch adds node '{}', which already exists", id)));
+            }
+        }
+        let kept = |id: &str| vertex.nodes.contains_key(id) && !removed_ids.contains(id);
+        for edge in &added_edges {
+            for id in [&edge.key.from_id, &edge.key.to_id] {
+                if !kept(id) && !added_ids.contains(id.as_str()) {
+                    return Err(missing_node(id));
+                }
+            }
+        }
+        for node_changes in &changed_attrs {
+            if !kept(&node_changes.id) {
+                return Err(missing_node(&node_changes.id));
+            }
+        }
+
+        let mut existing = edges_by_key(&vertex, py)?;
+        let mut doomed: Vec<Py<Edge>> = Vec::new();
+        for edge in &removed_edges {
+            let popped = match existing.groups.get_mut(&edge.key) {
+                Some(group) => match &edge.attr {
+                    Some(attr) => last_equal(py, group, attr)?.map(|pos| group.remove(pos)),
+                    None => group.pop(),
+                },
+                None => None,
+            };
+            let Some(popped) = popped else {
+                return Err(edge_not_found(format!(
+                    "Patch removes an edge from '{}' to '{}' that does not exist",
+                    edge.key.from_id, edge.key.to_id
+                )));
+            };
+            doomed.push(popped);
+        }
+
+        for edge in &doomed {
+            manipulation::detach_edge(&mut vertex, py, edge);
+        }
+        let mut removed = Vec::new();
+        let mut sorted_ids: Vec<&String> = removed_nodes.iter().collect();
+        sorted_ids.sort();
+        sorted_ids.dedup();
+        for id in sorted_ids {
+            removed.push(manipulation::remove_node(&mut vertex, py, id)?);
+        }
+        removed
+    };
+
+    let remove_cbs = vertex_handle.borrow(py).on_node_remove_callbacks.clone_ref(py);
+    for node in &removed {
+        callbacks::fire_node_remove_callbacks(
+            py,
+            remove_cbs.bind(py),
+            vertex_handle.clone_ref(py).into_any(),
+            node.clone_ref(py),
+        )?;
+    }
+
+    let vertex = vertex_handle.bind(py);
+    for (id, attr) in added_nodes {
+        vertex.call_method1("add_node", (id, attr))?;
+    }
+    for edge in added_edges {
+        vertex.call_method1("add_edge", (edge.key.from_id, edge.key.to_id, edge.attr.unwrap_or_default()))?;
+    }
+    for AttrChanges { id, changes } in changed_attrs {
+        let node = vertex_handle.borrow(py).nodes[&id].clone_ref(py);
+        for (key, new) in changes {
+            match new {
+                Some(value) => {
+                    node.bind(py).call_method1("attr_set", (key, value))?;
+                }
+                None => {
+                    node.borrow_mut(py).attr.remove(&key);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Position of the last edge in `group` whose attributes equal `attr`.
+fn last_equal(py: Python<'_>, group: &[Py<Edge>], attr: &HashMap<String, Py<PyAny>>) -> PyResult<Option<usize>> {
+    for (pos, edge) in group.iter().enumerate().rev() {
+        if attrs_equal(py, &edge.borrow(py).attr, attr, &HashSet::new())? {
+            return Ok(Some(pos));
+        }
+    }
+    Ok(None)
+}
+
+/// True if both graphs have the same node ids, equal node attributes, the
+/// same edges between each pair of nodes (as a multiset of attribute dicts)
+/// and equal `meta`, ignoring the keys in `ignore` everywhere.
+///
+/// Values are compared with `==`. Tags, edge ids and the `meta` of single
+/// nodes and edges are not compared.
+pub fn structurally_equal(
+    vertex: &Vertex,
+    py: Python<'_>,
+    other: &Vertex,
+    ignore: &HashSet<String>,
+) -> PyResult<bool> {
+    if vertex.nodes.len() != other.nodes.len() {
+        return Ok(false);
+    }
+    let meta: HashMap<String, Py<PyAny>> = vertex.meta.bind(py).extract()?;
+    let other_meta: HashMap<String, Py<PyAny>> = other.meta.bind(py).extract()?;
+    if !attrs_equal(py, &meta, &other_meta, ignore)? {
+        return Ok(false);
+    }
+
+    for (id, node) in &vertex.nodes {
+        let Some(other_node) = other.nodes.get(id) else { return Ok(false) };
+        let (node, other_node) = (node.borrow(py), other_node.borrow(py));
+        if !attrs_equal(py, &node.attr, &other_node.attr, ignore)? {
+            return Ok(false);
+        }
+
+        // Pair every edge with an unused equal edge of `other` to the same target
+        let mut unmatched: Vec<Py<Edge>> = other_node.edges.iter().map(|e| e.clone_ref(py)).collect();
+        if unmatched.len() != node.edges.len() {
+            return Ok(false);
+        }
+        for edge in &node.edges {
+            let edge = edge.borrow(py);
+            let to_id = &edge.to_node.borrow(py).id;
+            let mut found = None;
+            for (pos, candidate) in unmatched.iter().enumerate() {
+                let candidate = candidate.borrow(py);
+                if candidate.to_node.borrow(py).id == *to_id
+                    && attrs_equal(py, &edge.attr, &candidate.attr, ignore)?
+                {
+                    found = Some(pos);
+                    break;
+                }
+            }
+            match found {
+                Some(pos) => {
+                    unmatched.swap_remove(pos);
+                }
+                None => return Ok(false),
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Attribute dicts with the same keys and `==` values, skipping `ignore`.
+fn attrs_equal(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    other: &HashMap<String, Py<PyAny>>,
+    ignore: &HashSet<String>,
+) -> PyResult<bool> {
+    let keys = |a: &HashMap<String, Py<PyAny>>| a.keys().filter(|k| !ignore.contains(*k)).count();
+    if keys(attr) != keys(other) {
+        return Ok(false);
+    }
+    for (key, value) in attr {
+        if ignore.contains(key) {
+            continue;
+        }
+        match other.get(key) {
+            Some(other_value) if value.bind(py).eq(other_value.bind(py))? => {}
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+/// SHA-256 hex digest of a canonical JSON form of the graph.
+///
+/// The payload covers exactly what `structurally_equal` compares: node ids in
+/// sorted order, node attributes with sorted keys, each node's outgoing edges
+/// as a sorted list of `[target, attr]` pairs, and the graph `meta`. Edge
+/// insertion order, edge ids, tags and timestamps do not affect the hash.
+/// Values are hashed in their serialized form, so `1` and `1.0` differ.
+pub fn content_hash(vertex: &Vertex, py: Python<'_>) -> PyResult<String> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let mut nodes = Vec::with_capacity(ids.len());
+    for id in ids {
+        let node = vertex.nodes[id].borrow(py);
+        let mut edges = Vec::with_capacity(node.edges.len());
+        for edge in &node.edges {
+            let edge = edge.borrow(py);
+            let to_id = edge.to_node.borrow(py).id.clone();
+            edges.push(serde_json::json!([to_id, canonical_attr(py, &edge.attr)?]));
+        }
+        edges.sort_by_cached_key(|edge| edge.to_string());
+        nodes.push(serde_json::json!({
+            "id": id,
+            "attr": canonical_attr(py, &node.attr)?,
+            "edges": edges,
+        }));
+    }
+    let meta: HashMap<String, Py<PyAny>> = vertex.meta.bind(py).extract()?;
+    let payload = serde_json::json!({ "nodes": nodes, "meta": canonical_attr(py, &meta)? });
+
+    let bytes = PyBytes::new(py, payload.to_string().as_bytes());
+    py.import("hashlib")?
+        .call_method1("sha256", (bytes,))?
+        .call_method0("hexdigest")?
+        .extract()
+}
+
+/// Order-independent fingerprint of the edge set: the XOR of one hash per edge.
+///
+/// Each edge hashes its `(from, to)` ids with FNV-1a; parallel edges also mix
+/// in their occurrence number so a pair of duplicates does not cancel out.
+/// Attributes, edge ids and nodes without edges are ignored. FNV keeps the
+/// value stable across runs and builds, unlike `std`'s randomized hashers.
+pub fn edge_set_hash(vertex: &Vertex, py: Python<'_>) -> u64 {
+    let mut seen: HashMap<(String, String), u64> = HashMap::new();
+    let mut hash = 0;
+    for (from_id, node) in &vertex.nodes {
+        for edge in &node.borrow(py).edges {
+            let to_id = edge.borrow(py).to_node.borrow(py).id.clone();
+            let count = seen.entry((from_id.clone(), to_id.clone())).or_insert(0);
+            hash ^= edge_hash(from_id, &to_id, *count);
+            *count += 1;
+        }
+    }
+    hash
+}
+
+/// FNV-1a over `from \0 to \0 occurrence`, finished with a 64-bit mixer so
+/// that XOR-ing many edge hashes spreads over all bits.
+fn edge_hash(from_id: &str, to_id: &str, occurrence: u64) -> u64 {
+    let mut h: u64 = 0xcbf2_9ce4_8422_2325;
+    let bytes = from_id
+        .bytes()
+        .chain([0])
+        .chain(to_id.bytes())
+        .chain([0])
+        .chain(occurrence.to_le_bytes());
+    for byte in bytes {
+        h ^= u64::from(byte);
+        h = h.wrapping_mul(0x0100_0000_01b3);
+    }
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h
+}
+
+/// Attribute dict as a JSON object; `serde_json` maps keep their keys sorted.
+fn canonical_attr(py: Python<'_>, attr: &HashMap<String, Py<PyAny>>) -> PyResult<serde_json::Value> {
+    let mut values = HashMap::with_capacity(attr.len());
+    for (key, value) in attr {
+        values.insert(key.clone(), SerializableValue::from_python(py, value)?);
+    }
+    serde_json::to_value(values).map_err(|e| GraphError::new_err(e.to_string()))
+}
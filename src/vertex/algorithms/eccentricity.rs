@@ -0,0 +1,135 @@
+// vertex/algorithms/eccentricity.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::progress::{run_monitored, Monitor};
+use super::sssp::single_source;
+
+/// Relative tolerance when comparing weighted eccentricities.
+const TOLERANCE: f64 = 1e-9;
+
+/// Eccentricity of every node plus the component each node belongs to.
+struct Eccentricities {
+    values: Vec<f64>,
+    component: Vec<usize>,
+}
+
+/// Largest shortest-path distance from each node, with edge direction ignored.
+///
+/// Distances are hop counts without `weight_field`. On a disconnected graph
+/// every eccentricity is infinite, so this raises `GraphError` unless
+/// `per_component` is set, in which case each node only measures the nodes of
+/// its own component.
+fn compute(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<&str>,
+    per_component: bool,
+) -> PyResult<(Adjacency, Eccentricities)> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field)?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+    let component = components(&adj);
+    if !per_component && component.iter().any(|&c| c != 0) {
+        return Err(GraphError::new_err(
+            "Graph is not connected, so every eccentricity is infinite; pass per_component=True",
+        ));
+    }
+
+    let undirected = adj.undirected();
+    let values = run_monitored(py, undirected.len(), true, Monitor::default(), |idx| {
+        let run = single_source(&undirected, idx, weighted);
+        run.dist.iter().flatten().fold(0.0, |a: f64, &d| a.max(d))
+    })?;
+    Ok((adj, Eccentricities { values, component }))
+}
+
+/// Label each node with its weakly connected component, numbered from 0 in
+/// order of the smallest node index.
+fn components(adj: &Adjacency) -> Vec<usize> {
+    let neighbors = adj.undirected_neighbors();
+    let mut component = vec![usize::MAX; adj.len()];
+    let mut next = 0;
+    for root in 0..adj.len() {
+        if component[root] != usize::MAX {
+            continue;
+        }
+        component[root] = next;
+        let mut stack = vec![root];
+        while let Some(idx) = stack.pop() {
+            for &nb in &neighbors[idx] {
+                if component[nb] == usize::MAX {
+                    component[nb] = next;
+                    stack.push(nb);
+                }
+            }
+        }
+        next += 1;
+    }
+    component
+}
+
+/// Eccentricity of every node by id, see `compute`.
+pub fn eccentricity(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<&str>,
+    per_component: bool,
+) -> PyResult<HashMap<String, f64>> {
+    let (adj, ecc) = compute(vertex, py, weight_field, per_component)?;
+    Ok(adj.ids.into_iter().zip(ecc.values).collect())
+}
+
+/// Nodes of minimum eccentricity (per component with `per_component`), sorted.
+pub fn center(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<&str>,
+    per_component: bool,
+) -> PyResult<Vec<String>> {
+    extremes(vertex, py, weight_field, per_component, f64::min)
+}
+
+/// Nodes of maximum eccentricity (per component with `per_component`), sorted.
+pub fn periphery(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<&str>,
+    per_component: bool,
+) -> PyResult<Vec<String>> {
+    extremes(vertex, py, weight_field, per_component, f64::max)
+}
+
+/// Nodes whose eccentricity equals the `pick` of their component's values.
+fn extremes(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<&str>,
+    per_component: bool,
+    pick: fn(f64, f64) -> f64,
+) -> PyResult<Vec<String>> {
+    let (adj, ecc) = compute(vertex, py, weight_field, per_component)?;
+    let mut target: HashMap<usize, f64> = HashMap::new();
+    for (idx, &value) in ecc.values.iter().enumerate() {
+        target
+            .entry(ecc.component[idx])
+            .and_modify(|t| *t = pick(*t, value))
+            .or_insert(value);
+    }
+    // `ids` is sorted, so the result is too
+    Ok(adj
+        .ids
+        .iter()
+        .enumerate()
+        .filter(|&(idx, _)| {
+            let t = target[&ecc.component[idx]];
+            (ecc.values[idx] - t).abs() <= TOLERANCE * t.abs().max(1.0)
+        })
+        .map(|(_, id)| id.clone())
+        .collect())
+}
@@ -1,24 +1,38 @@
 // vertex/algorithms/expand.rs
 
 use pyo3::prelude::*;
-use std::collections::HashMap;
+use pyo3::IntoPyObjectExt;
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
 use crate::{Node, Edge};
+use crate::errors::GraphError;
 use super::super::core::Vertex;
+use super::adjacency::{edge_weight, Adjacency};
+use super::centrality::pagerank;
+use super::filter::copy_tags;
+use super::sssp::HeapEntry;
 
+/// Grow `vertex` with the nodes of `source_vertex` within `depth` hops.
+///
+/// With `annotate_distance` every result node gets `meta["distance"]`: the
+/// fewest hops from any node of `vertex` (0 for the seeds themselves).
 pub fn expand(
     vertex: &Vertex,
     py: Python<'_>,
     source_vertex: &Vertex,
-    depth: Option<usize>
+    depth: Option<usize>,
+    annotate_distance: bool,
 ) -> PyResult<Py<Vertex>> {
-    use std::collections::{VecDeque, HashSet};
+    use std::collections::VecDeque;
     
     let expansion_depth = depth.unwrap_or(1);
     let mut discovered_node_ids = HashSet::<String>::new();
+    let mut distances = HashMap::<String, usize>::new();
     
     // Start with all nodes from the current vertex
     for node_id in vertex.nodes.keys() {
         discovered_node_ids.insert(node_id.clone());
+        distances.insert(node_id.clone(), 0);
     }
     
     // For each node in the current vertex, perform BFS expansion from the source vertex
@@ -51,6 +65,8 @@ pub fn expand(
                     // If we haven't visited this node in this BFS traversal
                     if !visited.contains(&to_id) {
                         visited.insert(to_id.clone());
+                        let distance = distances.entry(to_id.clone()).or_insert(current_depth + 1);
+                        *distance = (*distance).min(current_depth + 1);
                         
                         // Add to discovered nodes (this will include it in the final result)
                         discovered_node_ids.insert(to_id.clone());
@@ -67,10 +83,244 @@ pub fn expand(
         }
     }
     
+    let result = build_expanded(py, source_vertex, &discovered_node_ids)?;
+    if annotate_distance {
+        write_distances(py, &result, &distances)?;
+    }
+    Ok(result)
+}
+
+/// Grow `vertex` with the nodes of `source_vertex` reachable within a
+/// cumulative edge-weight budget of `max_cost` and, if given, within
+/// `max_depth` hops.
+///
+/// Runs a multi-source Dijkstra over `source_vertex`, seeded with every node
+/// of `vertex` at cost 0. Edges without `weight_field` cost 1.0. An edge is
+/// only followed when both limits still hold after taking it. With a hop
+/// limit the cheapest path may be too long, so a node is settled again
+/// whenever a dearer path reaches it in fewer hops. With `annotate_distance`
+/// every result node gets its cheapest cost within the limits in
+/// `meta["distance"]`.
+pub fn expand_weighted(
+    vertex: &Vertex,
+    py: Python<'_>,
+    source_vertex: &Vertex,
+    max_cost: f64,
+    max_depth: Option<usize>,
+    weight_field: &str,
+    annotate_distance: bool,
+) -> PyResult<Py<Vertex>> {
+    if max_cost.is_nan() || max_cost < 0.0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "max_cost must be a non-negative number",
+        ));
+    }
+
+    let mut discovered_node_ids: HashSet<String> = vertex.nodes.keys().cloned().collect();
+    let mut best = HashMap::<String, f64>::new();
+    // Fewest hops each node has been settled with; hops stay 0 without a limit
+    let mut settled = HashMap::<String, usize>::new();
+    let mut queued = HashMap::<(String, usize), f64>::new();
+    let mut heap = BinaryHeap::new();
+
+    for node_id in vertex.nodes.keys() {
+        if source_vertex.nodes.contains_key(node_id) {
+            queued.insert((node_id.clone(), 0), 0.0);
+            heap.push(Frontier { cost: 0.0, hops: 0, node_id: node_id.clone() });
+        }
+    }
+
+    while let Some(Frontier { cost, hops, node_id }) = heap.pop() {
+        // Pops come in cost order, so an earlier settle with as few hops dominates
+        if settled.get(&node_id).is_some_and(|&known| known <= hops) {
+            continue;
+        }
+        settled.insert(node_id.clone(), hops);
+        best.entry(node_id.clone()).or_insert(cost);
+
+        let next_hops = match max_depth {
+            Some(limit) if hops >= limit => continue,
+            Some(_) => hops + 1,
+            None => 0,
+        };
+
+        let source_node = source_vertex.nodes[&node_id].borrow(py);
+        for edge in &source_node.edges {
+            let edge_ref = edge.borrow(py);
+            let weight = edge_weight(py, &edge_ref, Some(weight_field))?;
+            if weight < 0.0 {
+                return Err(GraphError::new_err(format!(
+                    "Negative edge weight {} on edge from '{}'",
+                    weight, node_id
+                )));
+            }
+
+            let next_cost = cost + weight;
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            if next_cost > max_cost || !source_vertex.nodes.contains_key(&to_id) {
+                continue;
+            }
+            if settled.get(&to_id).is_some_and(|&known| known <= next_hops) {
+                continue;
+            }
+            let state = (to_id.clone(), next_hops);
+            if queued.get(&state).is_none_or(|&known| next_cost < known) {
+                queued.insert(state, next_cost);
+                discovered_node_ids.insert(to_id.clone());
+                heap.push(Frontier { cost: next_cost, hops: next_hops, node_id: to_id });
+            }
+        }
+    }
+
+    let result = build_expanded(py, source_vertex, &discovered_node_ids)?;
+    if annotate_distance {
+        write_distances(py, &result, &best)?;
+    }
+    Ok(result)
+}
+
+/// Grow a subgraph from `seed_ids` up to `max_nodes` nodes, always adding
+/// the highest-scoring node one outgoing edge away from the current set.
+///
+/// Scores are `"pagerank"` (see `centrality::pagerank`) or `"degree"` (in
+/// plus out edges), computed once on the whole graph; ties go to the smaller
+/// id. Growth stops early when nothing more is reachable. The result is built
+/// like an expansion, with every edge among the chosen nodes.
+pub fn budget_subgraph(
+    vertex: &Vertex,
+    py: Python<'_>,
+    seed_ids: &[String],
+    max_nodes: usize,
+    scoring: &str,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let seeds: BTreeSet<usize> = seed_ids
+        .iter()
+        .map(|id| adj.require(id))
+        .collect::<PyResult<_>>()?;
+    if seeds.len() > max_nodes {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "max_nodes is {} but {} seeds were given",
+            max_nodes,
+            seeds.len()
+        )));
+    }
+    let scores = match scoring {
+        "pagerank" => py.allow_threads(|| pagerank(&adj)),
+        "degree" => {
+            let mut degree = vec![0.0; adj.len()];
+            for (from_idx, edges) in adj.out.iter().enumerate() {
+                for &(to_idx, _) in edges {
+                    degree[from_idx] += 1.0;
+                    degree[to_idx] += 1.0;
+                }
+            }
+            degree
+        }
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown scoring '{}'; expected 'pagerank' or 'degree'",
+                scoring
+            )))
+        }
+    };
+
+    let chosen = py.allow_threads(|| {
+        let mut chosen = vec![false; adj.len()];
+        let mut queued = vec![false; adj.len()];
+        // Negated scores, so the min-heap pops the best node (smallest index on ties)
+        let mut frontier = BinaryHeap::new();
+        let mut count = 0;
+        let mut add = |idx: usize, chosen: &mut Vec<bool>, frontier: &mut BinaryHeap<HeapEntry>| {
+            chosen[idx] = true;
+            for &(next, _) in &adj.out[idx] {
+                if !chosen[next] && !queued[next] {
+                    queued[next] = true;
+                    frontier.push(HeapEntry { cost: -scores[next], node: next });
+                }
+            }
+        };
+        for &seed in &seeds {
+            add(seed, &mut chosen, &mut frontier);
+            count += 1;
+        }
+        while count < max_nodes {
+            let Some(HeapEntry { node, .. }) = frontier.pop() else { break };
+            if chosen[node] {
+                continue;
+            }
+            add(node, &mut chosen, &mut frontier);
+            count += 1;
+        }
+        chosen
+    });
+
+    let ids: HashSet<String> = chosen
+        .iter()
+        .enumerate()
+        .filter(|&(_, &keep)| keep)
+        .map(|(idx, _)| adj.ids[idx].clone())
+        .collect();
+    build_expanded(py, vertex, &ids)
+}
+
+/// Store each node's distance from the seeds in its `meta["distance"]`.
+fn write_distances<T>(py: Python<'_>, result: &Py<Vertex>, distances: &HashMap<String, T>) -> PyResult<()>
+where
+    T: Copy + for<'py> IntoPyObject<'py>,
+{
+    for (node_id, node) in &result.borrow(py).nodes {
+        if let Some(&distance) = distances.get(node_id) {
+            let value = distance.into_py_any(py)?;
+            node.borrow_mut(py).meta.insert("distance".to_string(), value);
+        }
+    }
+    Ok(())
+}
+
+/// Min-heap entry for `expand_weighted`
+struct Frontier {
+    cost: f64,
+    hops: usize,
+    node_id: String,
+}
+
+impl PartialEq for Frontier {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frontier {}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.hops.cmp(&self.hops))
+            .then_with(|| other.node_id.cmp(&self.node_id))
+    }
+}
+
+/// Build the result of an expansion: the `discovered_node_ids` found in
+/// `source_vertex` as fresh nodes with their attributes and tags, keeping
+/// only edges between them.
+pub(crate) fn build_expanded(
+    py: Python<'_>,
+    source_vertex: &Vertex,
+    discovered_node_ids: &HashSet<String>,
+) -> PyResult<Py<Vertex>> {
     // Now create the result vertex with all discovered nodes and their filtered edges
     let mut result_nodes = HashMap::<String, Py<Node>>::new();
     
-    for node_id in &discovered_node_ids {
+    for node_id in discovered_node_ids {
         // Get the node from the source vertex (which has the complete node data)
         if let Some(source_node) = source_vertex.nodes.get(node_id) {
             let source_node_ref = source_node.bind(py);
@@ -140,6 +390,7 @@ pub fn expand(
         final_result_nodes.insert(node_id.clone(), final_node);
     }
     
+    copy_tags(py, source_vertex, &final_result_nodes);
     let result_vertex = Vertex::from_nodes(py, final_result_nodes);
     Py::new(py, result_vertex)
 }
@@ -1,27 +1,41 @@
 // vertex/algorithms/filter.rs
 
 use pyo3::prelude::*;
+use pyo3::types::PySet;
+use regex::Regex;
 use std::collections::HashMap;
 use crate::{Node, Edge};
+use crate::errors::node_not_found;
 use super::super::core::Vertex;
+use super::super::manipulation::count_edges;
 
+/// Subgraph of `node_ids` and the edges between them.
+///
+/// With `strict`, an unknown id raises `ValueError`. Otherwise unknown ids are
+/// skipped and the result gets its own copy of `meta` with the ids that were
+/// found stored as a set under `"found_ids"`.
 pub fn filter(
     vertex: &Vertex,
     py: Python<'_>,
-    node_ids: Vec<String>
+    node_ids: Vec<String>,
+    strict: bool,
 ) -> PyResult<Py<Vertex>> {
     use std::collections::HashSet;
     
     // Convert node_ids to a HashSet for efficient lookups
-    let filter_set: HashSet<String> = node_ids.into_iter().collect();
+    let mut filter_set: HashSet<String> = node_ids.into_iter().collect();
     
     // Validate that all requested nodes exist in the source vertex
-    for node_id in &filter_set {
-        if !vertex.nodes.contains_key(node_id) {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                format!("Node with id '{}' not found in vertex", node_id)
-            ));
+    if strict {
+        for node_id in &filter_set {
+            if !vertex.nodes.contains_key(node_id) {
+                return Err(node_not_found(
+                    format!("Node with id '{}' not found in vertex", node_id)
+                ));
+            }
         }
+    } else {
+        filter_set.retain(|node_id| vertex.nodes.contains_key(node_id));
     }
     
     // First pass: Create nodes with their original edges (we'll filter edges in second pass)
@@ -96,13 +110,190 @@ pub fn filter(
         final_result_nodes.insert(node_id.clone(), final_node);
     }
 
-    let result_vertex = Vertex {
-        nodes: final_result_nodes,
+    let mut result = derived_vertex(vertex, py, final_result_nodes);
+    if !strict {
+        let meta = vertex.meta.bind(py).copy()?;
+        meta.set_item("found_ids", PySet::new(py, &filter_set)?)?;
+        result.meta = meta.unbind();
+    }
+
+    Py::new(py, result)
+}
+
+/// Python `re` flags that have an inline equivalent in the `regex` crate.
+const INLINE_FLAGS: [(i64, char); 4] = [(2, 'i'), (8, 'm'), (16, 's'), (64, 'x')];
+/// `re.UNICODE`, implied for str patterns and the `regex` crate's default.
+const RE_UNICODE: i64 = 32;
+
+/// Regex source for a `str` or compiled `re.Pattern`.
+///
+/// The flags of a compiled pattern become an inline `(?imsx)` prefix; any
+/// other flag (`re.ASCII`, `re.LOCALE`, ...) raises `ValueError` rather than
+/// being dropped silently.
+pub fn pattern_source(pattern: &Bound<'_, PyAny>) -> PyResult<String> {
+    if pattern.is_instance_of::<pyo3::types::PyString>() {
+        return pattern.extract();
+    }
+    let source: String = pattern.getattr("pattern")?.extract()?;
+    let mut flags: i64 = pattern.getattr("flags")?.extract()?;
+    let mut inline = String::new();
+    for (bit, letter) in INLINE_FLAGS {
+        if flags & bit != 0 {
+            inline.push(letter);
+            flags &= !bit;
+        }
+    }
+    if flags & !RE_UNICODE != 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unsupported pattern flags {}; only IGNORECASE, MULTILINE, DOTALL and VERBOSE can be translated",
+            flags & !RE_UNICODE
+        )));
+    }
+    Ok(if inline.is_empty() { source } else { format!("(?{}){}", inline, source) })
+}
+
+/// Subgraph of the nodes whose id starts with `prefix` and/or contains a
+/// match of the regex `pattern` (both must hold when both are given).
+pub fn filter_by_id(
+    vertex: &Vertex,
+    py: Python<'_>,
+    prefix: Option<&str>,
+    pattern: Option<&str>,
+) -> PyResult<Py<Vertex>> {
+    if prefix.is_none() && pattern.is_none() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Must specify prefix or pattern",
+        ));
+    }
+    let regex = pattern
+        .map(Regex::new)
+        .transpose()
+        .map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!(
+                "Invalid pattern (lookarounds and backreferences are not supported): {}",
+                e
+            ))
+        })?;
+
+    let node_ids: Vec<String> = vertex
+        .nodes
+        .keys()
+        .filter(|id| prefix.is_none_or(|prefix| id.starts_with(prefix)))
+        .filter(|id| regex.as_ref().is_none_or(|regex| regex.is_match(id)))
+        .cloned()
+        .collect();
+    filter(vertex, py, node_ids, true)
+}
+
+/// Give each of `nodes` the tags of the node with the same id in `source`.
+pub(crate) fn copy_tags(py: Python<'_>, source: &Vertex, nodes: &HashMap<String, Py<Node>>) {
+    for (node_id, node) in nodes {
+        if let Some(source_node) = source.nodes.get(node_id) {
+            let tags = source_node.borrow(py).tags.clone();
+            node.borrow_mut(py).tags = tags;
+        }
+    }
+}
+
+/// Wrap `nodes` in a vertex sharing `vertex`'s meta and callback lists,
+/// copying tags over from `vertex`'s nodes (see `copy_tags`).
+pub(crate) fn derived_vertex(vertex: &Vertex, py: Python<'_>, nodes: HashMap<String, Py<Node>>) -> Vertex {
+    copy_tags(py, vertex, &nodes);
+    Vertex {
+        edge_count: count_edges(py, &nodes),
+        nodes,
         meta: vertex.meta.clone_ref(py),
         on_edge_add_callbacks: vertex.on_edge_add_callbacks.clone_ref(py),
         on_node_add_callbacks: vertex.on_node_add_callbacks.clone_ref(py),
         on_node_update_callbacks: vertex.on_node_update_callbacks.clone_ref(py),
         on_edge_update_callbacks: vertex.on_edge_update_callbacks.clone_ref(py),
-    };
-    Py::new(py, result_vertex)
+        on_vertex_meta_change_callbacks: vertex.on_vertex_meta_change_callbacks.clone_ref(py),
+        on_node_remove_callbacks: vertex.on_node_remove_callbacks.clone_ref(py),
+        binary_snapshot: None,
+    }
+}
+
+/// True if every `filters` key is present in the node's `attr` with an equal value.
+pub fn node_matches(
+    py: Python<'_>,
+    node: &Py<Node>,
+    filters: &HashMap<String, Py<PyAny>>,
+) -> PyResult<bool> {
+    let node_ref = node.borrow(py);
+    for (key, value) in filters {
+        match node_ref.attr.get(key) {
+            Some(node_val) => {
+                if !node_val.bind(py).eq(value.bind(py))? {
+                    return Ok(false);
+                }
+            }
+            None => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+/// Split `vertex` into the nodes matching `filters` and the rest, in one pass.
+///
+/// Both sides get fresh nodes. Edges within a side are always kept; an edge
+/// crossing the boundary is dropped unless `keep_cross_edges` is set, in which
+/// case its source side keeps it, pointing at the node in the other side.
+pub fn partition(
+    vertex: &Vertex,
+    py: Python<'_>,
+    filters: HashMap<String, Py<PyAny>>,
+    keep_cross_edges: bool,
+) -> PyResult<(Py<Vertex>, Py<Vertex>)> {
+    let mut matched = HashMap::<String, Py<Node>>::new();
+    let mut rest = HashMap::<String, Py<Node>>::new();
+
+    for (node_id, node) in &vertex.nodes {
+        let attr: HashMap<String, Py<PyAny>> = node
+            .borrow(py)
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let new_node = Py::new(py, Node::new(py, node_id.clone(), Some(attr), None))?;
+        if node_matches(py, node, &filters)? {
+            matched.insert(node_id.clone(), new_node);
+        } else {
+            rest.insert(node_id.clone(), new_node);
+        }
+    }
+
+    for (node_id, node) in &vertex.nodes {
+        let from_in_matched = matched.contains_key(node_id);
+        let new_from = if from_in_matched { &matched[node_id] } else { &rest[node_id] };
+
+        for edge in &node.borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            let new_to = match (matched.get(&to_id), rest.get(&to_id)) {
+                (Some(target), _) if from_in_matched || keep_cross_edges => target,
+                (_, Some(target)) if !from_in_matched || keep_cross_edges => target,
+                _ => continue,
+            };
+
+            let edge_attr: HashMap<String, Py<PyAny>> = edge_ref
+                .attr
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+                .collect();
+            let new_edge = Py::new(py, Edge::new(
+                py,
+                new_from.clone_ref(py),
+                new_to.clone_ref(py),
+                Some(edge_attr),
+                edge_ref.id.clone(),
+            ))?;
+            new_from.borrow_mut(py).edges.push(new_edge.clone_ref(py));
+            new_to.borrow_mut(py).inverse_edges.push(new_edge);
+        }
+    }
+
+    Ok((
+        Py::new(py, derived_vertex(vertex, py, matched))?,
+        Py::new(py, derived_vertex(vertex, py, rest))?,
+    ))
 }
@@ -0,0 +1,87 @@
+// vertex/algorithms/flatten.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::super::manipulation;
+
+/// Inline every nested subgraph (see `Node.set_subgraph`) into one flat graph.
+///
+/// Top-level nodes keep their ids; a node of the subgraph attached to `p`
+/// becomes `p{separator}{id}`, recursively for deeper levels. Container nodes
+/// stay in the result without their subgraph, and every edge (with its
+/// attributes and id) is copied within its own level. Nodes are fresh copies
+/// carrying the original attributes and tags. A subgraph that contains one of
+/// its own ancestors raises `GraphError`.
+pub fn flatten(vertex: &Vertex, py: Python<'_>, separator: &str) -> PyResult<Py<Vertex>> {
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let mut ancestors: Vec<*const Vertex> = Vec::new();
+    inline(vertex, py, "", separator, &mut ancestors, &mut result)?;
+    Py::new(py, result)
+}
+
+fn inline(
+    vertex: &Vertex,
+    py: Python<'_>,
+    prefix: &str,
+    separator: &str,
+    ancestors: &mut Vec<*const Vertex>,
+    result: &mut Vertex,
+) -> PyResult<()> {
+    if ancestors.contains(&(vertex as *const Vertex)) {
+        return Err(GraphError::new_err(format!(
+            "Nested subgraph at '{}' contains one of its own ancestors",
+            prefix.trim_end_matches(separator)
+        )));
+    }
+    ancestors.push(vertex as *const Vertex);
+
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        let attr: HashMap<String, Py<PyAny>> = node
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let copy = manipulation::add_node(result, py, format!("{}{}", prefix, id), Some(attr))?;
+        copy.borrow_mut(py).tags = node.tags.clone();
+    }
+
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        for edge in &node.edges {
+            let edge_ref = edge.borrow(py);
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            if !vertex.nodes.contains_key(&to_id) {
+                continue;
+            }
+            let attr: HashMap<String, Py<PyAny>> = edge_ref
+                .attr
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+                .collect();
+            let copy = manipulation::add_edge(
+                result,
+                py,
+                format!("{}{}", prefix, id),
+                format!("{}{}", prefix, to_id),
+                Some(attr),
+            )?;
+            copy.borrow_mut(py).id = edge_ref.id.clone();
+        }
+    }
+
+    for id in &ids {
+        let Some(subgraph) = vertex.nodes[*id].borrow(py).subgraph.as_ref().map(|s| s.clone_ref(py)) else {
+            continue;
+        };
+        let nested_prefix = format!("{}{}{}", prefix, id, separator);
+        inline(&subgraph.borrow(py), py, &nested_prefix, separator, ancestors, result)?;
+    }
+
+    ancestors.pop();
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// vertex/algorithms/floyd_warshall.rs
+
+use pyo3::prelude::*;
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::progress::Monitor;
+
+/// All-pairs shortest distances by Floyd-Warshall.
+///
+/// Returns the sorted node ids and the distance matrix in that order:
+/// `matrix[i][j]` is the summed `weight_field` of the cheapest path from
+/// `ids[i]` to `ids[j]` (edges without the field weigh 1.0), `inf` when
+/// unreachable and 0.0 on the diagonal. Negative weights are allowed, but a
+/// negative cycle raises `GraphError` naming one of its nodes. Runs in
+/// O(n³) time and O(n²) memory, so it suits small, dense graphs.
+///
+/// Each pivot `k` depends on the previous one, so with a `monitor` the
+/// pivots run one at a time without the GIL, and `should_cancel` and
+/// `progress(k + 1, n)` are checked between them.
+pub fn floyd_warshall(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: &str,
+    monitor: Monitor<'_, '_>,
+) -> PyResult<(Vec<String>, Vec<Vec<f64>>)> {
+    let adj = Adjacency::from_vertex(vertex, py, Some(weight_field))?;
+    let n = adj.len();
+    let mut dist = initial_distances(&adj);
+    if monitor.is_active() {
+        for k in 0..n {
+            monitor.check_cancelled(k, n)?;
+            py.allow_threads(|| relax_via(&mut dist, k));
+            monitor.report(k + 1, n)?;
+        }
+    } else {
+        py.allow_threads(|| (0..n).for_each(|k| relax_via(&mut dist, k)));
+    }
+
+    if let Some(idx) = (0..adj.len()).find(|&idx| dist[idx][idx] < 0.0) {
+        return Err(GraphError::new_err(format!(
+            "Negative cycle through node '{}'",
+            adj.ids[idx]
+        )));
+    }
+    Ok((adj.ids, dist))
+}
+
+/// Direct edge weights, 0.0 on the diagonal and `inf` elsewhere.
+fn initial_distances(adj: &Adjacency) -> Vec<Vec<f64>> {
+    let n = adj.len();
+    let mut dist = vec![vec![f64::INFINITY; n]; n];
+    for (idx, row) in dist.iter_mut().enumerate() {
+        row[idx] = 0.0;
+    }
+    // Parallel edges keep the lightest; a negative self-loop marks a cycle
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, weight) in edges {
+            let cell = &mut dist[from_idx][to_idx];
+            *cell = cell.min(weight);
+        }
+    }
+    dist
+}
+
+/// One Floyd-Warshall pivot: shorten every path by routing it through `k`.
+fn relax_via(dist: &mut [Vec<f64>], k: usize) {
+    let via = dist[k].clone();
+    for row in dist.iter_mut() {
+        let to_k = row[k];
+        if to_k == f64::INFINITY {
+            continue;
+        }
+        for (cell, &from_k) in row.iter_mut().zip(&via) {
+            let candidate = to_k + from_k;
+            if candidate < *cell {
+                *cell = candidate;
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+// vertex/algorithms/layout.rs
+
+use pyo3::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::sampling::make_rng;
+use super::spectral::symmetric_edges;
+
+/// Starting temperature, the largest step a node may take in one iteration.
+const INITIAL_TEMPERATURE: f64 = 0.1;
+/// Floor for the distance between two nodes, so coincident nodes still repel.
+const MIN_DISTANCE: f64 = 0.01;
+
+/// 2-D Fruchterman-Reingold layout, scaled into `[-1, 1]` around the origin.
+///
+/// Nodes start at seeded random positions in the unit square. Every pair
+/// repels with `k² / d` and every edge (direction ignored) attracts with
+/// `w · d² / k`, where `k = 1 / sqrt(n)` and `w` is the edge weight (1.0
+/// without `weight_field`). Steps are capped by a temperature that cools
+/// linearly to zero over `iterations`.
+pub fn spring_layout(
+    vertex: &Vertex,
+    py: Python<'_>,
+    iterations: usize,
+    seed: Option<u64>,
+    weight_field: Option<&str>,
+) -> PyResult<HashMap<String, (f64, f64)>> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field)?;
+    if weight_field.is_some() {
+        adj.ensure_non_negative()?;
+    }
+    let edges = symmetric_edges(&adj);
+    let mut rng = make_rng(seed);
+    let mut pos: Vec<[f64; 2]> = (0..adj.len()).map(|_| [rng.gen(), rng.gen()]).collect();
+    py.allow_threads(|| fruchterman_reingold(&mut pos, &edges, iterations));
+    rescale(&mut pos);
+    Ok(adj.ids.into_iter().zip(pos.into_iter().map(|[x, y]| (x, y))).collect())
+}
+
+fn fruchterman_reingold(pos: &mut [[f64; 2]], edges: &[Vec<(usize, f64)>], iterations: usize) {
+    let n = pos.len();
+    if n < 2 {
+        return;
+    }
+    let k = (1.0 / n as f64).sqrt();
+    let mut temperature = INITIAL_TEMPERATURE;
+    let cooling = INITIAL_TEMPERATURE / (iterations as f64 + 1.0);
+    let mut disp = vec![[0.0f64; 2]; n];
+    for _ in 0..iterations {
+        disp.iter_mut().for_each(|d| *d = [0.0, 0.0]);
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let delta = [pos[i][0] - pos[j][0], pos[i][1] - pos[j][1]];
+                let dist = delta[0].hypot(delta[1]).max(MIN_DISTANCE);
+                let force = k * k / dist;
+                for axis in 0..2 {
+                    let push = delta[axis] / dist * force;
+                    disp[i][axis] += push;
+                    disp[j][axis] -= push;
+                }
+            }
+        }
+        // `symmetric_edges` lists every edge from both ends
+        for (i, neighbors) in edges.iter().enumerate() {
+            for &(j, weight) in neighbors {
+                let delta = [pos[i][0] - pos[j][0], pos[i][1] - pos[j][1]];
+                let dist = delta[0].hypot(delta[1]).max(MIN_DISTANCE);
+                let force = weight * dist * dist / k;
+                for axis in 0..2 {
+                    disp[i][axis] -= delta[axis] / dist * force;
+                }
+            }
+        }
+        for (p, d) in pos.iter_mut().zip(&disp) {
+            let length = d[0].hypot(d[1]);
+            if length > 0.0 {
+                let step = length.min(temperature) / length;
+                p[0] += d[0] * step;
+                p[1] += d[1] * step;
+            }
+        }
+        temperature -= cooling;
+    }
+}
+
+/// Center the positions on the origin and scale the largest coordinate to 1.
+fn rescale(pos: &mut [[f64; 2]]) {
+    if pos.is_empty() {
+        return;
+    }
+    let n = pos.len() as f64;
+    let center = [
+        pos.iter().map(|p| p[0]).sum::<f64>() / n,
+        pos.iter().map(|p| p[1]).sum::<f64>() / n,
+    ];
+    let mut extent: f64 = 0.0;
+    for p in pos.iter_mut() {
+        p[0] -= center[0];
+        p[1] -= center[1];
+        extent = extent.max(p[0].abs()).max(p[1].abs());
+    }
+    if extent > 0.0 {
+        for p in pos.iter_mut() {
+            p[0] /= extent;
+            p[1] /= extent;
+        }
+    }
+}
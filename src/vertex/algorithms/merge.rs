@@ -0,0 +1,104 @@
+// vertex/algorithms/merge.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::Node;
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::pattern::attrs_contain;
+
+/// Combine `vertex` and `other` into a new graph.
+///
+/// Every node of both graphs is kept. When a node id exists in both, its
+/// attributes come from `on_conflict(node_id, self_node, other_node)` (which
+/// must return a dict) or, without a callback, from `vertex`; its tags are the
+/// union of both sides' tags. Edges are the
+/// union of both graphs: an edge of `other` is skipped when the result already
+/// has an edge between the same endpoints with the same id and equal
+/// attributes. Nodes are visited in id order, so the result is deterministic.
+pub fn merge(
+    vertex: &Vertex,
+    py: Python<'_>,
+    other: &Vertex,
+    on_conflict: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<Vertex>> {
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+
+    for id in sorted_ids(vertex) {
+        let attr = copy_attr(py, &vertex.nodes[id].borrow(py).attr);
+        let node = manipulation::add_node(&mut result, py, id.clone(), Some(attr))?;
+        node.borrow_mut(py).tags = vertex.nodes[id].borrow(py).tags.clone();
+    }
+    for id in sorted_ids(other) {
+        let other_node = &other.nodes[id];
+        match (vertex.nodes.get(id), on_conflict) {
+            (Some(self_node), Some(callback)) => {
+                let attr: HashMap<String, Py<PyAny>> = callback
+                    .call1((id, self_node.clone_ref(py), other_node.clone_ref(py)))?
+                    .extract()?;
+                result.nodes[id].borrow_mut(py).attr = attr;
+            }
+            (Some(_), None) => {}
+            (None, _) => {
+                let attr = copy_attr(py, &other_node.borrow(py).attr);
+                manipulation::add_node(&mut result, py, id.clone(), Some(attr))?;
+            }
+        }
+        let other_tags = other_node.borrow(py).tags.clone();
+        result.nodes[id].borrow_mut(py).tags.extend(other_tags);
+    }
+
+    copy_edges(&mut result, py, vertex)?;
+    copy_edges(&mut result, py, other)?;
+    Py::new(py, result)
+}
+
+fn sorted_ids(vertex: &Vertex) -> Vec<&String> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    ids
+}
+
+fn copy_attr(py: Python<'_>, attr: &HashMap<String, Py<PyAny>>) -> HashMap<String, Py<PyAny>> {
+    attr.iter().map(|(k, v)| (k.clone(), v.clone_ref(py))).collect()
+}
+
+/// Add the edges of `source` to `result`, skipping duplicates and edges whose
+/// target is not a node of `source`.
+fn copy_edges(result: &mut Vertex, py: Python<'_>, source: &Vertex) -> PyResult<()> {
+    for from_id in sorted_ids(source) {
+        for edge in &source.nodes[from_id].borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            if !source.nodes.contains_key(&to_id)
+                || has_equal_edge(py, &result.nodes[from_id], &to_id, edge_ref.id.as_deref(), &edge_ref.attr)?
+            {
+                continue;
+            }
+            let attr = copy_attr(py, &edge_ref.attr);
+            let new_edge = manipulation::add_edge(result, py, from_id.clone(), to_id, Some(attr))?;
+            new_edge.borrow_mut(py).id = edge_ref.id.clone();
+        }
+    }
+    Ok(())
+}
+
+fn has_equal_edge(
+    py: Python<'_>,
+    from_node: &Py<Node>,
+    to_id: &str,
+    id: Option<&str>,
+    attr: &HashMap<String, Py<PyAny>>,
+) -> PyResult<bool> {
+    for edge in &from_node.borrow(py).edges {
+        let edge_ref = edge.borrow(py);
+        if edge_ref.to_node.borrow(py).id == to_id
+            && edge_ref.id.as_deref() == id
+            && edge_ref.attr.len() == attr.len()
+            && attrs_contain(py, &edge_ref.attr, attr)?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
@@ -4,8 +4,70 @@ mod shortest_path_bfs;
 mod expand;
 mod filter;
 mod random_walks;
+mod adjacency;
+mod progress;
+mod sssp;
+mod batch_shortest_paths;
+mod sampling;
+mod coloring;
+mod scc;
+mod connectivity;
+mod diameter;
+mod parallel_bfs;
+mod centrality;
+mod coarsen;
+mod quotient;
+mod pattern;
+mod merge;
+mod modularity;
+mod spectral;
+mod shortest_path_tree;
+mod propagate;
+mod path_neighborhood;
+mod bfs_frontiers;
+mod steiner;
+mod eccentricity;
+mod normalize;
+mod flatten;
+mod multi_edges;
+mod triangles;
+mod floyd_warshall;
+mod bellman_ford;
+mod diff;
+mod layout;
 
 pub use shortest_path_bfs::shortest_path_bfs;
-pub use expand::expand;
-pub use filter::filter;
+pub use expand::{budget_subgraph, expand, expand_weighted};
+pub use filter::{filter, filter_by_id, node_matches, partition, pattern_source};
 pub use random_walks::random_walks;
+pub use batch_shortest_paths::batch_shortest_paths;
+pub use sampling::{sample_nodes, sample_edges, sample_by_pagerank, sample_subgraph, sparsify};
+pub use coloring::greedy_coloring;
+pub use scc::{is_dag, condensation, largest_scc};
+pub use connectivity::{is_weakly_connected, is_strongly_connected, common_reachable, probable_reachable};
+pub use diameter::approximate_diameter;
+pub use parallel_bfs::parallel_bfs;
+pub use centrality::{betweenness_centrality_sampled, closeness_centrality, harmonic_centrality};
+pub use coarsen::coarsen;
+pub use quotient::quotient_by;
+pub use pattern::match_pattern;
+pub use merge::merge;
+pub use modularity::{cut_size, degree_assortativity, modularity};
+pub use progress::Monitor;
+pub(crate) use adjacency::attr_weight;
+pub use sssp::PathLimits;
+pub use spectral::{to_laplacian, fiedler_vector};
+pub use shortest_path_tree::shortest_path_tree;
+pub use propagate::propagate;
+pub use path_neighborhood::path_neighborhood;
+pub use bfs_frontiers::{bfs_frontiers, BfsFrontiers};
+pub use steiner::steiner_tree;
+pub use eccentricity::{eccentricity, center, periphery};
+pub use normalize::{derive_edge_weight, map_edge_attrs, map_node_attrs, normalize_edge_weights};
+pub use flatten::flatten;
+pub use multi_edges::collapse_multi_edges;
+pub use triangles::transitivity;
+pub use floyd_warshall::floyd_warshall;
+pub use bellman_ford::bellman_ford;
+pub use diff::{apply_patch, content_hash, diff, edge_set_hash, structurally_equal};
+pub use layout::spring_layout;
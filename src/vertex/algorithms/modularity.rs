@@ -0,0 +1,131 @@
+// vertex/algorithms/modularity.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+
+/// Modularity of a community assignment on the undirected weighted view.
+///
+/// Every directed edge counts as one undirected edge of its weight, so with
+/// `m` the total weight, `L_c` the weight inside community `c` and `d_c` the
+/// summed weighted degree of its nodes, the score is
+/// `sum_c (L_c / m - (d_c / 2m)^2)`, the same value as the
+/// `(1/2m) * sum_ij (A_ij - k_i k_j / 2m) * delta(c_i, c_j)` form.
+/// A graph without edges scores 0.
+pub fn modularity(
+    vertex: &Vertex,
+    py: Python<'_>,
+    communities: HashMap<String, i64>,
+    weight_field: Option<String>,
+) -> PyResult<f64> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    adj.ensure_non_negative()?;
+    let community_of = labels(&adj, &communities, "community")?;
+
+    let mut total_weight = 0.0;
+    let mut internal: HashMap<i64, f64> = HashMap::new();
+    let mut degree: HashMap<i64, f64> = HashMap::new();
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, weight) in edges {
+            let (from_c, to_c) = (community_of[from_idx], community_of[to_idx]);
+            total_weight += weight;
+            *degree.entry(from_c).or_default() += weight;
+            *degree.entry(to_c).or_default() += weight;
+            if from_c == to_c {
+                *internal.entry(from_c).or_default() += weight;
+            }
+        }
+    }
+
+    if total_weight == 0.0 {
+        return Ok(0.0);
+    }
+    Ok(degree
+        .iter()
+        .map(|(community, &d)| {
+            let inside = internal.get(community).copied().unwrap_or(0.0);
+            inside / total_weight - (d / (2.0 * total_weight)).powi(2)
+        })
+        .sum())
+}
+
+/// Number of edges (or their summed weight) joining nodes of different parts.
+///
+/// Every directed edge counts once; self-loops never cross.
+pub fn cut_size(
+    vertex: &Vertex,
+    py: Python<'_>,
+    partition: HashMap<String, i64>,
+    weight_field: Option<String>,
+) -> PyResult<f64> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    let part_of = labels(&adj, &partition, "part")?;
+    Ok(adj
+        .out
+        .iter()
+        .enumerate()
+        .flat_map(|(from_idx, edges)| edges.iter().map(move |&(to_idx, weight)| (from_idx, to_idx, weight)))
+        .filter(|&(from_idx, to_idx, _)| part_of[from_idx] != part_of[to_idx])
+        .map(|(_, _, weight)| weight)
+        .sum())
+}
+
+/// Degree assortativity coefficient on the undirected view.
+///
+/// The Pearson correlation between the degrees at the two ends of every edge,
+/// with each edge counted in both orientations so the measure is symmetric.
+/// Degrees count incident edges (in and out), or sum their `weight_field`
+/// values when given; edges themselves are not weighted. Returns NaN when the
+/// coefficient is undefined: no edges, or every endpoint with equal degree.
+pub fn degree_assortativity(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: Option<String>,
+) -> PyResult<f64> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    let mut degree = vec![0.0; adj.len()];
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, weight) in edges {
+            degree[from_idx] += weight;
+            degree[to_idx] += weight;
+        }
+    }
+
+    // Both orientations share their sums, so sum(x) == sum(y) and sum(x^2) == sum(y^2)
+    let (mut pairs, mut sum, mut sum_sq, mut sum_xy) = (0.0, 0.0, 0.0, 0.0);
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, _) in edges {
+            let (a, b) = (degree[from_idx], degree[to_idx]);
+            pairs += 2.0;
+            sum += a + b;
+            sum_sq += a * a + b * b;
+            sum_xy += 2.0 * a * b;
+        }
+    }
+    if pairs == 0.0 {
+        return Ok(f64::NAN);
+    }
+    let mean = sum / pairs;
+    let variance = sum_sq / pairs - mean * mean;
+    if variance <= f64::EPSILON * mean * mean {
+        return Ok(f64::NAN);
+    }
+    Ok((sum_xy / pairs - mean * mean) / variance)
+}
+
+/// Label of every node in `adj.ids` order; raises `ValueError` when a node
+/// has no label or a key of `assignment` is not a node.
+fn labels(adj: &Adjacency, assignment: &HashMap<String, i64>, what: &str) -> PyResult<Vec<i64>> {
+    for node_id in assignment.keys() {
+        adj.require(node_id)?;
+    }
+    adj.ids
+        .iter()
+        .map(|id| {
+            assignment.get(id).copied().ok_or_else(|| {
+                pyo3::exceptions::PyValueError::new_err(format!("Node with id '{}' has no {}", id, what))
+            })
+        })
+        .collect()
+}
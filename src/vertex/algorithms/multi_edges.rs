@@ -0,0 +1,67 @@
+// vertex/algorithms/multi_edges.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::edge_weight;
+
+/// Turn a multigraph into a weighted simple graph.
+///
+/// Parallel edges with the same source and target become one edge that keeps
+/// the attributes and id of the first of them, with `weight_field` set to the
+/// summed weight (1.0 per edge without it, so unweighted edges are counted)
+/// and `attr["multiplicity"]` to the number of edges merged. Nodes are fresh
+/// copies with their attributes and tags; edge direction is kept.
+pub fn collapse_multi_edges(vertex: &Vertex, py: Python<'_>, weight_field: &str) -> PyResult<Py<Vertex>> {
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        let attr: HashMap<String, Py<PyAny>> = node
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let copy = manipulation::add_node(&mut result, py, (*id).clone(), Some(attr))?;
+        copy.borrow_mut(py).tags = node.tags.clone();
+    }
+
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        // Targets in order of their first edge, with the merged weight and count
+        let mut order: Vec<String> = Vec::new();
+        let mut merged: HashMap<String, (usize, f64, usize)> = HashMap::new();
+        for (pos, edge) in node.edges.iter().enumerate() {
+            let edge_ref = edge.borrow(py);
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            if !vertex.nodes.contains_key(&to_id) {
+                continue;
+            }
+            let weight = edge_weight(py, &edge_ref, Some(weight_field))?;
+            let entry = merged.entry(to_id.clone()).or_insert_with(|| {
+                order.push(to_id);
+                (pos, 0.0, 0)
+            });
+            entry.1 += weight;
+            entry.2 += 1;
+        }
+
+        for to_id in order {
+            let (first, weight, count) = merged[&to_id];
+            let original = node.edges[first].borrow(py);
+            let mut attr: HashMap<String, Py<PyAny>> = original
+                .attr
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+                .collect();
+            attr.insert(weight_field.to_string(), weight.into_pyobject(py)?.into_any().unbind());
+            attr.insert("multiplicity".to_string(), count.into_pyobject(py)?.into_any().unbind());
+            let edge = manipulation::add_edge(&mut result, py, (*id).clone(), to_id, Some(attr))?;
+            edge.borrow_mut(py).id = original.id.clone();
+        }
+    }
+
+    Py::new(py, result)
+}
@@ -0,0 +1,204 @@
+// vertex/algorithms/normalize.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::{Edge, Node};
+use crate::edge::map_to_dict;
+use crate::errors::GraphError;
+use crate::node::numeric_value;
+use super::super::core::Vertex;
+use super::adjacency::edge_weight;
+
+/// Rescale edge weights in place through `Edge.attr_set`, so edge update
+/// callbacks fire for every weight that changes.
+///
+/// `"outgoing"` divides each node's outgoing weights by their sum, turning
+/// them into transition probabilities; nodes without outgoing edges or with a
+/// zero sum are left unchanged. `"global"` min-max scales every weight in the
+/// graph onto `[0, 1]`, setting all of them to 1.0 when they are equal. A
+/// missing weight counts as 1.0 and is written back normalized.
+pub fn normalize_edge_weights(
+    vertex: &Vertex,
+    py: Python<'_>,
+    weight_field: &str,
+    mode: &str,
+) -> PyResult<()> {
+    if mode != "outgoing" && mode != "global" {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown mode '{}', expected 'outgoing' or 'global'",
+            mode
+        )));
+    }
+
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    // Outgoing edges and their current weights, grouped by source node
+    let mut groups: Vec<Vec<(Py<Edge>, f64)>> = Vec::with_capacity(ids.len());
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        let mut group = Vec::with_capacity(node.edges.len());
+        for edge in &node.edges {
+            let weight = edge_weight(py, &edge.borrow(py), Some(weight_field))?;
+            if mode == "outgoing" && weight < 0.0 {
+                return Err(GraphError::new_err(format!(
+                    "Negative edge weight {} on an outgoing edge of '{}'",
+                    weight, id
+                )));
+            }
+            group.push((edge.clone_ref(py), weight));
+        }
+        groups.push(group);
+    }
+
+    let scaled: Vec<(Py<Edge>, f64)> = if mode == "outgoing" {
+        let mut scaled = Vec::new();
+        for group in groups {
+            let total: f64 = group.iter().map(|(_, w)| w).sum();
+            if total > 0.0 {
+                scaled.extend(group.into_iter().map(|(edge, w)| (edge, w / total)));
+            }
+        }
+        scaled
+    } else {
+        let all: Vec<(Py<Edge>, f64)> = groups.into_iter().flatten().collect();
+        let min = all.iter().map(|(_, w)| *w).fold(f64::INFINITY, f64::min);
+        let max = all.iter().map(|(_, w)| *w).fold(f64::NEG_INFINITY, f64::max);
+        all.into_iter()
+            .map(|(edge, w)| {
+                let w = if max > min { (w - min) / (max - min) } else { 1.0 };
+                (edge, w)
+            })
+            .collect()
+    };
+
+    // Callbacks may touch the graph, so no borrows are held while they run
+    for (edge, weight) in scaled {
+        let value = weight.into_pyobject(py)?.into_any().unbind();
+        Edge::attr_set(edge.bind(py).borrow_mut(), py, weight_field.to_string(), value)?;
+    }
+    Ok(())
+}
+
+/// Store `expression(edge)` as a float at `out_field` on every edge.
+///
+/// Edges are visited by sorted source id. Every value is computed before any
+/// is written, so an exception or a non-numeric result leaves the graph
+/// unchanged. Values are written with `Edge.attr_set`, firing edge update
+/// callbacks for each one that changes.
+pub fn derive_edge_weight(
+    vertex: &Vertex,
+    py: Python<'_>,
+    out_field: &str,
+    expression: &Bound<'_, PyAny>,
+) -> PyResult<()> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let edges: Vec<Py<Edge>> = ids
+        .iter()
+        .flat_map(|id| {
+            let node = vertex.nodes[*id].borrow(py);
+            node.edges.iter().map(|edge| edge.clone_ref(py)).collect::<Vec<_>>()
+        })
+        .collect();
+
+    let mut values: Vec<f64> = Vec::with_capacity(edges.len());
+    for edge in &edges {
+        let value = expression.call1((edge.clone_ref(py),))?;
+        let Some(weight) = numeric_value(py, &value.clone().unbind())? else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "expression must return an int or float, got '{}'",
+                value.get_type().name()?
+            )));
+        };
+        values.push(weight);
+    }
+
+    // Callbacks may touch the graph, so no borrows are held while they run
+    for (edge, weight) in edges.into_iter().zip(values) {
+        let value = weight.into_pyobject(py)?.into_any().unbind();
+        Edge::attr_set(edge.bind(py).borrow_mut(), py, out_field.to_string(), value)?;
+    }
+    Ok(())
+}
+
+/// Replace every node's `attr` with `func(copy of attr)`, by sorted id.
+///
+/// All dicts are computed before any is written, so an exception or a
+/// non-dict result leaves the graph unchanged. With `fire`, keys whose value
+/// changes go through `Node.attr_set` (firing node update callbacks, in sorted
+/// key order) and dropped keys are removed silently; otherwise `attr` is
+/// replaced directly.
+pub fn map_node_attrs(vertex: &Vertex, py: Python<'_>, func: &Bound<'_, PyAny>, fire: bool) -> PyResult<()> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let nodes: Vec<Py<Node>> = ids.iter().map(|id| vertex.nodes[*id].clone_ref(py)).collect();
+    let mut mapped = Vec::with_capacity(nodes.len());
+    for node in &nodes {
+        mapped.push(mapped_attr(py, &node.borrow(py).attr, func)?);
+    }
+
+    // Callbacks may touch the graph, so no borrows are held while they run
+    for (node, attr) in nodes.into_iter().zip(mapped) {
+        if !fire {
+            node.borrow_mut(py).attr = attr;
+            continue;
+        }
+        node.borrow_mut(py).attr.retain(|key, _| attr.contains_key(key));
+        for (key, value) in sorted_entries(attr) {
+            Node::attr_set(node.bind(py).borrow_mut(), py, key, value)?;
+        }
+    }
+    Ok(())
+}
+
+/// Edge analogue of `map_node_attrs`, visiting edges by sorted source id.
+pub fn map_edge_attrs(vertex: &Vertex, py: Python<'_>, func: &Bound<'_, PyAny>, fire: bool) -> PyResult<()> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let edges: Vec<Py<Edge>> = ids
+        .iter()
+        .flat_map(|id| {
+            let node = vertex.nodes[*id].borrow(py);
+            node.edges.iter().map(|edge| edge.clone_ref(py)).collect::<Vec<_>>()
+        })
+        .collect();
+    let mut mapped = Vec::with_capacity(edges.len());
+    for edge in &edges {
+        mapped.push(mapped_attr(py, &edge.borrow(py).attr, func)?);
+    }
+
+    for (edge, attr) in edges.into_iter().zip(mapped) {
+        if !fire {
+            edge.borrow_mut(py).attr = attr;
+            continue;
+        }
+        edge.borrow_mut(py).attr.retain(|key, _| attr.contains_key(key));
+        for (key, value) in sorted_entries(attr) {
+            Edge::attr_set(edge.bind(py).borrow_mut(), py, key, value)?;
+        }
+    }
+    Ok(())
+}
+
+/// `func` applied to a fresh dict copy of `attr`, which must return a dict.
+fn mapped_attr(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    func: &Bound<'_, PyAny>,
+) -> PyResult<HashMap<String, Py<PyAny>>> {
+    let result = func.call1((map_to_dict(py, attr)?,))?;
+    result.extract().map_err(|_| {
+        let type_name = result.get_type().name().map(|n| n.to_string()).unwrap_or_default();
+        pyo3::exceptions::PyTypeError::new_err(format!(
+            "func must return a dict with str keys, got '{}'",
+            type_name
+        ))
+    })
+}
+
+fn sorted_entries(attr: HashMap<String, Py<PyAny>>) -> Vec<(String, Py<PyAny>)> {
+    let mut entries: Vec<(String, Py<PyAny>)> = attr.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
@@ -0,0 +1,58 @@
+// vertex/algorithms/parallel_bfs.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::Node;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+
+/// Level-synchronous BFS from `start_node_id` following edge directions.
+///
+/// The topology is copied into an `Adjacency` under a single GIL acquisition
+/// and the search itself runs inside `allow_threads`, so other Python threads
+/// keep running alongside it. Python is re-entered once at the end to build
+/// the result, which holds the original nodes and the visit order in
+/// `meta["nodelist"]`.
+pub fn parallel_bfs(
+    vertex: &Vertex,
+    py: Python<'_>,
+    start_node_id: String,
+    depth: Option<usize>,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let start = adj.require(&start_node_id)?;
+
+    let order: Vec<usize> = py.allow_threads(|| {
+        let mut visited = vec![false; adj.len()];
+        visited[start] = true;
+        let mut order = vec![start];
+        let mut frontier = vec![start];
+        let mut level = 0;
+
+        while !frontier.is_empty() && depth.is_none_or(|max| level < max) {
+            let mut next = Vec::new();
+            for &idx in &frontier {
+                for &(to, _) in &adj.out[idx] {
+                    if !visited[to] {
+                        visited[to] = true;
+                        next.push(to);
+                    }
+                }
+            }
+            frontier = next;
+            order.extend_from_slice(&frontier);
+            level += 1;
+        }
+        order
+    });
+
+    let mut found = HashMap::<String, Py<Node>>::with_capacity(order.len());
+    let mut nodelist = Vec::with_capacity(order.len());
+    for idx in order {
+        let id = &adj.ids[idx];
+        found.insert(id.clone(), vertex.nodes[id].clone_ref(py));
+        nodelist.push(id.clone());
+    }
+
+    Py::new(py, Vertex::from_nodes_with_path(py, found, nodelist)?)
+}
@@ -0,0 +1,78 @@
+// vertex/algorithms/path_neighborhood.rs
+
+use pyo3::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+use super::expand::build_expanded;
+use super::sssp::{single_source_within, PathLimits};
+
+/// Shortest path from `root_node_id` to `target_node_id` plus every node
+/// within `padding` outgoing hops of a path node.
+///
+/// The path minimises hops, or the summed `weight_field` when given, and must
+/// fit within both `limits` (see `single_source_limited`). The
+/// result holds fresh copies of the selected nodes with the edges among them,
+/// and `meta["nodelist"]` lists the path in order. With `edge_filter` the path
+/// and the padding only follow matching edges.
+#[allow(clippy::too_many_arguments)]
+pub fn path_neighborhood(
+    vertex: &Vertex,
+    py: Python<'_>,
+    root_node_id: String,
+    target_node_id: String,
+    padding: usize,
+    weight_field: Option<String>,
+    limits: PathLimits,
+    edge_filter: Option<HashMap<String, Py<PyAny>>>,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex_filtered(vertex, py, weight_field.as_deref(), &edge_filter)?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+    let root = adj.require(&root_node_id)?;
+    let target = adj.require(&target_node_id)?;
+
+    let path = py
+        .allow_threads(|| single_source_within(&adj, root, weighted, limits).path_to(target))
+        .ok_or_else(|| {
+            GraphError::new_err(format!(
+                "Target node '{}' not reachable from '{}'",
+                target_node_id, root_node_id
+            ))
+        })?;
+
+    // Multi-source BFS from every path node, `padding` levels deep
+    let mut depth: Vec<Option<usize>> = vec![None; adj.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    for &idx in &path {
+        depth[idx] = Some(0);
+        queue.push_back(idx);
+    }
+    while let Some(current) = queue.pop_front() {
+        let level = depth[current].expect("queued nodes have a depth");
+        if level >= padding {
+            continue;
+        }
+        for &(next, _) in &adj.out[current] {
+            if depth[next].is_none() {
+                depth[next] = Some(level + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let selected: HashSet<String> = depth
+        .iter()
+        .enumerate()
+        .filter(|(_, d)| d.is_some())
+        .map(|(idx, _)| adj.ids[idx].clone())
+        .collect();
+    let result = build_expanded(py, vertex, &selected)?;
+
+    let nodelist: Vec<&String> = path.iter().map(|&idx| &adj.ids[idx]).collect();
+    result.borrow(py).meta.bind(py).set_item("nodelist", nodelist)?;
+    Ok(result)
+}
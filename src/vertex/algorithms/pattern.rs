@@ -0,0 +1,209 @@
+// vertex/algorithms/pattern.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::{HashMap, HashSet};
+use super::super::core::Vertex;
+
+/// Largest pattern accepted by `match_pattern`; the backtracking search is
+/// exponential in the pattern size.
+pub const MAX_PATTERN_NODES: usize = 10;
+
+/// A pattern edge and the graph node pairs joined by an edge that satisfies it.
+struct PatternEdge {
+    from: usize,
+    to: usize,
+    allowed: HashSet<(usize, usize)>,
+}
+
+/// Find every embedding of `pattern` in `vertex` (subgraph monomorphism).
+///
+/// Pattern nodes map to distinct graph nodes whose `attr` contains every
+/// attribute of the pattern node with an equal value; every pattern edge
+/// needs a graph edge in the same direction whose `attr` contains the pattern
+/// edge's attributes (e.g. `type`). Extra graph edges are allowed. Matches
+/// are sorted by the graph ids assigned to the pattern nodes (taken in
+/// pattern id order); the search stops once `max_matches` are found.
+pub fn match_pattern(
+    vertex: &Vertex,
+    py: Python<'_>,
+    pattern: &Vertex,
+    max_matches: Option<usize>,
+) -> PyResult<Py<PyAny>> {
+    if pattern.nodes.len() > MAX_PATTERN_NODES {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Pattern has {} nodes; at most {} are supported",
+            pattern.nodes.len(),
+            MAX_PATTERN_NODES
+        )));
+    }
+
+    let mut graph_ids: Vec<&String> = vertex.nodes.keys().collect();
+    graph_ids.sort();
+    let graph_index: HashMap<&str, usize> = graph_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+
+    let mut pattern_ids: Vec<&String> = pattern.nodes.keys().collect();
+    pattern_ids.sort();
+    let pattern_index: HashMap<&str, usize> = pattern_ids
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (id.as_str(), i))
+        .collect();
+
+    // Graph nodes satisfying each pattern node's attribute constraints
+    let mut candidates: Vec<Vec<usize>> = Vec::with_capacity(pattern_ids.len());
+    for id in &pattern_ids {
+        let pattern_node = pattern.nodes[*id].borrow(py);
+        let mut matching = Vec::new();
+        for (idx, graph_id) in graph_ids.iter().enumerate() {
+            if attrs_contain(py, &vertex.nodes[*graph_id].borrow(py).attr, &pattern_node.attr)? {
+                matching.push(idx);
+            }
+        }
+        candidates.push(matching);
+    }
+
+    // Graph edges satisfying each pattern edge's attribute constraints
+    let mut pattern_edges: Vec<PatternEdge> = Vec::new();
+    for (from_p, id) in pattern_ids.iter().enumerate() {
+        for edge in &pattern.nodes[*id].borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let to_id = edge_ref.to_node.borrow(py).id.clone();
+            let Some(&to_p) = pattern_index.get(to_id.as_str()) else { continue };
+            let mut allowed = HashSet::new();
+            for (from_g, graph_id) in graph_ids.iter().enumerate() {
+                for graph_edge in &vertex.nodes[*graph_id].borrow(py).edges {
+                    let graph_edge = graph_edge.borrow(py);
+                    let to_id = graph_edge.to_node.borrow(py).id.clone();
+                    let Some(&to_g) = graph_index.get(to_id.as_str()) else { continue };
+                    if attrs_contain(py, &graph_edge.attr, &edge_ref.attr)? {
+                        allowed.insert((from_g, to_g));
+                    }
+                }
+            }
+            pattern_edges.push(PatternEdge { from: from_p, to: to_p, allowed });
+        }
+    }
+
+    let matches = py.allow_threads(|| {
+        let order = search_order(pattern_ids.len(), &candidates, &pattern_edges);
+        let mut search = Search {
+            order: &order,
+            candidates: &candidates,
+            pattern_edges: &pattern_edges,
+            assigned: vec![usize::MAX; pattern_ids.len()],
+            used: vec![false; graph_ids.len()],
+            matches: Vec::new(),
+            max_matches: max_matches.unwrap_or(usize::MAX),
+        };
+        if !pattern_ids.is_empty() {
+            search.extend(0);
+        }
+        let mut matches = search.matches;
+        matches.sort();
+        matches
+    });
+
+    let result = pyo3::types::PyList::empty(py);
+    for assigned in matches {
+        let mapping = PyDict::new(py);
+        for (p, &g) in assigned.iter().enumerate() {
+            mapping.set_item(pattern_ids[p], graph_ids[g])?;
+        }
+        result.append(mapping)?;
+    }
+    Ok(result.into_any().unbind())
+}
+
+/// True if every key of `required` is in `attr` with an equal value.
+pub(crate) fn attrs_contain(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    required: &HashMap<String, Py<PyAny>>,
+) -> PyResult<bool> {
+    for (key, value) in required {
+        match attr.get(key) {
+            Some(actual) if actual.bind(py).eq(value.bind(py))? => {}
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+/// Order pattern nodes so each one is as constrained as possible by the nodes
+/// placed before it: most edges to placed nodes first, then fewest candidates.
+fn search_order(
+    n: usize,
+    candidates: &[Vec<usize>],
+    pattern_edges: &[PatternEdge],
+) -> Vec<usize> {
+    let mut order = Vec::with_capacity(n);
+    let mut placed = vec![false; n];
+    while order.len() < n {
+        let next = (0..n)
+            .filter(|&p| !placed[p])
+            .max_by_key(|&p| {
+                let links = pattern_edges
+                    .iter()
+                    .filter(|e| (e.from == p && placed[e.to]) || (e.to == p && placed[e.from]))
+                    .count();
+                (links, std::cmp::Reverse(candidates[p].len()), std::cmp::Reverse(p))
+            })
+            .unwrap();
+        placed[next] = true;
+        order.push(next);
+    }
+    order
+}
+
+struct Search<'a> {
+    order: &'a [usize],
+    candidates: &'a [Vec<usize>],
+    pattern_edges: &'a [PatternEdge],
+    /// Graph node assigned to each pattern node (`usize::MAX` while unassigned)
+    assigned: Vec<usize>,
+    used: Vec<bool>,
+    matches: Vec<Vec<usize>>,
+    max_matches: usize,
+}
+
+impl Search<'_> {
+    fn extend(&mut self, depth: usize) {
+        let p = self.order[depth];
+        let candidates = self.candidates;
+        for &g in &candidates[p] {
+            if self.matches.len() >= self.max_matches {
+                return;
+            }
+            if self.used[g] {
+                continue;
+            }
+            self.assigned[p] = g;
+            if self.edges_hold(p) {
+                if depth + 1 == self.order.len() {
+                    self.matches.push(self.assigned.clone());
+                } else {
+                    self.used[g] = true;
+                    self.extend(depth + 1);
+                    self.used[g] = false;
+                }
+            }
+            self.assigned[p] = usize::MAX;
+        }
+    }
+
+    /// Check the pattern edges between `p` and the already assigned nodes.
+    fn edges_hold(&self, p: usize) -> bool {
+        self.pattern_edges.iter().all(|e| {
+            if e.from != p && e.to != p {
+                return true;
+            }
+            let (from_g, to_g) = (self.assigned[e.from], self.assigned[e.to]);
+            from_g == usize::MAX || to_g == usize::MAX || e.allowed.contains(&(from_g, to_g))
+        })
+    }
+}
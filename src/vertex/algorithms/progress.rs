@@ -0,0 +1,87 @@
+// vertex/algorithms/progress.rs
+
+use pyo3::prelude::*;
+use rayon::prelude::*;
+use crate::errors::CancelledError;
+
+/// Number of batches a monitored run is split into at most.
+const PROGRESS_STEPS: usize = 100;
+
+/// Optional Python callables that observe a long-running algorithm.
+#[derive(Clone, Copy, Default)]
+pub struct Monitor<'a, 'py> {
+    /// Called as `progress(completed, total)` after every batch.
+    pub progress: Option<&'a Bound<'py, PyAny>>,
+    /// Polled before every batch; a truthy result raises `CancelledError`.
+    pub should_cancel: Option<&'a Bound<'py, PyAny>>,
+}
+
+impl Monitor<'_, '_> {
+    pub fn is_active(&self) -> bool {
+        self.progress.is_some() || self.should_cancel.is_some()
+    }
+
+    /// Poll `should_cancel` before work continues after `done` of `total` items.
+    pub fn check_cancelled(&self, done: usize, total: usize) -> PyResult<()> {
+        if let Some(should_cancel) = self.should_cancel {
+            if should_cancel.call0()?.is_truthy()? {
+                return Err(CancelledError::new_err(format!(
+                    "cancelled after {} of {} items",
+                    done, total
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Call `progress(done, total)` if given.
+    pub fn report(&self, done: usize, total: usize) -> PyResult<()> {
+        if let Some(progress) = self.progress {
+            progress.call1((done, total))?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute `work(i)` for every `i` in `0..total` under `monitor`.
+///
+/// Without callbacks everything runs in one batch. With any, the items are
+/// processed in up to `PROGRESS_STEPS` batches; each batch runs without the
+/// GIL (across threads when `parallel`), and the GIL is only taken back
+/// between batches to poll `should_cancel` and call `progress`. Results are
+/// only returned once every batch has finished, so a cancelled run leaves
+/// nothing behind.
+pub fn run_monitored<T, F>(
+    py: Python<'_>,
+    total: usize,
+    parallel: bool,
+    monitor: Monitor<'_, '_>,
+    work: F,
+) -> PyResult<Vec<T>>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync,
+{
+    let chunk = if monitor.is_active() {
+        total.div_ceil(PROGRESS_STEPS).max(1)
+    } else {
+        total.max(1)
+    };
+    let mut results = Vec::with_capacity(total);
+    let mut start = 0;
+    while start < total {
+        monitor.check_cancelled(start, total)?;
+        let end = (start + chunk).min(total);
+        let part: Vec<T> = py.allow_threads(|| {
+            if parallel {
+                (start..end).into_par_iter().map(&work).collect()
+            } else {
+                (start..end).map(&work).collect()
+            }
+        });
+        results.extend(part);
+        monitor.report(end, total)?;
+        start = end;
+    }
+    Ok(results)
+}
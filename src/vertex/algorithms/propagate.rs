@@ -0,0 +1,78 @@
+// vertex/algorithms/propagate.rs
+
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use crate::node::{aggregate_values, neighbor_direction, numeric_value};
+use super::super::core::Vertex;
+
+/// Write the aggregate of each node's neighbour values into `out_key`.
+///
+/// The first iteration reads `attr_key`; each later one reads the previous
+/// iteration's results. Every iteration computes all nodes from the same
+/// snapshot, so no node sees a partially updated pass. Neighbours follow the
+/// `Node.aggregate_neighbors` rules (one read per edge, only nodes inside the
+/// vertex, non-numeric values skipped). Nodes whose aggregate is None are left
+/// untouched. Values are written straight into `attr`, without update callbacks.
+pub fn propagate(
+    vertex: &Vertex,
+    py: Python<'_>,
+    attr_key: &str,
+    out_key: &str,
+    op: &str,
+    iterations: usize,
+    direction: &str,
+) -> PyResult<()> {
+    let (outgoing, incoming) = neighbor_direction(direction)?;
+    aggregate_values(&[], op)?;
+    if iterations == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "iterations must be at least 1",
+        ));
+    }
+
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let index: HashMap<&String, usize> = ids.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); ids.len()];
+    let mut values = Vec::with_capacity(ids.len());
+    for (idx, id) in ids.iter().enumerate() {
+        let node = vertex.nodes[*id].borrow(py);
+        if outgoing {
+            for edge in &node.edges {
+                let to_id = edge.borrow(py).to_node.borrow(py).id.clone();
+                neighbors[idx].extend(index.get(&to_id).copied());
+            }
+        }
+        if incoming {
+            for edge in &node.inverse_edges {
+                let from_id = edge.borrow(py).from_node.borrow(py).id.clone();
+                neighbors[idx].extend(index.get(&from_id).copied());
+            }
+        }
+        values.push(match node.attr.get(attr_key) {
+            Some(value) => numeric_value(py, value)?,
+            None => None,
+        });
+    }
+
+    for _ in 0..iterations {
+        let mut next = Vec::with_capacity(values.len());
+        for neighbor_list in &neighbors {
+            let inputs: Vec<f64> = neighbor_list.iter().filter_map(|&nb| values[nb]).collect();
+            next.push(aggregate_values(&inputs, op)?);
+        }
+        values = next;
+    }
+
+    for (id, value) in ids.iter().zip(values) {
+        let Some(value) = value else { continue };
+        let value = if op == "count" {
+            (value as usize).into_pyobject(py)?.into_any().unbind()
+        } else {
+            value.into_pyobject(py)?.into_any().unbind()
+        };
+        vertex.nodes[*id].borrow_mut(py).attr.insert(out_key.to_string(), value);
+    }
+    Ok(())
+}
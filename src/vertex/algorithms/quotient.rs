@@ -0,0 +1,88 @@
+// vertex/algorithms/quotient.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::{BTreeMap, HashMap};
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::Adjacency;
+
+/// Merge all nodes sharing a value of `attr_key` into one super-node.
+///
+/// A super-node is named after `str(value)` and carries `attr_key` (the value
+/// itself), `members` (sorted original ids) and `size`. Nodes without the
+/// attribute are left out together with their edges. The edges between two
+/// groups become one edge with `count` (number of original edges) and
+/// `weight` (their summed `weight_field`, 1.0 each without it); edges inside a
+/// group become such a self-loop unless `self_loops` is false. The result's
+/// `meta["group_members"]` maps every super-node id to its members.
+pub fn quotient_by(
+    vertex: &Vertex,
+    py: Python<'_>,
+    attr_key: &str,
+    weight_field: Option<&str>,
+    self_loops: bool,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field)?;
+
+    // Group label per node index, plus the original value of each label
+    let mut label_of: Vec<Option<String>> = Vec::with_capacity(adj.len());
+    let mut groups: BTreeMap<String, (Py<PyAny>, Vec<usize>)> = BTreeMap::new();
+    for (idx, id) in adj.ids.iter().enumerate() {
+        let node_ref = vertex.nodes[id].borrow(py);
+        let label = match node_ref.attr.get(attr_key) {
+            Some(value) => {
+                let label = value.bind(py).str()?.to_string();
+                groups
+                    .entry(label.clone())
+                    .or_insert_with(|| (value.clone_ref(py), Vec::new()))
+                    .1
+                    .push(idx);
+                Some(label)
+            }
+            None => None,
+        };
+        label_of.push(label);
+    }
+
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let group_members = PyDict::new(py);
+    for (label, (value, members)) in &groups {
+        let member_ids: Vec<&String> = members.iter().map(|&idx| &adj.ids[idx]).collect();
+        let mut attr = HashMap::new();
+        attr.insert(attr_key.to_string(), value.clone_ref(py));
+        attr.insert("members".to_string(), member_ids.clone().into_pyobject(py)?.into_any().unbind());
+        attr.insert("size".to_string(), members.len().into_pyobject(py)?.into_any().unbind());
+        manipulation::add_node(&mut result, py, label.clone(), Some(attr))?;
+        group_members.set_item(label, member_ids)?;
+    }
+
+    let mut links: BTreeMap<(&str, &str), (usize, f64)> = BTreeMap::new();
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        let Some(from_label) = &label_of[from_idx] else { continue };
+        for &(to_idx, weight) in edges {
+            let Some(to_label) = &label_of[to_idx] else { continue };
+            if from_label == to_label && !self_loops {
+                continue;
+            }
+            let link = links.entry((from_label, to_label)).or_default();
+            link.0 += 1;
+            link.1 += weight;
+        }
+    }
+    for ((from_label, to_label), (count, weight)) in links {
+        let mut attr = HashMap::new();
+        attr.insert("count".to_string(), count.into_pyobject(py)?.into_any().unbind());
+        attr.insert("weight".to_string(), weight.into_pyobject(py)?.into_any().unbind());
+        manipulation::add_edge(
+            &mut result,
+            py,
+            from_label.to_string(),
+            to_label.to_string(),
+            Some(attr),
+        )?;
+    }
+
+    result.meta.bind(py).set_item("group_members", group_members)?;
+    Py::new(py, result)
+}
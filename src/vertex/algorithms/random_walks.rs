@@ -4,16 +4,26 @@ use pyo3::prelude::*;
 use pyo3::types::PyList;
 use std::collections::{HashMap, HashSet};
 use crate::{Node, Edge};
+use crate::errors::node_not_found;
 use super::super::core::Vertex;
+use super::sampling::make_rng;
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
 use rand::Rng;
 
-// Structure to hold a walk with optional edge types
+// Structure to hold a walk with optional edge labels
 #[derive(Clone)]
 struct Walk {
     nodes: Vec<String>,
-    edges: Vec<String>, // Edge types between nodes
+    edges: Vec<String>, // Edge types or ids between nodes
+}
+
+// What, if anything, is recorded for each traversed edge
+#[derive(Clone, Copy, PartialEq)]
+enum EdgeLabel {
+    Nothing,
+    Type,
+    Id,
 }
 
 fn validate_params(
@@ -22,11 +32,12 @@ fn validate_params(
     max_length: usize,
     min_len: usize,
     stratified: bool,
+    stop_prob: f64,
 ) -> PyResult<()> {
     match start_node_id {
         Some(id) => {
             if !vertex.nodes.contains_key(id) {
-                return Err(pyo3::exceptions::PyValueError::new_err(
+                return Err(node_not_found(
                     format!("Start node with id '{}' not found", id),
                 ));
             }
@@ -57,6 +68,13 @@ fn validate_params(
         ));
     }
 
+    if !(0.0..=1.0).contains(&stop_prob) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "stop_prob must be in [0, 1], got {}",
+            stop_prob
+        )));
+    }
+
     Ok(())
 }
 
@@ -81,7 +99,7 @@ fn deduplicate_walks(walks: Vec<Walk>, include_edges: bool) -> Vec<Walk> {
 }
 
 // Pick an index with probability proportional to its weight.
-fn weighted_pick_index(weights: &[f64], rng: &mut rand::rngs::ThreadRng) -> usize {
+fn weighted_pick_index(weights: &[f64], rng: &mut StdRng) -> usize {
     let total: f64 = weights.iter().sum();
     if total <= 0.0 {
         return 0;
@@ -111,30 +129,45 @@ pub fn random_walks(
     num_attempts: usize,
     allow_revisit: Option<bool>,
     include_edge_types: Option<bool>,
+    include_edge_ids: Option<bool>,
     edge_type_field: Option<String>,
-    stratified: Option<bool>
+    stratified: Option<bool>,
+    stop_prob: Option<f64>,
+    seed: Option<u64>,
 ) -> PyResult<Py<PyList>> {
     let min_len = min_length.unwrap_or(1);
     let allow_revisit_nodes = allow_revisit.unwrap_or(false);
-    let include_edges = include_edge_types.unwrap_or(false);
+    let edge_label = match (include_edge_types.unwrap_or(false), include_edge_ids.unwrap_or(false)) {
+        (false, false) => EdgeLabel::Nothing,
+        (true, false) => EdgeLabel::Type,
+        (false, true) => EdgeLabel::Id,
+        (true, true) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "include_edge_types and include_edge_ids cannot both be True",
+            ));
+        }
+    };
+    let include_edges = edge_label != EdgeLabel::Nothing;
     let type_field = edge_type_field.unwrap_or_else(|| "type".to_string());
     let stratified_mode = stratified.unwrap_or(false);
+    let stop_prob = stop_prob.unwrap_or(0.0);
 
-    validate_params(vertex, &start_node_id, max_length, min_len, stratified_mode)?;
+    validate_params(vertex, &start_node_id, max_length, min_len, stratified_mode, stop_prob)?;
 
     // Visit counts persist across all attempts of this call so that later
     // walks are steered towards nodes that earlier walks neglected.
     let mut visit_counts: HashMap<String, u64> = HashMap::new();
 
     let mut all_walks = Vec::new();
-    let mut rng = thread_rng();    // Perform multiple random walk attempts
+    let mut rng = make_rng(seed);    // Perform multiple random walk attempts
     for _ in 0..num_attempts {
         let walk_start = match &start_node_id {
             Some(id) => id.clone(),
             None => {
                 // Stratified start: sample over all nodes, favouring the
                 // least-visited ones.
-                let ids: Vec<&String> = vertex.nodes.keys().collect();
+                let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+                ids.sort();
                 let weights: Vec<f64> = ids
                     .iter()
                     .map(|id| stratified_weight(&visit_counts, id))
@@ -149,9 +182,10 @@ pub fn random_walks(
             walk_start,
             max_length,
             allow_revisit_nodes,
-            include_edges,
+            edge_label,
             &type_field,
             stratified_mode,
+            stop_prob,
             &mut visit_counts,
             &mut rng
         )? {
@@ -168,7 +202,7 @@ pub fn random_walks(
     let result = PyList::empty(py);
     for walk in unique_walks {
         if include_edges {
-            // Return list of [node, edge_type, node, edge_type, ...] format
+            // Return list of [node, edge_label, node, edge_label, ...] format
             let py_walk = PyList::empty(py);
             for i in 0..walk.nodes.len() {
                 py_walk.append(&walk.nodes[i])?;
@@ -197,11 +231,12 @@ fn perform_simple_random_walk(
     start_node_id: String,
     max_length: usize,
     allow_revisit: bool,
-    include_edge_types: bool,
+    edge_label: EdgeLabel,
     edge_type_field: &str,
     stratified: bool,
+    stop_prob: f64,
     visit_counts: &mut HashMap<String, u64>,
-    rng: &mut rand::rngs::ThreadRng
+    rng: &mut StdRng
 ) -> PyResult<Option<Walk>> {    let mut walk_nodes = Vec::new();
     let mut walk_edges = Vec::new();
     let mut visited = HashSet::new();
@@ -220,6 +255,12 @@ fn perform_simple_random_walk(
             visited.insert(current_node_id.clone());
         }
 
+        // Terminate early with probability stop_prob, giving walks a
+        // geometric length distribution capped at max_length
+        if stop_prob > 0.0 && rng.gen::<f64>() < stop_prob {
+            break;
+        }
+
         // Get the current node
         let current_node = match vertex.nodes.get(&current_node_id) {
             Some(node) => node,
@@ -247,18 +288,23 @@ fn perform_simple_random_walk(
                         if let Ok(to_id_str) = to_id.extract::<String>() {
                             // Include node if revisiting is allowed OR if we haven't visited it
                             if allow_revisit || !visited.contains(&to_id_str) {
-                                // Get edge type if needed
-                                let edge_type = if include_edge_types {
-                                    edge_ref.getattr("attr")
+                                // Get edge type or id if needed; an edge without
+                                // an id is recorded as "from->to"
+                                let label = match edge_label {
+                                    EdgeLabel::Type => edge_ref.getattr("attr")
                                         .ok()
                                         .and_then(|attr| attr.get_item(edge_type_field).ok())
                                         .and_then(|type_val| type_val.extract::<String>().ok())
-                                        .unwrap_or_else(|| "unknown".to_string())
-                                } else {
-                                    String::new()
+                                        .unwrap_or_else(|| "unknown".to_string()),
+                                    EdgeLabel::Id => edge_ref.getattr("id")
+                                        .ok()
+                                        .and_then(|id| id.extract::<Option<String>>().ok())
+                                        .flatten()
+                                        .unwrap_or_else(|| format!("{}->{}", current_node_id, to_id_str)),
+                                    EdgeLabel::Nothing => String::new(),
                                 };
-                                
-                                valid_next_options.push((to_id_str, edge_type));
+
+                                valid_next_options.push((to_id_str, label));
                             }
                         }
                     }
@@ -284,9 +330,9 @@ fn perform_simple_random_walk(
             valid_next_options.choose(rng)
         };
 
-        if let Some((next_node, edge_type)) = chosen {
-            if include_edge_types {
-                walk_edges.push(edge_type.clone());
+        if let Some((next_node, label)) = chosen {
+            if edge_label != EdgeLabel::Nothing {
+                walk_edges.push(label.clone());
             }
             current_node_id = next_node.clone();
         } else {
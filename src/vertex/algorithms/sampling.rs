@@ -0,0 +1,357 @@
+// vertex/algorithms/sampling.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, VecDeque};
+use crate::{Edge, Node};
+use super::super::core::Vertex;
+use super::adjacency::{attr_weight, Adjacency};
+use super::centrality::pagerank;
+use super::filter::{derived_vertex, filter};
+
+/// Seeded RNG when `seed` is given, otherwise one seeded from OS entropy.
+pub fn make_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+/// Draw `k` positions out of `weights.len()` items.
+///
+/// Unweighted draws are uniform. Weighted draws without replacement use the
+/// Efraimidis-Spirakis keys `u^(1/w)`, so items with zero weight are never
+/// picked. The result keeps draw order.
+fn sample_indices(
+    rng: &mut StdRng,
+    weights: Option<&[f64]>,
+    n: usize,
+    k: usize,
+    replace: bool,
+) -> PyResult<Vec<usize>> {
+    if let Some(weights) = weights {
+        if let Some(w) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Sampling weights must be finite and non-negative, got {}",
+                w
+            )));
+        }
+    }
+
+    let available = match weights {
+        Some(weights) => weights.iter().filter(|w| **w > 0.0).count(),
+        None => n,
+    };
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+    if available == 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Cannot sample from an empty population",
+        ));
+    }
+    if !replace && k > available {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Cannot sample {} items without replacement from a population of {}",
+            k, available
+        )));
+    }
+
+    match (weights, replace) {
+        (None, false) => Ok(index::sample(rng, n, k).into_vec()),
+        (None, true) => Ok((0..k).map(|_| rng.gen_range(0..n)).collect()),
+        (Some(weights), true) => {
+            let dist = WeightedIndex::new(weights).map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("Invalid sampling weights: {}", e))
+            })?;
+            Ok((0..k).map(|_| dist.sample(rng)).collect())
+        }
+        (Some(weights), false) => {
+            let mut keyed: Vec<(f64, usize)> = weights
+                .iter()
+                .enumerate()
+                .filter(|(_, w)| **w > 0.0)
+                .map(|(i, w)| (rng.gen::<f64>().powf(1.0 / w), i))
+                .collect();
+            keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+            Ok(keyed.into_iter().take(k).map(|(_, i)| i).collect())
+        }
+    }
+}
+
+/// Sample `k` node ids, optionally weighted by the numeric `weight_field` attribute.
+pub fn sample_nodes(
+    vertex: &Vertex,
+    py: Python<'_>,
+    k: usize,
+    weight_field: Option<String>,
+    seed: Option<u64>,
+    replace: bool,
+) -> PyResult<Vec<String>> {
+    // Sort ids so that a seed reproduces the same sample regardless of HashMap order
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let weights = match &weight_field {
+        Some(field) => Some(
+            ids.iter()
+                .map(|id| attr_weight(py, &vertex.nodes[*id].borrow(py).attr, Some(field), "Node"))
+                .collect::<PyResult<Vec<f64>>>()?,
+        ),
+        None => None,
+    };
+
+    let mut rng = make_rng(seed);
+    let picked = sample_indices(&mut rng, weights.as_deref(), ids.len(), k, replace)?;
+    Ok(picked.into_iter().map(|i| ids[i].clone()).collect())
+}
+
+/// Sample `k` distinct node ids with probability proportional to their PageRank.
+///
+/// PageRank is computed on the unweighted graph (see `centrality::pagerank`)
+/// and every node has a positive rank, so any `k` up to the node count works.
+pub fn sample_by_pagerank(
+    vertex: &Vertex,
+    py: Python<'_>,
+    k: usize,
+    seed: Option<u64>,
+) -> PyResult<Vec<String>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let rank = py.allow_threads(|| pagerank(&adj));
+    let mut rng = make_rng(seed);
+    let picked = sample_indices(&mut rng, Some(&rank), adj.len(), k, false)?;
+    Ok(picked.into_iter().map(|i| adj.ids[i].clone()).collect())
+}
+
+/// Sample `k` edges, optionally weighted by the numeric `weight_field` attribute.
+pub fn sample_edges(
+    vertex: &Vertex,
+    py: Python<'_>,
+    k: usize,
+    weight_field: Option<String>,
+    seed: Option<u64>,
+    replace: bool,
+) -> PyResult<Py<PyList>> {
+    let edges = sorted_edges(vertex, py);
+    let weights = edge_weights(py, &edges, weight_field.as_deref())?;
+
+    let mut rng = make_rng(seed);
+    let picked = sample_indices(&mut rng, weights.as_deref(), edges.len(), k, replace)?;
+    let result = PyList::empty(py);
+    for i in picked {
+        result.append(edges[i].clone_ref(py))?;
+    }
+    Ok(result.into())
+}
+
+/// All edges, grouped by source in sorted id order so that a seed reproduces
+/// the same sample regardless of HashMap order.
+fn sorted_edges(vertex: &Vertex, py: Python<'_>) -> Vec<Py<Edge>> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let mut edges: Vec<Py<Edge>> = Vec::new();
+    for id in ids {
+        for edge in &vertex.nodes[id].borrow(py).edges {
+            edges.push(edge.clone_ref(py));
+        }
+    }
+    edges
+}
+
+/// The `weight_field` value of every edge, or `None` for uniform sampling.
+fn edge_weights(py: Python<'_>, edges: &[Py<Edge>], weight_field: Option<&str>) -> PyResult<Option<Vec<f64>>> {
+    match weight_field {
+        Some(field) => Ok(Some(
+            edges
+                .iter()
+                .map(|edge| attr_weight(py, &edge.borrow(py).attr, Some(field), "Edge"))
+                .collect::<PyResult<Vec<f64>>>()?,
+        )),
+        None => Ok(None),
+    }
+}
+
+/// Copy of the graph keeping every node but only a random share of the edges.
+///
+/// `round(keep_fraction * edge_count)` edges are drawn without replacement,
+/// weighted by `weight_field` when given so heavy edges are more likely to
+/// survive. Zero-weight edges are always dropped, which can leave fewer edges
+/// than requested.
+pub fn sparsify(
+    vertex: &Vertex,
+    py: Python<'_>,
+    keep_fraction: f64,
+    weight_field: Option<String>,
+    seed: Option<u64>,
+) -> PyResult<Py<Vertex>> {
+    if !(0.0..=1.0).contains(&keep_fraction) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "keep_fraction must be in [0, 1], got {}",
+            keep_fraction
+        )));
+    }
+
+    let edges = sorted_edges(vertex, py);
+    let weights = edge_weights(py, &edges, weight_field.as_deref())?;
+    let available = match &weights {
+        Some(weights) => weights.iter().filter(|w| **w > 0.0).count(),
+        None => edges.len(),
+    };
+    let k = ((keep_fraction * edges.len() as f64).round() as usize).min(available);
+
+    let mut rng = make_rng(seed);
+    let mut picked = sample_indices(&mut rng, weights.as_deref(), edges.len(), k, false)?;
+    // Keep each node's surviving edges in their original order
+    picked.sort_unstable();
+
+    let mut nodes = HashMap::<String, Py<Node>>::new();
+    for (node_id, node) in &vertex.nodes {
+        let attr: HashMap<String, Py<PyAny>> = node
+            .borrow(py)
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        nodes.insert(node_id.clone(), Py::new(py, Node::new(py, node_id.clone(), Some(attr), None))?);
+    }
+
+    for i in picked {
+        let edge_ref = edges[i].borrow(py);
+        let (Some(new_from), Some(new_to)) = (
+            nodes.get(&edge_ref.from_node.borrow(py).id),
+            nodes.get(&edge_ref.to_node.borrow(py).id),
+        ) else {
+            continue;
+        };
+        let edge_attr: HashMap<String, Py<PyAny>> = edge_ref
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let new_edge = Py::new(py, Edge::new(
+            py,
+            new_from.clone_ref(py),
+            new_to.clone_ref(py),
+            Some(edge_attr),
+            edge_ref.id.clone(),
+        ))?;
+        new_from.borrow_mut(py).edges.push(new_edge.clone_ref(py));
+        new_to.borrow_mut(py).inverse_edges.push(new_edge);
+    }
+
+    Py::new(py, derived_vertex(vertex, py, nodes))
+}
+
+/// Pick up to `n` node positions with forest-fire sampling.
+///
+/// Edges are burned in both directions. Each burning node ignites a
+/// geometrically distributed number of unburned neighbours (mean
+/// `p / (1 - p)`), processed in BFS order. When the fire dies out early it is
+/// rekindled from a burned node that still has unburned neighbours, so the
+/// sample stays connected whenever the graph is; only once the current
+/// component is exhausted does it jump to a fresh random node.
+fn forest_fire(adj: &Adjacency, rng: &mut StdRng, n: usize, burn_prob: f64) -> Vec<usize> {
+    let neighbors = adj.undirected_neighbors();
+
+    let mut burned = vec![false; adj.len()];
+    let mut order: Vec<usize> = Vec::new();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+
+    while order.len() < n {
+        let mut forced = false;
+        if queue.is_empty() {
+            let rekindle: Vec<usize> = order
+                .iter()
+                .copied()
+                .filter(|&idx| neighbors[idx].iter().any(|&nb| !burned[nb]))
+                .collect();
+            match rekindle.choose(rng) {
+                Some(&idx) => {
+                    queue.push_back(idx);
+                    forced = true;
+                }
+                None => {
+                    let unburned: Vec<usize> = (0..adj.len()).filter(|&idx| !burned[idx]).collect();
+                    let &start = unburned.choose(rng).expect("n is capped at the node count");
+                    burned[start] = true;
+                    order.push(start);
+                    queue.push_back(start);
+                    continue;
+                }
+            }
+        }
+
+        let current = queue.pop_front().expect("queue was just filled");
+        let mut candidates: Vec<usize> = neighbors[current].iter().copied().filter(|&nb| !burned[nb]).collect();
+        candidates.shuffle(rng);
+
+        let mut to_burn = if forced { 1 } else { 0 };
+        while rng.gen::<f64>() < burn_prob {
+            to_burn += 1;
+        }
+
+        for nb in candidates.into_iter().take(to_burn) {
+            if order.len() == n {
+                break;
+            }
+            burned[nb] = true;
+            order.push(nb);
+            queue.push_back(nb);
+        }
+    }
+
+    order
+}
+
+/// Extract a random subgraph of at most `n` nodes.
+///
+/// `"induced"` keeps the subgraph induced by `n` uniformly drawn nodes;
+/// `"forest_fire"` grows the sample with `forest_fire`, which better
+/// preserves local community structure.
+pub fn sample_subgraph(
+    vertex: &Vertex,
+    py: Python<'_>,
+    n: usize,
+    method: &str,
+    seed: Option<u64>,
+    burn_prob: f64,
+) -> PyResult<Py<Vertex>> {
+    if !(0.0..1.0).contains(&burn_prob) {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "burn_prob must be in [0, 1)",
+        ));
+    }
+
+    let n = n.min(vertex.nodes.len());
+    let mut rng = make_rng(seed);
+    let ids = match method {
+        "induced" => {
+            let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+            ids.sort();
+            index::sample(&mut rng, ids.len(), n)
+                .into_iter()
+                .map(|i| ids[i].clone())
+                .collect()
+        }
+        "forest_fire" => {
+            let adj = Adjacency::from_vertex(vertex, py, None)?;
+            forest_fire(&adj, &mut rng, n, burn_prob)
+                .into_iter()
+                .map(|i| adj.ids[i].clone())
+                .collect()
+        }
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown sampling method '{}', expected 'induced' or 'forest_fire'",
+                other
+            )))
+        }
+    };
+
+    filter(vertex, py, ids, true)
+}
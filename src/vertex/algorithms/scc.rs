@@ -0,0 +1,174 @@
+// vertex/algorithms/scc.rs
+
+use pyo3::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::Adjacency;
+use super::filter::filter;
+
+/// Strongly connected components in topological order.
+///
+/// Iterative Tarjan: every edge between two components points from an earlier
+/// component to a later one. Members of each component are sorted.
+pub fn strongly_connected_components(adj: &Adjacency) -> Vec<Vec<usize>> {
+    let n = adj.len();
+    let mut index: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack: Vec<usize> = Vec::new();
+    let mut components: Vec<Vec<usize>> = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..n {
+        if index[root].is_some() {
+            continue;
+        }
+
+        // (node, position of the next outgoing edge to inspect)
+        let mut call_stack: Vec<(usize, usize)> = vec![(root, 0)];
+        index[root] = Some(next_index);
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut edge_pos)) = call_stack.last_mut() {
+            if let Some(&(next, _)) = adj.out[node].get(*edge_pos) {
+                *edge_pos += 1;
+                match index[next] {
+                    None => {
+                        index[next] = Some(next_index);
+                        lowlink[next] = next_index;
+                        next_index += 1;
+                        stack.push(next);
+                        on_stack[next] = true;
+                        call_stack.push((next, 0));
+                    }
+                    Some(next_index_value) if on_stack[next] => {
+                        lowlink[node] = lowlink[node].min(next_index_value);
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
+
+            if Some(lowlink[node]) == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+    }
+
+    // Tarjan emits sink components first
+    components.reverse();
+    components
+}
+
+/// True if the vertex has no directed cycle (self-loops count as cycles).
+pub fn is_dag(vertex: &Vertex, py: Python<'_>) -> PyResult<bool> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let mut in_degree = vec![0usize; adj.len()];
+    for edges in &adj.out {
+        for &(to_idx, _) in edges {
+            in_degree[to_idx] += 1;
+        }
+    }
+
+    // Kahn's algorithm: the graph is acyclic iff every node gets removed
+    let mut ready: Vec<usize> = (0..adj.len()).filter(|&idx| in_degree[idx] == 0).collect();
+    let mut removed = 0;
+    while let Some(idx) = ready.pop() {
+        removed += 1;
+        for &(to_idx, _) in &adj.out[idx] {
+            in_degree[to_idx] -= 1;
+            if in_degree[to_idx] == 0 {
+                ready.push(to_idx);
+            }
+        }
+    }
+
+    Ok(removed == adj.len())
+}
+
+/// Contract every strongly connected component into one node.
+///
+/// Component ids follow topological order, so the returned DAG's node ids
+/// `"0"`, `"1"`, ... are already topologically sorted. Each super-node carries
+/// `members` (sorted original ids) and `size`; parallel edges between two
+/// components are merged and edges inside a component are dropped.
+pub fn condensation(
+    vertex: &Vertex,
+    py: Python<'_>,
+) -> PyResult<(Py<Vertex>, HashMap<String, usize>)> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let components = strongly_connected_components(&adj);
+
+    let mut component_of = vec![0; adj.len()];
+    for (component_id, members) in components.iter().enumerate() {
+        for &idx in members {
+            component_of[idx] = component_id;
+        }
+    }
+
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    for (component_id, members) in components.iter().enumerate() {
+        let member_ids: Vec<&String> = members.iter().map(|&idx| &adj.ids[idx]).collect();
+        let mut attr = HashMap::new();
+        attr.insert("members".to_string(), member_ids.into_pyobject(py)?.into_any().unbind());
+        attr.insert("size".to_string(), members.len().into_pyobject(py)?.into_any().unbind());
+        manipulation::add_node(&mut result, py, component_id.to_string(), Some(attr))?;
+    }
+
+    let mut links = BTreeSet::new();
+    for (from_idx, edges) in adj.out.iter().enumerate() {
+        for &(to_idx, _) in edges {
+            let (from_c, to_c) = (component_of[from_idx], component_of[to_idx]);
+            if from_c != to_c {
+                links.insert((from_c, to_c));
+            }
+        }
+    }
+    for (from_c, to_c) in links {
+        manipulation::add_edge(&mut result, py, from_c.to_string(), to_c.to_string(), None)?;
+    }
+
+    let mapping = adj
+        .ids
+        .iter()
+        .enumerate()
+        .map(|(idx, id)| (id.clone(), component_of[idx]))
+        .collect();
+
+    Ok((Py::new(py, result)?, mapping))
+}
+
+/// Induced subgraph of the largest strongly connected component.
+///
+/// Ties go to the component holding the smallest node id. An empty graph
+/// gives an empty result.
+pub fn largest_scc(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let components = strongly_connected_components(&adj);
+    // Members are sorted, so `members[0]` is each component's smallest id
+    let largest = components
+        .iter()
+        .max_by(|a, b| a.len().cmp(&b.len()).then(b[0].cmp(&a[0])));
+    let ids = largest
+        .map(|members| members.iter().map(|&idx| adj.ids[idx].clone()).collect())
+        .unwrap_or_default();
+    filter(vertex, py, ids, true)
+}
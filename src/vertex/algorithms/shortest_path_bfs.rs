@@ -1,32 +1,50 @@
 // vertex/algorithms/shortest_path_bfs.rs
 
 use pyo3::prelude::*;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::{Node, Edge};
+use crate::node::edge_matches_filter;
+use crate::errors::{node_not_found, GraphError};
 use super::super::core::Vertex;
 
+#[allow(clippy::too_many_arguments)]
 pub fn shortest_path_bfs(
     vertex: &Vertex,
     py: Python<'_>,
     root_node_id: String,
     target_node_id: String,
-    max_depth: Option<usize>
+    max_depth: Option<usize>,
+    exclude_nodes: Option<HashSet<String>>,
+    exclude_edges: Option<HashSet<(String, String)>>,
+    edge_filter: Option<HashMap<String, Py<PyAny>>>
 ) -> PyResult<Py<Vertex>> {
     use std::collections::{VecDeque, HashMap as StdHashMap};
+
+    let exclude_nodes = exclude_nodes.unwrap_or_default();
+    let exclude_edges = exclude_edges.unwrap_or_default();
     
     // Get the root node
     let root_node = vertex.nodes.get(&root_node_id)
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
+        .ok_or_else(|| node_not_found(
             format!("Root node with id '{}' not found", root_node_id)
         ))?
         .clone_ref(py);
     
     // Check if target exists in the graph
     if !vertex.nodes.contains_key(&target_node_id) {
-        return Err(pyo3::exceptions::PyValueError::new_err(
+        return Err(node_not_found(
             format!("Target node with id '{}' not found", target_node_id)
         ));
     }
+
+    // Excluded endpoints can never be part of a path
+    for endpoint in [&root_node_id, &target_node_id] {
+        if exclude_nodes.contains(endpoint) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                format!("Node '{}' is both a path endpoint and excluded", endpoint)
+            ));
+        }
+    }
     
     // Check if root is the target
     if root_node_id == target_node_id {
@@ -66,10 +84,22 @@ pub fn shortest_path_bfs(
         let current_id = current_ref.getattr("id")?.extract::<String>()?;
         
         for edge in edges {
+            // Only follow relations matching the attribute criteria
+            if !edge_matches_filter(py, &edge, &edge_filter, &None)? {
+                continue;
+            }
+
             let edge_ref = edge.bind(py);
             let to_node_actual: Py<Node> = edge_ref.getattr("to_node")?.extract()?;
             let to_node_ref = to_node_actual.bind(py);
             let to_id = to_node_ref.getattr("id")?.extract::<String>()?;
+
+            // Skip forbidden nodes and relationships entirely
+            if exclude_nodes.contains(&to_id)
+                || exclude_edges.contains(&(current_id.clone(), to_id.clone()))
+            {
+                continue;
+            }
             
             // If not visited, mark and enqueue
             if !visited.contains(&to_id) {
@@ -112,7 +142,10 @@ pub fn shortest_path_bfs(
                                 let edge_to_id = edge_to_node_ref.getattr("id")?.extract::<String>()?;
                                 
                                 // Only include edge if target is also in the path
-                                if path_set.contains(&edge_to_id) {
+                                if path_set.contains(&edge_to_id)
+                                    && !exclude_edges.contains(&(path_id.clone(), edge_to_id))
+                                    && edge_matches_filter(py, &edge, &edge_filter, &None)?
+                                {
                                     filtered_edges.push(edge.clone_ref(py));
                                 }
                             }
@@ -132,7 +165,7 @@ pub fn shortest_path_bfs(
     }
     
     // Target not found within max_depth
-    Err(pyo3::exceptions::PyValueError::new_err(
+    Err(GraphError::new_err(
         format!("Target node '{}' not reachable from '{}' within max_depth {:?}", 
                 target_node_id, root_node_id, max_depth)
     ))
@@ -0,0 +1,85 @@
+// vertex/algorithms/shortest_path_tree.rs
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use crate::Edge;
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::{edge_weight, Adjacency};
+use super::sssp::single_source;
+
+/// Tree of shortest paths from `root_node_id` to every reachable node.
+///
+/// Built from the SSSP predecessor map: every reached node except the root
+/// keeps exactly one incoming edge, a copy of the (lightest) original edge from
+/// its predecessor. Nodes are fresh copies with the original attributes, and
+/// `meta["distances"]` maps each node id to its distance from the root.
+pub fn shortest_path_tree(
+    vertex: &Vertex,
+    py: Python<'_>,
+    root_node_id: String,
+    weight_field: Option<String>,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, weight_field.as_deref())?;
+    let weighted = weight_field.is_some();
+    if weighted {
+        adj.ensure_non_negative()?;
+    }
+    let root = adj.require(&root_node_id)?;
+    let run = py.allow_threads(|| single_source(&adj, root, weighted));
+
+    let mut reached: Vec<(f64, usize)> = run
+        .dist
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, d)| d.map(|d| (d, idx)))
+        .collect();
+    reached.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let distances = PyDict::new(py);
+    for &(dist, idx) in &reached {
+        let id = &adj.ids[idx];
+        let attr: HashMap<String, Py<PyAny>> = vertex.nodes[id]
+            .borrow(py)
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        manipulation::add_node(&mut result, py, id.clone(), Some(attr))?;
+        distances.set_item(id, dist)?;
+    }
+
+    for &(_, idx) in &reached {
+        let Some(pred) = run.pred[idx] else { continue };
+        let (from_id, to_id) = (&adj.ids[pred], &adj.ids[idx]);
+
+        // Parallel edges: the lightest one is the one the shortest path used
+        let from_node = vertex.nodes[from_id].borrow(py);
+        let mut best: Option<(f64, Py<Edge>)> = None;
+        for edge in &from_node.edges {
+            let edge_ref = edge.borrow(py);
+            if edge_ref.to_node.borrow(py).id != *to_id {
+                continue;
+            }
+            let weight = edge_weight(py, &edge_ref, weight_field.as_deref())?;
+            if best.as_ref().is_none_or(|(w, _)| weight < *w) {
+                best = Some((weight, edge.clone_ref(py)));
+            }
+        }
+        let Some((_, original)) = best else { continue };
+        let original = original.borrow(py);
+
+        let attr: HashMap<String, Py<PyAny>> = original
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let edge = manipulation::add_edge(&mut result, py, from_id.clone(), to_id.clone(), Some(attr))?;
+        edge.borrow_mut(py).id = original.id.clone();
+    }
+
+    result.meta.bind(py).set_item("distances", distances)?;
+    Py::new(py, result)
+}
@@ -0,0 +1,233 @@
+// vertex/algorithms/sssp.rs
+
+use pyo3::prelude::*;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use super::adjacency::Adjacency;
+
+/// Distances and shortest-path predecessors from a single source.
+pub struct ShortestPaths {
+    pub source: usize,
+    /// Distance from the source, `None` if unreachable
+    pub dist: Vec<Option<f64>>,
+    /// Predecessor on the shortest path, `None` for the source and unreachable nodes
+    pub pred: Vec<Option<usize>>,
+    /// Set by `single_source_limited`, whose best routes need not share
+    /// prefixes: `path_to` then follows these labels instead of `pred`.
+    routes: Option<Routes>,
+}
+
+/// Search labels of a hop-limited run: `(node, parent label)` per label and
+/// the label of each node's best route.
+struct Routes {
+    labels: Vec<(usize, Option<usize>)>,
+    best: Vec<Option<usize>>,
+}
+
+impl ShortestPaths {
+    /// Node indices from the source to `target`, or `None` if unreachable.
+    pub fn path_to(&self, target: usize) -> Option<Vec<usize>> {
+        self.dist[target]?;
+        if let Some(routes) = &self.routes {
+            let mut path = Vec::new();
+            let mut label = routes.best[target];
+            while let Some(current) = label {
+                let (node, parent) = routes.labels[current];
+                path.push(node);
+                label = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(parent) = self.pred[current] {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Min-heap entry for Dijkstra (BinaryHeap is a max-heap, so ordering is reversed).
+#[derive(PartialEq)]
+pub(crate) struct HeapEntry {
+    pub cost: f64,
+    pub node: usize,
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// `single_source`, or `single_source_limited` when `limits` are set.
+pub fn single_source_within(adj: &Adjacency, source: usize, weighted: bool, limits: PathLimits) -> ShortestPaths {
+    if limits.is_unlimited() {
+        single_source(adj, source, weighted)
+    } else {
+        single_source_limited(adj, source, limits)
+    }
+}
+
+/// Single-source shortest paths.
+///
+/// Runs a BFS (hop counts) when `weighted` is false and Dijkstra over the
+/// snapshot's edge weights otherwise. Weights must be non-negative, see
+/// `Adjacency::ensure_non_negative`.
+pub fn single_source(adj: &Adjacency, source: usize, weighted: bool) -> ShortestPaths {
+    let n = adj.len();
+    let mut dist: Vec<Option<f64>> = vec![None; n];
+    let mut pred: Vec<Option<usize>> = vec![None; n];
+    dist[source] = Some(0.0);
+
+    if weighted {
+        let mut settled = vec![false; n];
+        let mut heap = BinaryHeap::new();
+        heap.push(HeapEntry { cost: 0.0, node: source });
+
+        while let Some(HeapEntry { cost, node }) = heap.pop() {
+            if settled[node] {
+                continue;
+            }
+            settled[node] = true;
+
+            for &(next, weight) in &adj.out[node] {
+                let candidate = cost + weight;
+                if dist[next].is_none_or(|d| candidate < d) {
+                    dist[next] = Some(candidate);
+                    pred[next] = Some(node);
+                    heap.push(HeapEntry { cost: candidate, node: next });
+                }
+            }
+        }
+    } else {
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+
+        while let Some(node) = queue.pop_front() {
+            let next_dist = dist[node].unwrap_or(0.0) + 1.0;
+            for &(next, _) in &adj.out[node] {
+                if dist[next].is_none() {
+                    dist[next] = Some(next_dist);
+                    pred[next] = Some(node);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    ShortestPaths { source, dist, pred, routes: None }
+}
+
+/// Hop and cost budgets that a single path must respect together.
+#[derive(Clone, Copy, Default)]
+pub struct PathLimits {
+    /// Largest summed edge weight (hop count when unweighted)
+    pub max_cost: Option<f64>,
+    /// Largest number of edges
+    pub max_depth: Option<usize>,
+}
+
+impl PathLimits {
+    /// Limits from user arguments, rejecting a negative or NaN `max_cost`.
+    pub fn new(max_cost: Option<f64>, max_depth: Option<usize>) -> PyResult<Self> {
+        if max_cost.is_some_and(|cost| cost.is_nan() || cost < 0.0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "max_cost must be a non-negative number",
+            ));
+        }
+        Ok(PathLimits { max_cost, max_depth })
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.max_cost.is_none() && self.max_depth.is_none()
+    }
+}
+
+/// Label of the hop-limited search, ordered by cost, then hops, then node.
+#[derive(PartialEq)]
+struct LimitedEntry {
+    cost: f64,
+    hops: usize,
+    node: usize,
+    label: usize,
+}
+
+impl Eq for LimitedEntry {}
+
+impl Ord for LimitedEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then_with(|| other.hops.cmp(&self.hops))
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+impl PartialOrd for LimitedEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Cheapest paths from `source` that stay within both `limits` at once.
+///
+/// A cheap node reached over too many hops may still be reachable by a
+/// dearer, shorter path, so plain Dijkstra distances are not enough. This
+/// searches `(node, hops)` labels in cost order and keeps a label only if it
+/// uses fewer hops than every cheaper label of its node; the first label of a
+/// node is its best route. Relaxation skips an edge when the next label would
+/// exceed `max_depth` hops or `max_cost`. Unweighted adjacencies weigh every
+/// edge 1.0, which turns this into a BFS with a hop budget. Weights must be
+/// non-negative.
+pub fn single_source_limited(adj: &Adjacency, source: usize, limits: PathLimits) -> ShortestPaths {
+    let n = adj.len();
+    let mut dist: Vec<Option<f64>> = vec![None; n];
+    let mut pred: Vec<Option<usize>> = vec![None; n];
+    let mut best: Vec<Option<usize>> = vec![None; n];
+    let mut fewest_hops = vec![usize::MAX; n];
+    let mut labels: Vec<(usize, Option<usize>)> = vec![(source, None)];
+    let mut heap = BinaryHeap::new();
+    heap.push(LimitedEntry { cost: 0.0, hops: 0, node: source, label: 0 });
+
+    while let Some(LimitedEntry { cost, hops, node, label }) = heap.pop() {
+        if hops >= fewest_hops[node] {
+            continue;
+        }
+        fewest_hops[node] = hops;
+        if best[node].is_none() {
+            best[node] = Some(label);
+            dist[node] = Some(cost);
+            pred[node] = labels[label].1.map(|parent| labels[parent].0);
+        }
+        if limits.max_depth.is_some_and(|limit| hops >= limit) {
+            continue;
+        }
+
+        for &(next, weight) in &adj.out[node] {
+            let candidate = cost + weight;
+            if limits.max_cost.is_some_and(|limit| candidate > limit) || hops + 1 >= fewest_hops[next] {
+                continue;
+            }
+            labels.push((next, Some(label)));
+            heap.push(LimitedEntry { cost: candidate, hops: hops + 1, node: next, label: labels.len() - 1 });
+        }
+    }
+
+    ShortestPaths { source, dist, pred, routes: Some(Routes { labels, best }) }
+}
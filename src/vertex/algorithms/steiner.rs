@@ -0,0 +1,194 @@
+// vertex/algorithms/steiner.rs
+
+use pyo3::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use crate::Edge;
+use crate::errors::GraphError;
+use super::super::core::Vertex;
+use super::super::manipulation;
+use super::adjacency::{edge_weight, Adjacency};
+use super::sssp::{single_source, ShortestPaths};
+
+/// Approximate minimum Steiner tree connecting `terminals`.
+///
+/// Uses the metric-closure heuristic (Kou, Markowsky and Berman), which is
+/// within a factor of 2 of the optimum: shortest paths between all terminals,
+/// an MST over that closure, expanded back into graph edges, then an MST of
+/// those edges with non-terminal leaves pruned. Edge direction is ignored.
+/// Tree edges are copies of the lightest original edge between their
+/// endpoints, in its original direction; `meta["total_weight"]` holds the
+/// summed weight.
+pub fn steiner_tree(
+    vertex: &Vertex,
+    py: Python<'_>,
+    terminals: &[String],
+    weight_field: &str,
+) -> PyResult<Py<Vertex>> {
+    let adj = Adjacency::from_vertex(vertex, py, Some(weight_field))?;
+    adj.ensure_non_negative()?;
+    let terminals: BTreeSet<usize> = terminals
+        .iter()
+        .map(|id| adj.require(id))
+        .collect::<PyResult<_>>()?;
+    let terminals: Vec<usize> = terminals.into_iter().collect();
+
+    let tree = py.allow_threads(|| kou_markowsky_berman(&adj.undirected(), &terminals));
+    let tree = tree.map_err(|(a, b)| {
+        GraphError::new_err(format!(
+            "Terminal '{}' is not connected to '{}'",
+            adj.ids[b], adj.ids[a]
+        ))
+    })?;
+
+    let mut result = Vertex::from_nodes(py, HashMap::new());
+    let mut nodes: BTreeSet<usize> = terminals.iter().copied().collect();
+    nodes.extend(tree.iter().flat_map(|edge| [edge.a, edge.b]));
+    for &idx in &nodes {
+        let id = &adj.ids[idx];
+        let attr: HashMap<String, Py<PyAny>> = vertex.nodes[id]
+            .borrow(py)
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        manipulation::add_node(&mut result, py, id.clone(), Some(attr))?;
+    }
+
+    let mut total_weight = 0.0;
+    for &TreeEdge { a, b, weight } in &tree {
+        let (a_id, b_id) = (&adj.ids[a], &adj.ids[b]);
+        // Lightest original edge between the endpoints, in either direction
+        let mut best: Option<(f64, Py<Edge>)> = None;
+        for (from_id, to_id) in [(a_id, b_id), (b_id, a_id)] {
+            for edge in &vertex.nodes[from_id].borrow(py).edges {
+                let edge_ref = edge.borrow(py);
+                if edge_ref.to_node.borrow(py).id != *to_id {
+                    continue;
+                }
+                let weight = edge_weight(py, &edge_ref, Some(weight_field))?;
+                if best.as_ref().is_none_or(|(w, _)| weight < *w) {
+                    best = Some((weight, edge.clone_ref(py)));
+                }
+            }
+        }
+        let Some((_, original)) = best else { continue };
+        let original = original.borrow(py);
+
+        let attr: HashMap<String, Py<PyAny>> = original
+            .attr
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone_ref(py)))
+            .collect();
+        let from_id = original.from_node.borrow(py).id.clone();
+        let to_id = original.to_node.borrow(py).id.clone();
+        let edge = manipulation::add_edge(&mut result, py, from_id, to_id, Some(attr))?;
+        edge.borrow_mut(py).id = original.id.clone();
+        total_weight += weight;
+    }
+
+    result.meta.bind(py).set_item("total_weight", total_weight)?;
+    Py::new(py, result)
+}
+
+/// An undirected tree edge between node indices `a < b`.
+struct TreeEdge {
+    a: usize,
+    b: usize,
+    weight: f64,
+}
+
+/// Tree edges sorted by endpoints, or the first pair of terminals that are
+/// not connected.
+fn kou_markowsky_berman(adj: &Adjacency, terminals: &[usize]) -> Result<Vec<TreeEdge>, (usize, usize)> {
+    let runs: Vec<ShortestPaths> = terminals
+        .iter()
+        .map(|&t| single_source(adj, t, true))
+        .collect();
+
+    // Prim's MST over the metric closure of the terminals
+    let k = terminals.len();
+    let mut in_tree = vec![false; k];
+    let mut closure_edges: Vec<(usize, usize)> = Vec::new();
+    if k > 0 {
+        in_tree[0] = true;
+    }
+    for _ in 1..k {
+        let mut best: Option<(f64, usize, usize)> = None;
+        for i in (0..k).filter(|&i| in_tree[i]) {
+            for j in (0..k).filter(|&j| !in_tree[j]) {
+                let Some(d) = runs[i].dist[terminals[j]] else { continue };
+                if best.is_none_or(|(bd, _, _)| d < bd) {
+                    best = Some((d, i, j));
+                }
+            }
+        }
+        let Some((_, i, j)) = best else {
+            let j = (0..k).find(|&j| !in_tree[j]).unwrap();
+            return Err((terminals[0], terminals[j]));
+        };
+        in_tree[j] = true;
+        closure_edges.push((i, j));
+    }
+
+    // Expand closure edges into graph edges, keeping the lightest per pair
+    let mut candidates: HashMap<(usize, usize), f64> = HashMap::new();
+    for (i, j) in closure_edges {
+        let path = runs[i].path_to(terminals[j]).unwrap();
+        for step in path.windows(2) {
+            let (a, b) = (step[0].min(step[1]), step[0].max(step[1]));
+            let weight = adj.out[step[0]]
+                .iter()
+                .filter(|&&(to, _)| to == step[1])
+                .map(|&(_, w)| w)
+                .fold(f64::INFINITY, f64::min);
+            candidates.insert((a, b), weight);
+        }
+    }
+
+    // Kruskal over the expanded edges removes any cycles the paths formed
+    let mut edges: Vec<TreeEdge> = candidates
+        .into_iter()
+        .map(|((a, b), weight)| TreeEdge { a, b, weight })
+        .collect();
+    edges.sort_by(|x, y| x.weight.total_cmp(&y.weight).then((x.a, x.b).cmp(&(y.a, y.b))));
+    let mut parent: Vec<usize> = (0..adj.len()).collect();
+    let mut tree: Vec<TreeEdge> = Vec::new();
+    for edge in edges {
+        let (ra, rb) = (find(&mut parent, edge.a), find(&mut parent, edge.b));
+        if ra != rb {
+            parent[ra] = rb;
+            tree.push(edge);
+        }
+    }
+
+    // Repeatedly drop leaves that are not terminals
+    let mut is_terminal = vec![false; adj.len()];
+    for &t in terminals {
+        is_terminal[t] = true;
+    }
+    loop {
+        let mut degree = vec![0usize; adj.len()];
+        for edge in &tree {
+            degree[edge.a] += 1;
+            degree[edge.b] += 1;
+        }
+        let prunable = |x: usize| degree[x] == 1 && !is_terminal[x];
+        let before = tree.len();
+        tree.retain(|edge| !prunable(edge.a) && !prunable(edge.b));
+        if tree.len() == before {
+            break;
+        }
+    }
+
+    tree.sort_by_key(|edge| (edge.a, edge.b));
+    Ok(tree)
+}
+
+/// Union-find root of `x`, halving the path on the way.
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
@@ -0,0 +1,61 @@
+// vertex/algorithms/triangles.rs
+
+use pyo3::prelude::*;
+use super::super::core::Vertex;
+use super::adjacency::Adjacency;
+
+/// Number of triangles through each node of an undirected graph.
+///
+/// `neighbors` must be sorted and free of duplicates and self-loops, as
+/// returned by `Adjacency::undirected_neighbors`. Each triangle `u < v < w`
+/// is found once from its edge `(u, v)` and credited to all three corners.
+pub(crate) fn triangles_per_node(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let mut triangles = vec![0; neighbors.len()];
+    for (u, u_neighbors) in neighbors.iter().enumerate() {
+        for &v in u_neighbors.iter().filter(|&&v| v > u) {
+            // Sorted merge of both lists, keeping common neighbours above `v`
+            let (a, b) = (&u_neighbors[..], &neighbors[v][..]);
+            let (mut i, mut j) = (0, 0);
+            while i < a.len() && j < b.len() {
+                match a[i].cmp(&b[j]) {
+                    std::cmp::Ordering::Less => i += 1,
+                    std::cmp::Ordering::Greater => j += 1,
+                    std::cmp::Ordering::Equal => {
+                        let w = a[i];
+                        if w > v {
+                            triangles[u] += 1;
+                            triangles[v] += 1;
+                            triangles[w] += 1;
+                        }
+                        i += 1;
+                        j += 1;
+                    }
+                }
+            }
+        }
+    }
+    triangles
+}
+
+/// Global transitivity of the undirected view: `3 * triangles / triples`.
+///
+/// A connected triple is a path of two edges, counted as `d * (d - 1) / 2`
+/// per node of degree `d`. Parallel edges and edges in both directions count
+/// once and self-loops are ignored. Returns 0.0 when there are no triples.
+pub fn transitivity(vertex: &Vertex, py: Python<'_>) -> PyResult<f64> {
+    let adj = Adjacency::from_vertex(vertex, py, None)?;
+    let neighbors = adj.undirected_neighbors();
+    Ok(py.allow_threads(|| {
+        // Every triangle is credited to its three corners, i.e. counted 3 times
+        let corners: usize = triangles_per_node(&neighbors).iter().sum();
+        let triples: usize = neighbors
+            .iter()
+            .map(|list| list.len() * list.len().saturating_sub(1) / 2)
+            .sum();
+        if triples == 0 {
+            0.0
+        } else {
+            corners as f64 / triples as f64
+        }
+    }))
+}
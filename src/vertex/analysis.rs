@@ -1,27 +1,48 @@
 // vertex/analysis.rs
 
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict};
+use pyo3::types::{PyAny, PyBool, PyDict, PyString};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use super::Vertex;
+use crate::serialization::SerializableValue;
 
-pub fn get_metadata(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<PyAny>> {
+pub fn get_metadata(vertex: &Vertex, py: Python<'_>, undirected: bool) -> PyResult<Py<PyAny>> {
     let dict = PyDict::new(py);
     
     // Count nodes
     dict.set_item("node_count", vertex.nodes.len())?;
     
-    // Count edges
-    let mut edge_count = 0;
-    for node_py in vertex.nodes.values() {
-        let node_ref = node_py.bind(py);
-        let edges: Vec<Py<crate::Edge>> = node_ref.getattr("edges")?.extract()?;
-        edge_count += edges.len();
+    // Count directed edges and collect the distinct (from, to) pairs
+    let mut directed_count = 0;
+    let mut pairs: HashSet<(String, String)> = HashSet::new();
+    for (node_id, node_py) in &vertex.nodes {
+        let node_ref = node_py.borrow(py);
+        directed_count += node_ref.edges.len();
+        for edge in &node_ref.edges {
+            let to_id = edge.borrow(py).to_node.borrow(py).id.clone();
+            pairs.insert((node_id.clone(), to_id));
+        }
     }
+
+    // Pairs with edges in both directions, each unordered pair counted once
+    let reciprocal_edge_count = pairs
+        .iter()
+        .filter(|(from, to)| from < to && pairs.contains(&(to.clone(), from.clone())))
+        .count();
+
+    let edge_count = if undirected {
+        pairs.len() - reciprocal_edge_count
+    } else {
+        directed_count
+    };
     dict.set_item("edge_count", edge_count)?;
+    dict.set_item("reciprocal_edge_count", reciprocal_edge_count)?;
     
     // Calculate average degree
     if !vertex.nodes.is_empty() {
-        let avg_degree = (edge_count as f64) / (vertex.nodes.len() as f64);
+        // Every undirected edge adds to the degree of both endpoints
+        let endpoints = if undirected { 2 * edge_count } else { edge_count };
+        let avg_degree = (endpoints as f64) / (vertex.nodes.len() as f64);
         dict.set_item("average_degree", avg_degree)?;
     } else {
         dict.set_item("average_degree", 0.0)?;
@@ -34,6 +55,229 @@ pub fn get_metadata(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<PyAny>> {
     Ok(dict.into())
 }
 
+/// Map each degree value to the number of nodes having it.
+///
+/// `direction` selects out-degree (`edges`), in-degree (`inverse_edges`) or
+/// their sum (`"total"`).
+pub fn degree_distribution(
+    vertex: &Vertex,
+    py: Python<'_>,
+    direction: &str,
+) -> PyResult<BTreeMap<usize, usize>> {
+    let (count_out, count_in) = match direction {
+        "out" => (true, false),
+        "in" => (false, true),
+        "total" => (true, true),
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Unknown direction '{}', expected 'out', 'in' or 'total'",
+                other
+            )))
+        }
+    };
+
+    let mut distribution = BTreeMap::new();
+    for node_py in vertex.nodes.values() {
+        let node = node_py.borrow(py);
+        let mut degree = 0;
+        if count_out {
+            degree += node.edges.len();
+        }
+        if count_in {
+            degree += node.inverse_edges.len();
+        }
+        *distribution.entry(degree).or_insert(0) += 1;
+    }
+    Ok(distribution)
+}
+
+/// Count the edges carrying each distinct value of `attr[field]`.
+///
+/// Values are grouped by their `SerializableValue`, so lists and dicts are
+/// counted too. The result maps the first Python value seen for each group
+/// to its count, or `str(value)` when that value is unhashable; groups that
+/// Python considers equal (`1` and `1.0`) are added together. Keys follow
+/// first appearance in sorted node id order. Edges without `field` are skipped.
+pub fn edge_attr_histogram(vertex: &Vertex, py: Python<'_>, field: &str) -> PyResult<Py<PyAny>> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let mut slot_of: HashMap<SerializableValue, usize> = HashMap::new();
+    let mut groups: Vec<(Py<PyAny>, usize)> = Vec::new();
+    for id in ids {
+        for edge in &vertex.nodes[id].borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let Some(value) = edge_ref.attr.get(field) else { continue };
+            let key = SerializableValue::from_python(py, value)?;
+            let slot = *slot_of.entry(key).or_insert_with(|| {
+                groups.push((value.clone_ref(py), 0));
+                groups.len() - 1
+            });
+            groups[slot].1 += 1;
+        }
+    }
+
+    let histogram = PyDict::new(py);
+    for (value, count) in groups {
+        let value = value.bind(py);
+        let key = if value.hash().is_ok() { value.clone() } else { value.str()?.into_any() };
+        let previous: usize = match histogram.get_item(&key)? {
+            Some(existing) => existing.extract()?,
+            None => 0,
+        };
+        histogram.set_item(key, previous + count)?;
+    }
+    Ok(histogram.into_any().unbind())
+}
+
+/// Observed types and occurrence count of one attribute key
+#[derive(Default)]
+struct FieldSchema {
+    types: BTreeSet<&'static str>,
+    count: usize,
+}
+
+fn classify(py: Python<'_>, value: &Py<PyAny>) -> PyResult<&'static str> {
+    // `from_python` tries int before bool, so a Python bool would be reported as int
+    if value.bind(py).is_instance_of::<PyBool>() {
+        return Ok("bool");
+    }
+    Ok(SerializableValue::from_python(py, value)?.type_name())
+}
+
+fn record_attrs(
+    py: Python<'_>,
+    attr: &HashMap<String, Py<PyAny>>,
+    fields: &mut BTreeMap<String, FieldSchema>,
+) -> PyResult<()> {
+    for (key, value) in attr {
+        let field = fields.entry(key.clone()).or_default();
+        field.types.insert(classify(py, value)?);
+        field.count += 1;
+    }
+    Ok(())
+}
+
+fn schema_to_dict<'py>(
+    py: Python<'py>,
+    fields: BTreeMap<String, FieldSchema>,
+    total: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, field) in fields {
+        let entry = PyDict::new(py);
+        entry.set_item("types", field.types.iter().collect::<Vec<_>>())?;
+        entry.set_item("mixed", field.types.len() > 1)?;
+        entry.set_item("count", field.count)?;
+        entry.set_item("required", field.count == total)?;
+        dict.set_item(key, entry)?;
+    }
+    Ok(dict)
+}
+
+pub fn infer_schema(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<PyAny>> {
+    let mut node_fields: BTreeMap<String, FieldSchema> = BTreeMap::new();
+    let mut edge_fields: BTreeMap<String, FieldSchema> = BTreeMap::new();
+    let mut edge_total = 0;
+
+    for node_py in vertex.nodes.values() {
+        let node = node_py.borrow(py);
+        record_attrs(py, &node.attr, &mut node_fields)?;
+
+        for edge_py in &node.edges {
+            let edge = edge_py.borrow(py);
+            record_attrs(py, &edge.attr, &mut edge_fields)?;
+            edge_total += 1;
+        }
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("nodes", schema_to_dict(py, node_fields, vertex.nodes.len())?)?;
+    dict.set_item("edges", schema_to_dict(py, edge_fields, edge_total)?)?;
+    Ok(dict.into())
+}
+
+/// Attribute `value` of `attr` or `None`, for one row of a column export.
+fn column_value(py: Python<'_>, attr: &HashMap<String, Py<PyAny>>, field: &str) -> Py<PyAny> {
+    attr.get(field).map_or_else(|| py.None(), |value| value.clone_ref(py))
+}
+
+/// Reject requested fields that would overwrite a reserved key column.
+fn check_columns(fields: &[String], reserved: &[&str]) -> PyResult<()> {
+    match fields.iter().find(|field| reserved.contains(&field.as_str())) {
+        Some(field) => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Field '{}' is reserved for a key column",
+            field
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Export node attributes as columns aligned with an `id` column.
+///
+/// Rows follow the sorted node ids; a node without a field gets `None`.
+pub fn nodes_to_columns(vertex: &Vertex, py: Python<'_>, fields: &[String]) -> PyResult<Py<PyAny>> {
+    check_columns(fields, &["id"])?;
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let mut columns: Vec<Vec<Py<PyAny>>> = fields.iter().map(|_| Vec::with_capacity(ids.len())).collect();
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        for (column, field) in columns.iter_mut().zip(fields) {
+            column.push(column_value(py, &node.attr, field));
+        }
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("id", ids)?;
+    for (field, column) in fields.iter().zip(columns) {
+        dict.set_item(field, column)?;
+    }
+    Ok(dict.into())
+}
+
+/// Export edge attributes as columns aligned with `from_id`/`to_id` columns.
+///
+/// Rows follow the sorted source ids and then each node's edge order; a
+/// missing field gets `None`.
+pub fn edges_to_columns(vertex: &Vertex, py: Python<'_>, fields: &[String]) -> PyResult<Py<PyAny>> {
+    check_columns(fields, &["from_id", "to_id"])?;
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let mut from_ids: Vec<&String> = Vec::new();
+    let mut to_ids: Vec<String> = Vec::new();
+    let mut columns: Vec<Vec<Py<PyAny>>> = fields.iter().map(|_| Vec::new()).collect();
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        for edge_py in &node.edges {
+            let edge = edge_py.borrow(py);
+            from_ids.push(id);
+            to_ids.push(edge.to_node.borrow(py).id.clone());
+            for (column, field) in columns.iter_mut().zip(fields) {
+                column.push(column_value(py, &edge.attr, field));
+            }
+        }
+    }
+
+    let dict = PyDict::new(py);
+    dict.set_item("from_id", from_ids)?;
+    dict.set_item("to_id", to_ids)?;
+    for (field, column) in fields.iter().zip(columns) {
+        dict.set_item(field, column)?;
+    }
+    Ok(dict.into())
+}
+
+/// Build a `networkx.DiGraph` with the same nodes, edges and attributes.
+///
+/// Nodes and edges go in through one `add_nodes_from` and one
+/// `add_edges_from` call with `(id, attr)` and `(from, to, attr)` tuples, so
+/// the cost no longer grows with one Python round-trip per node, edge and
+/// attribute. Attribute values are shared, not copied. A DiGraph keeps one
+/// edge per pair, so the attributes of parallel edges are merged in edge
+/// order, later edges winning on shared keys.
 pub fn to_networkx(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<PyAny>> {
     // Import networkx
     let networkx = py.import("networkx")
@@ -43,68 +287,119 @@ pub fn to_networkx(vertex: &Vertex, py: Python<'_>) -> PyResult<Py<PyAny>> {
     
     // Create a new directed graph
     let digraph = networkx.call_method0("DiGraph")?;
-    
-    // Add all nodes first
-    for (node_id, _) in &vertex.nodes {
-        digraph.call_method1("add_node", (node_id,))?;
-    }
-    
-    // Then add node attributes
+
+    // One Python string per id, shared by every edge that touches the node
+    let names: HashMap<&String, Bound<'_, PyString>> = vertex
+        .nodes
+        .keys()
+        .map(|id| (id, PyString::new(py, id)))
+        .collect();
+    let name = |id: &String| names.get(id).cloned().unwrap_or_else(|| PyString::new(py, id));
+
+    let mut nodes = Vec::with_capacity(vertex.nodes.len());
+    let mut edges = Vec::new();
     for (node_id, node_py) in &vertex.nodes {
-        let node_ref = node_py.bind(py);
-        
-        // Get node attributes and add them to the NetworkX node
-        if let Ok(attr) = node_ref.getattr("attr") {
-            // Get the nodes dict from the graph
-            let nodes_dict = digraph.getattr("nodes")?;
-            let node_dict = nodes_dict.get_item(node_id)?;
-            
-            // Extract attributes from the Python dict and add them to NetworkX node
-            if let Ok(attr_dict) = attr.downcast::<PyDict>() {
-                for item in attr_dict.items() {
-                    let (key, value) = item.extract::<(String, Py<PyAny>)>()?;
-                    node_dict.set_item(key, value)?;
-                }
-            }
+        let node_ref = node_py.borrow(py);
+        nodes.push((name(node_id), attr_dict(py, &node_ref.attr)?));
+        for edge_py in &node_ref.edges {
+            let edge_ref = edge_py.borrow(py);
+            let to_id = name(&edge_ref.to_node.borrow(py).id);
+            edges.push((name(node_id), to_id, attr_dict(py, &edge_ref.attr)?));
         }
     }
+
+    // Nodes first so edges never create attribute-less placeholders
+    digraph.call_method1("add_nodes_from", (nodes,))?;
+    digraph.call_method1("add_edges_from", (edges,))?;
     
-    // Add all edges with their attributes
-    for (node_id, node_py) in &vertex.nodes {
-        let node_ref = node_py.bind(py);
-        
-        // Get edges from this node
-        if let Ok(edges) = node_ref.getattr("edges") {
-            if let Ok(edges_vec) = edges.extract::<Vec<Py<PyAny>>>() {
-                for edge_py in edges_vec {
-                    let edge_ref = edge_py.bind(py);
-                    
-                    // Get target node
-                    if let Ok(to_node) = edge_ref.getattr("to_node") {
-                        if let Ok(to_id) = to_node.getattr("id").and_then(|id| id.extract::<String>()) {
-                            // Add edge first
-                            digraph.call_method1("add_edge", (node_id, &to_id))?;
-                            
-                            // Then add edge attributes
-                            if let Ok(edge_attr) = edge_ref.getattr("attr") {
-                                // Get the edges dict from the graph
-                                let edges_dict = digraph.getattr("edges")?;
-                                let edge_dict = edges_dict.get_item((node_id, &to_id))?;
-                                
-                                // Extract attributes from the Python dict and add them to NetworkX edge
-                                if let Ok(attr_dict) = edge_attr.downcast::<PyDict>() {
-                                    for item in attr_dict.items() {
-                                        let (key, value) = item.extract::<(String, Py<PyAny>)>()?;
-                                        edge_dict.set_item(key, value)?;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+    Ok(digraph.into())
+}
+
+/// Fresh dict holding the values of `attr`.
+fn attr_dict<'py>(py: Python<'py>, attr: &HashMap<String, Py<PyAny>>) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (key, value) in attr {
+        dict.set_item(key, value.bind(py))?;
+    }
+    Ok(dict)
+}
+
+/// Quote `s` as a DOT string literal.
+fn dot_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Smallest and largest node width emitted for `size_by`.
+const DOT_MIN_WIDTH: f64 = 0.5;
+const DOT_MAX_WIDTH: f64 = 2.0;
+
+/// Render the graph in Graphviz DOT syntax.
+///
+/// With `color_by`, each distinct value of that node attribute (compared by
+/// its `str()`, numbered in sorted node id order) gets its own HSV color,
+/// spread around the hue circle by the golden ratio. With `size_by`, the
+/// numeric attribute is scaled linearly onto a node width between
+/// `DOT_MIN_WIDTH` and `DOT_MAX_WIDTH`. Nodes missing an attribute keep the
+/// Graphviz defaults.
+pub fn to_dot(
+    vertex: &Vertex,
+    py: Python<'_>,
+    color_by: Option<&str>,
+    size_by: Option<&str>,
+) -> PyResult<String> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let mut color_index = HashMap::<String, usize>::new();
+    let mut sizes = HashMap::<&String, f64>::new();
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        if let Some(value) = color_by.and_then(|key| node.attr.get(key)) {
+            let label = value.bind(py).str()?.to_string();
+            let next = color_index.len();
+            color_index.entry(label).or_insert(next);
+        }
+        if let Some(key) = size_by {
+            if let Some(value) = node.attr.get(key) {
+                let size = crate::node::numeric_value(py, value)?.ok_or_else(|| {
+                    pyo3::exceptions::PyTypeError::new_err(format!(
+                        "Node attribute '{}' must be numeric to be used for size_by",
+                        key
+                    ))
+                })?;
+                sizes.insert(*id, size);
             }
         }
     }
-    
-    Ok(digraph.into())
+    let lo = sizes.values().copied().fold(f64::INFINITY, f64::min);
+    let hi = sizes.values().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let mut out = String::from("digraph {\n");
+    for id in &ids {
+        let node = vertex.nodes[*id].borrow(py);
+        let mut attrs = vec![format!("label={}", dot_quote(id))];
+        if let Some(value) = color_by.and_then(|key| node.attr.get(key)) {
+            let index = color_index[&value.bind(py).str()?.to_string()];
+            let hue = (index as f64 * 0.618_033_988_75).fract();
+            let color = dot_quote(&format!("{:.3} 0.650 0.900", hue));
+            attrs.push(format!("style=filled, color={}, fillcolor={}", color, color));
+        }
+        if let Some(size) = sizes.get(id) {
+            let width = if hi > lo {
+                DOT_MIN_WIDTH + (size - lo) / (hi - lo) * (DOT_MAX_WIDTH - DOT_MIN_WIDTH)
+            } else {
+                (DOT_MIN_WIDTH + DOT_MAX_WIDTH) / 2.0
+            };
+            attrs.push(format!("width={:.3}", width));
+        }
+        out.push_str(&format!("  {} [{}];\n", dot_quote(id), attrs.join(", ")));
+    }
+    for id in &ids {
+        for edge in &vertex.nodes[*id].borrow(py).edges {
+            let to_id = edge.borrow(py).to_node.borrow(py).id.clone();
+            out.push_str(&format!("  {} -> {};\n", dot_quote(id), dot_quote(&to_id)));
+        }
+    }
+    out.push_str("}\n");
+    Ok(out)
 }
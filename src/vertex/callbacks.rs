@@ -26,6 +26,19 @@ pub fn fire_node_add_callbacks(
     Ok(())
 }
 
+/// Fire node-remove callbacks stored on the Vertex.
+///
+/// Each callback receives `(vertex, node)` for a node that has already been
+/// removed and may return `False` to stop further callbacks from being invoked.
+pub fn fire_node_remove_callbacks(
+    py: Python<'_>,
+    callbacks_list: &Bound<'_, PyList>,
+    vertex: Py<PyAny>,
+    node: Py<Node>,
+) -> PyResult<()> {
+    fire_node_add_callbacks(py, callbacks_list, vertex, node)
+}
+
 /// Fire edge-add callbacks stored on the Vertex.
 ///
 /// Each callback receives `(vertex, edge)` and may return `False` to stop
@@ -112,3 +125,34 @@ pub fn fire_edge_update_callbacks(
     }
     Ok(())
 }
+
+/// Fire vertex meta-change callbacks when a key of ``Vertex.meta`` changes.
+///
+/// Each callback receives `(vertex, key, new_value, old_value)` and may
+/// return `False` to stop further callbacks from being invoked.
+pub fn fire_vertex_meta_change_callbacks(
+    py: Python<'_>,
+    callbacks_list: &Bound<'_, PyList>,
+    vertex: &Py<PyAny>,
+    key: &str,
+    new_value: &Py<PyAny>,
+    old_value: Option<&Py<PyAny>>,
+) -> PyResult<()> {
+    for callback in callbacks_list.iter() {
+        let cb: Py<PyAny> = callback.into();
+        let result = cb.call1(
+            py,
+            (
+                vertex.clone_ref(py),
+                key.to_string(),
+                new_value.clone_ref(py),
+                old_value.map(|v| v.clone_ref(py)),
+            ),
+        )?;
+        let should_continue: bool = result.extract(py).unwrap_or(true);
+        if !should_continue {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -2,9 +2,11 @@
 
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict, PyList};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::{Edge, Node};
+use crate::errors::{missing_node, node_not_found};
+use crate::serialization::BinarySnapshot;
 
 // Import the helper modules as sibling modules
 use super::algorithms;
@@ -13,9 +15,16 @@ use super::callbacks;
 use super::manipulation;
 use super::serialization;
 
+/// Node ids shown by ``__repr__`` before it is cut off
+const REPR_MAX_IDS: usize = 10;
+/// Node ids sampled by ``__str__``
+const STR_SAMPLE_IDS: usize = 5;
+/// Edge types listed by ``__str__``
+const STR_TOP_EDGE_TYPES: usize = 5;
+
 #[pyclass]
 pub struct Vertex {
-    #[pyo3(get, set)]
+    #[pyo3(get)]
     pub nodes: HashMap<String, Py<Node>>,
     #[pyo3(get, set)]
     pub meta: Py<PyDict>,
@@ -27,6 +36,18 @@ pub struct Vertex {
     pub on_node_update_callbacks: Py<PyList>,
     #[pyo3(get, set)]
     pub on_edge_update_callbacks: Py<PyList>,
+    /// Callbacks fired when a ``meta`` key changes via ``meta_set``/``meta_update``.
+    #[pyo3(get, set)]
+    pub on_vertex_meta_change_callbacks: Py<PyList>,
+    /// Callbacks fired with ``(vertex, node)`` after a node is removed.
+    #[pyo3(get, set)]
+    pub on_node_remove_callbacks: Py<PyList>,
+    /// Cached number of outgoing edges over all nodes, kept in step by
+    /// ``add_edge``/``remove_edge``/``prune``. See ``validate``/``repair``.
+    pub edge_count: usize,
+    /// The binary file this graph last saved, loaded or appended, so that
+    /// ``append_to_binary`` can write only what changed since.
+    pub binary_snapshot: Option<BinarySnapshot>,
 }
 
 #[pymethods]
@@ -40,6 +61,10 @@ impl Vertex {
             on_edge_add_callbacks: PyList::empty(py).into(),
             on_node_update_callbacks: PyList::empty(py).into(),
             on_edge_update_callbacks: PyList::empty(py).into(),
+            on_vertex_meta_change_callbacks: PyList::empty(py).into(),
+            on_node_remove_callbacks: PyList::empty(py).into(),
+            edge_count: 0,
+            binary_snapshot: None,
         }
     }
 
@@ -47,12 +72,16 @@ impl Vertex {
     #[staticmethod]
     pub fn from_nodes(py: Python<'_>, nodes: HashMap<String, Py<Node>>) -> Self {
         Vertex {
+            edge_count: manipulation::count_edges(py, &nodes),
             nodes,
             meta: PyDict::new(py).into(),
             on_node_add_callbacks: PyList::empty(py).into(),
             on_edge_add_callbacks: PyList::empty(py).into(),
             on_node_update_callbacks: PyList::empty(py).into(),
             on_edge_update_callbacks: PyList::empty(py).into(),
+            on_vertex_meta_change_callbacks: PyList::empty(py).into(),
+            on_node_remove_callbacks: PyList::empty(py).into(),
+            binary_snapshot: None,
         }
     }
 
@@ -67,38 +96,131 @@ impl Vertex {
         meta.set_item("nodelist", nodelist)?;
 
         Ok(Vertex {
+            edge_count: manipulation::count_edges(py, &nodes),
             nodes,
             meta: meta.into(),
             on_node_add_callbacks: PyList::empty(py).into(),
             on_edge_add_callbacks: PyList::empty(py).into(),
             on_node_update_callbacks: PyList::empty(py).into(),
             on_edge_update_callbacks: PyList::empty(py).into(),
+            on_vertex_meta_change_callbacks: PyList::empty(py).into(),
+            on_node_remove_callbacks: PyList::empty(py).into(),
+            binary_snapshot: None,
         })
     }
 
-    fn __getitem__(&self, py: Python<'_>, key: String) -> PyResult<Py<Node>> {
-        self.nodes
-            .get(&key)
-            .map(|n| n.clone_ref(py))
-            .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(key))
+    /// ``v["a"]`` returns the node; ``v[["a", "b"]]`` the induced subgraph, like
+    /// ``filter(ids=...)``. Every missing id of a list is named in the error.
+    fn __getitem__(&self, py: Python<'_>, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Ok(id) = key.extract::<String>() {
+            return self
+                .nodes
+                .get(&id)
+                .map(|n| n.clone_ref(py).into_any())
+                .ok_or_else(|| missing_node(&id));
+        }
+        let Ok(ids) = key.extract::<Vec<String>>() else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "Vertex indices must be a node id (str) or a list of ids",
+            ));
+        };
+
+        // Each missing id once, in first-seen order
+        let mut seen: HashSet<&String> = HashSet::new();
+        let missing: Vec<&String> = ids
+            .iter()
+            .filter(|id| !self.nodes.contains_key(*id) && seen.insert(*id))
+            .collect();
+        if !missing.is_empty() {
+            let quoted: Vec<String> = missing.iter().map(|id| format!("'{}'", id)).collect();
+            return Err(node_not_found(format!("Nodes not found: {}", quoted.join(", "))));
+        }
+        Ok(algorithms::filter(self, py, ids, true)?.into_any())
     }
 
-    fn keys(&self) -> Vec<String> {
-        self.nodes.keys().cloned().collect()
+    /// Replace all nodes; the cached edge count is recomputed.
+    #[setter]
+    fn set_nodes(&mut self, py: Python<'_>, nodes: HashMap<String, Py<Node>>) {
+        self.edge_count = manipulation::count_edges(py, &nodes);
+        self.nodes = nodes;
     }
 
-    fn __repr__(&self, py: Python<'_>) -> String {
-        let keys: Vec<String> = self
-            .nodes
-            .values()
-            .filter_map(|n| {
-                n.bind(py)
-                    .getattr("id")
-                    .ok()
-                    .and_then(|o| o.extract::<String>().ok())
-            })
-            .collect();
-        format!("Vertex({})", keys.join(", "))
+    /// Return the node ids
+    ///
+    /// Args:
+    ///     ordered (bool): Sort the ids so the result is the same on every run.
+    ///         Otherwise they come in the arbitrary order of the internal hash
+    ///         map, which changes between runs. Defaults to ``False``
+    ///
+    /// Returns:
+    ///     list[str]: The node ids
+    #[pyo3(signature = (ordered=false))]
+    fn keys(&self, ordered: bool) -> Vec<String> {
+        let mut keys: Vec<String> = self.nodes.keys().cloned().collect();
+        if ordered {
+            keys.sort();
+        }
+        keys
+    }
+
+    fn __repr__(&self) -> String {
+        // Sorted so the shown ids do not depend on hash map order
+        let mut keys: Vec<&str> = self.nodes.keys().map(|k| k.as_str()).collect();
+        keys.sort_unstable();
+        keys.truncate(REPR_MAX_IDS);
+        if self.nodes.len() > REPR_MAX_IDS {
+            format!(
+                "Vertex({}, ... (+{} more), {} nodes)",
+                keys.join(", "),
+                self.nodes.len() - REPR_MAX_IDS,
+                self.nodes.len()
+            )
+        } else {
+            format!("Vertex({})", keys.join(", "))
+        }
+    }
+
+    /// Multi-line summary: node and edge counts, a few node ids and the most
+    /// common edge ``type`` values.
+    fn __str__(&self, py: Python<'_>) -> PyResult<String> {
+        let mut edge_count = 0;
+        let mut type_counts: HashMap<String, usize> = HashMap::new();
+        for node in self.nodes.values() {
+            for edge in &node.borrow(py).edges {
+                edge_count += 1;
+                if let Some(typ) = edge.borrow(py).attr.get("type") {
+                    let typ = typ.bind(py).str()?.to_string();
+                    *type_counts.entry(typ).or_default() += 1;
+                }
+            }
+        }
+
+        let mut sample: Vec<&str> = self.nodes.keys().map(|k| k.as_str()).collect();
+        sample.sort_unstable();
+        let more = if sample.len() > STR_SAMPLE_IDS { ", ..." } else { "" };
+        sample.truncate(STR_SAMPLE_IDS);
+
+        let mut types: Vec<(String, usize)> = type_counts.into_iter().collect();
+        types.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        let types = if types.is_empty() {
+            "none".to_string()
+        } else {
+            types
+                .iter()
+                .take(STR_TOP_EDGE_TYPES)
+                .map(|(typ, count)| format!("{} ({})", typ, count))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        Ok(format!(
+            "Vertex with {} nodes and {} edges\n  nodes: {}{}\n  edge types: {}",
+            self.nodes.len(),
+            edge_count,
+            sample.join(", "),
+            more,
+            types
+        ))
     }
 
     fn toJSON(&self, py: Python<'_>) -> Py<PyAny> {
@@ -120,6 +242,24 @@ impl Vertex {
         self.nodes.contains_key(&id)
     }
 
+    /// Return every node carrying ``tag``, sorted by node ID
+    ///
+    /// Args:
+    ///     tag (str): Tag to look for (see ``Node.add_tag``)
+    ///
+    /// Returns:
+    ///     list[Node]: The tagged nodes
+    fn nodes_with_tag(&self, py: Python<'_>, tag: &str) -> Vec<Py<Node>> {
+        let mut ids: Vec<&String> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| node.borrow(py).tags.contains(tag))
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort();
+        ids.into_iter().map(|id| self.nodes[id].clone_ref(py)).collect()
+    }
+
     /// Get the number of nodes in the graph
     ///
     /// Returns:
@@ -128,6 +268,34 @@ impl Vertex {
         self.nodes.len()
     }
 
+    /// Get the number of edges in the graph in O(1)
+    ///
+    /// The count is cached and kept up to date by ``add_edge``, ``remove_edge`` and
+    /// ``prune``. Edges added by assigning ``node.edges`` directly are not seen;
+    /// call ``repair()`` afterwards.
+    ///
+    /// Returns:
+    ///     int: The number of outgoing edges summed over all nodes
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// Check the cached edge count against a full recount
+    ///
+    /// Returns:
+    ///     list[str]: A description of each inconsistency found; empty if none
+    fn validate(&self, py: Python<'_>) -> Vec<String> {
+        manipulation::validate(self, py)
+    }
+
+    /// Recompute cached values from the node and edge lists
+    ///
+    /// Returns:
+    ///     list[str]: The inconsistencies that were fixed, as reported by ``validate``
+    fn repair(&mut self, py: Python<'_>) -> Vec<String> {
+        manipulation::repair(self, py)
+    }
+
     // Manipulation methods
     /// Add a new node to the graph
     ///
@@ -139,7 +307,7 @@ impl Vertex {
     ///     Node: The created node
     ///     
     /// Raises:
-    ///     ValueError: If a node with the same ID already exists
+    ///     GraphError: If a node with the same ID already exists
     fn add_node(
         mut slf: PyRefMut<'_, Self>,
         py: Python<'_>,
@@ -218,6 +386,183 @@ impl Vertex {
         Ok(edge)
     }
 
+    /// Remove edges between two nodes in the graph
+    ///
+    /// Args:
+    ///     from_id (str): ID of the source node
+    ///     to_id (str): ID of the target node
+    ///     edge_id (str, optional): Only remove the edge with this ID. If None, every
+    ///         edge from ``from_id`` to ``to_id`` is removed.
+    ///
+    /// Returns:
+    ///     int: The number of edges removed
+    ///
+    /// Raises:
+    ///     ValueError: If either node doesn't exist
+    #[pyo3(signature = (from_id, to_id, edge_id=None))]
+    fn remove_edge(
+        &mut self,
+        py: Python<'_>,
+        from_id: String,
+        to_id: String,
+        edge_id: Option<String>,
+    ) -> PyResult<usize> {
+        manipulation::remove_edge(self, py, &from_id, &to_id, edge_id.as_deref())
+    }
+
+    /// Give every node without ``key`` the attribute ``attr[key] = value``
+    ///
+    /// Useful to ensure a uniform schema before algorithms that expect an attribute
+    /// to exist. Values are written with ``Node.attr_set``, so
+    /// ``on_node_update_callbacks`` fire once per node that gets the default; nodes
+    /// that already have ``key`` are untouched, whatever its value. Every node
+    /// receives the same ``value`` object, so prefer immutable defaults.
+    ///
+    /// Args:
+    ///     key (str): Attribute key
+    ///     value: Default value
+    ///
+    /// Returns:
+    ///     int: The number of nodes that received the default
+    fn set_default_attr(&self, py: Python<'_>, key: &str, value: Py<PyAny>) -> PyResult<usize> {
+        manipulation::set_default_attr(self, py, key, &value)
+    }
+
+    /// Give every edge without ``key`` the attribute ``attr[key] = value``
+    ///
+    /// The edge analogue of ``set_default_attr``, firing ``on_edge_update_callbacks``
+    /// once per edge that gets the default.
+    ///
+    /// Args:
+    ///     key (str): Attribute key
+    ///     value: Default value
+    ///
+    /// Returns:
+    ///     int: The number of edges that received the default
+    fn set_default_edge_attr(&self, py: Python<'_>, key: &str, value: Py<PyAny>) -> PyResult<usize> {
+        manipulation::set_default_edge_attr(self, py, key, &value)
+    }
+
+    /// Rename an attribute key on every node or edge that has it
+    ///
+    /// Schema-migration helper for graphs imported with inconsistent field
+    /// names. Values are kept; the new key is written with ``attr_set``, so
+    /// ``on_node_update_callbacks`` (or ``on_edge_update_callbacks``) fire once
+    /// per renamed item, and the old key is removed. Items without
+    /// ``old_key`` are skipped.
+    ///
+    /// Args:
+    ///     old_key (str): Attribute to rename
+    ///     new_key (str): New name for it
+    ///     on (str): ``"nodes"`` or ``"edges"``. Defaults to ``"nodes"``.
+    ///
+    /// Returns:
+    ///     int: The number of items renamed
+    ///
+    /// Raises:
+    ///     ValueError: If ``on`` is not ``"nodes"`` or ``"edges"``
+    ///     GraphError: If an item already has ``new_key``; nothing is renamed
+    #[pyo3(signature = (old_key, new_key, on="nodes"))]
+    fn rename_attr(&self, py: Python<'_>, old_key: &str, new_key: &str, on: &str) -> PyResult<usize> {
+        manipulation::rename_attr(self, py, old_key, new_key, on)
+    }
+
+    /// Rename many nodes at once
+    ///
+    /// All renames are validated first and then applied together, so swaps
+    /// such as ``{"a": "b", "b": "a"}`` work and a failed call leaves the graph
+    /// unchanged. Nodes keep their attributes and edges; only ``Node.id`` and
+    /// the key in ``nodes`` change. No callbacks fire.
+    ///
+    /// Args:
+    ///     mapping (dict[str, str]): Old node ID to new node ID; nodes not in
+    ///         the mapping keep their ID
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If a key of ``mapping`` is not a node
+    ///     GraphError: If two nodes would get the same ID, or a new ID is taken
+    ///         by a node that is not renamed
+    fn relabel(&mut self, py: Python<'_>, mapping: HashMap<String, String>) -> PyResult<()> {
+        manipulation::relabel(self, py, &mapping)
+    }
+
+    /// Flip the direction of every edge without copying the graph
+    ///
+    /// Each edge swaps ``from_node`` and ``to_node`` and each node swaps its
+    /// ``edges`` and ``inverse_edges``, so former successors become
+    /// predecessors while the ``Edge`` objects (and their attributes) stay the
+    /// same. Use this instead of building a reversed copy when the original
+    /// orientation is no longer needed. No callbacks fire; edges to nodes
+    /// outside the graph should be removed with ``prune`` first.
+    fn reverse_in_place(&mut self, py: Python<'_>) {
+        manipulation::reverse_in_place(self, py)
+    }
+
+    /// Set a graph-level metadata value
+    ///
+    /// Fires ``on_vertex_meta_change_callbacks`` with
+    /// ``(vertex, key, new_value, old_value)`` if the value changed.
+    ///
+    /// Args:
+    ///     key (str): Metadata key
+    ///     value: Value to store
+    fn meta_set(slf: PyRef<'_, Self>, py: Python<'_>, key: String, value: Py<PyAny>) -> PyResult<()> {
+        let handle: Py<Self> = slf.into();
+        manipulation::meta_set(&handle, py, key, value)
+    }
+
+    /// Get a graph-level metadata value
+    ///
+    /// Args:
+    ///     key (str): Metadata key
+    ///     default (optional): Returned when the key is missing. Defaults to None.
+    ///
+    /// Returns:
+    ///     The stored value, or ``default``
+    #[pyo3(signature = (key, default=None))]
+    fn meta_get(&self, py: Python<'_>, key: String, default: Option<Py<PyAny>>) -> PyResult<Py<PyAny>> {
+        match self.meta.bind(py).get_item(&key)? {
+            Some(value) => Ok(value.unbind()),
+            None => Ok(default.unwrap_or_else(|| py.None())),
+        }
+    }
+
+    /// Set several graph-level metadata values at once
+    ///
+    /// Equivalent to calling ``meta_set`` for every item, so callbacks fire
+    /// once per changed key.
+    ///
+    /// Args:
+    ///     values (dict): Keys and values to store
+    fn meta_update(slf: PyRef<'_, Self>, py: Python<'_>, values: &Bound<'_, PyDict>) -> PyResult<()> {
+        let handle: Py<Self> = slf.into();
+        manipulation::meta_update(&handle, py, values)
+    }
+
+    /// Count the callbacks registered in each callback list
+    ///
+    /// Callbacks are not serialized: a graph loaded from any file format starts
+    /// with empty lists, so this is a quick check whether they need to be
+    /// registered again.
+    ///
+    /// Returns:
+    ///     dict[str, int]: Callback list name (e.g. ``"on_node_add_callbacks"``) ->
+    ///     number of registered callbacks
+    fn has_callbacks(&self, py: Python<'_>) -> PyResult<Py<PyDict>> {
+        let counts = PyDict::new(py);
+        for (name, list) in [
+            ("on_node_add_callbacks", &self.on_node_add_callbacks),
+            ("on_edge_add_callbacks", &self.on_edge_add_callbacks),
+            ("on_node_update_callbacks", &self.on_node_update_callbacks),
+            ("on_edge_update_callbacks", &self.on_edge_update_callbacks),
+            ("on_vertex_meta_change_callbacks", &self.on_vertex_meta_change_callbacks),
+            ("on_node_remove_callbacks", &self.on_node_remove_callbacks),
+        ] {
+            counts.set_item(name, list.bind(py).len())?;
+        }
+        Ok(counts.unbind())
+    }
+
     /// Get a node by its ID
     ///
     /// Args:
@@ -237,256 +582,2062 @@ impl Vertex {
     ///
     /// Args:
     ///     file_path (str, optional): Path to save the graph to. If None, returns JSON string.
+    ///     include_timestamp (bool): Record the save time in ``metadata["timestamp"]``.
+    ///         Pass False for output that only depends on the graph. Defaults to True
     ///     
     /// Returns:
     ///     None if file_path is provided, or str (JSON) if file_path is None
     ///     
     /// Raises:
     ///     RuntimeError: If saving/serialization fails
-    #[pyo3(signature = (file_path=None))]
-    fn save_to_json(&self, py: Python<'_>, file_path: Option<String>) -> PyResult<Py<PyAny>> {
-        serialization::save_to_json(self, py, file_path)
+    #[pyo3(signature = (file_path=None, include_timestamp=true))]
+    fn save_to_json(&self, py: Python<'_>, file_path: Option<String>, include_timestamp: bool) -> PyResult<Py<PyAny>> {
+        serialization::save_to_json(self, py, file_path, include_timestamp)
     }
 
     /// Save the graph to a binary file (more efficient for large graphs)
     ///
     /// Args:
     ///     file_path (str): Path to save the graph to
+    ///     include_timestamp (bool): Record the save time in ``metadata["timestamp"]``.
+    ///         Pass False for output that only depends on the graph. Defaults to True
     ///     
     /// Raises:
     ///     RuntimeError: If saving fails
-    fn save_to_binary(&self, py: Python<'_>, file_path: String) -> PyResult<()> {
-        serialization::save_to_binary(self, py, file_path)
+    #[pyo3(signature = (file_path, include_timestamp=true))]
+    fn save_to_binary(&mut self, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+        serialization::save_to_binary(self, py, file_path, include_timestamp)
+    }
+
+    /// Append this graph's nodes and edges to an existing binary graph file
+    ///
+    /// The records are written as a length-prefixed delta after the current
+    /// file contents, so nothing is rewritten, and ``load_from_binary`` merges
+    /// all deltas into the base graph.
+    ///
+    /// If this graph last saved, loaded or appended the same file, only what
+    /// changed since is written: new and changed nodes and edges, plus the
+    /// graph meta. Changed records replace the stored ones, and nothing is
+    /// written if nothing changed. Removals cannot be appended; save the graph
+    /// instead.
+    ///
+    /// Any other graph is appended whole. All of its edges are added, even ones
+    /// equal to an edge already in the file, since parallel edges may share
+    /// attributes. Nodes already present keep their edges and take the delta's
+    /// value for conflicting attribute keys. To add an edge touching an existing
+    /// node, include that node (attributes may be empty) in the appended vertex.
+    ///
+    /// Args:
+    ///     file_path (str): Binary file to append to. Created if it doesn't exist.
+    ///
+    /// Raises:
+    ///     RuntimeError: If appending fails, e.g. because the file was written
+    ///         with an older binary format version, this graph removed a node or
+    ///         edge it had written, or another writer changed the file since
+    fn append_to_binary(&mut self, py: Python<'_>, file_path: String) -> PyResult<()> {
+        serialization::append_to_binary(self, py, file_path)
+    }
+
+    /// Save the graph to a zstd-compressed binary file
+    ///
+    /// The bincode payload is wrapped in a zstd stream, which shrinks
+    /// attribute-heavy graphs considerably. The file header marks the payload
+    /// as compressed, so ``load_from_binary`` reads it too. Compressed files
+    /// cannot be extended with ``append_to_binary``.
+    ///
+    /// Args:
+    ///     file_path (str): Path to save the graph to
+    ///     level (int): zstd compression level (default 3; higher is smaller and slower)
+    ///     include_timestamp (bool): Record the save time in ``metadata["timestamp"]``.
+    ///         Pass False for output that only depends on the graph. Defaults to True
+    ///
+    /// Raises:
+    ///     ValueError: If ``level`` is outside the range zstd supports
+    ///     RuntimeError: If saving fails
+    #[pyo3(signature = (file_path, level=3, include_timestamp=true))]
+    fn save_to_binary_zstd(&self, py: Python<'_>, file_path: String, level: i32, include_timestamp: bool) -> PyResult<()> {
+        serialization::save_to_binary_zstd(self, py, file_path, level, include_timestamp)
     }
 
     /// Save the graph to a binary file using f16 precision for floats
-    #[pyo3(text_signature = "(self, file_path)")]
-    fn save_to_binary_f16(&self, py: Python<'_>, file_path: String) -> PyResult<()> {
-        serialization::save_to_binary_f16(self, py, file_path)
+    ///
+    /// Floats keep about three significant decimal digits; magnitudes above
+    /// 65504 become infinity. The file header records the downcast, so both
+    /// ``load_from_binary`` and ``load_from_binary_f16`` restore the values as
+    /// regular floats.
+    ///
+    /// Args:
+    ///     file_path (str): Path to save the graph to
+    ///     include_timestamp (bool): Record the save time in ``metadata["timestamp"]``.
+    ///         Pass False for output that only depends on the graph. Defaults to True
+    ///
+    /// Raises:
+    ///     RuntimeError: If saving fails
+    #[pyo3(signature = (file_path, include_timestamp=true))]
+    fn save_to_binary_f16(&self, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+        serialization::save_to_binary_f16(self, py, file_path, include_timestamp)
     }
 
-    /// Load a graph from a JSON file, JSON string, or dict
+    /// Save the graph to a JSON Lines file
+    ///
+    /// The first line holds the graph metadata, followed by one JSON object per
+    /// node and one per edge. The format is append-friendly and streams into
+    /// line-oriented big-data tools.
     ///
     /// Args:
-    ///     source (str | dict): Either a file path, a JSON string, or a dict representing the graph
-    ///     
+    ///     file_path (str): Path to save the graph to
+    ///     include_timestamp (bool): Record the save time in ``metadata["timestamp"]``.
+    ///         Pass False for output that only depends on the graph. Defaults to True
+    ///
+    /// Raises:
+    ///     RuntimeError: If saving fails
+    #[pyo3(signature = (file_path, include_timestamp=true))]
+    fn save_to_jsonl(&self, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+        serialization::save_to_jsonl(self, py, file_path, include_timestamp)
+    }
+
+    /// Load a graph from a JSON Lines file written by ``save_to_jsonl``
+    ///
+    /// The file is parsed one line at a time.
+    ///
+    /// Args:
+    ///     file_path (str): Path to load the graph from
+    ///
     /// Returns:
     ///     Vertex: The loaded graph
-    ///     
+    ///
     /// Raises:
     ///     RuntimeError: If loading fails
-    ///     TypeError: If source is not a valid type
     #[staticmethod]
-    fn load_from_json(py: Python<'_>, source: &Bound<'_, PyAny>) -> PyResult<Py<Vertex>> {
-        serialization::load_from_json(py, source)
+    fn load_from_jsonl(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+        serialization::load_from_jsonl(py, file_path)
     }
 
-    /// Load a graph from a binary file
+    /// Load a graph from a JSON file, JSON string, or dict
     ///
     /// Args:
-    ///     file_path (str): Path to load the graph from
+    ///     source (str | dict): Either a file path, a JSON string, or a dict representing the graph
+    ///     coerce_numeric (bool): Convert string attribute values such as ``"42"`` or
+    ///         ``"3.14"`` to int or float, as in CSV-derived JSON. Strings that do not
+    ///         parse stay unchanged. Defaults to False.
     ///     
     /// Returns:
     ///     Vertex: The loaded graph
     ///     
     /// Raises:
     ///     RuntimeError: If loading fails
+    ///     TypeError: If source is not a valid type
     #[staticmethod]
-    fn load_from_binary(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
-        serialization::load_from_binary(py, file_path)
-    }
-
-    // Analysis methods
-    /// Get metadata about the graph (node count, edge count, etc.)
-    fn get_metadata(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        analysis::get_metadata(self, py)
+    #[pyo3(signature = (source, coerce_numeric=false))]
+    fn load_from_json(py: Python<'_>, source: &Bound<'_, PyAny>, coerce_numeric: bool) -> PyResult<Py<Vertex>> {
+        serialization::load_from_json(py, source, coerce_numeric)
     }
 
-    /// Convert the graph to a NetworkX DiGraph object
+    /// Build a graph from an adjacency dict
+    ///
+    /// Nodes referenced only as targets are created automatically, with empty
+    /// attributes.
+    ///
+    /// Args:
+    ///     adj (dict): Either ``{node_id: [target_id, ...]}`` or, for attributed
+    ///         edges, ``{node_id: {target_id: attr_dict_or_None}}``
     ///
     /// Returns:
-    ///     networkx.DiGraph: A NetworkX directed graph representation of this vertex
-    ///     
+    ///     Vertex: The new graph
+    ///
     /// Raises:
-    ///     RuntimeError: If NetworkX is not available or conversion fails
-    fn to_networkx(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
-        analysis::to_networkx(self, py)
+    ///     TypeError: If an id is not a string or an adjacency entry is neither
+    ///         a list of ids nor a dict
+    #[staticmethod]
+    fn from_adjacency(py: Python<'_>, adj: &Bound<'_, PyDict>) -> PyResult<Py<Vertex>> {
+        serialization::from_adjacency(py, adj)
     }
 
-    // Algorithm methods
-    /// Find the shortest path between source and target nodes using Breadth-First Search
+    /// Build a graph from a pandas DataFrame edge list, like networkx's function of the same name
+    ///
+    /// Every row becomes one edge from its ``source`` to its ``target`` value;
+    /// nodes are created automatically, with empty attributes. Each used column
+    /// is read once as a Python list, so no per-row pandas access is needed.
     ///
     /// Args:
-    ///     root_node_id (str): ID of the source node to start the search from
-    ///     target_node_id (str): ID of the target node to find
-    ///     max_depth (int, optional): Maximum depth to search. If None, searches indefinitely.
-    ///     
+    ///     df (pandas.DataFrame): The edge list (any object with ``columns`` and
+    ///         ``df[name].tolist()`` works)
+    ///     source (str): Column with the source node ids. Defaults to ``"source"``.
+    ///     target (str): Column with the target node ids. Defaults to ``"target"``.
+    ///     edge_attr (str | list[str] | bool, optional): Columns copied into the
+    ///         edge attributes. ``True`` takes every other column; ``None`` (default)
+    ///         adds no attributes.
+    ///
     /// Returns:
-    ///     Vertex: A new vertex containing only the nodes in the shortest path from source to target
-    ///     
+    ///     Vertex: The new graph
+    ///
     /// Raises:
-    ///     ValueError: If either source or target node doesn't exist, or if target is not reachable within max_depth
-    #[pyo3(signature = (root_node_id, target_node_id, max_depth=None))]
-    fn shortest_path_bfs(
-        &self,
+    ///     KeyError: If a requested column does not exist
+    ///     TypeError: If a node id is not a string
+    #[staticmethod]
+    #[pyo3(signature = (df, source="source", target="target", edge_attr=None))]
+    fn from_pandas_edgelist(
         py: Python<'_>,
-        root_node_id: String,
-        target_node_id: String,
-        max_depth: Option<usize>,
+        df: &Bound<'_, PyAny>,
+        source: &str,
+        target: &str,
+        edge_attr: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<Py<Vertex>> {
-        algorithms::shortest_path_bfs(self, py, root_node_id, target_node_id, max_depth)
+        serialization::from_pandas_edgelist(py, df, source, target, edge_attr)
     }
 
-    /// Expand the current vertex by adding neighbor nodes from a source vertex
+    /// Export the graph as an adjacency dict
+    ///
+    /// The inverse of ``from_adjacency``: every node is a key, including nodes
+    /// without outgoing edges.
     ///
     /// Args:
-    ///     source_vertex (Vertex): The source vertex to expand from (contains the full graph)
-    ///     depth (int, optional): Maximum depth to traverse for expansion. Defaults to 1.
+    ///     with_attrs (bool): Map each target to a copy of the edge attributes
+    ///         instead of listing target ids (parallel edges keep the last
+    ///         edge's attributes). Defaults to ``False``
+    ///
+    /// Returns:
+    ///     dict: ``{node_id: [target_id, ...]}`` or ``{node_id: {target_id: attr}}``
+    #[pyo3(signature = (with_attrs=false))]
+    fn to_adjacency_dict(&self, py: Python<'_>, with_attrs: bool) -> PyResult<Py<PyAny>> {
+        serialization::to_adjacency_dict(self, py, with_attrs)
+    }
+
+    /// Load a graph from a binary file
+    ///
+    /// Deltas written by ``append_to_binary`` are merged into the base graph.
+    /// zstd-compressed and f16 files are detected from the header.
+    ///
+    /// Args:
+    ///     file_path (str): Path to load the graph from
     ///     
     /// Returns:
-    ///     Vertex: A new vertex containing the original nodes plus neighbors found within the specified depth
+    ///     Vertex: The loaded graph
     ///     
     /// Raises:
-    ///     ValueError: If expansion fails
-    #[pyo3(signature = (source_vertex, depth=None))]
-    fn expand(
-        &self,
-        py: Python<'_>,
-        source_vertex: &Vertex,
-        depth: Option<usize>,
-    ) -> PyResult<Py<Vertex>> {
-        algorithms::expand(self, py, source_vertex, depth)
+    ///     RuntimeError: If loading fails
+    #[staticmethod]
+    fn load_from_binary(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+        serialization::load_from_binary(py, file_path)
     }
 
-    /// Create a new vertex containing only the specified nodes and their connecting edges
+    /// Load a graph from a binary file written by ``save_to_binary_zstd``
     ///
     /// Args:
-    ///     ids (list, optional): List of node IDs to include
-    ///     id (str, optional): Single node ID to include
-    ///     **kwargs: Attribute key/value pairs to match nodes
+    ///     file_path (str): Path to load the graph from
     ///
     /// Returns:
-    ///     Vertex: A new vertex containing only the specified nodes and edges between them
+    ///     Vertex: The loaded graph
     ///
     /// Raises:
-    ///     ValueError: If any of the specified node IDs don't exist in the vertex or
-    ///                 no filter criteria are provided
-    #[pyo3(signature = (**kwargs))]
-    fn filter(
-        &self,
-        py: Python<'_>,
-        kwargs: Option<&Bound<'_, PyDict>>,
-    ) -> PyResult<Py<Vertex>> {
-        let kwargs = kwargs.ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
-                "Must specify ids, id, or attribute filters",
-            )
-        })?;
-
-        let mut filters: HashMap<String, Py<PyAny>> = kwargs.extract()?;
+    ///     RuntimeError: If loading fails or the file is not zstd-compressed
+    #[staticmethod]
+    fn load_from_binary_zstd(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+        serialization::load_from_binary_zstd(py, file_path)
+    }
 
-        // Determine which node IDs to include based on the provided keyword arguments
-        let node_ids: Vec<String> = if let Some(ids_any) = filters.remove("ids") {
-            ids_any.extract(py)?
-        } else if let Some(id_any) = filters.remove("id") {
-            vec![id_any.extract(py)?]
-        } else if !filters.is_empty() {
-            let mut matches = Vec::new();
-            for (node_id, node) in &self.nodes {
-                let node_ref = node.bind(py);
-                let attrs: HashMap<String, Py<PyAny>> =
-                    node_ref.getattr("attr")?.extract().unwrap_or_default();
-
-                let mut all_match = true;
-                for (key, value) in &filters {
-                    match attrs.get(key) {
-                        Some(node_val) => {
-                            if !node_val.bind(py).eq(value.bind(py))? {
-                                all_match = false;
-                                break;
-                            }
-                        }
-                        None => {
-                            all_match = false;
-                            break;
-                        }
-                    }
-                }
+    /// Load a graph from a binary file written by ``save_to_binary_f16``
+    ///
+    /// Float values come back as regular floats carrying the f16 rounding.
+    ///
+    /// Args:
+    ///     file_path (str): Path to load the graph from
+    ///
+    /// Returns:
+    ///     Vertex: The loaded graph
+    ///
+    /// Raises:
+    ///     RuntimeError: If loading fails or the file was not saved with f16 floats
+    #[staticmethod]
+    fn load_from_binary_f16(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+        serialization::load_from_binary_f16(py, file_path)
+    }
 
-                if all_match {
-                    matches.push(node_id.clone());
-                }
-            }
-            matches
-        } else {
-            return Err(pyo3::exceptions::PyValueError::new_err(
-                "Must specify ids, id, or attribute filters",
-            ));
-        };
+    // Analysis methods
+    /// Get metadata about the graph (node count, edge count, etc.)
+    ///
+    /// Args:
+    ///     undirected (bool): Count each unordered node pair with at least one edge
+    ///         once, so reciprocal and parallel edges are not double counted.
+    ///         Defaults to False (every directed edge counts).
+    ///
+    /// Returns:
+    ///     dict: ``node_count``, ``edge_count``, ``reciprocal_edge_count`` (node pairs
+    ///     linked in both directions), ``average_degree`` and ``node_ids``
+    #[pyo3(signature = (undirected=false))]
+    fn get_metadata(&self, py: Python<'_>, undirected: bool) -> PyResult<Py<PyAny>> {
+        analysis::get_metadata(self, py, undirected)
+    }
 
-        algorithms::filter(self, py, node_ids)
+    /// Count how many nodes have each degree
+    ///
+    /// Args:
+    ///     direction (str): ``"out"`` (outgoing edges), ``"in"`` (incoming edges)
+    ///         or ``"total"`` (both). Defaults to "out".
+    ///
+    /// Returns:
+    ///     dict[int, int]: Degree value → number of nodes with that degree
+    ///
+    /// Raises:
+    ///     ValueError: If direction is unknown
+    #[pyo3(signature = (direction="out"))]
+    fn degree_distribution(&self, py: Python<'_>, direction: &str) -> PyResult<BTreeMap<usize, usize>> {
+        analysis::degree_distribution(self, py, direction)
     }
-    /// Remove edges and inverse_edges that reference nodes not present in the vertex.
+
+    /// Count how many edges carry each distinct value of an edge attribute
     ///
-    /// This is useful after filtering or subsetting the graph, when edges may still
-    /// point to nodes that are no longer part of the vertex.
+    /// Args:
+    ///     field (str): Edge attribute to count, e.g. ``"type"``
     ///
     /// Returns:
-    ///     int: The number of edges removed
-    fn prune(&self, py: Python<'_>) -> PyResult<usize> {
-        manipulation::prune(self, py)
+    ///     dict: Value → number of edges with it. Edges without ``field`` are skipped.
+    ///     Unhashable values such as lists are keyed by ``str(value)``.
+    fn edge_attr_histogram(&self, py: Python<'_>, field: &str) -> PyResult<Py<PyAny>> {
+        analysis::edge_attr_histogram(self, py, field)
     }
 
-    /// Perform multiple random walks from a starting node
+    /// Infer the attribute schema of nodes and edges
+    ///
+    /// Each attribute value is classified with the same rules used for
+    /// serialization (``string``, ``int``, ``float``, ``half``, ``bool``,
+    /// ``none``, ``list`` or ``dict``).
+    ///
+    /// Returns:
+    ///     dict: ``{"nodes": {...}, "edges": {...}}`` mapping each attribute key to
+    ///     a dict with ``types`` (sorted list of observed type names), ``mixed``
+    ///     (more than one type seen), ``count`` (items carrying the key) and
+    ///     ``required`` (present on every node, or every edge)
+    fn infer_schema(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        analysis::infer_schema(self, py)
+    }
+
+    /// Export node attributes as columns, e.g. for ``pandas.DataFrame``
     ///
     /// Args:
-    ///     start_node_id (str, optional): ID of the node to start the random walks from.
-    ///         May be None only when stratified=True, in which case each walk's start
-    ///         is sampled across all nodes with probability inversely proportional to
-    ///         how often each node has been visited so far.
-    ///     max_length (int): Maximum length of each random walk
-    ///     num_attempts (int): Number of random walk attempts to perform
-    ///     min_length (int, optional): Minimum length of each random walk. Defaults to 1.
-    ///     allow_revisit (bool, optional): Whether to allow revisiting nodes. Defaults to False.
-    ///     include_edge_types (bool, optional): Whether to include edge types in the result. Defaults to False.
-    ///     edge_type_field (str, optional): Field name to extract edge type from. Defaults to "type".
-    ///     stratified (bool, optional): Equalize node visit frequencies. Every choice
-    ///         (the start node when start_node_id is None, and each step) is weighted by
-    ///         1 / (1 + times_visited), steering walks towards least-visited nodes.
-    ///         Visit counts persist across all attempts of one call. Defaults to False.
+    ///     fields (list[str]): Node attributes to export, one column each
     ///
     /// Returns:
-    ///     list: A list of lists. If include_edge_types is False, each inner list contains node IDs.
-    ///           If include_edge_types is True, each inner list alternates between node IDs and edge types.
-    ///           Duplicates are automatically removed.
+    ///     dict[str, list]: An ``id`` column of sorted node ids plus one column per
+    ///     field aligned with it; nodes without a field get ``None``
     ///
     /// Raises:
-    ///     ValueError: If start_node_id doesn't exist, is None without stratified=True,
-    ///         max_length is 0, or min_length > max_length
-    #[pyo3(signature = (start_node_id, max_length, num_attempts, min_length=None, allow_revisit=None, include_edge_types=None, edge_type_field=None, stratified=None))]
-    fn random_walks(
+    ///     ValueError: If a field is named ``id``
+    fn nodes_to_columns(&self, py: Python<'_>, fields: Vec<String>) -> PyResult<Py<PyAny>> {
+        analysis::nodes_to_columns(self, py, &fields)
+    }
+
+    /// Export edge attributes as columns, e.g. for ``pandas.DataFrame``
+    ///
+    /// Args:
+    ///     fields (list[str]): Edge attributes to export, one column each
+    ///
+    /// Returns:
+    ///     dict[str, list]: ``from_id`` and ``to_id`` columns (edges ordered by
+    ///     source id, then insertion order) plus one column per field aligned with
+    ///     them; edges without a field get ``None``
+    ///
+    /// Raises:
+    ///     ValueError: If a field is named ``from_id`` or ``to_id``
+    fn edges_to_columns(&self, py: Python<'_>, fields: Vec<String>) -> PyResult<Py<PyAny>> {
+        analysis::edges_to_columns(self, py, &fields)
+    }
+
+    /// Convert the graph to a NetworkX DiGraph object
+    ///
+    /// Node and edge attributes are carried over; parallel edges collapse into
+    /// one DiGraph edge with their attributes merged, later edges winning.
+    /// Everything is inserted with a single ``add_nodes_from`` and a single
+    /// ``add_edges_from`` call.
+    ///
+    /// Returns:
+    ///     networkx.DiGraph: A NetworkX directed graph representation of this vertex
+    ///     
+    /// Raises:
+    ///     RuntimeError: If NetworkX is not available or conversion fails
+    fn to_networkx(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        analysis::to_networkx(self, py)
+    }
+
+    /// Export the graph Laplacian as a dense numpy array
+    ///
+    /// Edge direction is ignored: every edge adds its weight to both symmetric
+    /// entries of the adjacency matrix ``A``, and self-loops are skipped.
+    ///
+    /// Args:
+    ///     normalized (bool): Return the symmetric normalized Laplacian
+    ///         ``I - D^-1/2 A D^-1/2`` instead of ``D - A``. Defaults to ``False``
+    ///     weight_field (str, optional): Numeric edge attribute used as the weight.
+    ///         Without it every edge weighs 1.0
+    ///
+    /// Returns:
+    ///     tuple[numpy.ndarray, list[str]]: The ``n x n`` Laplacian and the node IDs
+    ///     of its rows and columns (sorted)
+    ///
+    /// Raises:
+    ///     RuntimeError: If NumPy is not available
+    ///     GraphError: If an edge weight is negative
+    #[pyo3(signature = (normalized=false, weight_field=None))]
+    fn to_laplacian(
         &self,
         py: Python<'_>,
-        start_node_id: Option<String>,
-        max_length: usize,
-        num_attempts: usize,
-        min_length: Option<usize>,
-        allow_revisit: Option<bool>,
-        include_edge_types: Option<bool>,
-        edge_type_field: Option<String>,
-        stratified: Option<bool>,
-    ) -> PyResult<Py<PyList>> {
-        algorithms::random_walks(
+        normalized: bool,
+        weight_field: Option<String>,
+    ) -> PyResult<(Py<PyAny>, Vec<String>)> {
+        algorithms::to_laplacian(self, py, normalized, weight_field.as_deref())
+    }
+
+    /// Compute the Fiedler vector, a 1-D spectral ordering of the nodes
+    ///
+    /// Returns the eigenvector of the second-smallest eigenvalue of the
+    /// unnormalized Laplacian (see ``to_laplacian``), found by power iteration
+    /// in Rust, so NumPy is not needed. Nodes on opposite sides of a sparse cut
+    /// get opposite signs.
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Numeric edge attribute used as the weight.
+    ///         Without it every edge weighs 1.0
+    ///
+    /// Returns:
+    ///     dict[str, float]: Node ID to its entry of the unit-length vector. The
+    ///     largest entry is positive. Graphs with fewer than two nodes or no
+    ///     edges map every node to 0.0
+    ///
+    /// Raises:
+    ///     GraphError: If an edge weight is negative
+    #[pyo3(signature = (weight_field=None))]
+    fn fiedler_vector(
+        &self,
+        py: Python<'_>,
+        weight_field: Option<String>,
+    ) -> PyResult<HashMap<String, f64>> {
+        algorithms::fiedler_vector(self, py, weight_field.as_deref())
+    }
+
+    /// Compute 2-D node positions with the Fruchterman-Reingold force-directed layout
+    ///
+    /// Every pair of nodes repels and every edge attracts its endpoints (direction
+    /// ignored), with steps capped by a temperature that cools over the iterations.
+    /// The result is centered on the origin and scaled into ``[-1, 1]``.
+    ///
+    /// Args:
+    ///     iterations (int): Number of simulation steps. Defaults to 50
+    ///     seed (int, optional): Seed for the random starting positions, for
+    ///         reproducible layouts
+    ///     weight_field (str, optional): Numeric edge attribute scaling the attraction.
+    ///         Without it every edge weighs 1.0
+    ///
+    /// Returns:
+    ///     dict[str, tuple[float, float]]: Node ID to its ``(x, y)`` position
+    ///
+    /// Raises:
+    ///     GraphError: If an edge weight is negative
+    #[pyo3(signature = (iterations=50, seed=None, weight_field=None))]
+    fn spring_layout(
+        &self,
+        py: Python<'_>,
+        iterations: usize,
+        seed: Option<u64>,
+        weight_field: Option<String>,
+    ) -> PyResult<HashMap<String, (f64, f64)>> {
+        algorithms::spring_layout(self, py, iterations, seed, weight_field.as_deref())
+    }
+
+    /// Render the graph in Graphviz DOT syntax
+    ///
+    /// Args:
+    ///     color_by (str, optional): Node attribute whose distinct values get distinct
+    ///         ``color``/``fillcolor`` values (e.g. community labels)
+    ///     size_by (str, optional): Numeric node attribute scaled onto the node ``width``
+    ///         (0.5 for the smallest value up to 2.0 for the largest)
+    ///
+    /// Returns:
+    ///     str: The DOT source; nodes missing an attribute keep the Graphviz defaults
+    ///
+    /// Raises:
+    ///     TypeError: If a ``size_by`` value is not numeric
+    #[pyo3(signature = (color_by=None, size_by=None))]
+    fn to_dot(&self, py: Python<'_>, color_by: Option<String>, size_by: Option<String>) -> PyResult<String> {
+        analysis::to_dot(self, py, color_by.as_deref(), size_by.as_deref())
+    }
+
+    // Algorithm methods
+    /// Find the shortest path between source and target nodes using Breadth-First Search
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the source node to start the search from
+    ///     target_node_id (str): ID of the target node to find
+    ///     max_depth (int, optional): Maximum depth to search. If None, searches indefinitely.
+    ///     exclude_nodes (set[str], optional): Node IDs that must not appear on the path
+    ///     exclude_edges (set[tuple[str, str]], optional): ``(from_id, to_id)`` pairs whose
+    ///         edges must not be followed
+    ///     edge_filter (dict, optional): Only follow edges whose attributes match every
+    ///         key/value pair (e.g. ``{"type": "is_a"}``). A list, tuple or set value
+    ///         matches any of its elements (e.g. ``{"type": ["is_a", "part_of"]}``).
+    ///     
+    /// Returns:
+    ///     Vertex: A new vertex containing only the nodes in the shortest path from source to target
+    ///     
+    /// Raises:
+    ///     ValueError: If either source or target node doesn't exist or is excluded, or if target
+    ///         is not reachable within max_depth
+    #[pyo3(signature = (root_node_id, target_node_id, max_depth=None, exclude_nodes=None, exclude_edges=None, edge_filter=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn shortest_path_bfs(
+        &self,
+        py: Python<'_>,
+        root_node_id: String,
+        target_node_id: String,
+        max_depth: Option<usize>,
+        exclude_nodes: Option<HashSet<String>>,
+        exclude_edges: Option<HashSet<(String, String)>>,
+        edge_filter: Option<HashMap<String, Py<PyAny>>>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::shortest_path_bfs(
             self,
             py,
-            start_node_id,
-            max_length,
-            min_length,
-            num_attempts,
-            allow_revisit,
-            include_edge_types,
-            edge_type_field,
-            stratified,
+            root_node_id,
+            target_node_id,
+            max_depth,
+            exclude_nodes,
+            exclude_edges,
+            edge_filter,
         )
     }
+
+    /// Check whether a path leads from one node to another without raising
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the node to start from
+    ///     target_node_id (str): ID of the node to reach
+    ///     max_depth (int, optional): Maximum number of hops. If None, searches indefinitely.
+    ///     edge_filter (dict, optional): Only follow matching edges, as in ``shortest_path_bfs``
+    ///
+    /// Returns:
+    ///     bool: True if the target is reachable along edge directions (a node always
+    ///     reaches itself), False otherwise
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If either node doesn't exist
+    #[pyo3(signature = (root_node_id, target_node_id, max_depth=None, edge_filter=None))]
+    fn is_reachable(
+        &self,
+        py: Python<'_>,
+        root_node_id: &str,
+        target_node_id: String,
+        max_depth: Option<usize>,
+        edge_filter: Option<HashMap<String, Py<PyAny>>>,
+    ) -> PyResult<bool> {
+        let root = self.nodes.get(root_node_id).ok_or_else(|| missing_node(root_node_id))?;
+        if !self.nodes.contains_key(&target_node_id) {
+            return Err(missing_node(&target_node_id));
+        }
+        let path = crate::node::bfs_search_iterative(py, root.clone_ref(py), target_node_id, max_depth, &edge_filter, &None)?;
+        Ok(path.is_some())
+    }
+
+    /// Compute shortest paths for many ``(source, target)`` pairs at once
+    ///
+    /// Pairs are grouped by source so each distinct source needs a single
+    /// shortest-path run; the runs execute on a thread pool without holding the GIL.
+    ///
+    /// Args:
+    ///     pairs (list[tuple[str, str]]): ``(source, target)`` node ID pairs
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, paths minimise the hop count (BFS). Missing weights count as 1.0.
+    ///     max_cost (float, optional): Largest summed weight (hop count without
+    ///         ``weight_field``) a path may have
+    ///     max_depth (int, optional): Largest number of hops a path may have. With
+    ///         ``max_cost`` both must hold on the same path, so the result is the
+    ///         cheapest path within the hop budget, or None if there is none.
+    ///     parallel (bool, optional): Spread the work across threads. Defaults to True.
+    ///     progress (callable, optional): Called as ``progress(completed, total)`` after
+    ///         each batch of distinct sources; the work between calls runs without the GIL
+    ///     should_cancel (callable, optional): Polled before each batch; returning True
+    ///         stops the computation with ``CancelledError``
+    ///     edge_filter (dict, optional): Only follow matching edges, as in ``shortest_path_bfs``
+    ///
+    /// Returns:
+    ///     list[Path | None]: One entry per pair, in order; None if the target is unreachable
+    ///
+    /// Raises:
+    ///     ValueError: If a node doesn't exist, a weight or ``max_cost`` is negative
+    ///     TypeError: If a weight attribute is not numeric
+    ///     CancelledError: If ``should_cancel`` returned True
+    #[pyo3(signature = (pairs, weight_field=None, parallel=true, progress=None, should_cancel=None, max_cost=None, max_depth=None, edge_filter=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn batch_shortest_paths(
+        &self,
+        py: Python<'_>,
+        pairs: Vec<(String, String)>,
+        weight_field: Option<String>,
+        parallel: bool,
+        progress: Option<Bound<'_, PyAny>>,
+        should_cancel: Option<Bound<'_, PyAny>>,
+        max_cost: Option<f64>,
+        max_depth: Option<usize>,
+        edge_filter: Option<HashMap<String, Py<PyAny>>>,
+    ) -> PyResult<Py<PyList>> {
+        let limits = algorithms::PathLimits::new(max_cost, max_depth)?;
+        let monitor = algorithms::Monitor {
+            progress: progress.as_ref(),
+            should_cancel: should_cancel.as_ref(),
+        };
+        algorithms::batch_shortest_paths(self, py, pairs, weight_field, limits, parallel, monitor, edge_filter)
+    }
+
+    /// Iterate over BFS levels lazily
+    ///
+    /// Each step yields the IDs of one BFS level (index 0 is ``[root_node_id]``),
+    /// following edge directions. Levels are computed on demand from the live
+    /// nodes and only the current frontier is held, so breaking out early on a
+    /// huge graph skips the rest of the traversal.
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the start node
+    ///     max_depth (int, optional): Last level to yield. None means no limit.
+    ///
+    /// Returns:
+    ///     BfsFrontiers: An iterator of ``list[str]``
+    ///
+    /// Raises:
+    ///     ValueError: If the root node doesn't exist
+    #[pyo3(signature = (root_node_id, max_depth=None))]
+    fn bfs_frontiers(
+        &self,
+        py: Python<'_>,
+        root_node_id: String,
+        max_depth: Option<usize>,
+    ) -> PyResult<algorithms::BfsFrontiers> {
+        algorithms::bfs_frontiers(self, py, root_node_id, max_depth)
+    }
+
+    /// Extract a shortest path together with its surroundings
+    ///
+    /// Finds the shortest path from ``root_node_id`` to ``target_node_id`` and
+    /// adds every node within ``padding`` outgoing hops of any path node, which
+    /// gives visual context around the route.
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the path's start node
+    ///     target_node_id (str): ID of the path's end node
+    ///     padding (int): Hops of context around the path. Defaults to 1; 0 keeps only the path.
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, the path minimises the hop count; otherwise the summed weight.
+    ///     max_cost (float, optional): Largest summed weight (hop count without
+    ///         ``weight_field``) the path may have
+    ///     max_depth (int, optional): Largest number of hops the path may have. With
+    ///         ``max_cost`` both must hold on the same path.
+    ///     edge_filter (dict, optional): Only follow matching edges, as in
+    ///         ``shortest_path_bfs``, both along the path and for the padding
+    ///
+    /// Returns:
+    ///     Vertex: Copies of the selected nodes and the edges among them.
+    ///     ``meta["nodelist"]`` holds the path in order.
+    ///
+    /// Raises:
+    ///     ValueError: If a node doesn't exist, the target is unreachable within the
+    ///         limits, or a weight or ``max_cost`` is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (root_node_id, target_node_id, padding=1, weight_field=None, max_cost=None, max_depth=None, edge_filter=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn path_neighborhood(
+        &self,
+        py: Python<'_>,
+        root_node_id: String,
+        target_node_id: String,
+        padding: usize,
+        weight_field: Option<String>,
+        max_cost: Option<f64>,
+        max_depth: Option<usize>,
+        edge_filter: Option<HashMap<String, Py<PyAny>>>,
+    ) -> PyResult<Py<Vertex>> {
+        let limits = algorithms::PathLimits::new(max_cost, max_depth)?;
+        algorithms::path_neighborhood(self, py, root_node_id, target_node_id, padding, weight_field, limits, edge_filter)
+    }
+
+    /// Build the tree of shortest paths from a root to every reachable node
+    ///
+    /// Each reachable node keeps only the edge from its predecessor on a shortest
+    /// path, so the result is a tree rooted at ``root_node_id``.
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the root node
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, paths minimise the hop count (BFS tree); otherwise the summed
+    ///         weight (Dijkstra tree). Missing weights count as 1.0.
+    ///
+    /// Returns:
+    ///     Vertex: Copies of the reachable nodes and their tree edges (with the original
+    ///     attributes and IDs). ``meta["distances"]`` maps each node ID to its distance
+    ///     from the root.
+    ///
+    /// Raises:
+    ///     ValueError: If the root doesn't exist or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (root_node_id, weight_field=None))]
+    fn shortest_path_tree(
+        &self,
+        py: Python<'_>,
+        root_node_id: String,
+        weight_field: Option<String>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::shortest_path_tree(self, py, root_node_id, weight_field)
+    }
+
+    /// Compute distances from a root with Bellman-Ford, allowing negative edge weights
+    ///
+    /// Use this instead of the Dijkstra-based methods when weights model gains as
+    /// well as losses. Takes O(n * m) time.
+    ///
+    /// Args:
+    ///     root_node_id (str): ID of the node to measure from
+    ///     weight_field (str): Edge attribute holding the weight. Edges without it
+    ///         weigh 1.0. Defaults to "weight".
+    ///
+    /// Returns:
+    ///     dict[str, float]: Distance from the root to every reachable node (0.0 for
+    ///     the root itself)
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If the root doesn't exist
+    ///     GraphError: If a negative cycle is reachable from the root; the message
+    ///         names a node on it
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (root_node_id, weight_field="weight"))]
+    fn bellman_ford(&self, py: Python<'_>, root_node_id: &str, weight_field: &str) -> PyResult<HashMap<String, f64>> {
+        algorithms::bellman_ford(self, py, root_node_id, weight_field)
+    }
+
+    /// Compute the full distance matrix with Floyd-Warshall
+    ///
+    /// Simpler and more cache-friendly than a shortest-path run from every node on
+    /// small, dense graphs, and unlike Dijkstra it accepts negative weights. Takes
+    /// O(n³) time and O(n²) memory.
+    ///
+    /// Args:
+    ///     weight_field (str): Edge attribute holding the weight. Edges without it
+    ///         weigh 1.0. Defaults to "weight".
+    ///     progress (callable, optional): Called as ``progress(completed, total)`` after
+    ///         each of the n pivot passes; each pass runs without the GIL
+    ///     should_cancel (callable, optional): Polled before each pass; returning True
+    ///         stops the computation with ``CancelledError``
+    ///
+    /// Returns:
+    ///     tuple[list[str], list[list[float]]]: The sorted node IDs and the matrix in
+    ///     that order; ``matrix[i][j]`` is the distance from ``ids[i]`` to ``ids[j]``,
+    ///     ``inf`` if unreachable
+    ///
+    /// Raises:
+    ///     GraphError: If the graph has a negative cycle
+    ///     TypeError: If a weight attribute is not numeric
+    ///     CancelledError: If ``should_cancel`` returned True
+    #[pyo3(signature = (weight_field="weight", progress=None, should_cancel=None))]
+    fn floyd_warshall(
+        &self,
+        py: Python<'_>,
+        weight_field: &str,
+        progress: Option<Bound<'_, PyAny>>,
+        should_cancel: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<(Vec<String>, Vec<Vec<f64>>)> {
+        let monitor = algorithms::Monitor { progress: progress.as_ref(), should_cancel: should_cancel.as_ref() };
+        algorithms::floyd_warshall(self, py, weight_field, monitor)
+    }
+
+    /// Approximate the minimum-weight tree connecting a set of terminal nodes
+    ///
+    /// Uses the metric-closure MST heuristic, which is at most twice the optimal
+    /// weight: shortest paths between the terminals, an MST over those distances,
+    /// then expanded back into the original edges. Edge direction is ignored.
+    ///
+    /// Args:
+    ///     terminals (list[str]): IDs of the nodes that must be connected
+    ///     weight_field (str): Edge attribute holding a non-negative weight. Missing
+    ///         weights count as 1.0. Defaults to ``"weight"``
+    ///
+    /// Returns:
+    ///     Vertex: Copies of the terminals and the intermediate nodes, joined by copies
+    ///     of the chosen edges (original direction, attributes and IDs).
+    ///     ``meta["total_weight"]`` holds the summed edge weight.
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If a terminal doesn't exist
+    ///     GraphError: If the terminals are not all connected or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (terminals, weight_field="weight"))]
+    fn steiner_tree(&self, py: Python<'_>, terminals: Vec<String>, weight_field: &str) -> PyResult<Py<Vertex>> {
+        algorithms::steiner_tree(self, py, &terminals, weight_field)
+    }
+
+    /// Breadth-First Search from a node without holding the GIL
+    ///
+    /// The topology is snapshotted once, then the search runs in Rust with the GIL
+    /// released, so other Python threads keep running alongside it. Visit order
+    /// matches ``Node.bfs`` without filters.
+    ///
+    /// Args:
+    ///     start_node_id (str): ID of the node to start from
+    ///     depth (int, optional): Maximum number of hops. If None, traverses all reachable nodes.
+    ///
+    /// Returns:
+    ///     Vertex: The reachable nodes, with the visit order in ``meta["nodelist"]``
+    ///
+    /// Raises:
+    ///     ValueError: If the start node doesn't exist
+    #[pyo3(signature = (start_node_id, depth=None))]
+    fn parallel_bfs(
+        &self,
+        py: Python<'_>,
+        start_node_id: String,
+        depth: Option<usize>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::parallel_bfs(self, py, start_node_id, depth)
+    }
+
+    /// Expand the current vertex by adding neighbor nodes from a source vertex
+    ///
+    /// Args:
+    ///     source_vertex (Vertex): The source vertex to expand from (contains the full graph)
+    ///     depth (int, optional): Maximum depth to traverse for expansion. Defaults to 1.
+    ///     annotate_distance (bool): Write each result node's hop distance from the
+    ///         nearest node of this vertex to its ``meta["distance"]`` (0 for the
+    ///         original nodes). Defaults to ``False``
+    ///     
+    /// Returns:
+    ///     Vertex: A new vertex containing the original nodes plus neighbors found within the specified depth
+    ///     
+    /// Raises:
+    ///     ValueError: If expansion fails
+    #[pyo3(signature = (source_vertex, depth=None, annotate_distance=false))]
+    fn expand(
+        &self,
+        py: Python<'_>,
+        source_vertex: &Vertex,
+        depth: Option<usize>,
+        annotate_distance: bool,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::expand(self, py, source_vertex, depth, annotate_distance)
+    }
+
+    /// Expand the current vertex by a cumulative edge-weight budget instead of hop depth
+    ///
+    /// Args:
+    ///     source_vertex (Vertex): The source vertex to expand from (contains the full graph)
+    ///     max_cost (float): Include nodes whose cheapest path from any node of this
+    ///         vertex costs at most this much
+    ///     max_depth (int, optional): Also limit that path to this many hops; a node
+    ///         is only included when one path satisfies both limits. None means no hop
+    ///         limit. Defaults to None.
+    ///     weight_field (str): Edge attribute holding the cost. Edges without it cost 1.0.
+    ///         Defaults to "weight".
+    ///     annotate_distance (bool): Write each result node's cheapest cost from this
+    ///         vertex (within both limits) to its ``meta["distance"]``. Defaults to ``False``
+    ///
+    /// Returns:
+    ///     Vertex: A new vertex containing the original nodes plus every node within budget
+    ///
+    /// Raises:
+    ///     ValueError: If max_cost is negative
+    ///     GraphError: If an edge weight is negative
+    ///     TypeError: If an edge weight is not numeric
+    #[pyo3(signature = (source_vertex, max_cost, weight_field="weight", annotate_distance=false, max_depth=None))]
+    fn expand_weighted(
+        &self,
+        py: Python<'_>,
+        source_vertex: &Vertex,
+        max_cost: f64,
+        weight_field: &str,
+        annotate_distance: bool,
+        max_depth: Option<usize>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::expand_weighted(self, py, source_vertex, max_cost, max_depth, weight_field, annotate_distance)
+    }
+
+    /// Expand from seed nodes to the ``max_nodes`` most relevant nodes
+    ///
+    /// Starting from the seeds, repeatedly adds the highest-scoring node that an
+    /// outgoing edge of the current set reaches, e.g. to fill a fixed-size
+    /// context window in a graph-RAG pipeline. Scores are computed once on the
+    /// whole graph; ties go to the smaller ID. Fewer than ``max_nodes`` nodes are
+    /// returned when nothing more is reachable.
+    ///
+    /// Args:
+    ///     seed_ids (list[str]): Nodes the subgraph always contains
+    ///     max_nodes (int): Size budget of the result
+    ///     scoring (str): ``"pagerank"`` or ``"degree"`` (in plus out edges).
+    ///         Defaults to ``"pagerank"``.
+    ///
+    /// Returns:
+    ///     Vertex: A new vertex with the chosen nodes and every edge among them
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If a seed does not exist
+    ///     ValueError: If there are more seeds than ``max_nodes`` or ``scoring`` is unknown
+    #[pyo3(signature = (seed_ids, max_nodes, scoring="pagerank"))]
+    fn budget_subgraph(
+        &self,
+        py: Python<'_>,
+        seed_ids: Vec<String>,
+        max_nodes: usize,
+        scoring: &str,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::budget_subgraph(self, py, &seed_ids, max_nodes, scoring)
+    }
+
+    /// Create a new vertex containing only the specified nodes and their connecting edges
+    ///
+    /// Args:
+    ///     ids (list, optional): List of node IDs to include
+    ///     id (str, optional): Single node ID to include
+    ///     strict (bool, optional): Raise on unknown ids (default). With False they are
+    ///         skipped and the result's meta["found_ids"] holds the set of ids found
+    ///     **kwargs: Attribute key/value pairs to match nodes
+    ///
+    /// Returns:
+    ///     Vertex: A new vertex containing only the specified nodes and edges between them
+    ///
+    /// Raises:
+    ///     ValueError: If strict and any of the specified node IDs don't exist in the
+    ///                 vertex, or no filter criteria are provided
+    #[pyo3(signature = (**kwargs))]
+    fn filter(
+        &self,
+        py: Python<'_>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<Vertex>> {
+        let kwargs = kwargs.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(
+                "Must specify ids, id, or attribute filters",
+            )
+        })?;
+
+        let mut filters: HashMap<String, Py<PyAny>> = kwargs.extract()?;
+        let strict = match filters.remove("strict") {
+            Some(strict) => strict.extract::<bool>(py)?,
+            None => true,
+        };
+
+        // Determine which node IDs to include based on the provided keyword arguments
+        let node_ids: Vec<String> = if let Some(ids_any) = filters.remove("ids") {
+            ids_any.extract(py)?
+        } else if let Some(id_any) = filters.remove("id") {
+            vec![id_any.extract(py)?]
+        } else if !filters.is_empty() {
+            let mut matches = Vec::new();
+            for (node_id, node) in &self.nodes {
+                if algorithms::node_matches(py, node, &filters)? {
+                    matches.push(node_id.clone());
+                }
+            }
+            matches
+        } else {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "Must specify ids, id, or attribute filters",
+            ));
+        };
+
+        algorithms::filter(self, py, node_ids, strict)
+    }
+
+    /// Create a new vertex containing the nodes whose id matches a prefix or regex
+    ///
+    /// Args:
+    ///     prefix (str, optional): Keep ids starting with this string, e.g. ``"person:"``
+    ///     pattern (str | re.Pattern, optional): Keep ids containing a match of this
+    ///         regular expression (searched, not anchored). The flags of a compiled
+    ///         pattern are kept as inline ``(?imsx)`` groups. With ``prefix`` both must hold
+    ///
+    /// Returns:
+    ///     Vertex: A new vertex containing the selected nodes and the edges between them
+    ///
+    /// Raises:
+    ///     ValueError: If neither argument is given, the pattern is invalid or uses
+    ///         syntax the Rust ``regex`` crate lacks (lookarounds, backreferences),
+    ///         or a compiled pattern has flags other than IGNORECASE, MULTILINE,
+    ///         DOTALL and VERBOSE
+    #[pyo3(signature = (prefix=None, pattern=None))]
+    fn filter_by_id(
+        &self,
+        py: Python<'_>,
+        prefix: Option<String>,
+        pattern: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Vertex>> {
+        let pattern = pattern.map(algorithms::pattern_source).transpose()?;
+        algorithms::filter_by_id(self, py, prefix.as_deref(), pattern.as_deref())
+    }
+
+    /// Inline nested subgraphs (see ``Node.set_subgraph``) into one flat graph
+    ///
+    /// Top-level node IDs are kept; a node ``c`` in the subgraph of node ``p``
+    /// becomes ``p/c`` (recursively for deeper levels). Container nodes remain,
+    /// without their subgraph, and edges are copied within their own level.
+    ///
+    /// Args:
+    ///     separator (str): Joins the container ID and the nested ID. Defaults to "/".
+    ///
+    /// Returns:
+    ///     Vertex: A new vertex with copies of all nodes and edges of every level
+    ///
+    /// Raises:
+    ///     GraphError: If a subgraph contains one of its own ancestors
+    ///     ValueError: If a namespaced ID collides with an existing one
+    #[pyo3(signature = (separator="/"))]
+    fn flatten(&self, py: Python<'_>, separator: &str) -> PyResult<Py<Vertex>> {
+        algorithms::flatten(self, py, separator)
+    }
+
+    /// Split the graph into nodes matching attribute filters and the rest, in one pass
+    ///
+    /// Args:
+    ///     keep_cross_edges (bool): Keep edges that cross between the two sides. Such an
+    ///         edge stays on its source node and points at the node in the other vertex.
+    ///         Defaults to False, which drops them.
+    ///     **kwargs: Attribute key/value pairs a node must all match (as in ``filter``)
+    ///
+    /// Returns:
+    ///     tuple[Vertex, Vertex]: ``(matching, non_matching)``; together they hold every
+    ///     node exactly once
+    ///
+    /// Raises:
+    ///     ValueError: If no attribute filters are given
+    #[pyo3(signature = (keep_cross_edges=false, **kwargs))]
+    fn partition(
+        &self,
+        py: Python<'_>,
+        keep_cross_edges: bool,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<(Py<Vertex>, Py<Vertex>)> {
+        let filters: HashMap<String, Py<PyAny>> = match kwargs {
+            Some(kwargs) if !kwargs.is_empty() => kwargs.extract()?,
+            _ => {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "Must specify attribute filters",
+                ))
+            }
+        };
+        algorithms::partition(self, py, filters, keep_cross_edges)
+    }
+
+    /// Remove every node that has neither outgoing nor incoming edges
+    ///
+    /// Fires ``on_node_remove_callbacks`` with ``(vertex, node)`` for each removed
+    /// node, in node ID order, after all of them have been removed.
+    ///
+    /// Returns:
+    ///     int: The number of nodes removed
+    fn remove_isolated_nodes(mut slf: PyRefMut<'_, Self>, py: Python<'_>) -> PyResult<usize> {
+        let removed = manipulation::remove_isolated_nodes(&mut slf, py);
+        let remove_cbs = slf.on_node_remove_callbacks.clone_ref(py);
+        let py_self: Py<Self> = slf.into();
+
+        for node in &removed {
+            callbacks::fire_node_remove_callbacks(
+                py,
+                remove_cbs.bind(py),
+                py_self.clone_ref(py).into_any(),
+                node.clone_ref(py),
+            )?;
+        }
+        Ok(removed.len())
+    }
+
+    /// Remove every edge, keeping all nodes
+    ///
+    /// Returns:
+    ///     int: The number of edges removed
+    fn clear_edges(&mut self, py: Python<'_>) -> usize {
+        manipulation::clear_edges(self, py)
+    }
+
+    /// Remove all nodes and edges and reset ``meta`` to an empty dict
+    ///
+    /// Args:
+    ///     fire (bool): Fire ``on_node_remove_callbacks`` with ``(vertex, node)`` for
+    ///         each removed node, in node ID order, once everything has been removed.
+    ///         Defaults to ``True``
+    #[pyo3(signature = (fire=true))]
+    fn clear(mut slf: PyRefMut<'_, Self>, py: Python<'_>, fire: bool) -> PyResult<()> {
+        let removed = manipulation::clear(&mut slf, py);
+        if !fire {
+            return Ok(());
+        }
+        let remove_cbs = slf.on_node_remove_callbacks.clone_ref(py);
+        let py_self: Py<Self> = slf.into();
+
+        for node in &removed {
+            callbacks::fire_node_remove_callbacks(
+                py,
+                remove_cbs.bind(py),
+                py_self.clone_ref(py).into_any(),
+                node.clone_ref(py),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Remove edges and inverse_edges that reference nodes not present in the vertex.
+    ///
+    /// This is useful after filtering or subsetting the graph, when edges may still
+    /// point to nodes that are no longer part of the vertex.
+    ///
+    /// Returns:
+    ///     int: The number of edges removed
+    fn prune(&mut self, py: Python<'_>) -> PyResult<usize> {
+        manipulation::prune(self, py)
+    }
+
+    /// Perform multiple random walks from a starting node
+    ///
+    /// Args:
+    ///     start_node_id (str, optional): ID of the node to start the random walks from.
+    ///         May be None only when stratified=True, in which case each walk's start
+    ///         is sampled across all nodes with probability inversely proportional to
+    ///         how often each node has been visited so far.
+    ///     max_length (int): Maximum length of each random walk
+    ///     num_attempts (int): Number of random walk attempts to perform
+    ///     min_length (int, optional): Minimum length of each random walk. Defaults to 1.
+    ///     allow_revisit (bool, optional): Whether to allow revisiting nodes. Defaults to False.
+    ///     include_edge_types (bool, optional): Whether to include edge types in the result. Defaults to False.
+    ///     include_edge_ids (bool, optional): Whether to include the ``id`` of each traversed edge
+    ///         instead, so parallel edges can be told apart. Edges without an id are recorded
+    ///         as ``"from->to"``. Cannot be combined with include_edge_types. Defaults to False.
+    ///     edge_type_field (str, optional): Field name to extract edge type from. Defaults to "type".
+    ///     stratified (bool, optional): Equalize node visit frequencies. Every choice
+    ///         (the start node when start_node_id is None, and each step) is weighted by
+    ///         1 / (1 + times_visited), steering walks towards least-visited nodes.
+    ///         Visit counts persist across all attempts of one call. Defaults to False.
+    ///     stop_prob (float, optional): Probability of ending the walk after each visited
+    ///         node, giving a geometric length distribution (mean about 1 / stop_prob nodes)
+    ///         capped at max_length. min_length still filters the results. Defaults to 0.
+    ///     seed (int, optional): Seed for reproducible walks
+    ///
+    /// Returns:
+    ///     list: A list of lists. If include_edge_types is False, each inner list contains node IDs.
+    ///           If include_edge_types (or include_edge_ids) is True, each inner list alternates
+    ///           between node IDs and edge types (or edge ids). Duplicates are automatically removed.
+    ///
+    /// Raises:
+    ///     ValueError: If start_node_id doesn't exist, is None without stratified=True,
+    ///         max_length is 0, min_length > max_length, stop_prob is outside [0, 1],
+    ///         or both edge options are set
+    #[pyo3(signature = (start_node_id, max_length, num_attempts, min_length=None, allow_revisit=None, include_edge_types=None, edge_type_field=None, stratified=None, include_edge_ids=None, stop_prob=None, seed=None))]
+    fn random_walks(
+        &self,
+        py: Python<'_>,
+        start_node_id: Option<String>,
+        max_length: usize,
+        num_attempts: usize,
+        min_length: Option<usize>,
+        allow_revisit: Option<bool>,
+        include_edge_types: Option<bool>,
+        edge_type_field: Option<String>,
+        stratified: Option<bool>,
+        include_edge_ids: Option<bool>,
+        stop_prob: Option<f64>,
+        seed: Option<u64>,
+    ) -> PyResult<Py<PyList>> {
+        algorithms::random_walks(
+            self,
+            py,
+            start_node_id,
+            max_length,
+            min_length,
+            num_attempts,
+            allow_revisit,
+            include_edge_types,
+            include_edge_ids,
+            edge_type_field,
+            stratified,
+            stop_prob,
+            seed,
+        )
+    }
+
+    /// Draw a random sample of node IDs
+    ///
+    /// Args:
+    ///     k (int): Number of nodes to draw
+    ///     weight_field (str, optional): Numeric node attribute used as sampling weight.
+    ///         Nodes missing the attribute weigh 1.0; zero-weight nodes are never drawn.
+    ///     seed (int, optional): Seed for reproducible samples
+    ///     replace (bool): Sample with replacement. Defaults to False.
+    ///
+    /// Returns:
+    ///     list[str]: The sampled node IDs in draw order
+    ///
+    /// Raises:
+    ///     ValueError: If k exceeds the number of drawable nodes without replacement,
+    ///         or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (k, weight_field=None, seed=None, replace=false))]
+    fn sample_nodes(
+        &self,
+        py: Python<'_>,
+        k: usize,
+        weight_field: Option<String>,
+        seed: Option<u64>,
+        replace: bool,
+    ) -> PyResult<Vec<String>> {
+        algorithms::sample_nodes(self, py, k, weight_field, seed, replace)
+    }
+
+    /// Draw distinct node IDs with probability proportional to their PageRank
+    ///
+    /// PageRank (damping 0.85, unweighted edges) is computed on each call, so
+    /// well-linked nodes are over-represented, e.g. for importance-weighted
+    /// training sets. Every node has a positive rank and can be drawn.
+    ///
+    /// Args:
+    ///     k (int): Number of nodes to draw, without replacement
+    ///     seed (int, optional): Seed for reproducible samples
+    ///
+    /// Returns:
+    ///     list[str]: The sampled node IDs in draw order
+    ///
+    /// Raises:
+    ///     ValueError: If k exceeds the number of nodes
+    #[pyo3(signature = (k, seed=None))]
+    fn sample_by_pagerank(&self, py: Python<'_>, k: usize, seed: Option<u64>) -> PyResult<Vec<String>> {
+        algorithms::sample_by_pagerank(self, py, k, seed)
+    }
+
+    /// Draw a random sample of edges
+    ///
+    /// Args:
+    ///     k (int): Number of edges to draw
+    ///     weight_field (str, optional): Numeric edge attribute used as sampling weight.
+    ///         Edges missing the attribute weigh 1.0; zero-weight edges are never drawn.
+    ///     seed (int, optional): Seed for reproducible samples
+    ///     replace (bool): Sample with replacement. Defaults to False.
+    ///
+    /// Returns:
+    ///     list[Edge]: The sampled edges in draw order
+    ///
+    /// Raises:
+    ///     ValueError: If k exceeds the number of drawable edges without replacement,
+    ///         or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (k, weight_field=None, seed=None, replace=false))]
+    fn sample_edges(
+        &self,
+        py: Python<'_>,
+        k: usize,
+        weight_field: Option<String>,
+        seed: Option<u64>,
+        replace: bool,
+    ) -> PyResult<Py<PyList>> {
+        algorithms::sample_edges(self, py, k, weight_field, seed, replace)
+    }
+
+    /// Copy the graph keeping every node but only a random share of the edges
+    ///
+    /// ``round(keep_fraction * edge_count())`` edges are drawn without
+    /// replacement; with ``weight_field`` heavier edges are more likely to be
+    /// kept. Useful for visualizing or approximately analysing dense graphs.
+    ///
+    /// Args:
+    ///     keep_fraction (float): Share of edges to keep, between 0 and 1
+    ///     weight_field (str, optional): Numeric edge attribute biasing which edges survive.
+    ///         Edges missing the attribute weigh 1.0; zero-weight edges are always dropped.
+    ///     seed (int, optional): Seed for reproducible results
+    ///
+    /// Returns:
+    ///     Vertex: A new graph with all nodes and the kept edges
+    ///
+    /// Raises:
+    ///     ValueError: If keep_fraction is outside [0, 1] or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (keep_fraction, weight_field=None, seed=None))]
+    fn sparsify(
+        &self,
+        py: Python<'_>,
+        keep_fraction: f64,
+        weight_field: Option<String>,
+        seed: Option<u64>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::sparsify(self, py, keep_fraction, weight_field, seed)
+    }
+
+    /// Extract a random subgraph of at most n nodes
+    ///
+    /// Args:
+    ///     n (int): Maximum number of nodes in the sample
+    ///     method (str): ``"induced"`` keeps the subgraph induced by n uniformly drawn
+    ///         nodes. ``"forest_fire"`` spreads from a random node along edges in both
+    ///         directions, burning a geometric number of neighbours at each step, and
+    ///         stays connected on a (weakly) connected graph. Defaults to "induced".
+    ///     seed (int, optional): Seed for reproducible samples
+    ///     burn_prob (float): Forest-fire forward burning probability; each node burns
+    ///         on average burn_prob / (1 - burn_prob) neighbours. Defaults to 0.7.
+    ///
+    /// Returns:
+    ///     Vertex: New vertex with the sampled nodes and the edges between them
+    ///
+    /// Raises:
+    ///     ValueError: If method is unknown or burn_prob is outside [0, 1)
+    #[pyo3(signature = (n, method="induced", seed=None, burn_prob=0.7))]
+    fn sample_subgraph(
+        &self,
+        py: Python<'_>,
+        n: usize,
+        method: &str,
+        seed: Option<u64>,
+        burn_prob: f64,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::sample_subgraph(self, py, n, method, seed, burn_prob)
+    }
+
+    /// Color nodes greedily so that no two adjacent nodes share a color
+    ///
+    /// Edge direction is ignored and self-loops are skipped.
+    ///
+    /// Args:
+    ///     strategy (str): Order in which nodes are colored. ``"largest_first"``
+    ///         (descending degree), ``"smallest_last"``, ``"random_sequential"`` or
+    ///         ``"saturation_largest_first"`` (DSatur, exact on bipartite graphs).
+    ///         Defaults to "largest_first".
+    ///     seed (int, optional): Seed for ``"random_sequential"``
+    ///     return_num_colors (bool): Also return the number of colors used.
+    ///         Defaults to False.
+    ///
+    /// Returns:
+    ///     dict[str, int] | tuple[dict[str, int], int]: Color (0, 1, ...) per node ID,
+    ///     plus the number of colors when return_num_colors is True
+    ///
+    /// Raises:
+    ///     ValueError: If strategy is unknown
+    #[pyo3(signature = (strategy="largest_first", seed=None, return_num_colors=false))]
+    fn greedy_coloring(
+        &self,
+        py: Python<'_>,
+        strategy: &str,
+        seed: Option<u64>,
+        return_num_colors: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let coloring = algorithms::greedy_coloring(self, py, strategy, seed)?;
+        if return_num_colors {
+            let num_colors = coloring.values().max().map_or(0, |c| c + 1);
+            Ok((coloring, num_colors).into_pyobject(py)?.into_any().unbind())
+        } else {
+            Ok(coloring.into_pyobject(py)?.into_any().unbind())
+        }
+    }
+
+    /// Check whether the graph has no directed cycle
+    ///
+    /// Returns:
+    ///     bool: True if the graph is acyclic. A self-loop counts as a cycle.
+    fn is_dag(&self, py: Python<'_>) -> PyResult<bool> {
+        algorithms::is_dag(self, py)
+    }
+
+    /// Contract each strongly connected component into a single super-node
+    ///
+    /// Returns:
+    ///     tuple[Vertex, dict[str, int]]: The condensed DAG and a mapping from every
+    ///     original node ID to its component ID. Super-nodes are named ``"0"``, ``"1"``,
+    ///     ... in topological order and carry ``members`` (sorted original IDs) and
+    ///     ``size`` attributes. Edges inside a component are dropped and parallel
+    ///     edges between components are merged.
+    fn condensation(&self, py: Python<'_>) -> PyResult<(Py<Vertex>, HashMap<String, usize>)> {
+        algorithms::condensation(self, py)
+    }
+
+    /// Extract the largest strongly connected component
+    ///
+    /// Returns:
+    ///     Vertex: The induced subgraph of the biggest set of nodes that can all reach
+    ///     each other. Ties go to the component with the smallest node ID; an empty
+    ///     graph gives an empty Vertex.
+    fn largest_scc(&self, py: Python<'_>) -> PyResult<Py<Vertex>> {
+        algorithms::largest_scc(self, py)
+    }
+
+    /// Coarsen the graph by contracting node pairs, as in multilevel layout or partitioning
+    ///
+    /// All pairs are contracted at once; pairs that share a node are unioned into one
+    /// super-node.
+    ///
+    /// Args:
+    ///     matching (list[tuple[str, str]]): Node ID pairs to contract
+    ///
+    /// Returns:
+    ///     Vertex: The coarse graph. Each super-node is named after its smallest member
+    ///     ID and carries ``members`` (sorted original IDs) and ``size``; unmatched nodes
+    ///     become one-member super-nodes. Edges inside a super-node are dropped and
+    ///     parallel edges are merged, with ``count`` holding how many they replace.
+    ///     ``meta["coarse_to_original"]`` maps each super-node ID to its members.
+    ///
+    /// Raises:
+    ///     ValueError: If a node in ``matching`` doesn't exist
+    fn coarsen(&self, py: Python<'_>, matching: Vec<(String, String)>) -> PyResult<Py<Vertex>> {
+        algorithms::coarsen(self, py, matching)
+    }
+
+    /// Combine this graph with another one into a new graph
+    ///
+    /// Args:
+    ///     other (Vertex): Graph to merge in
+    ///     on_conflict (callable, optional): Called as ``(node_id, self_node, other_node)``
+    ///         for every node ID present in both graphs; must return the merged
+    ///         attribute dict. Without it the attributes of this graph are kept.
+    ///         Tags of a shared node are always the union of both sides
+    ///
+    /// Returns:
+    ///     Vertex: A new graph with the nodes of both graphs and the union of their
+    ///     edges. An edge of ``other`` is dropped when the same endpoints already
+    ///     have an edge with the same ID and equal attributes.
+    ///
+    /// Raises:
+    ///     TypeError: If ``on_conflict`` does not return a dict
+    #[pyo3(signature = (other, on_conflict=None))]
+    fn merge(
+        &self,
+        py: Python<'_>,
+        other: PyRef<'_, Vertex>,
+        on_conflict: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::merge(self, py, &other, on_conflict.as_ref())
+    }
+
+    /// Describe the changes from this graph to another snapshot of it
+    ///
+    /// Nodes are matched by ID. Edges with the same source, target and ``type``
+    /// attribute are paired one to one by equal attributes, so an edge whose other
+    /// attributes changed is reported as removed (old attributes) and added (new).
+    ///
+    /// Args:
+    ///     other (Vertex): The newer snapshot
+    ///
+    /// Returns:
+    ///     dict: ``added_nodes`` (``{id: attr}``), ``removed_nodes`` (sorted IDs),
+    ///     ``added_edges`` and ``removed_edges`` (lists of ``{"from_id", "to_id",
+    ///     "type", "attr"}``) and ``changed_attrs`` (``{id: {key: {"old", "new"}}}``
+    ///     for nodes in both graphs; ``"old"`` is missing for an added key and
+    ///     ``"new"`` for a removed one)
+    fn diff(&self, py: Python<'_>, other: PyRef<'_, Vertex>) -> PyResult<Py<PyAny>> {
+        algorithms::diff(self, py, &other)
+    }
+
+    /// Apply a ``diff`` result to this graph in place
+    ///
+    /// The patch is validated before anything changes. Edges are removed first,
+    /// then nodes with any remaining incident edges (firing
+    /// ``on_node_remove_callbacks``); added nodes and edges go through
+    /// ``add_node`` / ``add_edge`` and changed values through ``Node.attr_set``, so
+    /// their callbacks fire as well. Missing sections count as empty.
+    ///
+    /// Args:
+    ///     patch (dict): A dict as returned by ``diff``
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If a removed node, a node with changed attributes or
+    ///         an endpoint of an added edge does not exist
+    ///     EdgeNotFoundError: If a removed edge does not exist; an entry with
+    ///         ``attr`` needs an edge with equal attributes
+    ///     GraphError: If an added node already exists
+    fn apply_patch(slf: PyRef<'_, Self>, py: Python<'_>, patch: &Bound<'_, PyDict>) -> PyResult<()> {
+        let handle: Py<Self> = slf.into();
+        algorithms::apply_patch(&handle, py, patch)
+    }
+
+    /// Compare topology and attributes with another graph
+    ///
+    /// Both graphs must have the same node IDs with equal attributes, the same
+    /// number of edges between every pair of nodes with pairwise equal attributes,
+    /// and equal ``meta``. Values are compared with ``==``. Tags, edge IDs and the
+    /// ``meta`` of single nodes and edges are not compared.
+    ///
+    /// Args:
+    ///     other (Vertex): The graph to compare with
+    ///     ignore_attrs (list[str], optional): Keys skipped in node and edge
+    ///         attributes and in ``meta``, e.g. timestamps that legitimately differ
+    ///
+    /// Returns:
+    ///     bool: True if the graphs are equal apart from the ignored keys
+    #[pyo3(signature = (other, ignore_attrs=None))]
+    fn structurally_equal(
+        &self,
+        py: Python<'_>,
+        other: PyRef<'_, Vertex>,
+        ignore_attrs: Option<Vec<String>>,
+    ) -> PyResult<bool> {
+        let ignore: HashSet<String> = ignore_attrs.unwrap_or_default().into_iter().collect();
+        algorithms::structurally_equal(self, py, &other, &ignore)
+    }
+
+    /// Stable hash of the graph content, for caching and change detection
+    ///
+    /// A SHA-256 hex digest over a canonical serialization: nodes in id order,
+    /// attributes with sorted keys, each node's outgoing edges sorted, plus the
+    /// graph ``meta``. Graphs that are ``structurally_equal`` hash the same no
+    /// matter the order they were built in; like ``structurally_equal``, tags,
+    /// edge ids and the ``meta`` of single nodes and edges are left out, and no
+    /// timestamp is included. Values are hashed in serialized form, so ``1``
+    /// and ``1.0`` give different hashes.
+    ///
+    /// Returns:
+    ///     str: 64-character lowercase hex digest
+    fn content_hash(&self, py: Python<'_>) -> PyResult<String> {
+        algorithms::content_hash(self, py)
+    }
+
+    /// Cheap fingerprint of the edge set, for spotting topology changes
+    ///
+    /// XOR of one hash per edge over its source and target ids, so the result
+    /// does not depend on insertion order. Much cheaper than ``content_hash``
+    /// since attributes are ignored: equal values do not prove equal graphs,
+    /// but different values prove the edge sets differ. Parallel edges count
+    /// individually, and isolated nodes do not affect the hash.
+    ///
+    /// Returns:
+    ///     int: Unsigned 64-bit hash, stable across runs
+    fn edge_set_hash(&self, py: Python<'_>) -> u64 {
+        algorithms::edge_set_hash(self, py)
+    }
+
+    /// Summarize the graph by merging nodes that share an attribute value (quotient graph)
+    ///
+    /// Args:
+    ///     attr_key (str): Node attribute to group by. Nodes without it are left out,
+    ///         together with their edges
+    ///     weight_field (str, optional): Numeric edge attribute summed into each merged
+    ///         edge's ``weight``. Without it every edge counts as 1.0
+    ///     self_loops (bool): Keep edges inside a group as a weighted self-loop on its
+    ///         super-node. Defaults to ``True``
+    ///
+    /// Returns:
+    ///     Vertex: One super-node per distinct value, named ``str(value)`` and carrying
+    ///     ``attr_key`` (the value), ``members`` (sorted original IDs) and ``size``.
+    ///     Parallel edges between groups are merged into one edge with ``count`` and
+    ///     ``weight``. ``meta["group_members"]`` maps each super-node ID to its members.
+    ///
+    /// Raises:
+    ///     TypeError: If a ``weight_field`` value is not numeric
+    #[pyo3(signature = (attr_key, weight_field=None, self_loops=true))]
+    fn quotient_by(
+        &self,
+        py: Python<'_>,
+        attr_key: String,
+        weight_field: Option<String>,
+        self_loops: bool,
+    ) -> PyResult<Py<Vertex>> {
+        algorithms::quotient_by(self, py, &attr_key, weight_field.as_deref(), self_loops)
+    }
+
+    /// Merge parallel edges into one weighted edge per (source, target) pair
+    ///
+    /// Args:
+    ///     weight_field (str): Edge attribute summed into the merged edge. Edges
+    ///         without it count as 1.0, so unweighted edges are counted. Defaults to "weight".
+    ///
+    /// Returns:
+    ///     Vertex: A copy of the graph without parallel edges. Each merged edge keeps the
+    ///     attributes and ID of the first original edge, with ``weight_field`` set to the
+    ///     sum and ``multiplicity`` to the number of edges merged.
+    ///
+    /// Raises:
+    ///     TypeError: If a ``weight_field`` value is not numeric
+    #[pyo3(signature = (weight_field="weight"))]
+    fn collapse_multi_edges(&self, py: Python<'_>, weight_field: &str) -> PyResult<Py<Vertex>> {
+        algorithms::collapse_multi_edges(self, py, weight_field)
+    }
+
+    /// Find every occurrence of a small motif (subgraph isomorphism by backtracking)
+    ///
+    /// Pattern nodes map to distinct graph nodes. A graph node qualifies when its
+    /// ``attr`` contains every attribute of the pattern node with an equal value,
+    /// and every pattern edge needs a graph edge in the same direction whose
+    /// ``attr`` contains the pattern edge's attributes (e.g. ``{"type": "knows"}``).
+    /// Additional graph edges between matched nodes are allowed.
+    ///
+    /// Args:
+    ///     pattern (Vertex): The motif, with at most 10 nodes
+    ///     max_matches (int, optional): Stop after this many matches
+    ///
+    /// Returns:
+    ///     list[dict[str, str]]: One mapping from pattern node ID to graph node ID per
+    ///     match, sorted by the assigned graph IDs
+    ///
+    /// Raises:
+    ///     ValueError: If the pattern has more than 10 nodes
+    #[pyo3(signature = (pattern, max_matches=None))]
+    fn match_pattern(
+        &self,
+        py: Python<'_>,
+        pattern: PyRef<'_, Vertex>,
+        max_matches: Option<usize>,
+    ) -> PyResult<Py<PyAny>> {
+        algorithms::match_pattern(self, py, &pattern, max_matches)
+    }
+
+    /// Compute closeness centrality for every node
+    ///
+    /// Closeness uses incoming distances: how close the other nodes are to a node,
+    /// following edge directions. For a node reached by ``r`` other nodes with a total
+    /// shortest-path distance ``d`` the uncorrected score is ``r / d``. With
+    /// ``wf_improved`` (the Wasserman-Faust correction, networkx's default) it becomes
+    /// ``(r / d) * (r / (n - 1))``, scaling by the fraction of the graph that can
+    /// reach the node so that small components do not produce inflated scores.
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts. Missing weights count as 1.0.
+    ///     wf_improved (bool, optional): Apply the Wasserman-Faust correction. Defaults to True.
+    ///     progress (callable, optional): Called as ``progress(completed, total)`` after
+    ///         each batch of nodes; the work between calls runs without the GIL
+    ///     should_cancel (callable, optional): Polled before each batch; returning True
+    ///         stops the computation with ``CancelledError``
+    ///
+    /// Returns:
+    ///     dict[str, float]: Closeness per node ID; 0.0 for nodes no other node reaches
+    ///
+    /// Raises:
+    ///     ValueError: If a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    ///     CancelledError: If ``should_cancel`` returned True
+    #[pyo3(signature = (weight_field=None, wf_improved=true, progress=None, should_cancel=None))]
+    fn closeness_centrality(
+        &self,
+        py: Python<'_>,
+        weight_field: Option<String>,
+        wf_improved: bool,
+        progress: Option<Bound<'_, PyAny>>,
+        should_cancel: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<HashMap<String, f64>> {
+        let monitor = algorithms::Monitor {
+            progress: progress.as_ref(),
+            should_cancel: should_cancel.as_ref(),
+        };
+        algorithms::closeness_centrality(self, py, weight_field, wf_improved, monitor)
+    }
+
+    /// Rescale edge weights in place, e.g. before random walks or PageRank
+    ///
+    /// Weights are written with ``Edge.attr_set``, so ``on_edge_update_callbacks``
+    /// fire for every weight that changes. A missing weight counts as 1.0.
+    ///
+    /// Args:
+    ///     weight_field (str): Numeric edge attribute to rescale. Defaults to "weight".
+    ///     mode (str): "outgoing" divides each node's outgoing weights by their sum so
+    ///         they add up to 1 (nodes without outgoing edges, or with a zero sum, are
+    ///         left unchanged); "global" min-max scales all weights onto [0, 1] (all
+    ///         become 1.0 if they are equal). Defaults to "outgoing".
+    ///
+    /// Raises:
+    ///     ValueError: For an unknown mode
+    ///     GraphError: If ``mode="outgoing"`` meets a negative weight
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (weight_field="weight", mode="outgoing"))]
+    fn normalize_edge_weights(&self, py: Python<'_>, weight_field: &str, mode: &str) -> PyResult<()> {
+        algorithms::normalize_edge_weights(self, py, weight_field, mode)
+    }
+
+    /// Compute a value from every edge and store it as an edge attribute
+    ///
+    /// Runs one pass over all edges, e.g. to derive a cost from a confidence once
+    /// instead of in a Python loop. All values are computed before any is written,
+    /// so a failing ``expression`` leaves the graph unchanged. Values are written
+    /// with ``Edge.attr_set``, so ``on_edge_update_callbacks`` fire for every value
+    /// that changes.
+    ///
+    /// Args:
+    ///     out_field (str): Edge attribute receiving the value
+    ///     expression (callable): Called with each ``Edge``; returns an int or float
+    ///
+    /// Raises:
+    ///     TypeError: If ``expression`` returns a non-numeric value
+    fn derive_edge_weight(&self, py: Python<'_>, out_field: &str, expression: &Bound<'_, PyAny>) -> PyResult<()> {
+        algorithms::derive_edge_weight(self, py, out_field, expression)
+    }
+
+    /// Replace every node's attributes with ``func(attr)`` in one pass
+    ///
+    /// The functional way to transform all attributes at once, e.g. lowercasing
+    /// every string. ``func`` gets a copy of each node's ``attr`` dict and returns
+    /// the new dict. All results are computed before any is written, so a
+    /// failing ``func`` leaves the graph unchanged.
+    ///
+    /// Args:
+    ///     func (callable): ``func(attr: dict) -> dict``
+    ///     fire (bool): Write changed values with ``Node.attr_set`` so
+    ///         ``on_node_update_callbacks`` fire per changed key; keys missing from
+    ///         the result are removed without callbacks. With False the dicts are
+    ///         swapped in silently. Defaults to True.
+    ///
+    /// Raises:
+    ///     TypeError: If ``func`` does not return a dict with string keys
+    #[pyo3(signature = (func, fire=true))]
+    fn map_node_attrs(&self, py: Python<'_>, func: &Bound<'_, PyAny>, fire: bool) -> PyResult<()> {
+        algorithms::map_node_attrs(self, py, func, fire)
+    }
+
+    /// Replace every edge's attributes with ``func(attr)`` in one pass
+    ///
+    /// The edge counterpart of ``map_node_attrs``; with ``fire`` the
+    /// ``on_edge_update_callbacks`` fire per changed key.
+    ///
+    /// Args:
+    ///     func (callable): ``func(attr: dict) -> dict``
+    ///     fire (bool): Write changed values with ``Edge.attr_set``. Defaults to True.
+    ///
+    /// Raises:
+    ///     TypeError: If ``func`` does not return a dict with string keys
+    #[pyo3(signature = (func, fire=true))]
+    fn map_edge_attrs(&self, py: Python<'_>, func: &Bound<'_, PyAny>, fire: bool) -> PyResult<()> {
+        algorithms::map_edge_attrs(self, py, func, fire)
+    }
+
+    /// Propagate a numeric attribute one hop, writing each node's neighbour aggregate
+    ///
+    /// A batched ``Node.aggregate_neighbors``: every iteration computes all nodes from a
+    /// consistent snapshot of the previous one, so no node reads values updated in the
+    /// same pass. The first iteration reads ``attr_key``, later ones the previous results.
+    /// Useful for label smoothing and simple diffusion.
+    ///
+    /// Args:
+    ///     attr_key (str): Numeric node attribute to read
+    ///     out_key (str): Node attribute to write the result to (may equal ``attr_key``)
+    ///     op (str, optional): "sum", "mean", "min", "max" or "count". Defaults to "mean".
+    ///     iterations (int, optional): Number of passes. Defaults to 1.
+    ///     direction (str, optional): "out", "in" or "both" neighbours. Defaults to "out".
+    ///
+    /// Nodes whose aggregate is None (e.g. a mean over no numeric values) keep their
+    /// current ``out_key``. Values are written directly, without update callbacks.
+    ///
+    /// Raises:
+    ///     ValueError: For an unknown op or direction, or if ``iterations`` is 0
+    #[pyo3(signature = (attr_key, out_key, op="mean", iterations=1, direction="out"))]
+    fn propagate(
+        &self,
+        py: Python<'_>,
+        attr_key: &str,
+        out_key: &str,
+        op: &str,
+        iterations: usize,
+        direction: &str,
+    ) -> PyResult<()> {
+        algorithms::propagate(self, py, attr_key, out_key, op, iterations, direction)
+    }
+
+    /// Score a community assignment by modularity on the undirected weighted view
+    ///
+    /// Uses ``Q = (1/2m) * sum_ij (A_ij - k_i * k_j / 2m) * delta(c_i, c_j)``, where every
+    /// directed edge counts as an undirected edge of its weight, ``m`` is the total edge
+    /// weight and ``k_i`` the weighted degree of node ``i``. Any clustering can be
+    /// evaluated, not only one produced by this library.
+    ///
+    /// Args:
+    ///     communities (dict[str, int]): Community label for every node
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, every edge weighs 1.0. Missing weights count as 1.0.
+    ///
+    /// Returns:
+    ///     float: The modularity, between -0.5 and 1. 0.0 for a graph without edges.
+    ///
+    /// Raises:
+    ///     ValueError: If a node has no community, a community key is not a node,
+    ///         or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (communities, weight_field=None))]
+    fn modularity(
+        &self,
+        py: Python<'_>,
+        communities: HashMap<String, i64>,
+        weight_field: Option<String>,
+    ) -> PyResult<f64> {
+        algorithms::modularity(self, py, communities, weight_field)
+    }
+
+    /// Count the edges crossing between parts of a partition
+    ///
+    /// The objective minimized by graph partitioning. Every directed edge whose
+    /// endpoints are in different parts counts once; self-loops never cross.
+    ///
+    /// Args:
+    ///     partition (dict[str, int]): Part label for every node
+    ///     weight_field (str, optional): Edge attribute to sum instead of counting
+    ///         edges. Missing weights count as 1.0.
+    ///
+    /// Returns:
+    ///     float: The number of crossing edges, or their summed weight
+    ///
+    /// Raises:
+    ///     ValueError: If a node has no part or a partition key is not a node
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (partition, weight_field=None))]
+    fn cut_size(
+        &self,
+        py: Python<'_>,
+        partition: HashMap<String, i64>,
+        weight_field: Option<String>,
+    ) -> PyResult<f64> {
+        algorithms::cut_size(self, py, partition, weight_field)
+    }
+
+    /// Measure whether high-degree nodes connect to other high-degree nodes
+    ///
+    /// The degree assortativity coefficient: the Pearson correlation of the
+    /// degrees at both ends of every edge, computed in one pass over the edge
+    /// list with edge direction ignored. Positive values mean hubs link to hubs
+    /// (common in social networks), negative values mean hubs link to leaves.
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute summed into each node's
+    ///         degree (its strength) instead of counting edges. Missing weights
+    ///         count as 1.0.
+    ///
+    /// Returns:
+    ///     float: Coefficient in [-1, 1], or NaN if the graph has no edges or
+    ///     every edge joins nodes of the same degree
+    ///
+    /// Raises:
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (weight_field=None))]
+    fn degree_assortativity(&self, py: Python<'_>, weight_field: Option<String>) -> PyResult<f64> {
+        algorithms::degree_assortativity(self, py, weight_field)
+    }
+
+    /// Global transitivity (graph-level clustering) on the undirected view
+    ///
+    /// Computes ``3 * triangles / connected triples``, where a connected triple is a
+    /// path of two edges. Unlike the average of local clustering coefficients every
+    /// triple weighs the same, so high-degree nodes dominate. Parallel edges and edges
+    /// in both directions count once; self-loops are ignored.
+    ///
+    /// Returns:
+    ///     float: Between 0.0 and 1.0; 1.0 for a complete graph and 0.0 when there are
+    ///     no connected triples.
+    fn transitivity(&self, py: Python<'_>) -> PyResult<f64> {
+        algorithms::transitivity(self, py)
+    }
+
+    /// Compute harmonic centrality for every node
+    ///
+    /// Sums ``1 / d`` over the shortest-path distances from every other node to this
+    /// one, following edge directions. Unreachable nodes contribute 0, so unlike
+    /// closeness the score needs no correction on disconnected graphs.
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts. Missing weights count as 1.0.
+    ///     progress (callable, optional): Called as ``progress(completed, total)`` after
+    ///         each batch of nodes; the work between calls runs without the GIL
+    ///     should_cancel (callable, optional): Polled before each batch; returning True
+    ///         stops the computation with ``CancelledError``
+    ///
+    /// Returns:
+    ///     dict[str, float]: Harmonic centrality per node ID
+    ///
+    /// Raises:
+    ///     ValueError: If a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    ///     CancelledError: If ``should_cancel`` returned True
+    #[pyo3(signature = (weight_field=None, progress=None, should_cancel=None))]
+    fn harmonic_centrality(
+        &self,
+        py: Python<'_>,
+        weight_field: Option<String>,
+        progress: Option<Bound<'_, PyAny>>,
+        should_cancel: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<HashMap<String, f64>> {
+        let monitor = algorithms::Monitor {
+            progress: progress.as_ref(),
+            should_cancel: should_cancel.as_ref(),
+        };
+        algorithms::harmonic_centrality(self, py, weight_field, monitor)
+    }
+
+    /// Estimate betweenness centrality from a random sample of source nodes
+    ///
+    /// Runs Brandes' accumulation from ``num_samples`` distinct random sources and
+    /// scales the result by ``n / num_samples``. The score is unnormalized and
+    /// follows edge directions: the number of shortest paths between other ordered
+    /// node pairs passing through a node, each pair split evenly over its shortest
+    /// paths. Sampling every node gives the exact value.
+    ///
+    /// Args:
+    ///     num_samples (int): Number of source nodes, between 1 and the node count
+    ///     seed (int, optional): Seed for drawing the sources, for reproducible estimates
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts. Missing weights count as 1.0.
+    ///     progress (callable, optional): Called as ``progress(completed, total)`` after
+    ///         each batch of sources; the work between calls runs without the GIL
+    ///     should_cancel (callable, optional): Polled before each batch; returning True
+    ///         stops the computation with ``CancelledError``
+    ///
+    /// Returns:
+    ///     dict[str, float]: Estimated betweenness per node ID
+    ///
+    /// Raises:
+    ///     ValueError: If ``num_samples`` is out of range or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    ///     CancelledError: If ``should_cancel`` returned True
+    #[pyo3(signature = (num_samples, seed=None, weight_field=None, progress=None, should_cancel=None))]
+    fn betweenness_centrality_sampled(
+        &self,
+        py: Python<'_>,
+        num_samples: usize,
+        seed: Option<u64>,
+        weight_field: Option<String>,
+        progress: Option<Bound<'_, PyAny>>,
+        should_cancel: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<HashMap<String, f64>> {
+        let monitor = algorithms::Monitor {
+            progress: progress.as_ref(),
+            should_cancel: should_cancel.as_ref(),
+        };
+        algorithms::betweenness_centrality_sampled(self, py, num_samples, seed, weight_field, monitor)
+    }
+
+    /// Estimate the diameter with the double-sweep BFS heuristic, ignoring edge direction
+    ///
+    /// BFS from an arbitrary node finds the farthest node, then BFS from there
+    /// measures its eccentricity; ``iterations`` is the number of such sweeps per
+    /// component. This runs in O(iterations * (V + E)) instead of O(V * (V + E)).
+    ///
+    /// Args:
+    ///     iterations (int): BFS sweeps per component. Defaults to 2 (double sweep).
+    ///
+    /// Returns:
+    ///     int: A lower bound on the longest shortest-path length in hops. Exact for
+    ///     trees and forests, approximate for general graphs. The largest value over
+    ///     all components is returned, and 0 for an empty graph.
+    ///
+    /// Raises:
+    ///     ValueError: If ``iterations`` is 0.
+    #[pyo3(signature = (iterations=2))]
+    fn approximate_diameter(&self, py: Python<'_>, iterations: usize) -> PyResult<usize> {
+        algorithms::approximate_diameter(self, py, iterations)
+    }
+
+    /// Compute the eccentricity of every node, ignoring edge direction
+    ///
+    /// The eccentricity of a node is its largest shortest-path distance to any
+    /// other node. One SSSP run per node, spread across threads without the GIL.
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts. Missing weights count as 1.0.
+    ///     per_component (bool): Measure each node only within its weakly connected
+    ///         component. Defaults to False, which requires a connected graph.
+    ///
+    /// Returns:
+    ///     dict[str, float]: Node ID -> eccentricity
+    ///
+    /// Raises:
+    ///     GraphError: If the graph is disconnected and ``per_component`` is False,
+    ///         or a weight is negative
+    ///     TypeError: If a weight attribute is not numeric
+    #[pyo3(signature = (weight_field=None, per_component=false))]
+    fn eccentricity(
+        &self,
+        py: Python<'_>,
+        weight_field: Option<String>,
+        per_component: bool,
+    ) -> PyResult<HashMap<String, f64>> {
+        algorithms::eccentricity(self, py, weight_field.as_deref(), per_component)
+    }
+
+    /// Find the nodes of minimum eccentricity, ignoring edge direction
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts.
+    ///     per_component (bool): Return the center of every weakly connected component
+    ///         instead of raising on a disconnected graph. Defaults to False.
+    ///
+    /// Returns:
+    ///     list[str]: Sorted IDs of the most central nodes
+    ///
+    /// Raises:
+    ///     GraphError: If the graph is disconnected and ``per_component`` is False
+    #[pyo3(signature = (weight_field=None, per_component=false))]
+    fn center(&self, py: Python<'_>, weight_field: Option<String>, per_component: bool) -> PyResult<Vec<String>> {
+        algorithms::center(self, py, weight_field.as_deref(), per_component)
+    }
+
+    /// Find the nodes of maximum eccentricity, ignoring edge direction
+    ///
+    /// Args:
+    ///     weight_field (str, optional): Edge attribute holding a non-negative weight.
+    ///         If None, distances are hop counts.
+    ///     per_component (bool): Return the periphery of every weakly connected
+    ///         component instead of raising on a disconnected graph. Defaults to False.
+    ///
+    /// Returns:
+    ///     list[str]: Sorted IDs of the least central nodes
+    ///
+    /// Raises:
+    ///     GraphError: If the graph is disconnected and ``per_component`` is False
+    #[pyo3(signature = (weight_field=None, per_component=false))]
+    fn periphery(&self, py: Python<'_>, weight_field: Option<String>, per_component: bool) -> PyResult<Vec<String>> {
+        algorithms::periphery(self, py, weight_field.as_deref(), per_component)
+    }
+
+    /// Check whether the graph is a single component when edge direction is ignored
+    ///
+    /// Returns:
+    ///     bool: True if weakly connected. An empty graph returns False.
+    fn is_weakly_connected(&self, py: Python<'_>) -> PyResult<bool> {
+        algorithms::is_weakly_connected(self, py)
+    }
+
+    /// Check whether every node can reach every other node following edge directions
+    ///
+    /// Returns:
+    ///     bool: True if the whole graph is one strongly connected component.
+    ///     An empty graph returns False.
+    fn is_strongly_connected(&self, py: Python<'_>) -> PyResult<bool> {
+        algorithms::is_strongly_connected(self, py)
+    }
+
+    /// Find the nodes that every given source can reach
+    ///
+    /// A node counts as reachable from a source when a directed path of at least
+    /// one edge leads to it, so a source is only included if it lies on a cycle.
+    ///
+    /// Args:
+    ///     source_ids (list[str]): Nodes whose descendant sets are intersected
+    ///
+    /// Returns:
+    ///     set[str]: IDs reachable from all sources; empty when ``source_ids`` is empty
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If a source does not exist
+    fn common_reachable(&self, py: Python<'_>, source_ids: Vec<String>) -> PyResult<HashSet<String>> {
+        algorithms::common_reachable(self, py, &source_ids)
+    }
+
+    /// Find the most probable path probability to every likely reachable node
+    ///
+    /// Models uncertain or noisy graphs where each edge exists with some
+    /// probability. A path's probability is the product of its edge
+    /// probabilities; a best-first search keeps the highest one per node and
+    /// prunes paths once they fall below ``min_prob``.
+    ///
+    /// Args:
+    ///     source_id (str): Start node, included with probability 1.0
+    ///     min_prob (float): Smallest path probability kept. Defaults to 0.1.
+    ///     prob_field (str): Edge attribute holding the probability; edges
+    ///         without it count as certain (1.0). Defaults to ``"probability"``.
+    ///
+    /// Returns:
+    ///     dict[str, float]: Best path probability for every node reached with
+    ///     at least ``min_prob``
+    ///
+    /// Raises:
+    ///     NodeNotFoundError: If the source does not exist
+    ///     ValueError: If ``min_prob`` or an edge probability is outside [0, 1]
+    ///     TypeError: If an edge probability is not numeric
+    #[pyo3(signature = (source_id, min_prob=0.1, prob_field="probability"))]
+    fn probable_reachable(
+        &self,
+        py: Python<'_>,
+        source_id: &str,
+        min_prob: f64,
+        prob_field: &str,
+    ) -> PyResult<HashMap<String, f64>> {
+        algorithms::probable_reachable(self, py, source_id, min_prob, prob_field)
+    }
 }
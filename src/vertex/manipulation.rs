@@ -1,9 +1,13 @@
 // vertex/manipulation.rs
 
 use pyo3::prelude::*;
-use std::collections::HashMap;
+use pyo3::class::basic::CompareOp;
+use pyo3::types::PyDict;
+use std::collections::{HashMap, HashSet};
 use crate::{Node, Edge};
+use crate::errors::{edge_not_found, missing_node, GraphError};
 use super::Vertex;
+use super::callbacks;
 
 pub fn add_node(
     vertex: &mut Vertex,
@@ -13,7 +17,7 @@ pub fn add_node(
 ) -> PyResult<Py<Node>> {
     // Check if node already exists
     if vertex.nodes.contains_key(&id) {
-        return Err(pyo3::exceptions::PyValueError::new_err(
+        return Err(GraphError::new_err(
             format!("Node with id '{}' already exists", id)
         ));
     }
@@ -36,15 +40,11 @@ pub fn add_edge(
 ) -> PyResult<Py<Edge>> {
     // Get the from and to nodes
     let from_node = vertex.nodes.get(&from_id)
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
-            format!("Node with id '{}' not found", from_id)
-        ))?
+        .ok_or_else(|| missing_node(&from_id))?
         .clone_ref(py);
         
     let to_node = vertex.nodes.get(&to_id)
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err(
-            format!("Node with id '{}' not found", to_id)
-        ))?
+        .ok_or_else(|| missing_node(&to_id))?
         .clone_ref(py);
 
     // Create the edge
@@ -58,22 +58,319 @@ pub fn add_edge(
     // Add the edge to the to_node's inverse_edges list
     let mut to_node_ref = to_node.borrow_mut(py);
     to_node_ref.inverse_edges.push(edge.clone_ref(py));
+    vertex.edge_count += 1;
 
     Ok(edge)
 }
 
+/// Remove edges from `from_id` to `to_id` (only the one with `edge_id`, if given)
+/// from both endpoint lists. Returns the number of edges removed; an `edge_id`
+/// matching no such edge raises `EdgeNotFoundError`.
+pub fn remove_edge(
+    vertex: &mut Vertex,
+    py: Python<'_>,
+    from_id: &str,
+    to_id: &str,
+    edge_id: Option<&str>,
+) -> PyResult<usize> {
+    let from_node = vertex.nodes.get(from_id)
+        .ok_or_else(|| missing_node(from_id))?
+        .clone_ref(py);
+    let to_node = vertex.nodes.get(to_id)
+        .ok_or_else(|| missing_node(to_id))?
+        .clone_ref(py);
+
+    let mut removed = Vec::new();
+    from_node.borrow_mut(py).edges.retain(|edge| {
+        let edge_ref = edge.borrow(py);
+        let matches = edge_ref.to_node.borrow(py).id == to_id
+            && edge_id.is_none_or(|id| edge_ref.id.as_deref() == Some(id));
+        if matches {
+            removed.push(edge.as_ptr());
+        }
+        !matches
+    });
+    to_node
+        .borrow_mut(py)
+        .inverse_edges
+        .retain(|edge| !removed.contains(&edge.as_ptr()));
+
+    if let (Some(edge_id), true) = (edge_id, removed.is_empty()) {
+        return Err(edge_not_found(format!(
+            "No edge with id '{}' from '{}' to '{}'",
+            edge_id, from_id, to_id
+        )));
+    }
+    vertex.edge_count = vertex.edge_count.saturating_sub(removed.len());
+    Ok(removed.len())
+}
+
+/// Detach a single edge from both endpoint lists.
+pub fn detach_edge(vertex: &mut Vertex, py: Python<'_>, edge: &Py<Edge>) {
+    let (from_node, to_node) = {
+        let edge_ref = edge.borrow(py);
+        (edge_ref.from_node.clone_ref(py), edge_ref.to_node.clone_ref(py))
+    };
+    let removed = {
+        let edges = &mut from_node.borrow_mut(py).edges;
+        let before = edges.len();
+        edges.retain(|e| e.as_ptr() != edge.as_ptr());
+        before - edges.len()
+    };
+    to_node.borrow_mut(py).inverse_edges.retain(|e| e.as_ptr() != edge.as_ptr());
+    vertex.edge_count = vertex.edge_count.saturating_sub(removed);
+}
+
+/// Remove node `id` together with its outgoing and incoming edges, returning
+/// the removed node. Callbacks are left to the caller.
+pub fn remove_node(vertex: &mut Vertex, py: Python<'_>, id: &str) -> PyResult<Py<Node>> {
+    let node = vertex.nodes.remove(id).ok_or_else(|| missing_node(id))?;
+    let (edges, inverse_edges) = {
+        let mut node_ref = node.borrow_mut(py);
+        (std::mem::take(&mut node_ref.edges), std::mem::take(&mut node_ref.inverse_edges))
+    };
+    for edge in &edges {
+        let to_node = edge.borrow(py).to_node.clone_ref(py);
+        if to_node.as_ptr() != node.as_ptr() {
+            to_node.borrow_mut(py).inverse_edges.retain(|e| e.as_ptr() != edge.as_ptr());
+        }
+    }
+    // Self-loops were counted with the outgoing edges; incoming edges count
+    // only if they were really in their source's list
+    let mut removed = edges.len();
+    for edge in &inverse_edges {
+        let from_node = edge.borrow(py).from_node.clone_ref(py);
+        if from_node.as_ptr() != node.as_ptr() {
+            let source_edges = &mut from_node.borrow_mut(py).edges;
+            let before = source_edges.len();
+            source_edges.retain(|e| e.as_ptr() != edge.as_ptr());
+            removed += before - source_edges.len();
+        }
+    }
+    vertex.edge_count = vertex.edge_count.saturating_sub(removed);
+    Ok(node)
+}
+
+/// Set `attr[key] = value` through `Node.attr_set` on every node lacking
+/// `key`, in node id order. Returns the number of nodes changed.
+pub fn set_default_attr(vertex: &Vertex, py: Python<'_>, key: &str, value: &Py<PyAny>) -> PyResult<usize> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let missing: Vec<Py<Node>> = ids
+        .into_iter()
+        .map(|id| &vertex.nodes[id])
+        .filter(|node| !node.borrow(py).attr.contains_key(key))
+        .map(|node| node.clone_ref(py))
+        .collect();
+    // Callbacks may touch the graph, so no borrows are held while they run
+    for node in &missing {
+        Node::attr_set(node.bind(py).borrow_mut(), py, key.to_string(), value.clone_ref(py))?;
+    }
+    Ok(missing.len())
+}
+
+/// Edge analogue of `set_default_attr`, visiting edges by sorted source id.
+pub fn set_default_edge_attr(vertex: &Vertex, py: Python<'_>, key: &str, value: &Py<PyAny>) -> PyResult<usize> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let mut missing: Vec<Py<Edge>> = Vec::new();
+    for id in ids {
+        for edge in &vertex.nodes[id].borrow(py).edges {
+            if !edge.borrow(py).attr.contains_key(key) {
+                missing.push(edge.clone_ref(py));
+            }
+        }
+    }
+    for edge in &missing {
+        Edge::attr_set(edge.bind(py).borrow_mut(), py, key.to_string(), value.clone_ref(py))?;
+    }
+    Ok(missing.len())
+}
+
+/// Move `attr[old_key]` to `attr[new_key]` on every node (`on="nodes"`) or
+/// edge (`on="edges"`) that has it, in sorted id order. The new key is
+/// written through `attr_set` so update callbacks fire; the old key is
+/// dropped silently, from every item before the first callback fires. If any
+/// item already has `new_key`, `GraphError` is raised before anything
+/// changes. Returns the number of items renamed.
+pub fn rename_attr(vertex: &Vertex, py: Python<'_>, old_key: &str, new_key: &str, on: &str) -> PyResult<usize> {
+    if on != "nodes" && on != "edges" {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Unknown target '{}'; expected 'nodes' or 'edges'",
+            on
+        )));
+    }
+    if old_key == new_key {
+        return Ok(0);
+    }
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+    let take = |attr: &HashMap<String, Py<PyAny>>, owner: String| -> PyResult<bool> {
+        if !attr.contains_key(old_key) {
+            return Ok(false);
+        }
+        if attr.contains_key(new_key) {
+            return Err(GraphError::new_err(format!(
+                "Cannot rename '{}' to '{}': {} already has '{}'",
+                old_key, new_key, owner, new_key
+            )));
+        }
+        Ok(true)
+    };
+
+    if on == "nodes" {
+        let mut nodes: Vec<Py<Node>> = Vec::new();
+        for id in ids {
+            let node = &vertex.nodes[id];
+            if take(&node.borrow(py).attr, format!("node '{}'", id))? {
+                nodes.push(node.clone_ref(py));
+            }
+        }
+        // Take every old value out first: callbacks fired by `attr_set` may
+        // touch the remaining nodes
+        let values: Vec<Py<PyAny>> = nodes
+            .iter()
+            .filter_map(|node| node.borrow_mut(py).attr.remove(old_key))
+            .collect();
+        for (node, value) in nodes.iter().zip(values) {
+            Node::attr_set(node.bind(py).borrow_mut(), py, new_key.to_string(), value)?;
+        }
+        return Ok(nodes.len());
+    }
+
+    let mut edges: Vec<Py<Edge>> = Vec::new();
+    for id in ids {
+        for edge in &vertex.nodes[id].borrow(py).edges {
+            let edge_ref = edge.borrow(py);
+            let owner = format!("edge '{}' -> '{}'", id, edge_ref.to_node.borrow(py).id);
+            if take(&edge_ref.attr, owner)? {
+                edges.push(edge.clone_ref(py));
+            }
+        }
+    }
+    let values: Vec<Py<PyAny>> = edges
+        .iter()
+        .filter_map(|edge| edge.borrow_mut(py).attr.remove(old_key))
+        .collect();
+    for (edge, value) in edges.iter().zip(values) {
+        Edge::attr_set(edge.bind(py).borrow_mut(), py, new_key.to_string(), value)?;
+    }
+    Ok(edges.len())
+}
+
+/// Rename every node in `mapping` at once, swaps and cycles included.
+///
+/// All sources must exist and the new ids must neither repeat nor clash with
+/// a node that keeps its id; nothing changes if either check fails. Edges
+/// hold their endpoints by reference, so they follow without being touched.
+pub fn relabel(vertex: &mut Vertex, py: Python<'_>, mapping: &HashMap<String, String>) -> PyResult<()> {
+    let mut sources: Vec<&String> = mapping.keys().collect();
+    sources.sort();
+    let mut targets: HashMap<&String, &String> = HashMap::with_capacity(mapping.len());
+    for source in sources {
+        if !vertex.nodes.contains_key(source) {
+            return Err(missing_node(source));
+        }
+        let target = &mapping[source];
+        if let Some(other) = targets.insert(target, source) {
+            return Err(GraphError::new_err(format!(
+                "Cannot relabel both '{}' and '{}' to '{}'",
+                other, source, target
+            )));
+        }
+        if vertex.nodes.contains_key(target) && !mapping.contains_key(target) {
+            return Err(GraphError::new_err(format!(
+                "Cannot relabel '{}' to '{}': a node with that id already exists",
+                source, target
+            )));
+        }
+    }
+
+    let mut nodes = HashMap::with_capacity(vertex.nodes.len());
+    for (id, node) in std::mem::take(&mut vertex.nodes) {
+        let id = mapping.get(&id).cloned().unwrap_or(id);
+        node.borrow_mut(py).id = id.clone();
+        nodes.insert(id, node);
+    }
+    vertex.nodes = nodes;
+    Ok(())
+}
+
+/// Flip every edge of the graph in place.
+///
+/// Each node swaps its `edges` and `inverse_edges`, and each edge touching a
+/// node of the graph swaps `from_node` and `to_node`, so edges stay the same
+/// objects. Nodes outside the graph (e.g. left behind by `from_nodes`) keep
+/// their lists; `prune` first to drop edges to them.
+pub fn reverse_in_place(vertex: &mut Vertex, py: Python<'_>) {
+    let mut seen: HashSet<*mut pyo3::ffi::PyObject> = HashSet::new();
+    for node in vertex.nodes.values() {
+        let mut node_ref = node.borrow_mut(py);
+        for edge in node_ref.edges.iter().chain(&node_ref.inverse_edges) {
+            if seen.insert(edge.as_ptr()) {
+                let mut edge_ref = edge.borrow_mut(py);
+                let edge_ref = &mut *edge_ref;
+                std::mem::swap(&mut edge_ref.from_node, &mut edge_ref.to_node);
+            }
+        }
+        let node_ref = &mut *node_ref;
+        std::mem::swap(&mut node_ref.edges, &mut node_ref.inverse_edges);
+    }
+    vertex.edge_count = count_edges(py, &vertex.nodes);
+}
+
 pub fn get_node(vertex: &Vertex, py: Python<'_>, id: String) -> PyResult<Py<Node>> {
     vertex.nodes
         .get(&id)
         .map(|n| n.clone_ref(py))
-        .ok_or_else(|| pyo3::exceptions::PyKeyError::new_err(
-            format!("Node with id '{}' not found", id)
-        ))
+        .ok_or_else(|| missing_node(&id))
+}
+
+/// Remove every node without `edges` and `inverse_edges`, returning the
+/// removed nodes sorted by id. Callbacks are left to the caller.
+pub fn remove_isolated_nodes(vertex: &mut Vertex, py: Python<'_>) -> Vec<Py<Node>> {
+    let mut isolated: Vec<String> = vertex
+        .nodes
+        .iter()
+        .filter(|(_, node)| {
+            let node_ref = node.borrow(py);
+            node_ref.edges.is_empty() && node_ref.inverse_edges.is_empty()
+        })
+        .map(|(id, _)| id.clone())
+        .collect();
+    isolated.sort();
+    isolated
+        .iter()
+        .filter_map(|id| vertex.nodes.remove(id))
+        .collect()
+}
+
+/// Remove every edge while keeping the nodes; returns the number removed.
+pub fn clear_edges(vertex: &mut Vertex, py: Python<'_>) -> usize {
+    let mut removed = 0;
+    for node in vertex.nodes.values() {
+        let mut node_ref = node.borrow_mut(py);
+        removed += node_ref.edges.len();
+        node_ref.edges.clear();
+        node_ref.inverse_edges.clear();
+    }
+    vertex.edge_count = 0;
+    removed
+}
+
+/// Remove all nodes and edges and empty `meta`, returning the removed nodes
+/// sorted by id. Callbacks are left to the caller.
+pub fn clear(vertex: &mut Vertex, py: Python<'_>) -> Vec<Py<Node>> {
+    clear_edges(vertex, py);
+    vertex.meta.bind(py).clear();
+    let mut nodes: Vec<(String, Py<Node>)> = vertex.nodes.drain().collect();
+    nodes.sort_by(|a, b| a.0.cmp(&b.0));
+    nodes.into_iter().map(|(_, node)| node).collect()
 }
 
 /// Remove edges and inverse_edges that point to nodes not present in the vertex.
 /// Returns the number of edges removed.
-pub fn prune(vertex: &Vertex, py: Python<'_>) -> PyResult<usize> {
+pub fn prune(vertex: &mut Vertex, py: Python<'_>) -> PyResult<usize> {
     let mut removed = 0usize;
 
     for node_py in vertex.nodes.values() {
@@ -91,7 +388,9 @@ pub fn prune(vertex: &Vertex, py: Python<'_>) -> PyResult<usize> {
                 .clone();
             vertex.nodes.contains_key(&to_id)
         });
-        removed += before_edges - node_ref.edges.len();
+        let removed_edges = before_edges - node_ref.edges.len();
+        removed += removed_edges;
+        vertex.edge_count = vertex.edge_count.saturating_sub(removed_edges);
 
         let before_inv = node_ref.inverse_edges.len();
         node_ref.inverse_edges.retain(|edge| {
@@ -110,3 +409,78 @@ pub fn prune(vertex: &Vertex, py: Python<'_>) -> PyResult<usize> {
 
     Ok(removed)
 }
+
+/// Number of outgoing edges over `nodes`, the value `Vertex::edge_count` caches.
+pub fn count_edges(py: Python<'_>, nodes: &HashMap<String, Py<Node>>) -> usize {
+    nodes.values().map(|node| node.borrow(py).edges.len()).sum()
+}
+
+/// Describe every cached value on `vertex` that disagrees with a full recount.
+pub fn validate(vertex: &Vertex, py: Python<'_>) -> Vec<String> {
+    let mut issues = Vec::new();
+    let actual = count_edges(py, &vertex.nodes);
+    if vertex.edge_count != actual {
+        issues.push(format!(
+            "edge_count is {} but the nodes hold {} edges",
+            vertex.edge_count, actual
+        ));
+    }
+    issues
+}
+
+/// Recompute cached values, returning the issues `validate` reported beforehand.
+pub fn repair(vertex: &mut Vertex, py: Python<'_>) -> Vec<String> {
+    let issues = validate(vertex, py);
+    vertex.edge_count = count_edges(py, &vertex.nodes);
+    issues
+}
+
+/// Set `meta[key] = value` on the vertex behind `vertex_handle`.
+/// Fires ``on_vertex_meta_change_callbacks`` if the value actually changed.
+pub fn meta_set(
+    vertex_handle: &Py<Vertex>,
+    py: Python<'_>,
+    key: String,
+    value: Py<PyAny>,
+) -> PyResult<()> {
+    let (meta, callbacks_list) = {
+        let vertex = vertex_handle.borrow(py);
+        (
+            vertex.meta.clone_ref(py),
+            vertex.on_vertex_meta_change_callbacks.clone_ref(py),
+        )
+    };
+    let meta = meta.bind(py);
+
+    let old_value: Option<Py<PyAny>> = meta.get_item(&key)?.map(|v| v.unbind());
+    let changed = match &old_value {
+        Some(old) => !old.bind(py).rich_compare(value.bind(py), CompareOp::Eq)?.is_truthy()?,
+        None => true,
+    };
+
+    meta.set_item(&key, value.clone_ref(py))?;
+
+    if changed {
+        callbacks::fire_vertex_meta_change_callbacks(
+            py,
+            callbacks_list.bind(py),
+            &vertex_handle.clone_ref(py).into_any(),
+            &key,
+            &value,
+            old_value.as_ref(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Apply `meta_set` for every item of `values`, in iteration order.
+pub fn meta_update(
+    vertex_handle: &Py<Vertex>,
+    py: Python<'_>,
+    values: &Bound<'_, PyDict>,
+) -> PyResult<()> {
+    for (key, value) in values.iter() {
+        meta_set(vertex_handle, py, key.extract::<String>()?, value.unbind())?;
+    }
+    Ok(())
+}
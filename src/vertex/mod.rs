@@ -8,3 +8,5 @@ mod analysis;
 mod algorithms;
 
 pub use core::Vertex;
+pub use algorithms::BfsFrontiers;
+pub(crate) use algorithms::attr_weight;
@@ -1,13 +1,22 @@
 // vertex/serialization.rs
 
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict};
-use crate::serialization::SerializableGraph;
+use pyo3::types::{PyAny, PyBool, PyDict, PyList, PyString};
+use std::collections::HashMap;
+use std::path::Path;
+use crate::edge::map_to_dict;
+use crate::serialization::{BinarySnapshot, EdgeIds, SerializableGraph, BINARY_FLAG_F16, BINARY_FLAG_ZSTD};
 use super::Vertex;
+use super::manipulation;
 
 /// Save graph to JSON file (when file_path is provided) or return JSON string (when file_path is None)
-pub fn save_to_json(vertex: &Vertex, py: Python<'_>, file_path: Option<String>) -> PyResult<Py<PyAny>> {
-    let serializable_graph = SerializableGraph::from_vertex(py, vertex)?;
+pub fn save_to_json(
+    vertex: &Vertex,
+    py: Python<'_>,
+    file_path: Option<String>,
+    include_timestamp: bool,
+) -> PyResult<Py<PyAny>> {
+    let serializable_graph = SerializableGraph::from_vertex(py, vertex, include_timestamp)?;
     
     match file_path {
         Some(path) => {
@@ -27,17 +36,52 @@ pub fn save_to_json(vertex: &Vertex, py: Python<'_>, file_path: Option<String>)
     }
 }
 
-pub fn save_to_binary(vertex: &Vertex, py: Python<'_>, file_path: String) -> PyResult<()> {
-    let serializable_graph = SerializableGraph::from_vertex(py, vertex)?;
+pub fn save_to_binary(vertex: &mut Vertex, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+    let (serializable_graph, edges) = SerializableGraph::from_vertex_with_edges(py, vertex, include_timestamp)?;
     serializable_graph.save_to_binary(&file_path)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Failed to save graph to binary: {}", e)
         ))?;
+    vertex.binary_snapshot = Some(capture_snapshot(&file_path, &serializable_graph, edges)?);
     Ok(())
 }
 
-pub fn save_to_binary_f16(vertex: &Vertex, py: Python<'_>, file_path: String) -> PyResult<()> {
-    let serializable_graph = SerializableGraph::from_vertex(py, vertex)?;
+/// Append `vertex` to a binary file, only its changes if it tracks that file
+pub fn append_to_binary(vertex: &mut Vertex, py: Python<'_>, file_path: String) -> PyResult<()> {
+    let (serializable_graph, edges) = SerializableGraph::from_vertex_with_edges(py, vertex, true)?;
+    vertex.binary_snapshot = serializable_graph
+        .append_to_binary(&file_path, edges, vertex.binary_snapshot.as_ref())
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to append graph to binary: {}", e)
+        ))?;
+    Ok(())
+}
+
+/// Record that `file_path` now holds `graph`, for later `append_to_binary` calls
+fn capture_snapshot(file_path: &str, graph: &SerializableGraph, edges: EdgeIds) -> PyResult<BinarySnapshot> {
+    BinarySnapshot::capture(Path::new(file_path), graph, edges)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to read back binary file: {}", e)
+        ))
+}
+
+/// Each edge of a loaded `vertex` with the id it was stored under
+fn loaded_edges(py: Python<'_>, vertex: &Vertex) -> EdgeIds {
+    vertex
+        .nodes
+        .values()
+        .flat_map(|node| {
+            node.borrow(py)
+                .edges
+                .iter()
+                .map(|edge| (edge.clone_ref(py), edge.borrow(py).id.clone().unwrap_or_default()))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub fn save_to_binary_f16(vertex: &Vertex, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+    let serializable_graph = SerializableGraph::from_vertex(py, vertex, include_timestamp)?;
     serializable_graph.save_to_binary_f16(&file_path)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Failed to save graph to binary: {}", e)
@@ -45,9 +89,55 @@ pub fn save_to_binary_f16(vertex: &Vertex, py: Python<'_>, file_path: String) ->
     Ok(())
 }
 
+/// Save graph to a zstd-compressed binary file
+pub fn save_to_binary_zstd(
+    vertex: &Vertex,
+    py: Python<'_>,
+    file_path: String,
+    level: i32,
+    include_timestamp: bool,
+) -> PyResult<()> {
+    if !zstd::compression_level_range().contains(&level) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "Compression level {} is out of range; expected {:?}",
+            level,
+            zstd::compression_level_range()
+        )));
+    }
+    let serializable_graph = SerializableGraph::from_vertex(py, vertex, include_timestamp)?;
+    serializable_graph.save_to_binary_zstd(&file_path, level)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to save graph to binary: {}", e)
+        ))?;
+    Ok(())
+}
+
+/// Save graph to a JSON Lines file (one record per line)
+pub fn save_to_jsonl(vertex: &Vertex, py: Python<'_>, file_path: String, include_timestamp: bool) -> PyResult<()> {
+    let serializable_graph = SerializableGraph::from_vertex(py, vertex, include_timestamp)?;
+    serializable_graph.save_to_jsonl(&file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to save graph to JSON Lines: {}", e)
+        ))?;
+    Ok(())
+}
+
+/// Load graph from a JSON Lines file written by `save_to_jsonl`
+pub fn load_from_jsonl(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+    let serializable_graph = SerializableGraph::load_from_jsonl(&file_path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to load graph from JSON Lines: {}", e)
+        ))?;
+    let vertex = serializable_graph.to_vertex(py)?;
+    Py::new(py, vertex)
+}
+
 /// Load graph from JSON file (when source is a string path) or from JSON string/dict (when source is a dict or JSON string)
-pub fn load_from_json(py: Python<'_>, source: &Bound<'_, PyAny>) -> PyResult<Py<Vertex>> {
-    let serializable_graph = if let Ok(path) = source.extract::<String>() {
+///
+/// With `coerce_numeric`, string attribute values that parse as an int or
+/// float are converted before the vertex is built.
+pub fn load_from_json(py: Python<'_>, source: &Bound<'_, PyAny>, coerce_numeric: bool) -> PyResult<Py<Vertex>> {
+    let mut serializable_graph = if let Ok(path) = source.extract::<String>() {
         // Try to parse as JSON string first, if that fails treat as file path
         if path.trim().starts_with('{') {
             // Looks like a JSON string
@@ -75,6 +165,9 @@ pub fn load_from_json(py: Python<'_>, source: &Bound<'_, PyAny>) -> PyResult<Py<
             "source must be a file path (str), JSON string (str), or dict"
         ));
     };
+    if coerce_numeric {
+        serializable_graph.coerce_numeric_attrs();
+    }
     
     let vertex = serializable_graph.to_vertex(py)?;
     Py::new(py, vertex)
@@ -85,6 +178,178 @@ pub fn load_from_binary(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
             format!("Failed to load graph from binary: {}", e)
         ))?;
+    let mut vertex = serializable_graph.to_vertex(py)?;
+    let edges = loaded_edges(py, &vertex);
+    vertex.binary_snapshot = Some(capture_snapshot(&file_path, &serializable_graph, edges)?);
+    Py::new(py, vertex)
+}
+
+/// Load graph from a binary file written by `save_to_binary_zstd`
+pub fn load_from_binary_zstd(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+    let serializable_graph = SerializableGraph::load_from_binary_requiring(&file_path, BINARY_FLAG_ZSTD)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to load graph from binary: {}", e)
+        ))?;
     let vertex = serializable_graph.to_vertex(py)?;
     Py::new(py, vertex)
 }
+
+/// Load graph from a binary file written by `save_to_binary_f16`
+pub fn load_from_binary_f16(py: Python<'_>, file_path: String) -> PyResult<Py<Vertex>> {
+    let serializable_graph = SerializableGraph::load_from_binary_requiring(&file_path, BINARY_FLAG_F16)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            format!("Failed to load graph from binary: {}", e)
+        ))?;
+    let mut vertex = serializable_graph.to_vertex(py)?;
+    let edges = loaded_edges(py, &vertex);
+    vertex.binary_snapshot = Some(capture_snapshot(&file_path, &serializable_graph, edges)?);
+    Py::new(py, vertex)
+}
+
+/// Build a graph from `{node: [targets]}` or `{node: {target: attr}}`.
+///
+/// Nodes are created in the order they are first mentioned, so targets that
+/// never appear as keys are created as well (with empty attributes).
+pub fn from_adjacency(py: Python<'_>, adj: &Bound<'_, PyDict>) -> PyResult<Py<Vertex>> {
+    let mut vertex = Vertex::from_nodes(py, HashMap::new());
+
+    for (key, value) in adj.iter() {
+        let from_id: String = key.extract()?;
+        ensure_node(&mut vertex, py, &from_id)?;
+
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            for (target, attr) in dict.iter() {
+                let attr = if attr.is_none() { None } else { Some(attr.extract()?) };
+                add_adjacency_edge(&mut vertex, py, &from_id, target.extract()?, attr)?;
+            }
+        } else if value.is_instance_of::<PyString>() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "Adjacency of node '{}' must be a list of node ids or a dict of target -> attr",
+                from_id
+            )));
+        } else {
+            for target in value.try_iter()? {
+                add_adjacency_edge(&mut vertex, py, &from_id, target?.extract()?, None)?;
+            }
+        }
+    }
+
+    Py::new(py, vertex)
+}
+
+/// Build a graph from the rows of a pandas DataFrame, one edge per row.
+///
+/// Each used column is turned into a Python list once with `tolist()` (which
+/// also unboxes numpy scalars), then the graph is built from those lists.
+/// Nodes are created in the order they are first mentioned. `edge_attr` is
+/// `None`, `True` (every other column), a column name or a list of names.
+pub fn from_pandas_edgelist(
+    py: Python<'_>,
+    df: &Bound<'_, PyAny>,
+    source: &str,
+    target: &str,
+    edge_attr: Option<&Bound<'_, PyAny>>,
+) -> PyResult<Py<Vertex>> {
+    let columns: Vec<String> = df.getattr("columns")?.call_method0("tolist")?.extract()?;
+    let attr_columns: Vec<String> = match edge_attr {
+        None => Vec::new(),
+        Some(value) if value.is_instance_of::<PyBool>() => {
+            if value.extract::<bool>()? {
+                columns.iter().filter(|c| *c != source && *c != target).cloned().collect()
+            } else {
+                Vec::new()
+            }
+        }
+        Some(value) if value.is_instance_of::<PyString>() => vec![value.extract()?],
+        Some(value) => value.extract()?,
+    };
+
+    let column = |name: &str| -> PyResult<Vec<Py<PyAny>>> {
+        if !columns.iter().any(|c| c == name) {
+            return Err(pyo3::exceptions::PyKeyError::new_err(format!(
+                "Column '{}' not found in DataFrame",
+                name
+            )));
+        }
+        df.get_item(name)?.call_method0("tolist")?.extract()
+    };
+    let sources = column(source)?;
+    let targets = column(target)?;
+    let attrs: Vec<(String, Vec<Py<PyAny>>)> = attr_columns
+        .into_iter()
+        .map(|name| Ok((name.clone(), column(&name)?)))
+        .collect::<PyResult<_>>()?;
+
+    let node_id = |value: &Py<PyAny>, name: &str| -> PyResult<String> {
+        value.extract(py).map_err(|_| {
+            pyo3::exceptions::PyTypeError::new_err(format!(
+                "Column '{}' must hold string node ids, got '{}'; convert it with .astype(str)",
+                name,
+                value.bind(py).get_type().name().map(|n| n.to_string()).unwrap_or_default()
+            ))
+        })
+    };
+
+    let mut vertex = Vertex::from_nodes(py, HashMap::new());
+    for (row, (from, to)) in sources.iter().zip(&targets).enumerate() {
+        let from_id = node_id(from, source)?;
+        let to_id = node_id(to, target)?;
+        ensure_node(&mut vertex, py, &from_id)?;
+        let attr = attrs
+            .iter()
+            .map(|(name, values)| (name.clone(), values[row].clone_ref(py)))
+            .collect();
+        add_adjacency_edge(&mut vertex, py, &from_id, to_id, Some(attr))?;
+    }
+
+    Py::new(py, vertex)
+}
+
+fn ensure_node(vertex: &mut Vertex, py: Python<'_>, id: &str) -> PyResult<()> {
+    if !vertex.nodes.contains_key(id) {
+        manipulation::add_node(vertex, py, id.to_string(), None)?;
+    }
+    Ok(())
+}
+
+fn add_adjacency_edge(
+    vertex: &mut Vertex,
+    py: Python<'_>,
+    from_id: &str,
+    to_id: String,
+    attr: Option<HashMap<String, Py<PyAny>>>,
+) -> PyResult<()> {
+    ensure_node(vertex, py, &to_id)?;
+    manipulation::add_edge(vertex, py, from_id.to_string(), to_id, attr)?;
+    Ok(())
+}
+
+/// Export the graph as `{node: [targets]}`, or `{node: {target: attr}}` when
+/// `with_attrs` is set. Every node gets a key; parallel edges repeat the
+/// target in the list form and keep the last edge's attributes in the dict form.
+pub fn to_adjacency_dict(vertex: &Vertex, py: Python<'_>, with_attrs: bool) -> PyResult<Py<PyAny>> {
+    let mut ids: Vec<&String> = vertex.nodes.keys().collect();
+    ids.sort();
+
+    let result = PyDict::new(py);
+    for id in ids {
+        let node_ref = vertex.nodes[id].bind(py).borrow();
+        if with_attrs {
+            let targets = PyDict::new(py);
+            for edge in &node_ref.edges {
+                let edge_ref = edge.bind(py).borrow();
+                let to_id = edge_ref.to_node.bind(py).borrow().id.clone();
+                targets.set_item(to_id, map_to_dict(py, &edge_ref.attr)?)?;
+            }
+            result.set_item(id, targets)?;
+        } else {
+            let targets = PyList::empty(py);
+            for edge in &node_ref.edges {
+                let edge_ref = edge.bind(py).borrow();
+                targets.append(edge_ref.to_node.bind(py).borrow().id.clone())?;
+            }
+            result.set_item(id, targets)?;
+        }
+    }
+    Ok(result.into_any().unbind())
+}
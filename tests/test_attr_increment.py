@@ -0,0 +1,175 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, GraphError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def test_node_attr_increment_fresh_and_existing_key():
+    v = Vertex()
+    node = v.add_node("a", {"score": 1.5})
+    calls = []
+    v.on_node_update_callbacks.append(
+        lambda vertex, n, key, new, old: calls.append((key, new, old))
+    )
+
+    assert node.attr_increment("visits") == 1
+    assert node.attr_increment("visits", 4) == 5
+    assert node.attr_increment("score", 2) == pytest.approx(3.5)
+
+    assert node.attr["visits"] == 5
+    assert calls == [("visits", 1, None), ("visits", 5, 1), ("score", 3.5, 1.5)]
+
+
+def test_edge_attr_increment_fires_callbacks_once():
+    v = Vertex()
+    v.add_node("a", {})
+    v.add_node("b", {})
+    edge = v.add_edge("a", "b", {"weight": 2})
+    calls = []
+    v.on_edge_update_callbacks.append(
+        lambda vertex, e, key, new, old: calls.append((key, new, old))
+    )
+
+    assert edge.attr_increment("weight", 0.5) == pytest.approx(2.5)
+    assert edge.attr_increment("count") == 1
+    assert calls == [("weight", 2.5, 2), ("count", 1, None)]
+
+
+def test_attr_increment_rejects_non_numeric_values():
+    v = Vertex()
+    node = v.add_node("a", {"label": "x", "flag": True})
+
+    with pytest.raises(TypeError, match="not numeric"):
+        node.attr_increment("label")
+    with pytest.raises(TypeError, match="not numeric"):
+        node.attr_increment("flag")
+    with pytest.raises(TypeError, match="int or float"):
+        node.attr_increment("visits", "1")
+    assert node.attr["label"] == "x"
+    assert "visits" not in node.attr
+
+
+# ---- set_default_attr ----
+
+def test_set_default_attr_only_fills_missing_nodes():
+    v = Vertex()
+    v.add_node("a", {"weight": 3})
+    v.add_node("b", {})
+    v.add_node("c", {"weight": None})
+    v.add_node("d", {"other": 1})
+    calls = []
+    v.on_node_update_callbacks.append(lambda vertex, n, key, new, old: calls.append((n.id, key, new, old)))
+
+    assert v.set_default_attr("weight", 1.0) == 2
+    assert [v[n].attr["weight"] for n in "abcd"] == [3, 1.0, None, 1.0]
+    assert calls == [("b", "weight", 1.0, None), ("d", "weight", 1.0, None)]
+    assert v.set_default_attr("weight", 2.0) == 0
+    assert len(calls) == 2
+
+
+def test_set_default_edge_attr_only_fills_missing_edges():
+    v = Vertex()
+    for node_id in "abc":
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 5})
+    v.add_edge("b", "c", {})
+    calls = []
+    v.on_edge_update_callbacks.append(lambda vertex, e, key, new, old: calls.append((e.from_node.id, new)))
+
+    assert v.set_default_edge_attr("weight", 1.0) == 1
+    assert v["a"].edges[0].attr["weight"] == 5
+    assert v["b"].edges[0].attr["weight"] == 1.0
+    assert calls == [("b", 1.0)]
+
+
+# ---- rename_attr ----
+
+def test_rename_attr_moves_name_to_label_on_every_node():
+    v = Vertex()
+    v.add_node("a", {"name": "A", "age": 1})
+    v.add_node("b", {"name": "B"})
+    v.add_node("c", {})
+    calls = []
+    v.on_node_update_callbacks.append(lambda vertex, n, key, new, old: calls.append((n.id, key, new, old)))
+
+    assert v.rename_attr("name", "label") == 2
+    assert v["a"].attr == {"label": "A", "age": 1}
+    assert v["b"].attr == {"label": "B"}
+    assert v["c"].attr == {}
+    assert calls == [("a", "label", "A", None), ("b", "label", "B", None)]
+
+
+def test_rename_attr_on_edges_and_collisions():
+    v = Vertex()
+    for node_id in "abc":
+        v.add_node(node_id, {"label": node_id})
+    v.add_edge("a", "b", {"w": 2.0})
+    v.add_edge("b", "c", {})
+    assert v.rename_attr("w", "weight", on="edges") == 1
+    assert v["a"].edges[0].attr == {"weight": 2.0}
+
+    v["a"].attr_set("name", "A")
+    with pytest.raises(GraphError, match="already has 'label'"):
+        v.rename_attr("name", "label")
+    assert v["a"].attr == {"label": "a", "name": "A"}
+    with pytest.raises(ValueError, match="expected 'nodes' or 'edges'"):
+        v.rename_attr("w", "weight", on="graph")
+
+
+def test_rename_attr_survives_callback_dropping_the_old_key_elsewhere():
+    v = Vertex()
+    v.add_node("a", {"k": 1})
+    v.add_node("b", {"k": 2})
+
+    def drop_k_from_b(vertex, node, key, new, old):
+        b = vertex["b"]
+        b.attr = {name: value for name, value in b.attr.items() if name != "k"}
+
+    v["a"].on_update_callbacks.append(drop_k_from_b)
+    # The old values are all taken out before the first callback fires
+    assert v.rename_attr("k", "j") == 2
+    assert v["a"].attr == {"j": 1}
+    assert v["b"].attr == {"j": 2}
+
+
+def test_typed_attr_getters_return_values_of_the_right_type():
+    v = Vertex()
+    node = v.add_node("a", {"name": "A", "age": 3, "score": 2, "ok": True})
+    v.add_node("b", {})
+    edge = v.add_edge("a", "b", {"weight": 1.5, "type": "knows"})
+
+    assert node.attr_get_str("name") == "A"
+    assert node.attr_get_int("age") == 3
+    score = node.attr_get_float("score")
+    assert score == 2.0 and isinstance(score, float)
+    assert node.attr_get_bool("ok") is True
+    assert node.attr_get_str("missing") is None
+    assert edge.attr_get_float("weight") == 1.5
+    assert edge.attr_get_str("type") == "knows"
+
+
+def test_typed_attr_getters_raise_type_error_on_mismatch():
+    v = Vertex()
+    node = v.add_node("a", {"name": "A", "age": 3, "ok": True})
+    v.add_node("b", {})
+    edge = v.add_edge("a", "b", {"weight": "heavy"})
+
+    with pytest.raises(TypeError, match="'age' must be str"):
+        node.attr_get_str("age")
+    with pytest.raises(TypeError):
+        node.attr_get_int("name")
+    with pytest.raises(TypeError):
+        node.attr_get_int("ok")  # bool is not accepted as int
+    with pytest.raises(TypeError):
+        node.attr_get_bool("age")
+    with pytest.raises(TypeError):
+        edge.attr_get_float("weight")
@@ -0,0 +1,169 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, CancelledError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def build(node_ids, edges, both_ways=False):
+    v = Vertex()
+    for node_id in node_ids:
+        v.add_node(node_id, {})
+    for u, w, *attr in edges:
+        v.add_edge(u, w, attr[0] if attr else {})
+        if both_ways:
+            v.add_edge(w, u, attr[0] if attr else {})
+    return v
+
+
+def disconnected():
+    """Undirected path a-b-c plus a separate pair d-e (edges in both directions)."""
+    return build(["a", "b", "c", "d", "e"], [("a", "b"), ("b", "c"), ("d", "e")], both_ways=True)
+
+
+# ---- closeness_centrality ----
+
+def test_closeness_uncorrected_on_disconnected_graph():
+    scores = disconnected().closeness_centrality(wf_improved=False)
+    assert scores["b"] == pytest.approx(1.0)
+    assert scores["a"] == pytest.approx(2 / 3)
+    # The centre of the tiny d-e island looks as central as b
+    assert scores["d"] == pytest.approx(1.0)
+
+
+def test_closeness_wasserman_faust_scales_by_reachable_fraction():
+    corrected = disconnected().closeness_centrality()
+    assert corrected["b"] == pytest.approx(1.0 * 2 / 4)
+    assert corrected["a"] == pytest.approx(2 / 3 * 2 / 4)
+    assert corrected["d"] == pytest.approx(1.0 * 1 / 4)
+    assert corrected["b"] > corrected["d"]
+
+
+def test_closeness_uses_incoming_weighted_distances():
+    v = build(["a", "b", "c"], [("a", "b", {"w": 2.0}), ("b", "c", {"w": 1.0})])
+    scores = v.closeness_centrality(weight_field="w", wf_improved=False)
+    assert scores["a"] == 0.0
+    assert scores["b"] == pytest.approx(1 / 2)
+    assert scores["c"] == pytest.approx(2 / (3.0 + 1.0))
+
+
+# ---- harmonic_centrality ----
+
+def test_harmonic_centrality_hand_computed_with_unreachable_node():
+    # a -> b -> c, a -> c; d is isolated and reaches nothing
+    v = build(["a", "b", "c", "d"], [("a", "b"), ("b", "c"), ("a", "c")])
+    scores = v.harmonic_centrality()
+    assert scores == pytest.approx({"a": 0.0, "b": 1.0, "c": 2.0, "d": 0.0})
+
+
+def test_harmonic_centrality_weighted():
+    v = build(["a", "b", "c"], [("a", "b", {"w": 2.0}), ("b", "c", {"w": 2.0})])
+    scores = v.harmonic_centrality(weight_field="w")
+    assert scores["c"] == pytest.approx(1 / 2 + 1 / 4)
+
+
+# ---- progress ----
+
+def test_progress_reports_increasing_completion():
+    calls = []
+    scores = disconnected().harmonic_centrality(progress=lambda done, total: calls.append((done, total)))
+    assert calls == [(1, 5), (2, 5), (3, 5), (4, 5), (5, 5)]
+    assert scores == disconnected().harmonic_centrality()
+
+    # 250 nodes are split into at most 100 batches
+    big = build([f"n{i:03d}" for i in range(250)], [(f"n{i:03d}", f"n{i + 1:03d}") for i in range(249)])
+    calls = []
+    big.closeness_centrality(progress=lambda done, total: calls.append(done))
+    assert len(calls) <= 100
+    assert calls == sorted(set(calls)) and calls[-1] == 250
+
+    calls = []
+    big.batch_shortest_paths([("n000", "n010"), ("n005", "n010")], progress=lambda *args: calls.append(args))
+    assert calls == [(1, 2), (2, 2)]
+
+
+# ---- betweenness_centrality_sampled ----
+
+def exact_betweenness(node_ids, edges):
+    """Brute force: for every ordered pair, the share of shortest paths through each node."""
+    out = {n: [w for u, w in edges if u == n] for n in node_ids}
+
+    def count_paths(source):
+        dist, sigma, queue = {source: 0}, {source: 1}, [source]
+        for node in queue:
+            for nxt in out[node]:
+                if nxt not in dist:
+                    dist[nxt] = dist[node] + 1
+                    sigma[nxt] = 0
+                    queue.append(nxt)
+                if dist[nxt] == dist[node] + 1:
+                    sigma[nxt] += sigma[node]
+        return dist, sigma
+
+    runs = {n: count_paths(n) for n in node_ids}
+    scores = dict.fromkeys(node_ids, 0.0)
+    for s in node_ids:
+        dist_s, sigma_s = runs[s]
+        for t in dist_s:
+            for v in node_ids:
+                if v in (s, t) or v not in dist_s or t not in runs[v][0]:
+                    continue
+                if dist_s[v] + runs[v][0][t] == dist_s[t]:
+                    scores[v] += sigma_s[v] * runs[v][1][t] / sigma_s[t]
+    return scores
+
+
+def test_betweenness_sampled_with_all_sources_is_exact():
+    node_ids = ["a", "b", "c", "d", "e", "f"]
+    edges = [("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("d", "e"), ("e", "f"), ("f", "a"), ("c", "e")]
+    v = build(node_ids, edges)
+    expected = exact_betweenness(node_ids, edges)
+    scores = v.betweenness_centrality_sampled(len(node_ids), seed=1)
+    assert scores == pytest.approx(expected)
+    assert scores["d"] > 0 and scores["e"] > scores["b"]
+
+
+def test_betweenness_sampled_estimate_is_seeded_and_weighted():
+    v = build(["a", "b", "c"], [("a", "b", {"w": 1.0}), ("b", "c", {"w": 1.0}), ("a", "c", {"w": 5.0})])
+    # Only the weighted path a -> b -> c runs through b
+    assert v.betweenness_centrality_sampled(3, weight_field="w") == {"a": 0.0, "b": 1.0, "c": 0.0}
+    assert v.betweenness_centrality_sampled(3)["b"] == 0.0
+    # Sampling only "a" (1 of 3 sources) scales its dependency by 3
+    estimates = {tuple(v.betweenness_centrality_sampled(1, seed=s, weight_field="w").values()) for s in range(20)}
+    assert {sum(e) for e in estimates} <= {0.0, 3.0}
+    assert v.betweenness_centrality_sampled(2, seed=5) == v.betweenness_centrality_sampled(2, seed=5)
+    with pytest.raises(ValueError, match="num_samples"):
+        v.betweenness_centrality_sampled(4)
+
+
+# ---- should_cancel ----
+
+def test_should_cancel_stops_after_first_batch():
+    polls, progress = [], []
+
+    def cancel_after_first():
+        polls.append(len(polls))
+        return len(polls) > 1
+
+    with pytest.raises(CancelledError, match="after 1 of 5"):
+        disconnected().closeness_centrality(
+            progress=lambda done, total: progress.append(done),
+            should_cancel=cancel_after_first,
+        )
+    assert progress == [1]
+    assert len(polls) == 2
+    assert issubclass(CancelledError, KeyboardInterrupt)
+
+    # A callback that never cancels changes nothing
+    scores = disconnected().harmonic_centrality(should_cancel=lambda: False)
+    assert scores == disconnected().harmonic_centrality()
+    with pytest.raises(CancelledError):
+        disconnected().batch_shortest_paths([("a", "c")], should_cancel=lambda: True)
@@ -0,0 +1,77 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+STRATEGIES = ["largest_first", "smallest_last", "random_sequential", "saturation_largest_first"]
+
+
+def complete_graph(n):
+    v = Vertex()
+    for i in range(n):
+        v.add_node(str(i), {})
+    for i in range(n):
+        for j in range(i + 1, n):
+            v.add_edge(str(i), str(j), None)
+    return v
+
+
+def bipartite_graph():
+    """K_{2,3} with edges pointing both ways between the two sides."""
+    v = Vertex()
+    left, right = ["l0", "l1"], ["r0", "r1", "r2"]
+    for node_id in left + right:
+        v.add_node(node_id, {})
+    for l in left:
+        for r in right:
+            v.add_edge(l, r, None)
+    v.add_edge("r0", "l1", None)
+    return v
+
+
+def assert_proper(v, coloring):
+    assert set(coloring) == set(v.keys())
+    for node in v:
+        for edge in node.edges:
+            if edge.to_node.id != node.id:
+                assert coloring[node.id] != coloring[edge.to_node.id]
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_complete_graph_needs_four_colors(strategy):
+    v = complete_graph(4)
+    coloring, num_colors = v.greedy_coloring(strategy, seed=1, return_num_colors=True)
+    assert num_colors == 4
+    assert_proper(v, coloring)
+
+
+@pytest.mark.parametrize("strategy", STRATEGIES)
+def test_bipartite_graph_needs_two_colors(strategy):
+    v = bipartite_graph()
+    coloring = v.greedy_coloring(strategy, seed=1)
+    assert_proper(v, coloring)
+    assert len(set(coloring.values())) == 2
+    assert coloring["l0"] == coloring["l1"]
+
+
+def test_self_loops_and_isolated_nodes():
+    v = Vertex()
+    v.add_node("a", {})
+    v.add_node("b", {})
+    v.add_edge("a", "a", None)
+    assert v.greedy_coloring() == {"a": 0, "b": 0}
+    assert Vertex().greedy_coloring(return_num_colors=True) == ({}, 0)
+
+
+def test_unknown_strategy_raises():
+    with pytest.raises(ValueError, match="Unknown coloring strategy"):
+        complete_graph(3).greedy_coloring("rainbow")
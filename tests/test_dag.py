@@ -0,0 +1,296 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, GraphError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def build(node_ids, edges):
+    v = Vertex()
+    for node_id in node_ids:
+        v.add_node(node_id, {})
+    for u, w in edges:
+        v.add_edge(u, w, None)
+    return v
+
+
+def one_cycle_graph():
+    """s -> a -> b -> c -> a, c -> t: the cycle a/b/c sits between s and t."""
+    return build(["s", "a", "b", "c", "t"], [("s", "a"), ("a", "b"), ("b", "c"), ("c", "a"), ("c", "t")])
+
+
+# ---- is_dag ----
+
+def test_is_dag():
+    assert build(["a", "b", "c"], [("a", "b"), ("b", "c"), ("a", "c")]).is_dag()
+    assert not one_cycle_graph().is_dag()
+    assert not build(["a"], [("a", "a")]).is_dag()
+    assert Vertex().is_dag()
+
+
+# ---- condensation ----
+
+def test_condensation_merges_cycle_into_one_super_node():
+    v = one_cycle_graph()
+    dag, mapping = v.condensation()
+
+    assert dag.is_dag()
+    assert len(dag) == 3
+    assert mapping["a"] == mapping["b"] == mapping["c"]
+    assert len({mapping["s"], mapping["a"], mapping["t"]}) == 3
+
+    merged = dag[str(mapping["a"])]
+    assert merged.attr["members"] == ["a", "b", "c"]
+    assert merged.attr["size"] == 3
+
+
+def test_condensation_is_topologically_ordered():
+    dag, mapping = one_cycle_graph().condensation()
+    assert mapping["s"] < mapping["a"] < mapping["t"]
+    for node in dag:
+        for edge in node.edges:
+            assert int(node.id) < int(edge.to_node.id)
+    # s -> cycle and cycle -> t, with the two internal edges dropped
+    assert sum(len(node.edges) for node in dag) == 2
+
+
+def test_condensation_of_dag_keeps_every_node():
+    v = build(["a", "b", "c"], [("a", "b"), ("a", "b"), ("b", "c")])
+    dag, mapping = v.condensation()
+    assert len(dag) == 3
+    assert sorted(mapping.values()) == [0, 1, 2]
+    assert len(dag[str(mapping["a"])].edges) == 1
+
+
+# ---- largest_scc ----
+
+def test_largest_scc_drops_peripheral_nodes():
+    v = one_cycle_graph()
+    v.add_node("u", {})
+    v.add_edge("t", "u", None)
+    v.add_edge("u", "t", None)
+
+    core = v.largest_scc()
+    assert set(core.keys()) == {"a", "b", "c"}
+    assert core.is_strongly_connected()
+    assert sorted((n.id, e.to_node.id) for n in core for e in n.edges) == [("a", "b"), ("b", "c"), ("c", "a")]
+
+
+def test_largest_scc_ties_and_empty_graph():
+    v = build(["x", "y"], [("x", "y")])
+    assert list(v.largest_scc().keys()) == ["x"]
+    assert len(Vertex().largest_scc()) == 0
+
+
+# ---- is_weakly_connected / is_strongly_connected ----
+
+def test_directed_cycle_is_strongly_connected():
+    v = build(["a", "b", "c"], [("a", "b"), ("b", "c"), ("c", "a")])
+    assert v.is_strongly_connected()
+    assert v.is_weakly_connected()
+
+
+def test_directed_chain_is_only_weakly_connected():
+    v = build(["a", "b", "c"], [("a", "b"), ("b", "c")])
+    assert v.is_weakly_connected()
+    assert not v.is_strongly_connected()
+
+
+def test_disconnected_graph_is_neither():
+    v = build(["a", "b", "c"], [("a", "b"), ("b", "a")])
+    assert not v.is_weakly_connected()
+    assert not v.is_strongly_connected()
+
+
+def test_connectivity_of_trivial_graphs():
+    assert build(["a"], []).is_strongly_connected()
+    assert build(["a"], []).is_weakly_connected()
+    assert not Vertex().is_weakly_connected()
+    assert not Vertex().is_strongly_connected()
+
+
+# ---- common_reachable ----
+
+def test_common_reachable_intersects_descendants():
+    # a and b each have a private branch but both feed into shared -> sink
+    v = build(
+        ["a", "b", "a_only", "b_only", "shared", "sink"],
+        [("a", "a_only"), ("b", "b_only"), ("a", "shared"), ("b", "shared"), ("shared", "sink")],
+    )
+    assert v.common_reachable(["a", "b"]) == {"shared", "sink"}
+    assert v.common_reachable(["a"]) == {"a_only", "shared", "sink"}
+    assert v.common_reachable([]) == set()
+
+
+def test_common_reachable_includes_source_only_on_cycle():
+    v = one_cycle_graph()
+    assert v.common_reachable(["s"]) == {"a", "b", "c", "t"}
+    assert v.common_reachable(["a", "s"]) == {"a", "b", "c", "t"}
+    with pytest.raises(KeyError):
+        v.common_reachable(["missing"])
+
+
+# ---- probable_reachable ----
+
+def test_probable_reachable_keeps_best_product_and_prunes_unlikely_nodes():
+    v = build(["s", "a", "b", "c", "far"], [])
+    v.add_edge("s", "a", {"probability": 0.9})
+    v.add_edge("s", "b", {"probability": 0.5})
+    v.add_edge("a", "b", {"probability": 0.8})   # s -> a -> b = 0.72 beats 0.5
+    v.add_edge("b", "c", {})                      # missing probability counts as 1.0
+    v.add_edge("c", "far", {"probability": 0.1})  # 0.072 is below the threshold
+
+    reach = v.probable_reachable("s")
+    assert set(reach) == {"s", "a", "b", "c"}
+    assert reach["s"] == 1.0
+    assert reach["b"] == pytest.approx(0.72)
+    assert reach["c"] == pytest.approx(0.72)
+    assert v.probable_reachable("s", min_prob=0.05)["far"] == pytest.approx(0.072)
+    assert set(v.probable_reachable("s", min_prob=0.8)) == {"s", "a"}
+
+    with pytest.raises(KeyError):
+        v.probable_reachable("missing")
+    with pytest.raises(ValueError):
+        v.probable_reachable("s", min_prob=1.5)
+    v.add_edge("s", "far", {"probability": 2.0})
+    with pytest.raises(ValueError):
+        v.probable_reachable("s")
+
+
+# ---- approximate_diameter ----
+
+def test_approximate_diameter_is_exact_on_path_graph():
+    ids = [f"n{i}" for i in range(6)]
+    # Mixed edge directions: the sweep treats the path as undirected
+    v = build(ids, [("n2", "n1"), ("n1", "n0"), ("n2", "n3"), ("n3", "n4"), ("n4", "n5")])
+    assert v.approximate_diameter() == 5
+
+
+def test_approximate_diameter_takes_largest_component():
+    v = build(["a", "b", "c", "x", "y"], [("a", "b"), ("b", "c"), ("x", "y")])
+    assert v.approximate_diameter() == 2
+    assert v.approximate_diameter(iterations=1) <= 2
+
+
+def test_approximate_diameter_trivial_graphs_and_bad_iterations():
+    assert Vertex().approximate_diameter() == 0
+    assert build(["a"], []).approximate_diameter() == 0
+    with pytest.raises(ValueError, match="iterations"):
+        build(["a"], []).approximate_diameter(iterations=0)
+
+
+# ---- eccentricity / center / periphery ----
+
+def test_center_and_periphery_of_path_graph():
+    ids = ["n0", "n1", "n2", "n3", "n4"]
+    # Mixed edge directions: eccentricity treats the path as undirected
+    v = build(ids, [("n0", "n1"), ("n2", "n1"), ("n2", "n3"), ("n3", "n4")])
+    assert v.eccentricity() == {"n0": 4.0, "n1": 3.0, "n2": 2.0, "n3": 3.0, "n4": 4.0}
+    assert v.center() == ["n2"]
+    assert v.periphery() == ["n0", "n4"]
+
+
+def test_weighted_center_and_per_component():
+    v = build(["a", "b", "c"], [])
+    v.add_edge("a", "b", {"w": 1.0})
+    v.add_edge("b", "c", {"w": 3.0})
+    assert v.eccentricity(weight_field="w") == {"a": 4.0, "b": 3.0, "c": 4.0}
+    assert v.center(weight_field="w") == ["b"]
+
+    v.add_node("x", {})
+    v.add_node("y", {})
+    v.add_edge("x", "y", {})
+    with pytest.raises(GraphError, match="not connected"):
+        v.center()
+    assert v.center(per_component=True) == ["b", "x", "y"]
+    assert v.periphery(per_component=True) == ["a", "c", "x", "y"]
+    assert Vertex().center() == []
+
+
+# ---- coarsen ----
+
+def test_coarsen_two_disjoint_pairs():
+    # a <-> b, c -> d, b -> c twice, d -> e
+    v = build(["a", "b", "c", "d", "e"], [("a", "b"), ("b", "a"), ("c", "d"), ("b", "c"), ("b", "c"), ("d", "e")])
+    coarse = v.coarsen([("a", "b"), ("d", "c")])
+
+    assert sorted(coarse.keys()) == ["a", "c", "e"]
+    assert coarse.meta["coarse_to_original"] == {"a": ["a", "b"], "c": ["c", "d"], "e": ["e"]}
+    assert coarse["a"].attr == {"members": ["a", "b"], "size": 2}
+    assert coarse["e"].attr["size"] == 1
+
+    assert [(e.to_node.id, e.attr["count"]) for e in coarse["a"].edges] == [("c", 2)]
+    assert [(e.to_node.id, e.attr["count"]) for e in coarse["c"].edges] == [("e", 1)]
+    assert coarse.edge_count() == 2
+
+
+def test_coarsen_unions_overlapping_pairs():
+    v = build(["a", "b", "c", "d"], [("a", "b"), ("c", "d")])
+    coarse = v.coarsen([("b", "c"), ("a", "b")])
+    assert coarse.meta["coarse_to_original"] == {"a": ["a", "b", "c"], "d": ["d"]}
+    assert [e.to_node.id for e in coarse["a"].edges] == ["d"]
+
+    with pytest.raises(ValueError, match="not found"):
+        v.coarsen([("a", "zz")])
+
+
+# ---- quotient_by ----
+
+def category_graph():
+    v = Vertex()
+    for node_id, category in [("apple", "fruit"), ("pear", "fruit"), ("leek", "veg"), ("kale", "veg"), ("salt", None)]:
+        v.add_node(node_id, {} if category is None else {"category": category})
+    v.add_edge("apple", "leek", {"weight": 2.0})
+    v.add_edge("pear", "leek", {"weight": 0.5})
+    v.add_edge("pear", "kale", {"weight": 1.0})
+    v.add_edge("apple", "pear", {"weight": 3.0})
+    v.add_edge("kale", "apple", {})
+    v.add_edge("salt", "leek", {})
+    return v
+
+
+def test_quotient_by_groups_nodes_into_summary_graph():
+    summary = category_graph().quotient_by("category", weight_field="weight")
+    assert sorted(summary.keys()) == ["fruit", "veg"]
+    assert summary.meta["group_members"] == {"fruit": ["apple", "pear"], "veg": ["kale", "leek"]}
+    assert summary["veg"].attr == {"category": "veg", "members": ["kale", "leek"], "size": 2}
+
+    fruit_edges = {e.to_node.id: e.attr for e in summary["fruit"].edges}
+    assert fruit_edges == {
+        "fruit": {"count": 1, "weight": 3.0},
+        "veg": {"count": 3, "weight": 3.5},
+    }
+    # The missing weight counts as 1.0
+    assert [(e.to_node.id, e.attr) for e in summary["veg"].edges] == [("fruit", {"count": 1, "weight": 1.0})]
+
+
+def test_quotient_by_can_drop_self_loops():
+    summary = category_graph().quotient_by("category", self_loops=False)
+    assert [e.to_node.id for e in summary["fruit"].edges] == ["veg"]
+    assert summary["fruit"].edges[0].attr == {"count": 3, "weight": 3.0}
+    assert summary.edge_count() == 2
+
+
+# ---- collapse_multi_edges ----
+
+def test_collapse_multi_edges_sums_parallel_edges():
+    v = build(["a", "b", "c"], [("a", "b"), ("a", "b"), ("a", "b"), ("b", "a")])
+    v.add_edge("a", "c", {"weight": 2.5, "label": "x"})
+    v.add_edge("a", "c", {"weight": 0.5})
+
+    simple = v.collapse_multi_edges()
+    assert [(e.to_node.id, e.attr) for e in simple["a"].edges] == [
+        ("b", {"weight": 3.0, "multiplicity": 3}),
+        ("c", {"weight": 3.0, "label": "x", "multiplicity": 2}),
+    ]
+    assert simple["b"].edges[0].attr == {"weight": 1.0, "multiplicity": 1}
+    assert simple.edge_count() == 3
+    assert v.edge_count() == 6
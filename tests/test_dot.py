@@ -0,0 +1,67 @@
+import os
+import re
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def community_graph():
+    v = Vertex()
+    v.add_node("a", {"community": 0, "score": 1.0})
+    v.add_node("b", {"community": 0, "score": 3.0})
+    v.add_node("c", {"community": 1, "score": 2.0})
+    v.add_node("d", {"community": 2})
+    v.add_node("e", {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+    return v
+
+
+def node_line(dot, node_id):
+    return next(line for line in dot.splitlines() if line.strip().startswith(f'"{node_id}" ['))
+
+
+def fillcolor(dot, node_id):
+    match = re.search(r'fillcolor="([^"]+)"', node_line(dot, node_id))
+    return match.group(1) if match else None
+
+
+def test_to_dot_lists_nodes_and_edges():
+    dot = community_graph().to_dot()
+    assert dot.startswith("digraph {")
+    assert '"a" -> "b";' in dot
+    assert '"b" -> "c";' in dot
+    assert "color" not in dot
+
+
+def test_to_dot_color_by_distinct_values_get_distinct_colors():
+    dot = community_graph().to_dot(color_by="community")
+    assert fillcolor(dot, "a") == fillcolor(dot, "b")
+    colors = {fillcolor(dot, n) for n in ("a", "c", "d")}
+    assert len(colors) == 3
+    assert fillcolor(dot, "e") is None
+
+
+def test_to_dot_size_by_scales_width():
+    dot = community_graph().to_dot(size_by="score")
+    assert "width=0.500" in node_line(dot, "a")
+    assert "width=2.000" in node_line(dot, "b")
+    assert "width=1.250" in node_line(dot, "c")
+    assert "width" not in node_line(dot, "d")
+
+
+def test_to_dot_escapes_and_rejects_non_numeric_size():
+    v = Vertex()
+    v.add_node('say "hi"', {"score": "high"})
+    assert '"say \\"hi\\""' in v.to_dot()
+    with pytest.raises(TypeError, match="size_by"):
+        v.to_dot(size_by="score")
@@ -0,0 +1,210 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Edge, Vertex, GraphError, NodeNotFoundError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def recount(v):
+    return sum(len(node.edges) for node in v.nodes.values())
+
+
+def test_edge_count_matches_recount_after_adds_and_removes():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+    v.add_edge("c", "a", {})
+    assert v.edge_count() == recount(v) == 4
+
+    assert v.remove_edge("a", "b") == 2
+    assert v.edge_count() == recount(v) == 2
+    assert v["b"].inverse_edges == []
+
+    assert v.remove_edge("a", "c") == 0
+    v.add_edge("c", "b", {})
+    assert v.remove_edge("b", "c") == 1
+    assert v.edge_count() == recount(v) == 2
+    assert v.validate() == []
+
+
+def test_remove_edge_by_id_and_missing_node():
+    v = Vertex()
+    v.add_node("a", {})
+    v.add_node("b", {})
+    v.add_edge("a", "b", {}).id = "keep"
+    v.add_edge("a", "b", {}).id = "drop"
+
+    assert v.remove_edge("a", "b", edge_id="drop") == 1
+    assert [e.id for e in v["a"].edges] == ["keep"]
+    assert [e.id for e in v["b"].inverse_edges] == ["keep"]
+    assert v.edge_count() == 1
+
+    with pytest.raises(ValueError, match="not found"):
+        v.remove_edge("a", "missing")
+
+
+def test_validate_and_repair_after_direct_edge_assignment():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("a", "c", {})
+    v["a"].edges = []
+    assert v.edge_count() == 0 and v.validate() == []
+
+    # Nodes of a derived graph have no owning vertex to keep in step
+    v.add_edge("a", "b", {})
+    v.add_edge("a", "c", {})
+    sub = v.filter(ids=["a", "b", "c"])
+    sub["a"].edges = []
+    assert sub.edge_count() == 2
+    issues = sub.validate()
+    assert len(issues) == 1 and "edge_count" in issues[0]
+    assert sub.repair() == issues
+    assert sub.edge_count() == 0
+    assert sub.validate() == []
+
+
+def test_edge_assignment_never_underflows_edge_count():
+    v = Vertex()
+    v.add_node("a", {})
+    v.add_node("b", {})
+    e = Edge(v["a"], v["b"], {}, None)
+    v["a"].edges = [e]
+    v["b"].inverse_edges = [e]
+    assert v.edge_count() == 1
+
+    assert v.remove_edge("a", "b") == 1
+    assert v.edge_count() == 0 and v.validate() == []
+
+    v["b"].inverse_edges = [e]
+    v.apply_patch(v.diff(v.filter(ids=["a"])))
+    assert v.keys() == ["a"]
+    assert v.edge_count() == 0 and v.validate() == []
+
+
+def test_derived_vertices_carry_edge_count():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {"keep": node_id != "c"})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+
+    sub = v.filter(ids=["a", "b"])
+    assert sub.edge_count() == recount(sub) == 1
+    assert v.prune() == 0
+
+    kept, _ = v.partition(keep=True, keep_cross_edges=True)
+    assert kept.edge_count() == 2
+    assert kept.prune() == 1
+    assert kept.edge_count() == recount(kept) == 1
+    assert v["a"].bfs().edge_count() == recount(v["a"].bfs())
+
+
+def test_remove_isolated_nodes_keeps_connected_nodes():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "lonely", "alone"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("c", "c", {})
+
+    removed = []
+    v.on_node_remove_callbacks.append(lambda vertex, node: removed.append(node.id))
+
+    assert v.remove_isolated_nodes() == 2
+    assert removed == ["alone", "lonely"]
+    assert sorted(v.keys()) == ["a", "b", "c"]
+    assert v.edge_count() == 2
+    assert v.remove_isolated_nodes() == 0
+
+
+def test_clear_edges_and_clear():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+    v.meta["source"] = "test"
+    removed = []
+    v.on_node_remove_callbacks.append(lambda vertex, node: removed.append(node.id))
+
+    assert v.clear_edges() == 2
+    assert v.edge_count() == 0 and v.node_count() == 3
+    assert v["b"].edges == [] and v["b"].inverse_edges == []
+
+    v.add_edge("a", "c", {})
+    v.clear()
+    assert v.node_count() == 0
+    assert v.edge_count() == 0
+    assert v.meta == {}
+    assert removed == ["a", "b", "c"]
+
+    v.add_node("d", {})
+    v.clear(fire=False)
+    assert v.node_count() == 0 and removed == ["a", "b", "c"]
+
+
+def test_relabel_prefixes_every_node_and_edges_follow():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {"name": node_id.upper()})
+    v.add_edge("a", "b", {"weight": 1.0})
+    v.add_edge("b", "c", {})
+    v.add_edge("c", "a", {})
+
+    v.relabel({node_id: f"x:{node_id}" for node_id in ["a", "b", "c"]})
+    assert sorted(v.nodes) == ["x:a", "x:b", "x:c"]
+    node = v.get_node("x:a")
+    assert node.id == "x:a" and node.attr["name"] == "A"
+    assert [(e.from_node.id, e.to_node.id) for e in node.edges] == [("x:a", "x:b")]
+    assert v.shortest_path_bfs("x:a", "x:c").node_count() == 3
+    assert not v.has_node("a")
+    assert v.edge_count() == 3
+
+    v.relabel({"x:a": "x:b", "x:b": "x:a"})
+    assert v.get_node("x:b").attr["name"] == "A"
+
+
+def test_reverse_in_place_turns_successors_into_predecessors():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    ab = v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("b", "c", {})
+    v.add_edge("c", "c", {})
+
+    v.reverse_in_place()
+    a, b = v.get_node("a"), v.get_node("b")
+    assert a.edges == [] and [e.from_node.id for e in a.inverse_edges] == ["b"]
+    assert sorted(e.to_node.id for e in b.edges) == ["a"]
+    assert sorted(e.from_node.id for e in b.inverse_edges) == ["c"]
+    assert b.edges[0] is ab
+    assert (ab.from_node.id, ab.to_node.id) == ("b", "a")
+    assert sorted((e.from_node.id, e.to_node.id) for e in v.get_node("c").edges) == [("c", "b"), ("c", "c")]
+    assert v.edge_count() == 3 and v.validate() == []
+    assert v.is_reachable("c", "a") and not v.is_reachable("a", "c")
+
+
+def test_relabel_rejects_collisions_without_changes():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    with pytest.raises(GraphError):
+        v.relabel({"a": "c"})
+    with pytest.raises(GraphError):
+        v.relabel({"a": "z", "b": "z"})
+    with pytest.raises(NodeNotFoundError):
+        v.relabel({"a": "y", "missing": "z"})
+    assert sorted(v.nodes) == ["a", "b", "c"]
+    assert v.get_node("a").id == "a"
@@ -0,0 +1,116 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, GraphError, NodeNotFoundError, EdgeNotFoundError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def small_graph():
+    """a -> b (weight 2), c is isolated."""
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"id": "ab", "weight": 2.0})
+    return v
+
+
+# ---- hierarchy ----
+
+def test_exception_hierarchy():
+    assert issubclass(GraphError, ValueError)
+    for cls in (NodeNotFoundError, EdgeNotFoundError):
+        assert issubclass(cls, GraphError)
+        assert issubclass(cls, KeyError)
+    assert NodeNotFoundError.__module__ == "ironweaver"
+
+
+# ---- node not found ----
+
+@pytest.mark.parametrize(
+    "call",
+    [
+        lambda v: v["missing"],
+        lambda v: v.get_node("missing"),
+        lambda v: v.add_edge("a", "missing", {}),
+        lambda v: v.remove_edge("missing", "b"),
+        lambda v: v.shortest_path_bfs("missing", "b"),
+        lambda v: v.shortest_path_bfs("a", "missing"),
+        lambda v: v.shortest_path_tree("missing"),
+        lambda v: v.bfs_frontiers("missing"),
+        lambda v: v.filter(ids=["missing"], strict=True),
+    ],
+)
+def test_missing_node_raises_node_not_found(call):
+    with pytest.raises(NodeNotFoundError, match="missing"):
+        call(small_graph())
+
+
+def test_node_not_found_message_is_not_quoted():
+    with pytest.raises(KeyError) as info:
+        small_graph()["missing"]
+    assert str(info.value) == "Node with id 'missing' not found"
+
+
+def test_getitem_scalar_and_list():
+    v = small_graph()
+    assert v["a"].id == "a"
+
+    sub = v[["a", "b"]]
+    assert isinstance(sub, Vertex)
+    assert sorted(sub.keys()) == ["a", "b"]
+    assert sub.edge_count() == 1
+
+    with pytest.raises(NodeNotFoundError) as info:
+        v[["a", "x", "y"]]
+    assert str(info.value) == "Nodes not found: 'x', 'y'"
+    with pytest.raises(NodeNotFoundError) as info:
+        v[["x", "a", "x", "y", "x"]]
+    assert str(info.value) == "Nodes not found: 'x', 'y'"
+    with pytest.raises(TypeError):
+        v[3]
+
+
+# ---- edge not found ----
+
+def test_remove_edge_unknown_edge_id_raises_edge_not_found():
+    v = small_graph()
+    with pytest.raises(EdgeNotFoundError, match="nope"):
+        v.remove_edge("a", "b", edge_id="nope")
+    assert v.edge_count() == 1
+    # Without edge_id nothing matching is not an error
+    assert v.remove_edge("b", "a") == 0
+
+
+# ---- graph errors ----
+
+def test_unreachable_target_raises_graph_error():
+    with pytest.raises(GraphError, match="not reachable") as info:
+        small_graph().shortest_path_bfs("a", "c")
+    assert not isinstance(info.value, KeyError)
+    with pytest.raises(GraphError, match="not reachable"):
+        small_graph().path_neighborhood("a", "c")
+
+
+def test_negative_weight_raises_graph_error():
+    v = small_graph()
+    v.add_edge("b", "c", {"weight": -1.0})
+    with pytest.raises(GraphError, match="Negative edge weight"):
+        v.shortest_path_tree("a", weight_field="weight")
+    with pytest.raises(GraphError, match="Negative edge weight"):
+        v.filter(ids=["a"]).expand_weighted(v, max_cost=10.0)
+
+
+def test_duplicate_node_raises_graph_error():
+    v = small_graph()
+    with pytest.raises(GraphError, match="already exists") as info:
+        v.add_node("a", {})
+    assert not isinstance(info.value, KeyError)
+    assert v.node_count() == 3
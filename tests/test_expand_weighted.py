@@ -0,0 +1,149 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def cost_graph():
+    """s -> a -> b -> c -> d is a cheap chain (0.5 each); s -> x is one expensive hop."""
+    v = Vertex()
+    for node_id in ["s", "a", "b", "c", "d", "x", "y"]:
+        v.add_node(node_id, {})
+    for u, w in [("s", "a"), ("a", "b"), ("b", "c"), ("c", "d")]:
+        v.add_edge(u, w, {"weight": 0.5})
+    v.add_edge("s", "x", {"weight": 5.0})
+    v.add_edge("x", "y", {"weight": 0.1})
+    return v
+
+
+def test_cheap_chain_included_expensive_hop_excluded():
+    full = cost_graph()
+    seed = full.filter(ids=["s"])
+    result = seed.expand_weighted(full, max_cost=2.0)
+    assert set(result.keys()) == {"s", "a", "b", "c", "d"}
+
+    # hop-depth expansion gets the opposite answer
+    assert "x" in seed.expand(full, depth=1)
+    assert "d" not in seed.expand(full, depth=1)
+
+
+def test_budget_is_inclusive_and_edges_are_rebuilt():
+    full = cost_graph()
+    result = full.filter(ids=["s"]).expand_weighted(full, max_cost=1.0)
+    assert set(result.keys()) == {"s", "a", "b"}
+    assert [e.to_node.id for e in result["a"].edges] == ["b"]
+    assert result["b"].edges == []
+
+
+def test_custom_weight_field_and_default_cost():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"cost": 3})
+    v.add_edge("a", "c", {})
+    result = v.filter(ids=["a"]).expand_weighted(v, 1.0, weight_field="cost")
+    assert set(result.keys()) == {"a", "c"}
+
+
+def test_negative_budget_or_weight_raises():
+    full = cost_graph()
+    seed = full.filter(ids=["s"])
+    with pytest.raises(ValueError):
+        seed.expand_weighted(full, max_cost=-1.0)
+
+    full.add_edge("a", "x", {"weight": -1.0})
+    with pytest.raises(ValueError, match="Negative edge weight"):
+        seed.expand_weighted(full, max_cost=10.0)
+
+
+def test_annotate_distance_records_hops_and_cost():
+    full = cost_graph()
+    seed = full.filter(ids=["s", "b"])
+    result = seed.expand(full, depth=2, annotate_distance=True)
+    hops = {node.id: node.meta["distance"] for node in result}
+    assert hops == {"s": 0, "b": 0, "a": 1, "x": 1, "c": 1, "y": 2, "d": 2}
+
+    weighted = full.filter(ids=["s"]).expand_weighted(full, max_cost=1.0, annotate_distance=True)
+    assert {node.id: node.meta["distance"] for node in weighted} == {"s": 0.0, "a": 0.5, "b": 1.0}
+
+    # Without the flag nothing is written
+    assert "distance" not in seed.expand(full)["a"].meta
+
+
+def test_hop_limit_cuts_off_path_within_cost_budget():
+    full = cost_graph()
+    seed = full.filter(ids=["s"])
+
+    # The cost budget alone reaches d after four cheap hops
+    assert "d" in seed.expand_weighted(full, max_cost=2.0)
+
+    both = seed.expand_weighted(full, max_cost=2.0, max_depth=2)
+    assert set(both.keys()) == {"s", "a", "b"}
+
+
+def test_hop_limit_falls_back_to_dearer_shorter_path():
+    v = Vertex()
+    for node_id in ["s", "a", "b", "t"]:
+        v.add_node(node_id, {})
+    v.add_edge("s", "a", {"weight": 0.1})
+    v.add_edge("a", "b", {"weight": 0.1})
+    v.add_edge("b", "t", {"weight": 0.1})
+    v.add_edge("s", "t", {"weight": 1.0})
+    seed = v.filter(ids=["s"])
+
+    result = seed.expand_weighted(v, max_cost=1.0, max_depth=1, annotate_distance=True)
+    assert {node.id: node.meta["distance"] for node in result} == {"s": 0.0, "a": 0.1, "t": 1.0}
+
+    # With room for three hops the cheap chain wins again
+    result = seed.expand_weighted(v, max_cost=1.0, max_depth=3, annotate_distance=True)
+    assert result["t"].meta["distance"] == pytest.approx(0.3)
+
+
+# ---- budget_subgraph ----
+
+def hub_graph():
+    """Seed "s" links to a hub and three plain nodes; the hub is pointed at by many."""
+    v = Vertex()
+    for node_id in ["s", "hub", "p1", "p2", "p3"] + [f"f{i}" for i in range(5)]:
+        v.add_node(node_id, {"name": node_id})
+    for target in ["p1", "hub", "p2", "p3"]:
+        v.add_edge("s", target, {})
+    for i in range(5):
+        v.add_edge(f"f{i}", "hub", {})
+    v.add_edge("hub", "p3", {})
+    v.add_edge("p1", "p2", {})
+    return v
+
+
+def test_budget_subgraph_has_max_nodes_and_all_seeds():
+    v = hub_graph()
+    for scoring in ["pagerank", "degree"]:
+        sub = v.budget_subgraph(["s", "f0"], 4, scoring=scoring)
+        assert sub.node_count() == 4
+        assert {"s", "f0"} <= set(sub.keys())
+        # The hub outranks p1/p2/p3 under either score
+        assert "hub" in sub.keys()
+    sub = v.budget_subgraph(["s"], 3)
+    assert sorted(sub.keys()) == ["hub", "p3", "s"]   # p3 is fed by s and the hub
+    assert sorted(e.to_node.id for e in sub["s"].edges) == ["hub", "p3"]
+    assert sub["hub"].attr["name"] == "hub"
+
+
+def test_budget_subgraph_stops_when_nothing_is_reachable():
+    v = hub_graph()
+    assert sorted(v.budget_subgraph(["p2"], 5).keys()) == ["p2"]
+    with pytest.raises(ValueError, match="seeds"):
+        v.budget_subgraph(["s", "hub"], 1)
+    with pytest.raises(ValueError, match="expected 'pagerank' or 'degree'"):
+        v.budget_subgraph(["s"], 3, scoring="random")
+    with pytest.raises(KeyError):
+        v.budget_subgraph(["missing"], 3)
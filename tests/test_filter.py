@@ -203,3 +203,122 @@ def test_filter_mixed_modes_raises():
     import pytest
     with pytest.raises(ValueError):
         v.filter(lambda n: True, type="A")
+
+
+def test_filter_ids_strict_raises_on_missing_id():
+    v = build_graph()
+    import pytest
+    with pytest.raises(ValueError, match="'gone' not found"):
+        v.filter(ids=["n1", "gone"])
+
+
+def test_filter_ids_non_strict_skips_missing_ids():
+    v = build_graph()
+    v.meta["source"] = "test"
+    sub = v.filter(ids=["n1", "n2", "gone"], strict=False)
+    assert sorted(sub.keys()) == ["n1", "n2"]
+    assert sub.node_count() == 2
+    assert [e.to_node.id for e in sub["n1"].edges] == ["n2"]
+    assert sub.meta["found_ids"] == {"n1", "n2"}
+    assert sub.meta["source"] == "test"
+    assert "found_ids" not in v.meta
+
+    empty = v.filter(ids=["gone"], strict=False)
+    assert empty.node_count() == 0
+    assert empty.meta["found_ids"] == set()
+
+
+def test_filter_by_id_prefix_and_regex():
+    import re
+    import pytest
+    v = build_rich_graph()
+    by_prefix = v.filter_by_id(prefix="test_")
+    assert sorted(by_prefix.keys()) == ["test_a", "test_b", "test_c"]
+    assert by_prefix.edge_count() == 2
+
+    by_regex = v.filter_by_id(pattern=re.compile(r"_[ce]$"))
+    assert sorted(by_regex.keys()) == ["other_e", "test_c"]
+    assert by_regex.edge_count() == 0
+
+    both = v.filter_by_id(prefix="other_", pattern="d")
+    assert sorted(both.keys()) == ["other_d"]
+
+    with pytest.raises(ValueError):
+        v.filter_by_id()
+    with pytest.raises(ValueError, match="Invalid pattern"):
+        v.filter_by_id(pattern="(")
+
+
+def test_filter_by_id_keeps_compiled_pattern_flags():
+    import re
+    import pytest
+    v = Vertex()
+    for node_id in ["Person:1", "person:2", "place:3"]:
+        v.add_node(node_id, {})
+    folded = v.filter_by_id(pattern=re.compile("^person", re.IGNORECASE))
+    assert sorted(folded.keys()) == ["Person:1", "person:2"]
+    assert sorted(v.filter_by_id(pattern=re.compile("^person")).keys()) == ["person:2"]
+    verbose = v.filter_by_id(pattern=re.compile(r"^ p \w+ : 3  # places", re.X))
+    assert sorted(verbose.keys()) == ["place:3"]
+
+    with pytest.raises(ValueError, match="flags"):
+        v.filter_by_id(pattern=re.compile("person", re.ASCII))
+    with pytest.raises(ValueError, match="lookarounds"):
+        v.filter_by_id(pattern=re.compile("person(?=:)"))
+
+
+def test_filter_and_expand_keep_tags():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v["a"].add_tag("vip")
+    v["b"].add_tag("new")
+    v["b"].add_tag("vip")
+
+    filtered = v.filter(ids=["a", "b"])
+    assert filtered["a"].tags() == ["vip"]
+    assert filtered["b"].tags() == ["new", "vip"]
+    assert [n.id for n in filtered.nodes_with_tag("vip")] == ["a", "b"]
+
+    expanded = v.filter(id="a").expand(v, 1)
+    assert expanded["b"].tags() == ["new", "vip"]
+    # The copies are independent of the source
+    filtered["a"].add_tag("seen")
+    assert v["a"].tags() == ["vip"]
+
+
+def test_partition_is_disjoint_and_complete():
+    v = build_graph()
+    v.add_node("n4", {"type": "field"})
+    matched, rest = v.partition(type="field")
+    assert set(matched.keys()) == {"n1", "n4"}
+    assert set(rest.keys()) == {"n2", "n3"}
+    assert set(matched.keys()).isdisjoint(rest.keys())
+    assert set(matched.keys()) | set(rest.keys()) == set(v.keys())
+
+
+def test_partition_drops_cross_edges_by_default():
+    v = build_graph()  # n1 -> n2 -> n3
+    matched, rest = v.partition(type="field")
+    assert matched["n1"].edges == []
+    assert [e.to_node.id for e in rest["n2"].edges] == ["n3"]
+    assert [e.from_node.id for e in rest["n3"].inverse_edges] == ["n2"]
+    assert rest["n2"].inverse_edges == []
+
+
+def test_partition_keep_cross_edges():
+    v = build_graph()
+    matched, rest = v.partition(type="field", keep_cross_edges=True)
+    cross = matched["n1"].edges
+    assert [e.to_node.id for e in cross] == ["n2"]
+    assert cross[0].to_node is rest["n2"]
+    assert rest["n2"].inverse_edges[0].from_node is matched["n1"]
+    # the dangling side can be cleaned up with prune
+    assert matched.prune() == 1
+
+
+def test_partition_without_filters_raises():
+    import pytest
+    with pytest.raises(ValueError):
+        build_graph().partition()
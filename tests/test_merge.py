@@ -0,0 +1,284 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, GraphError, NodeNotFoundError, EdgeNotFoundError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+# ---- merge ----
+
+def merge_inputs():
+    left = Vertex()
+    left.add_node("a", {"name": "Alice", "tags": ["x"]})
+    left.add_node("b", {})
+    left.add_edge("a", "b", {"type": "knows"})
+    right = Vertex()
+    right.add_node("a", {"age": 30, "tags": ["y"]})
+    right.add_node("c", {})
+    right.add_edge("a", "c", {"type": "knows"})
+    right.add_node("b", {})
+    right.add_edge("a", "b", {"type": "knows"})
+    right.add_edge("a", "b", {"type": "likes"})
+    return left, right
+
+
+def test_merge_on_conflict_combines_attributes():
+    left, right = merge_inputs()
+    seen = []
+
+    def combine(node_id, self_node, other_node):
+        seen.append(node_id)
+        merged = {**self_node.attr, **other_node.attr}
+        merged["tags"] = self_node.attr.get("tags", []) + other_node.attr.get("tags", [])
+        return merged
+
+    merged = left.merge(right, on_conflict=combine)
+    assert seen == ["a", "b"]
+    assert merged["a"].attr == {"name": "Alice", "age": 30, "tags": ["x", "y"]}
+    assert sorted(merged.keys()) == ["a", "b", "c"]
+    # a -> b "knows" exists in both and is kept once
+    types = sorted((e.to_node.id, e.attr["type"]) for e in merged["a"].edges)
+    assert types == [("b", "knows"), ("b", "likes"), ("c", "knows")]
+    assert merged.edge_count() == 3
+    # Inputs are untouched
+    assert left["a"].attr == {"name": "Alice", "tags": ["x"]}
+    assert left.edge_count() == 1
+
+
+def test_merge_defaults_to_self_attributes():
+    left, right = merge_inputs()
+    merged = left.merge(right)
+    assert merged["a"].attr == {"name": "Alice", "tags": ["x"]}
+    with pytest.raises(TypeError):
+        left.merge(right, on_conflict=lambda *args: 42)
+
+
+def test_merge_unions_node_tags():
+    left, right = merge_inputs()
+    left["a"].add_tag("x")
+    right["a"].add_tag("y")
+    right["c"].add_tag("z")
+    merged = left.merge(right, on_conflict=lambda nid, a, b: dict(a.attr))
+    assert merged["a"].tags() == ["x", "y"]
+    assert merged["b"].tags() == []
+    assert merged["c"].tags() == ["z"]
+    assert left["a"].tags() == ["x"]
+
+
+# ---- diff ----
+
+def test_diff_reports_added_node_and_changed_attribute():
+    before = Vertex()
+    before.add_node("a", {"score": 1, "name": "A"})
+    before.add_node("b", {})
+    before.add_edge("a", "b", {"type": "knows"})
+    after = Vertex()
+    after.add_node("a", {"score": 2, "name": "A"})
+    after.add_node("b", {})
+    after.add_node("c", {"name": "C"})
+    after.add_edge("a", "b", {"type": "knows"})
+
+    assert before.diff(after) == {
+        "added_nodes": {"c": {"name": "C"}},
+        "removed_nodes": [],
+        "added_edges": [],
+        "removed_edges": [],
+        "changed_attrs": {"a": {"score": {"old": 1, "new": 2}}},
+    }
+
+
+def test_diff_pairs_edges_by_endpoints_type_and_attributes():
+    before = Vertex()
+    before.add_node("a", {"gone": True})
+    before.add_node("b", {})
+    before.add_edge("a", "b", {"type": "knows"})
+    before.add_edge("a", "b", {"type": "knows"})
+    after = Vertex()
+    after.add_node("a", {})
+    after.add_node("b", {})
+    after.add_edge("a", "b", {"type": "knows"})
+    after.add_edge("a", "b", {"type": "knows", "since": 2020})
+    after.add_edge("a", "b", {"type": "likes"})
+
+    delta = before.diff(after)
+    # The changed parallel edge is reported as removed with its old attributes
+    # and added with the new ones; the unchanged one is not reported
+    assert delta["removed_edges"] == [
+        {"from_id": "a", "to_id": "b", "type": "knows", "attr": {"type": "knows"}}
+    ]
+    assert delta["added_edges"] == [
+        {"from_id": "a", "to_id": "b", "type": "knows", "attr": {"type": "knows", "since": 2020}},
+        {"from_id": "a", "to_id": "b", "type": "likes", "attr": {"type": "likes"}},
+    ]
+    assert delta["changed_attrs"] == {"a": {"gone": {"old": True}}}
+    assert after.diff(after)["changed_attrs"] == {}
+
+
+# ---- apply_patch ----
+
+def test_apply_patch_replays_diff():
+    a = Vertex()
+    for node_id in ("x", "y", "z"):
+        a.add_node(node_id, {"v": node_id})
+    a.add_edge("x", "y", {"type": "knows"})
+    a.add_edge("x", "y", {"type": "knows"})
+    a.add_edge("y", "z", {})
+    a.add_edge("z", "z", {"type": "self"})
+    b = Vertex()
+    b.add_node("x", {"v": "changed", "extra": 1})
+    b.add_node("y", {})
+    b.add_node("w", {"v": "w"})
+    b.add_edge("x", "y", {"type": "knows"})
+    b.add_edge("w", "x", {"type": "likes"})
+
+    a.apply_patch(a.diff(b))
+
+    assert a.structurally_equal(b)
+    assert a.diff(b) == b.diff(a) == {
+        "added_nodes": {},
+        "removed_nodes": [],
+        "added_edges": [],
+        "removed_edges": [],
+        "changed_attrs": {},
+    }
+    assert a.edge_count() == b.edge_count() == 2
+    assert sorted(a.keys()) == ["w", "x", "y"]
+
+
+def test_apply_patch_round_trips_edge_attribute_change():
+    a = Vertex()
+    b = Vertex()
+    for v in (a, b):
+        v.add_node("x", {})
+        v.add_node("y", {})
+    a.add_edge("x", "y", {"type": "road", "weight": 1.0})
+    a.add_edge("x", "y", {"type": "road", "weight": 5.0})
+    b.add_edge("x", "y", {"type": "road", "weight": 2.5})
+    b.add_edge("x", "y", {"type": "road", "weight": 5.0})
+
+    delta = a.diff(b)
+    assert [e["attr"]["weight"] for e in delta["removed_edges"]] == [1.0]
+    assert [e["attr"]["weight"] for e in delta["added_edges"]] == [2.5]
+
+    a.apply_patch(delta)
+    assert a.structurally_equal(b)
+    assert sorted(e.attr["weight"] for e in a["x"].edges) == [2.5, 5.0]
+    assert a.edge_count() == 2
+
+
+def test_apply_patch_fires_callbacks():
+    a = Vertex()
+    a.add_node("x", {"v": 1})
+    a.add_node("gone", {})
+    b = Vertex()
+    b.add_node("x", {"v": 2})
+    b.add_node("new", {})
+    events = []
+    a.on_node_add_callbacks.append(lambda v, n: events.append(("add", n.id)))
+    a.on_node_remove_callbacks.append(lambda v, n: events.append(("remove", n.id)))
+    a.on_node_update_callbacks.append(
+        lambda v, n, key, new, old: events.append(("update", n.id, key, new, old))
+    )
+
+    a.apply_patch(a.diff(b))
+
+    assert events == [("remove", "gone"), ("add", "new"), ("update", "x", "v", 2, 1)]
+
+
+def test_apply_patch_validates_before_changing():
+    a = Vertex()
+    a.add_node("x", {})
+    with pytest.raises(NodeNotFoundError):
+        a.apply_patch({"added_nodes": {"y": {}}, "removed_nodes": ["missing"]})
+    with pytest.raises(EdgeNotFoundError, match="does not exist"):
+        a.apply_patch({"removed_edges": [{"from_id": "x", "to_id": "x", "type": None}]})
+    with pytest.raises(NodeNotFoundError):
+        a.apply_patch({"added_edges": [{"from_id": "x", "to_id": "y", "attr": {}}]})
+    assert sorted(a.keys()) == ["x"]
+
+
+# ---- structurally_equal ----
+
+def snapshot(stamp, weight=1.0):
+    v = Vertex()
+    v.add_node("a", {"name": "A", "seen_at": stamp})
+    v.add_node("b", {"name": "B"})
+    v.add_edge("a", "b", {"weight": weight, "seen_at": stamp})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.meta_set("seen_at", stamp)
+    return v
+
+
+def test_structurally_equal_ignores_listed_attributes():
+    first, second = snapshot("2024-01-01"), snapshot("2024-06-30")
+    assert not first.structurally_equal(second)
+    assert first.structurally_equal(second, ignore_attrs=["seen_at"])
+    assert first.structurally_equal(first)
+
+
+def test_structurally_equal_detects_topology_and_value_changes():
+    base = snapshot("t")
+    assert not base.structurally_equal(snapshot("t", weight=3.0), ignore_attrs=["seen_at"])
+    extra_edge = snapshot("t")
+    extra_edge.add_edge("b", "a", {})
+    assert not base.structurally_equal(extra_edge)
+    extra_node = snapshot("t")
+    extra_node.add_node("c", {})
+    assert not base.structurally_equal(extra_node)
+    reloaded = Vertex.load_from_json(base.save_to_json())
+    assert base.structurally_equal(reloaded)
+
+
+# ---- content_hash ----
+
+def test_content_hash_matches_for_equal_graphs_built_in_any_order():
+    first = snapshot("t")
+    second = Vertex()
+    second.meta_set("seen_at", "t")
+    second.add_node("b", {"name": "B"})
+    second.add_node("a", {"seen_at": "t", "name": "A"})
+    second.add_edge("a", "b", {"weight": 2.0})
+    second.add_edge("a", "b", {"seen_at": "t", "weight": 1.0})
+    digest = first.content_hash()
+    assert len(digest) == 64 and int(digest, 16) >= 0
+    assert second.content_hash() == digest
+    assert first.content_hash() == digest
+
+    changed = snapshot("t", weight=3.0)
+    assert changed.content_hash() != digest
+    extra_edge = snapshot("t")
+    extra_edge.add_edge("b", "a", {})
+    assert extra_edge.content_hash() != digest
+
+
+# ---- edge_set_hash ----
+
+def test_edge_set_hash_tracks_topology_only():
+    base = snapshot("t")
+    fingerprint = base.edge_set_hash()
+    assert 0 <= fingerprint < 2**64
+    # Attributes and insertion order do not matter
+    assert snapshot("other", weight=5.0).edge_set_hash() == fingerprint
+    reordered = Vertex()
+    reordered.add_node("b", {})
+    reordered.add_node("a", {})
+    reordered.add_edge("a", "b", {})
+    reordered.add_edge("a", "b", {})
+    assert reordered.edge_set_hash() == fingerprint
+
+    base.add_edge("b", "a", {})
+    assert base.edge_set_hash() != fingerprint
+    base.remove_edge("b", "a")
+    assert base.edge_set_hash() == fingerprint
+    # Dropping one of the parallel edges changes it too
+    base.remove_edge("a", "b", base["a"].edges[0].id)
+    assert base.edge_set_hash() != fingerprint
+    assert Vertex().edge_set_hash() == 0
@@ -0,0 +1,86 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def test_meta_set_get_round_trip():
+    v = Vertex()
+    v.meta_set("name", "social")
+    v.meta_set("tags", ["a", "b"])
+    assert v.meta_get("name") == "social"
+    assert v.meta_get("tags") == ["a", "b"]
+    assert v.meta["name"] == "social"
+
+
+def test_meta_get_default():
+    v = Vertex()
+    assert v.meta_get("missing") is None
+    assert v.meta_get("missing", 42) == 42
+    v.meta["present"] = 0
+    assert v.meta_get("present", 42) == 0
+
+
+def test_meta_update_sets_all_keys():
+    v = Vertex()
+    v.meta_update({"a": 1, "b": 2})
+    assert v.meta_get("a") == 1
+    assert v.meta_get("b") == 2
+
+
+def test_meta_change_callbacks_fire_on_change_only():
+    v = Vertex()
+    calls = []
+
+    def on_change(vertex, key, new_value, old_value):
+        calls.append((vertex is v, key, new_value, old_value))
+        return True
+
+    v.on_vertex_meta_change_callbacks.append(on_change)
+    v.meta_set("version", 1)
+    v.meta_set("version", 1)  # unchanged: no callback
+    v.meta_set("version", 2)
+    v.meta_update({"version": 2, "owner": "me"})
+
+    assert calls == [
+        (True, "version", 1, None),
+        (True, "version", 2, 1),
+        (True, "owner", "me", None),
+    ]
+
+
+def test_meta_change_callback_can_stop_chain():
+    v = Vertex()
+    seen = []
+    v.on_vertex_meta_change_callbacks.append(lambda *args: seen.append("first") or False)
+    v.on_vertex_meta_change_callbacks.append(lambda *args: seen.append("second"))
+    v.meta_set("k", "v")
+    assert seen == ["first"]
+
+
+def test_has_callbacks_counts_and_reload_drops_them(tmp_path):
+    v = Vertex()
+    v.add_node("a", {})
+    v.on_node_add_callbacks.append(lambda vertex, node: None)
+    v.on_vertex_meta_change_callbacks.append(lambda *args: None)
+    v.on_vertex_meta_change_callbacks.append(lambda *args: None)
+    counts = v.has_callbacks()
+    assert counts["on_node_add_callbacks"] == 1
+    assert counts["on_vertex_meta_change_callbacks"] == 2
+    assert counts["on_edge_add_callbacks"] == 0
+    assert len(counts) == 6
+
+    path = str(tmp_path / "graph.bin")
+    v.save_to_binary(path)
+    reloaded = Vertex.load_from_binary(path)
+    assert set(reloaded.has_callbacks().values()) == {0}
+    assert set(Vertex.load_from_json(v.save_to_json()).has_callbacks().values()) == {0}
@@ -50,6 +50,31 @@ def test_get_metadata_empty_graph():
     assert meta["edge_count"] == 0
 
 
+def test_get_metadata_undirected_counts_reciprocal_pairs_once():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "a", {})
+    v.add_edge("b", "c", {})
+
+    directed = v.get_metadata()
+    assert directed["edge_count"] == 3
+    assert directed["reciprocal_edge_count"] == 1
+    assert directed["average_degree"] == 1.0
+
+    undirected = v.get_metadata(undirected=True)
+    assert undirected["edge_count"] == 2
+    assert undirected["reciprocal_edge_count"] == 1
+    assert undirected["average_degree"] == pytest.approx(4 / 3)
+
+
+def test_get_metadata_chain_has_no_reciprocal_edges():
+    meta = linear_graph().get_metadata(undirected=True)
+    assert meta["edge_count"] == 3
+    assert meta["reciprocal_edge_count"] == 0
+
+
 # ---- save_to_json (no file_path → returns JSON string) ----
 
 def test_save_to_json_no_path_returns_string():
@@ -200,6 +225,25 @@ def test_to_networkx_empty():
     assert g is not None
 
 
+def test_to_networkx_copies_attributes_and_merges_parallel_edges():
+    pytest.importorskip("networkx")
+    v = Vertex()
+    tags = ["x", "y"]
+    v.add_node("a", {"name": "A", "tags": tags})
+    v.add_node("b", {})
+    v.add_edge("a", "b", {"weight": 1.0, "label": "first"})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("b", "a", {})
+    g = v.to_networkx()
+    assert dict(g.nodes["a"]) == {"name": "A", "tags": ["x", "y"]}
+    assert g.nodes["a"]["tags"] is tags   # values are shared, not copied
+    assert dict(g.nodes["b"]) == {}
+    # A DiGraph keeps one a->b edge; later parallel edges win on shared keys
+    assert dict(g.edges["a", "b"]) == {"weight": 2.0, "label": "first"}
+    assert dict(g.edges["b", "a"]) == {}
+    assert g.number_of_edges() == 2
+
+
 # ---- on_edge_add_callbacks ----
 
 def test_on_edge_add_callback_fires():
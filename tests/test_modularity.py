@@ -0,0 +1,131 @@
+import math
+import os
+import random
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def two_cliques(size=6):
+    """Two cliques of *size* nodes ("l0".. and "r0"..) joined by one edge."""
+    v = Vertex()
+    for side in "lr":
+        for i in range(size):
+            v.add_node(f"{side}{i}", {})
+        for i in range(size):
+            for j in range(i + 1, size):
+                v.add_edge(f"{side}{i}", f"{side}{j}", {"w": 1.0})
+    v.add_edge("l0", "r0", {"w": 1.0})
+    return v
+
+
+def test_modularity_perfect_clique_partition_is_high():
+    v = two_cliques()
+    communities = {node_id: 0 if node_id.startswith("l") else 1 for node_id in v.keys()}
+    # 31 edges, 15 inside each clique, each side has weighted degree 31
+    expected = 2 * (15 / 31 - (31 / 62) ** 2)
+    assert v.modularity(communities) == pytest.approx(expected)
+    assert v.modularity(communities, weight_field="w") == pytest.approx(expected)
+    assert v.modularity(communities) > 0.45
+
+
+def test_modularity_random_partition_is_near_zero():
+    v = two_cliques()
+    rng = random.Random(0)
+    scores = []
+    for _ in range(20):
+        communities = {node_id: rng.randrange(2) for node_id in v.keys()}
+        scores.append(v.modularity(communities))
+    assert abs(sum(scores) / len(scores)) < 0.1
+
+
+def test_modularity_validates_communities():
+    v = two_cliques(2)
+    with pytest.raises(ValueError, match="no community"):
+        v.modularity({"l0": 0})
+    with pytest.raises(ValueError, match="not found"):
+        v.modularity({"l0": 0, "l1": 0, "r0": 1, "r1": 1, "zz": 2})
+    empty = Vertex()
+    empty.add_node("a", {})
+    assert empty.modularity({"a": 0}) == 0.0
+
+
+# ---- cut_size ----
+
+def test_cut_size_barbell_cuts_only_the_bridge():
+    v = two_cliques(4)
+    v.add_edge("l1", "l1", {"w": 5.0})
+    halves = {node_id: 0 if node_id.startswith("l") else 1 for node_id in v.keys()}
+    assert v.cut_size(halves) == 1
+    v.add_edge("r1", "l2", {"w": 2.5})
+    assert v.cut_size(halves) == 2
+    assert v.cut_size(halves, weight_field="w") == 3.5
+    assert v.cut_size({node_id: 0 for node_id in v.keys()}) == 0
+    with pytest.raises(ValueError, match="no part"):
+        v.cut_size({"l0": 0})
+
+
+# ---- degree_assortativity ----
+
+def test_degree_assortativity_cliques_of_different_sizes_is_one():
+    v = Vertex()
+    for size, prefix in [(3, "s"), (5, "b")]:
+        for i in range(size):
+            v.add_node(f"{prefix}{i}", {})
+        for i in range(size):
+            for j in range(i + 1, size):
+                v.add_edge(f"{prefix}{i}", f"{prefix}{j}", {})
+    # Every edge joins equal degrees, and the two degrees differ
+    assert v.degree_assortativity() == pytest.approx(1.0)
+
+
+def test_degree_assortativity_star_is_negative():
+    v = Vertex()
+    v.add_node("hub", {})
+    for i in range(5):
+        v.add_node(f"leaf{i}", {})
+        v.add_edge("hub", f"leaf{i}", {"w": 2.0})
+    assert v.degree_assortativity() == pytest.approx(-1.0)
+    assert v.degree_assortativity(weight_field="w") == pytest.approx(-1.0)
+
+    cycle = Vertex()
+    for i in range(4):
+        cycle.add_node(str(i), {})
+    for i in range(4):
+        cycle.add_edge(str(i), str((i + 1) % 4), {})
+    assert math.isnan(cycle.degree_assortativity())  # every degree equal
+    assert math.isnan(Vertex().degree_assortativity())
+
+
+# ---- transitivity ----
+
+def test_transitivity_complete_graph_is_one():
+    assert two_cliques(4).filter(ids=["l0", "l1", "l2", "l3"]).transitivity() == pytest.approx(1.0)
+
+
+def test_transitivity_counts_triangles_over_triples():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d"]:
+        v.add_node(node_id, {})
+    # Triangle a/b/c given in mixed directions and with a parallel edge, plus a pendant d
+    for u, w in [("a", "b"), ("b", "c"), ("a", "c"), ("c", "a"), ("c", "d"), ("d", "d")]:
+        v.add_edge(u, w, {})
+    # 1 triangle, triples: a 1, b 1, c 3
+    assert v.transitivity() == pytest.approx(3 / 5)
+
+    path = Vertex()
+    for node_id in ["x", "y", "z"]:
+        path.add_node(node_id, {})
+    path.add_edge("x", "y", {})
+    path.add_edge("y", "z", {})
+    assert path.transitivity() == 0.0
+    assert Vertex().transitivity() == 0.0
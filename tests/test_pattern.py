@@ -0,0 +1,82 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def build(nodes, edges):
+    v = Vertex()
+    for node_id, attr in nodes:
+        v.add_node(node_id, attr)
+    for a, b, attr in edges:
+        v.add_edge(a, b, attr)
+    return v
+
+
+def knowledge_graph():
+    """Alice knows Bob, Bob works at Acme, Carol knows Bob and works at Acme;
+    x -> y -> z -> x is a directed triangle."""
+    return build(
+        [
+            ("alice", {"kind": "person"}),
+            ("bob", {"kind": "person"}),
+            ("carol", {"kind": "person"}),
+            ("acme", {"kind": "company"}),
+            ("x", {}),
+            ("y", {}),
+            ("z", {}),
+        ],
+        [
+            ("alice", "bob", {"type": "knows"}),
+            ("carol", "bob", {"type": "knows"}),
+            ("bob", "acme", {"type": "works_at"}),
+            ("carol", "acme", {"type": "works_at"}),
+            ("x", "y", {}),
+            ("y", "z", {}),
+            ("z", "x", {}),
+        ],
+    )
+
+
+# ---- match_pattern ----
+
+def test_match_pattern_finds_directed_triangle():
+    triangle = build([("a", {}), ("b", {}), ("c", {})], [("a", "b", {}), ("b", "c", {}), ("c", "a", {})])
+    matches = knowledge_graph().match_pattern(triangle)
+    assert matches == [
+        {"a": "x", "b": "y", "c": "z"},
+        {"a": "y", "b": "z", "c": "x"},
+        {"a": "z", "b": "x", "c": "y"},
+    ]
+
+
+def test_match_pattern_directed_path_with_constraints():
+    path = build(
+        [("p", {"kind": "person"}), ("q", {}), ("r", {"kind": "company"})],
+        [("p", "q", {"type": "knows"}), ("q", "r", {"type": "works_at"})],
+    )
+    g = knowledge_graph()
+    assert g.match_pattern(path) == [
+        {"p": "alice", "q": "bob", "r": "acme"},
+        {"p": "carol", "q": "bob", "r": "acme"},
+    ]
+    assert len(g.match_pattern(path, max_matches=1)) == 1
+
+    # Edge direction and edge attributes both have to agree
+    reversed_path = build([("p", {}), ("q", {"kind": "company"})], [("q", "p", {"type": "works_at"})])
+    assert g.match_pattern(reversed_path) == []
+
+
+def test_match_pattern_rejects_large_patterns():
+    big = build([(f"n{i}", {}) for i in range(11)], [])
+    with pytest.raises(ValueError, match="at most 10"):
+        knowledge_graph().match_pattern(big)
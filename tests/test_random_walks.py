@@ -114,3 +114,36 @@ def test_optional_args_have_defaults():
     with pytest.raises(ValueError):
         v.random_walks("n1", 3, 5, min_length=4)  # min_length > max_length
 
+
+
+def test_include_edge_ids_distinguishes_parallel_edges():
+    v = build_vertex([("a", "b", "x"), ("b", "c", "y")])
+    v.add_edge("a", "b", {"type": "x"}).id = "ab2"
+    v["a"].edges[0].id = "ab1"
+    walks = v.random_walks("a", 3, 200, include_edge_ids=True)
+    # The b -> c edge has no id, so it falls back to "b->c"
+    assert sorted(walks) == [["a", "ab1", "b", "b->c", "c"], ["a", "ab2", "b", "b->c", "c"]]
+
+    import pytest
+    with pytest.raises(ValueError, match="cannot both be True"):
+        v.random_walks("a", 3, 5, include_edge_types=True, include_edge_ids=True)
+
+
+def test_stop_prob_shortens_walks_and_seed_reproduces_them():
+    ids = [f"n{i}" for i in range(10)]
+    v = build_vertex([(a, b, "x") for a in ids for b in ids if a != b])
+
+    full = v.random_walks("n0", 20, 50, allow_revisit=True, seed=1)
+    assert all(len(walk) == 20 for walk in full)
+
+    short = v.random_walks("n0", 20, 200, allow_revisit=True, stop_prob=0.5, seed=1)
+    assert sum(len(walk) for walk in short) / len(short) < 4
+    assert short == v.random_walks("n0", 20, 200, allow_revisit=True, stop_prob=0.5, seed=1)
+
+    # min_length still filters the shortened walks
+    long_only = v.random_walks("n0", 20, 200, min_length=3, allow_revisit=True, stop_prob=0.5, seed=1)
+    assert long_only and all(len(walk) >= 3 for walk in long_only)
+
+    import pytest
+    with pytest.raises(ValueError, match="stop_prob"):
+        v.random_walks("n0", 5, 5, stop_prob=1.5)
@@ -0,0 +1,75 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def chain(n, edge_type=None):
+    v = Vertex()
+    for i in range(n):
+        v.add_node(f"n{i}", {})
+    for i in range(n - 1):
+        v.add_edge(f"n{i}", f"n{i + 1}", {"type": edge_type} if edge_type else {})
+    return v
+
+
+# ---- __repr__ ----
+
+def test_repr_small_graph_lists_all_ids():
+    v = chain(3)
+    assert repr(v) == "Vertex(n0, n1, n2)"
+
+
+def test_repr_of_large_graph_is_bounded():
+    r = repr(chain(1000))
+    assert len(r) < 200
+    assert r.endswith(", ... (+990 more), 1000 nodes)")
+
+
+def test_repr_shows_sorted_prefix_of_ids():
+    v = Vertex()
+    for node_id in ["m", "c", "x", "a", "q", "f", "z", "b", "k", "t", "d", "h"]:
+        v.add_node(node_id, {})
+    assert repr(v) == "Vertex(a, b, c, d, f, h, k, m, q, t, ... (+2 more), 12 nodes)"
+
+
+def test_repr_of_10k_graph_is_short():
+    r = repr(chain(10_000))
+    assert len(r) < 300
+    assert "(+9990 more)" in r
+    assert "10000 nodes" in r
+    assert r.count(", n") == 9  # ten ids shown
+
+
+def test_repr_at_cap_is_not_truncated():
+    r = repr(chain(10))
+    assert "more" not in r
+    assert r.count("n") == 10
+
+
+# ---- __str__ ----
+
+def test_str_summarises_counts_and_edge_types():
+    v = chain(4, edge_type="next")
+    v.add_edge("n0", "n3", {"type": "skip"})
+    s = str(v)
+    lines = s.splitlines()
+    assert lines[0] == "Vertex with 4 nodes and 4 edges"
+    assert "n0, n1, n2, n3" in lines[1]
+    assert lines[2].strip() == "edge types: next (3), skip (1)"
+
+
+def test_str_of_large_graph_samples_ids():
+    s = str(chain(1000))
+    assert "1000 nodes and 999 edges" in s
+    assert "edge types: none" in s
+    assert len(s) < 200
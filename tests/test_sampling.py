@@ -0,0 +1,200 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def weighted_graph(n=30):
+    v = Vertex()
+    for i in range(n):
+        v.add_node(f"n{i}", {"w": i % 3})
+    for i in range(n - 1):
+        v.add_edge(f"n{i}", f"n{i + 1}", {"w": float(i % 2)})
+    return v
+
+
+def edge_key(edge):
+    return (edge.from_node.id, edge.to_node.id)
+
+
+def test_sample_nodes_seed_is_deterministic():
+    v = weighted_graph()
+    assert v.sample_nodes(10, seed=7) == v.sample_nodes(10, seed=7)
+    assert v.sample_nodes(10, weight_field="w", seed=7) == v.sample_nodes(10, weight_field="w", seed=7)
+    assert v.sample_nodes(10, seed=7) != v.sample_nodes(10, seed=8)
+
+
+def test_sample_edges_seed_is_deterministic():
+    v = weighted_graph()
+    first = [edge_key(e) for e in v.sample_edges(5, seed=3)]
+    second = [edge_key(e) for e in v.sample_edges(5, seed=3)]
+    assert first == second
+
+
+def test_sample_without_replacement_is_unique():
+    v = weighted_graph()
+    sample = v.sample_nodes(30, seed=1)
+    assert sorted(sample) == sorted(v.keys())
+
+
+def test_zero_weight_items_are_never_drawn():
+    v = weighted_graph()
+    nodes = v.sample_nodes(20, weight_field="w", seed=5)
+    assert all(v[n].attr["w"] > 0 for n in nodes)
+
+    edges = v.sample_edges(50, weight_field="w", seed=5, replace=True)
+    assert len(edges) == 50
+    assert all(e.attr["w"] > 0 for e in edges)
+
+
+def test_sample_too_many_without_replacement_raises():
+    v = weighted_graph()
+    with pytest.raises(ValueError):
+        v.sample_nodes(31)
+    # only 20 nodes have a positive weight
+    with pytest.raises(ValueError):
+        v.sample_nodes(21, weight_field="w")
+    assert len(v.sample_nodes(100, replace=True, seed=0)) == 100
+
+
+# ---- sample_subgraph ----
+
+def ring_of_cliques():
+    """Four 5-cliques joined in a ring by single edges."""
+    v = Vertex()
+    for c in range(4):
+        for i in range(5):
+            v.add_node(f"c{c}_{i}", {"community": c})
+    for c in range(4):
+        for i in range(5):
+            for j in range(5):
+                if i != j:
+                    v.add_edge(f"c{c}_{i}", f"c{c}_{j}", None)
+        v.add_edge(f"c{c}_0", f"c{(c + 1) % 4}_0", None)
+    return v
+
+
+def weakly_connected(v):
+    ids = set(v.keys())
+    if not ids:
+        return True
+    adjacency = {i: set() for i in ids}
+    for node in v:
+        for edge in node.edges:
+            if edge.to_node.id in ids:
+                adjacency[node.id].add(edge.to_node.id)
+                adjacency[edge.to_node.id].add(node.id)
+    start = next(iter(ids))
+    seen, stack = {start}, [start]
+    while stack:
+        for nb in adjacency[stack.pop()]:
+            if nb not in seen:
+                seen.add(nb)
+                stack.append(nb)
+    return seen == ids
+
+
+@pytest.mark.parametrize("seed", range(10))
+def test_forest_fire_sample_is_connected(seed):
+    v = ring_of_cliques()
+    sample = v.sample_subgraph(8, method="forest_fire", seed=seed)
+    assert len(sample) == 8
+    assert weakly_connected(sample)
+
+
+def test_induced_sample_keeps_internal_edges_only():
+    v = ring_of_cliques()
+    sample = v.sample_subgraph(6, seed=1)
+    assert len(sample) <= 6
+    ids = set(sample.keys())
+    for node in sample:
+        for edge in node.edges:
+            assert edge.to_node.id in ids
+            assert edge.to_node.id in {e.to_node.id for e in v[node.id].edges}
+
+
+def test_sample_subgraph_is_deterministic_and_capped():
+    v = ring_of_cliques()
+    a = v.sample_subgraph(7, method="forest_fire", seed=3)
+    b = v.sample_subgraph(7, method="forest_fire", seed=3)
+    assert sorted(a.keys()) == sorted(b.keys())
+    assert len(v.sample_subgraph(100, method="forest_fire", seed=0)) == 20
+
+
+def test_sample_subgraph_rejects_unknown_method():
+    with pytest.raises(ValueError, match="Unknown sampling method"):
+        ring_of_cliques().sample_subgraph(3, method="snowball")
+
+
+# ---- sparsify ----
+
+def dense_graph(n=20):
+    v = Vertex()
+    for i in range(n):
+        v.add_node(f"n{i}", {"i": i})
+    for i in range(n):
+        for j in range(n):
+            if i != j:
+                v.add_edge(f"n{i}", f"n{j}", {"w": float(j % 2)})
+    return v
+
+
+def test_sparsify_keeps_fraction_of_edges_and_all_nodes():
+    v = dense_graph()
+    sparse = v.sparsify(0.25, seed=1)
+    assert sparse.node_count() == v.node_count()
+    assert sparse["n3"].attr == {"i": 3}
+    assert sparse.edge_count() == round(0.25 * v.edge_count())
+    assert sparse.validate() == []
+    original = {edge_key(e) for n in v for e in n.edges}
+    assert {edge_key(e) for n in sparse for e in n.edges} <= original
+
+
+def test_sparsify_weighted_drops_zero_weight_edges_and_is_deterministic():
+    v = dense_graph()
+    sparse = v.sparsify(0.4, weight_field="w", seed=2)
+    assert all(e.attr["w"] == 1.0 for n in sparse for e in n.edges)
+    again = v.sparsify(0.4, weight_field="w", seed=2)
+    assert {edge_key(e) for n in sparse for e in n.edges} == {edge_key(e) for n in again for e in n.edges}
+
+
+def test_sparsify_bounds():
+    v = dense_graph(5)
+    assert v.sparsify(0.0).edge_count() == 0
+    assert v.sparsify(1.0).edge_count() == v.edge_count()
+    with pytest.raises(ValueError, match="keep_fraction"):
+        v.sparsify(1.5)
+
+
+def test_sample_by_pagerank_over_represents_high_rank_nodes():
+    # Every leaf points at the hub, which only points back at leaf0
+    v = Vertex()
+    v.add_node("hub", {})
+    leaves = [f"leaf{i}" for i in range(9)]
+    for leaf in leaves:
+        v.add_node(leaf, {})
+        v.add_edge(leaf, "hub", {})
+    v.add_edge("hub", "leaf0", {})
+
+    assert v.sample_by_pagerank(3, seed=5) == v.sample_by_pagerank(3, seed=5)
+    counts = dict.fromkeys(["hub"] + leaves, 0)
+    for seed in range(300):
+        picked = v.sample_by_pagerank(2, seed=seed)
+        assert len(set(picked)) == 2
+        for node_id in picked:
+            counts[node_id] += 1
+    assert counts["hub"] > 250
+    assert counts["leaf0"] > max(counts[leaf] for leaf in leaves[1:])
+
+    assert sorted(v.sample_by_pagerank(10, seed=1)) == sorted(["hub"] + leaves)
+    with pytest.raises(ValueError):
+        v.sample_by_pagerank(11)
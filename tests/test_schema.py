@@ -0,0 +1,123 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def test_infer_schema_reports_mixed_types():
+    v = Vertex()
+    v.add_node("a", {"name": "Alice", "age": 30, "active": True})
+    v.add_node("b", {"name": "Bob", "age": "unknown"})
+    v.add_node("c", {"name": "Carol", "age": 41, "score": 0.5})
+    v.add_edge("a", "b", {"weight": 1.5, "type": "knows"})
+    v.add_edge("b", "c", {"weight": 2})
+
+    schema = v.infer_schema()
+    nodes = schema["nodes"]
+    edges = schema["edges"]
+
+    assert nodes["age"]["types"] == ["int", "string"]
+    assert nodes["age"]["mixed"] is True
+    assert nodes["age"]["required"] is True
+
+    assert nodes["name"] == {"types": ["string"], "mixed": False, "count": 3, "required": True}
+    assert nodes["active"]["types"] == ["bool"]
+    assert nodes["score"]["required"] is False
+    assert nodes["score"]["count"] == 1
+
+    assert edges["weight"]["types"] == ["float", "int"]
+    assert edges["weight"]["required"] is True
+    assert edges["type"]["required"] is False
+
+
+def test_infer_schema_empty_graph():
+    assert Vertex().infer_schema() == {"nodes": {}, "edges": {}}
+
+
+# ---- degree_distribution ----
+
+def star_graph(leaves=5):
+    v = Vertex()
+    v.add_node("hub", {})
+    for i in range(leaves):
+        v.add_node(f"leaf{i}", {})
+        v.add_edge("hub", f"leaf{i}", None)
+    return v
+
+
+def test_degree_distribution_star():
+    v = star_graph(5)
+    assert v.degree_distribution() == {5: 1, 0: 5}
+    assert v.degree_distribution("in") == {0: 1, 1: 5}
+    assert v.degree_distribution("total") == {5: 1, 1: 5}
+
+
+def test_degree_distribution_sums_to_node_count():
+    v = star_graph(3)
+    v.add_edge("leaf0", "leaf1", None)
+    dist = v.degree_distribution("total")
+    assert sum(dist.values()) == len(v)
+    assert dist == {3: 1, 2: 2, 1: 1}
+
+
+# ---- edge_attr_histogram ----
+
+def test_edge_attr_histogram_counts_types():
+    v = star_graph(4)
+    v.add_edge("leaf0", "leaf1", {"type": "knows"})
+    v.add_edge("leaf1", "leaf2", {"type": "knows"})
+    v.add_edge("leaf2", "leaf3", {"type": "likes"})
+    v.add_edge("leaf3", "leaf0", {"type": ["a", "b"]})
+    v.add_edge("leaf3", "hub", {"type": 1})
+    v.add_edge("leaf3", "leaf1", {"type": 1.0})
+
+    # The star's untyped edges are skipped
+    assert v.edge_attr_histogram("type") == {"knows": 2, "likes": 1, "['a', 'b']": 1, 1: 2}
+    assert v.edge_attr_histogram("missing") == {}
+
+
+def test_degree_distribution_rejects_unknown_direction():
+    with pytest.raises(ValueError, match="Unknown direction"):
+        star_graph().degree_distribution("sideways")
+
+
+# ---- columnar export ----
+
+def test_nodes_and_edges_to_columns():
+    v = Vertex()
+    v.add_node("b", {"name": "Bob"})
+    v.add_node("a", {"name": "Alice", "age": 30})
+    v.add_node("c", {})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("a", "c", {})
+    v.add_edge("b", "c", {"weight": 1.0})
+
+    nodes = v.nodes_to_columns(["name", "age"])
+    assert nodes == {
+        "id": ["a", "b", "c"],
+        "name": ["Alice", "Bob", None],
+        "age": [30, None, None],
+    }
+    assert all(len(column) == v.node_count() for column in nodes.values())
+
+    edges = v.edges_to_columns(["weight"])
+    assert edges == {
+        "from_id": ["a", "a", "b"],
+        "to_id": ["b", "c", "c"],
+        "weight": [2.0, None, 1.0],
+    }
+    assert all(len(column) == v.edge_count() for column in edges.values())
+
+    with pytest.raises(ValueError, match="id"):
+        v.nodes_to_columns(["id"])
+    with pytest.raises(ValueError, match="to_id"):
+        v.edges_to_columns(["to_id"])
@@ -0,0 +1,587 @@
+import json
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def sample_graph():
+    v = Vertex()
+    v.add_node("a", {"name": "Alice", "score": 0.5, "tags": ["x", "y"]})
+    v.add_node("b", {"name": "Bob", "age": 30})
+    v.add_node("c", {})
+    v.add_edge("a", "b", {"type": "knows", "weight": 2.0})
+    v.add_edge("b", "c", {"type": "likes"})
+    v.meta["project"] = "demo"
+    return v
+
+
+def edge_set(v):
+    return {
+        (e.from_node.id, e.to_node.id, e.attr.get("type"))
+        for node in v
+        for e in node.edges
+    }
+
+
+# ---- JSON Lines ----
+
+def test_jsonl_roundtrip(tmp_path):
+    v = sample_graph()
+    path = tmp_path / "graph.jsonl"
+    v.save_to_jsonl(str(path))
+
+    loaded = Vertex.load_from_jsonl(str(path))
+    assert sorted(loaded.keys()) == ["a", "b", "c"]
+    assert loaded.get_node("a").attr == {"name": "Alice", "score": 0.5, "tags": ["x", "y"]}
+    assert loaded.get_node("b").attr["age"] == 30
+    assert edge_set(loaded) == edge_set(v)
+    assert loaded.meta["project"] == "demo"
+    assert [e.from_node.id for e in loaded.get_node("b").inverse_edges] == ["a"]
+
+
+def test_jsonl_one_record_per_line(tmp_path):
+    v = sample_graph()
+    path = tmp_path / "graph.jsonl"
+    v.save_to_jsonl(str(path))
+
+    lines = path.read_text().splitlines()
+    records = [json.loads(line)["record"] for line in lines]
+    assert records[0] == "metadata"
+    assert records.count("node") == 3
+    assert records.count("edge") == 2
+
+
+# ---- append_to_binary ----
+
+def test_append_to_binary_merges_delta(tmp_path):
+    path = tmp_path / "graph.bin"
+    base = sample_graph()
+    base.save_to_binary(str(path))
+    base_size = path.stat().st_size
+
+    delta = Vertex()
+    delta.add_node("c", {"name": "Carol"})
+    delta.add_node("d", {"name": "Dave"})
+    delta.add_edge("c", "d", {"type": "knows"})
+    delta.append_to_binary(str(path))
+    assert path.stat().st_size > base_size
+
+    merged = Vertex.load_from_binary(str(path))
+    assert sorted(merged.keys()) == ["a", "b", "c", "d"]
+    assert merged.get_node("c").attr == {"name": "Carol"}
+    assert merged.get_node("a").attr["name"] == "Alice"
+    assert edge_set(merged) == edge_set(base) | {("c", "d", "knows")}
+    assert [e.from_node.id for e in merged.get_node("c").inverse_edges] == ["b"]
+
+
+def test_append_to_binary_multiple_deltas(tmp_path):
+    path = tmp_path / "graph.bin"
+    sample_graph().save_to_binary(str(path))
+
+    for i in range(3):
+        delta = Vertex()
+        delta.add_node("a", {})
+        delta.add_node(f"x{i}", {"i": i})
+        delta.add_edge("a", f"x{i}", None)
+        delta.append_to_binary(str(path))
+
+    merged = Vertex.load_from_binary(str(path))
+    assert merged.node_count() == 6
+    assert merged.get_metadata()["edge_count"] == 5
+    assert {e.to_node.id for e in merged.get_node("a").edges} == {"b", "x0", "x1", "x2"}
+
+
+def test_append_to_binary_unchanged_graph_writes_nothing(tmp_path):
+    path = tmp_path / "graph.bin"
+    base = sample_graph()
+    base.save_to_binary(str(path))
+    size = path.stat().st_size
+    base.append_to_binary(str(path))
+    base.append_to_binary(str(path))
+    assert path.stat().st_size == size
+
+    merged = Vertex.load_from_binary(str(path))
+    assert merged.edge_count() == base.edge_count()
+    assert edge_set(merged) == edge_set(base)
+
+
+def test_append_to_binary_writes_only_changes(tmp_path):
+    path = tmp_path / "graph.bin"
+    base = sample_graph()
+    base.save_to_binary(str(path))
+    size = path.stat().st_size
+
+    base.get_node("c").attr_set("name", "Carol")
+    base.add_node("d", {})
+    base.add_edge("c", "d", {"type": "knows"})
+    # A parallel edge equal to an existing one is still a new edge
+    base.add_edge("a", "b", {"type": "knows", "weight": 2.0})
+    base.append_to_binary(str(path))
+    assert 0 < path.stat().st_size - size < size
+
+    merged = Vertex.load_from_binary(str(path))
+    assert merged.edge_count() == 4
+    assert len(merged.get_node("a").edges) == 2
+    assert merged.get_node("c").attr == {"name": "Carol"}
+    assert [e.from_node.id for e in merged.get_node("d").inverse_edges] == ["c"]
+
+    # Changed records replace the stored ones, so removed keys stay removed
+    base.get_node("b").attr = {"name": "Bob"}
+    base.get_node("a").edges[0].attr_set("weight", 3.0)
+    base.append_to_binary(str(path))
+    merged = Vertex.load_from_binary(str(path))
+    assert merged.get_node("b").attr == {"name": "Bob"}
+    assert sorted(e.attr["weight"] for e in merged.get_node("a").edges) == [2.0, 3.0]
+    assert merged.edge_count() == 4
+
+
+def test_append_to_binary_adds_parallel_edge_from_separate_graph(tmp_path):
+    path = tmp_path / "graph.bin"
+    base = Vertex()
+    base.add_node("a", {})
+    base.add_node("b", {})
+    base.add_edge("a", "b", {"w": 1})
+    base.save_to_binary(str(path))
+
+    delta = Vertex()
+    delta.add_node("a", {})
+    delta.add_node("b", {})
+    delta.add_edge("a", "b", {"w": 1})
+    delta.append_to_binary(str(path))
+
+    merged = Vertex.load_from_binary(str(path))
+    assert merged.edge_count() == 2
+    assert [e.attr for e in merged.get_node("a").edges] == [{"w": 1}, {"w": 1}]
+    assert len(merged.get_node("b").inverse_edges) == 2
+
+
+def test_append_to_binary_after_load(tmp_path):
+    path = tmp_path / "graph.bin"
+    sample_graph().save_to_binary(str(path))
+    size = path.stat().st_size
+
+    loaded = Vertex.load_from_binary(str(path))
+    loaded.append_to_binary(str(path))
+    assert path.stat().st_size == size
+
+    loaded.add_edge("c", "a", {"type": "knows"})
+    loaded.append_to_binary(str(path))
+    reloaded = Vertex.load_from_binary(str(path))
+    assert edge_set(reloaded) == edge_set(sample_graph()) | {("c", "a", "knows")}
+
+
+def test_append_to_binary_rejects_removals_and_stale_files(tmp_path):
+    path = tmp_path / "graph.bin"
+    base = sample_graph()
+    base.save_to_binary(str(path))
+    base.remove_edge("b", "c")
+    with pytest.raises(RuntimeError, match="removed"):
+        base.append_to_binary(str(path))
+
+    tracked = sample_graph()
+    tracked.save_to_binary(str(path))
+    other = Vertex()
+    other.add_node("z", {})
+    other.append_to_binary(str(path))
+    tracked.add_node("y", {})
+    with pytest.raises(RuntimeError, match="changed since"):
+        tracked.append_to_binary(str(path))
+
+
+def test_append_to_binary_creates_missing_file(tmp_path):
+    path = tmp_path / "fresh.bin"
+    sample_graph().append_to_binary(str(path))
+    assert Vertex.load_from_binary(str(path)).node_count() == 3
+
+
+# ---- binary header ----
+
+def test_binary_file_starts_with_header(tmp_path):
+    path = tmp_path / "graph.bin"
+    sample_graph().save_to_binary(str(path))
+    data = path.read_bytes()
+    assert data[:8] == b"IRONWVR\0"
+    assert int.from_bytes(data[8:12], "little") == 4
+    assert int.from_bytes(data[12:16], "little") == 0
+
+
+def test_load_legacy_headerless_binary_raises(tmp_path):
+    path = tmp_path / "graph.bin"
+    sample_graph().save_to_binary(str(path))
+    legacy = tmp_path / "legacy.bin"
+    legacy.write_bytes(path.read_bytes()[16:])
+    with pytest.raises(RuntimeError, match="legacy header-less"):
+        Vertex.load_from_binary(str(legacy))
+    with pytest.raises(RuntimeError, match="header"):
+        sample_graph().append_to_binary(str(legacy))
+
+
+def legacy_payload(tmp_path, version=2):
+    """Payload of a one-node graph in the layout of an older format version.
+
+    With fixint encoding the node "c" is laid out as: map length, key, id,
+    attr, meta, edge_ids, inverse_edge_ids, then the 8-byte length of its
+    empty tags list at bytes 58..66 (new in version 3) and the 1-byte tag of
+    its absent subgraph at byte 66 (new in version 4).
+    """
+    v = Vertex()
+    v.add_node("c", {})
+    v.meta["project"] = "demo"
+    path = tmp_path / "single.bin"
+    v.save_to_binary(str(path))
+    payload = path.read_bytes()[16:]
+    assert payload[58:67] == bytes(9)
+    cut = 58 if version < 3 else 66
+    return payload[:cut] + payload[67:]
+
+
+def test_load_version_1_binary_without_flags(tmp_path):
+    v1 = tmp_path / "v1.bin"
+    v1.write_bytes(b"IRONWVR\0" + (1).to_bytes(4, "little") + legacy_payload(tmp_path))
+    loaded = Vertex.load_from_binary(str(v1))
+    assert loaded.keys() == ["c"]
+    assert loaded["c"].tags() == []
+    assert loaded.meta["project"] == "demo"
+
+
+def test_load_version_2_binary_and_refuse_append(tmp_path):
+    v2 = tmp_path / "v2.bin"
+    header = b"IRONWVR\0" + (2).to_bytes(4, "little") + (0).to_bytes(4, "little")
+    v2.write_bytes(header + legacy_payload(tmp_path))
+    assert Vertex.load_from_binary(str(v2)).keys() == ["c"]
+    with pytest.raises(RuntimeError, match="version 2"):
+        sample_graph().append_to_binary(str(v2))
+
+
+def test_load_version_3_binary_without_subgraphs(tmp_path):
+    v3 = tmp_path / "v3.bin"
+    header = b"IRONWVR\0" + (3).to_bytes(4, "little") + (0).to_bytes(4, "little")
+    v3.write_bytes(header + legacy_payload(tmp_path, version=3))
+    loaded = Vertex.load_from_binary(str(v3))
+    assert loaded.keys() == ["c"]
+    assert loaded["c"].get_subgraph() is None
+    with pytest.raises(RuntimeError, match="version 3"):
+        sample_graph().append_to_binary(str(v3))
+
+
+def test_load_newer_binary_version_raises(tmp_path):
+    path = tmp_path / "graph.bin"
+    sample_graph().save_to_binary(str(path))
+    data = bytearray(path.read_bytes())
+    data[8:12] = (99).to_bytes(4, "little")
+    path.write_bytes(bytes(data))
+    with pytest.raises(RuntimeError, match="version 99 is newer"):
+        Vertex.load_from_binary(str(path))
+
+
+# ---- zstd ----
+
+def repetitive_graph():
+    v = Vertex()
+    for i in range(200):
+        v.add_node(f"n{i}", {"kind": "sensor", "label": "temperature reading", "value": 1.5})
+    for i in range(199):
+        v.add_edge(f"n{i}", f"n{i + 1}", {"type": "next", "weight": 1.0})
+    return v
+
+
+def test_binary_zstd_round_trip_and_smaller(tmp_path):
+    v = repetitive_graph()
+    plain = tmp_path / "plain.bin"
+    packed = tmp_path / "packed.bin"
+    v.save_to_binary(str(plain))
+    v.save_to_binary_zstd(str(packed))
+
+    assert packed.stat().st_size < plain.stat().st_size
+    assert packed.read_bytes()[:8] == b"IRONWVR\0"
+
+    for loaded in (Vertex.load_from_binary_zstd(str(packed)), Vertex.load_from_binary(str(packed))):
+        assert loaded.node_count() == 200
+        assert loaded.edge_count() == 199
+        assert loaded["n7"].attr == {"kind": "sensor", "label": "temperature reading", "value": 1.5}
+        assert loaded["n7"].edges[0].to_node.id == "n8"
+
+
+def test_binary_zstd_errors(tmp_path):
+    v = sample_graph()
+    plain = tmp_path / "plain.bin"
+    packed = tmp_path / "packed.bin"
+    v.save_to_binary(str(plain))
+    v.save_to_binary_zstd(str(packed), level=19)
+
+    with pytest.raises(RuntimeError, match="binary file is plain, expected zstd"):
+        Vertex.load_from_binary_zstd(str(plain))
+    with pytest.raises(RuntimeError, match="zstd-compressed"):
+        v.append_to_binary(str(packed))
+    with pytest.raises(ValueError, match="out of range"):
+        v.save_to_binary_zstd(str(packed), level=100)
+
+
+# ---- f16 ----
+
+def test_binary_f16_round_trip_within_tolerance(tmp_path):
+    v = sample_graph()
+    v.add_node("d", {"nested": {"values": [0.1, 1234.5]}})
+    path = tmp_path / "graph_f16.bin"
+    v.save_to_binary_f16(str(path))
+    assert int.from_bytes(path.read_bytes()[12:16], "little") == 2
+
+    for loaded in (Vertex.load_from_binary_f16(str(path)), Vertex.load_from_binary(str(path))):
+        score = loaded["a"].attr["score"]
+        assert isinstance(score, float)
+        assert score == pytest.approx(0.5, rel=1e-3)
+        assert loaded["a"].edges[0].attr["weight"] == pytest.approx(2.0, rel=1e-3)
+        values = loaded["d"].attr["nested"]["values"]
+        assert values == pytest.approx([0.1, 1234.5], rel=1e-3)
+        assert loaded["b"].attr["age"] == 30
+
+
+def test_binary_f16_loader_requires_f16_file(tmp_path):
+    v = sample_graph()
+    path = tmp_path / "graph.bin"
+    v.save_to_binary(str(path))
+    assert edge_set(Vertex.load_from_binary(str(path))) == edge_set(v)
+    with pytest.raises(RuntimeError, match="binary file is plain, expected f16"):
+        Vertex.load_from_binary_f16(str(path))
+
+
+# ---- adjacency dict ----
+
+def test_adjacency_list_roundtrip_auto_creates_targets():
+    adj = {"a": ["b", "c"], "b": ["c"], "c": [], "d": ["e"]}
+    v = Vertex.from_adjacency(adj)
+    assert sorted(v.keys()) == ["a", "b", "c", "d", "e"]
+    assert v.edge_count() == 4
+    assert v.to_adjacency_dict() == {**adj, "e": []}
+    assert v.to_adjacency_dict()["a"] == ["b", "c"]
+
+
+def test_adjacency_dict_with_attrs_roundtrip():
+    adj = {"a": {"b": {"type": "knows", "weight": 2.0}, "c": {}}, "b": {}, "c": {"a": None}}
+    v = Vertex.from_adjacency(adj)
+    assert edge_set(v) == {("a", "b", "knows"), ("a", "c", None), ("c", "a", None)}
+    assert v.to_adjacency_dict(with_attrs=True) == {
+        "a": {"b": {"type": "knows", "weight": 2.0}, "c": {}},
+        "b": {},
+        "c": {"a": {}},
+    }
+    assert Vertex.from_adjacency(v.to_adjacency_dict(with_attrs=True)).to_adjacency_dict(
+        with_attrs=True
+    ) == v.to_adjacency_dict(with_attrs=True)
+
+
+def test_from_adjacency_rejects_string_entry():
+    with pytest.raises(TypeError, match="list of node ids"):
+        Vertex.from_adjacency({"a": "bc"})
+
+
+# ---- pandas edge list ----
+
+def test_from_pandas_edgelist_builds_nodes_and_edges():
+    pd = pytest.importorskip("pandas")
+    df = pd.DataFrame({
+        "src": ["a", "a", "b", "c"],
+        "dst": ["b", "c", "c", "d"],
+        "weight": [1.0, 2.0, 3.0, 4.0],
+        "kind": ["x", "y", "x", "y"],
+    })
+    v = Vertex.from_pandas_edgelist(df, source="src", target="dst")
+    assert v.node_count() == 4
+    assert v.edge_count() == 4
+    assert sorted(v.keys()) == ["a", "b", "c", "d"]
+    assert all(dict(e.attr) == {} for e in v["a"].edges)
+
+    v = Vertex.from_pandas_edgelist(df, "src", "dst", edge_attr="weight")
+    assert sorted(e.attr["weight"] for e in v["a"].edges) == [1.0, 2.0]
+    assert type(v["a"].edges[0].attr["weight"]) is float   # numpy scalars are unboxed
+    v = Vertex.from_pandas_edgelist(df, "src", "dst", edge_attr=True)
+    assert dict(v["c"].edges[0].attr) == {"weight": 4.0, "kind": "y"}
+    v = Vertex.from_pandas_edgelist(df, "src", "dst", edge_attr=["kind"])
+    assert dict(v["b"].edges[0].attr) == {"kind": "x"}
+
+    with pytest.raises(KeyError, match="missing"):
+        Vertex.from_pandas_edgelist(df, source="missing", target="dst")
+    with pytest.raises(TypeError, match="astype"):
+        Vertex.from_pandas_edgelist(pd.DataFrame({"source": [1], "target": [2]}))
+
+
+# ---- deterministic order ----
+
+def test_keys_ordered_is_sorted_and_stable():
+    v = Vertex()
+    ids = [f"n{i}" for i in range(50)]
+    for node_id in reversed(ids):
+        v.add_node(node_id, {})
+    assert sorted(v.keys()) == sorted(ids)
+    assert v.keys(ordered=True) == sorted(ids)
+    assert v.keys(ordered=True) == v.keys(ordered=True)
+
+
+def test_json_output_is_reproducible_and_keeps_edge_order():
+    def build(order):
+        v = Vertex()
+        for node_id in order:
+            v.add_node(node_id, {})
+        for target in ["b", "d", "c"]:
+            v.add_edge("a", target, {})
+        for i in range(12):
+            v.add_edge("c", "d", {"i": i})
+        return v
+
+    first = build(["a", "b", "c", "d"]).save_to_json()
+    second = build(["d", "c", "b", "a"]).save_to_json()
+    strip = lambda s: {k: v for k, v in json.loads(s).items() if k != "metadata"}
+    assert strip(first) == strip(second)
+    assert list(json.loads(first)["nodes"]) == ["a", "b", "c", "d"]
+
+    loaded = Vertex.load_from_json(first)
+    assert [e.to_node.id for e in loaded["a"].edges] == ["b", "d", "c"]
+    assert [e.attr["i"] for e in loaded["c"].edges] == list(range(12))
+    assert [e.attr["i"] for e in loaded["d"].inverse_edges if "i" in e.attr] == list(range(12))
+
+
+def test_save_without_timestamp_is_byte_identical(tmp_path):
+    def build():
+        v = Vertex()
+        v.add_node("a", {"z": 1, "y": {"q": 1, "p": [1, 2]}, "x": "s"})
+        v.add_node("b", {"k": 2.5})
+        v.add_edge("a", "b", {"w": 1, "type": "t", "c": None})
+        v.meta_set("source", "test")
+        v.meta_set("run", 3)
+        return v
+
+    first = build().save_to_json(include_timestamp=False)
+    assert first == build().save_to_json(include_timestamp=False)
+    assert "timestamp" not in json.loads(first)["metadata"]
+    assert "timestamp" in json.loads(build().save_to_json())["metadata"]
+
+    for save in ("save_to_binary", "save_to_binary_zstd", "save_to_binary_f16", "save_to_jsonl"):
+        paths = [tmp_path / f"{save}_{i}" for i in range(2)]
+        for path in paths:
+            getattr(build(), save)(str(path), include_timestamp=False)
+        assert paths[0].read_bytes() == paths[1].read_bytes(), save
+
+
+# ---- numeric coercion ----
+
+def test_load_from_json_coerce_numeric():
+    v = Vertex()
+    v.add_node("a", {"pi": "3.14", "count": "42", "name": "abc", "codes": ["7", "x"]})
+    v.add_node("b", {})
+    v.add_edge("a", "b", {"weight": "0.5"})
+    text = v.save_to_json()
+
+    plain = Vertex.load_from_json(text)
+    assert plain["a"].attr["pi"] == "3.14"
+    assert plain["a"].edges[0].attr["weight"] == "0.5"
+
+    coerced = Vertex.load_from_json(text, coerce_numeric=True)
+    assert coerced["a"].attr["pi"] == 3.14 and isinstance(coerced["a"].attr["pi"], float)
+    assert coerced["a"].attr["count"] == 42 and isinstance(coerced["a"].attr["count"], int)
+    assert coerced["a"].attr["name"] == "abc"
+    assert coerced["a"].attr["codes"] == [7, "x"]
+    assert coerced["a"].edges[0].attr["weight"] == 0.5
+
+
+# ---- tags ----
+
+def test_tags_survive_save_and_load(tmp_path):
+    v = sample_graph()
+    v["a"].add_tag("vip")
+    v["a"].add_tag("reviewed")
+    v["a"].add_tag("vip")
+    v["c"].add_tag("vip")
+    assert v["a"].tags() == ["reviewed", "vip"]
+    assert v["a"].has_tag("vip") and not v["b"].has_tag("vip")
+    assert "vip" not in v["a"].attr
+
+    for save, load, name in [
+        ("save_to_json", "load_from_json", "graph.json"),
+        ("save_to_jsonl", "load_from_jsonl", "graph.jsonl"),
+        ("save_to_binary", "load_from_binary", "graph.bin"),
+    ]:
+        path = str(tmp_path / name)
+        getattr(v, save)(path)
+        loaded = getattr(Vertex, load)(path)
+        assert [n.id for n in loaded.nodes_with_tag("vip")] == ["a", "c"]
+        assert loaded["a"].tags() == ["reviewed", "vip"]
+        assert loaded.nodes_with_tag("missing") == []
+
+
+# ---- nested subgraphs ----
+
+def nested_graph():
+    """build -> deploy at the top; build expands into compile -> link, and
+    compile expands further into parse -> codegen."""
+    compile_steps = Vertex()
+    compile_steps.add_node("parse", {"cost": 1})
+    compile_steps.add_node("codegen", {"cost": 2})
+    compile_steps.add_edge("parse", "codegen", {"type": "then"})
+
+    build_steps = Vertex()
+    build_steps.add_node("compile", {})
+    build_steps.add_node("link", {})
+    build_steps.add_edge("compile", "link", {"type": "then"})
+    build_steps["compile"].set_subgraph(compile_steps)
+
+    v = Vertex()
+    v.add_node("build", {"kind": "process"})
+    v.add_node("deploy", {})
+    v.add_edge("build", "deploy", {"type": "then"})
+    v["build"].set_subgraph(build_steps)
+    return v
+
+
+def test_flatten_two_level_nested_graph():
+    flat = nested_graph().flatten()
+    assert sorted(flat.keys()) == [
+        "build",
+        "build/compile",
+        "build/compile/codegen",
+        "build/compile/parse",
+        "build/link",
+        "deploy",
+    ]
+    assert flat.edge_count() == 3
+    assert [e.to_node.id for e in flat["build/compile/parse"].edges] == ["build/compile/codegen"]
+    assert flat["build/compile/codegen"].attr == {"cost": 2}
+    assert flat["build"].get_subgraph() is None
+    assert sorted(nested_graph().flatten(separator="::").keys())[1] == "build::compile"
+
+
+def test_flatten_rejects_self_nesting():
+    v = Vertex()
+    v.add_node("loop", {})
+    v["loop"].set_subgraph(v)
+    with pytest.raises(ValueError, match="ancestors"):
+        v.flatten()
+    with pytest.raises(ValueError, match="ancestors"):
+        v.save_to_json()
+
+
+def test_nested_subgraphs_survive_save_and_load(tmp_path):
+    v = nested_graph()
+    for save, load, name in [
+        ("save_to_json", "load_from_json", "graph.json"),
+        ("save_to_jsonl", "load_from_jsonl", "graph.jsonl"),
+        ("save_to_binary", "load_from_binary", "graph.bin"),
+        ("save_to_binary_f16", "load_from_binary_f16", "graph.f16"),
+    ]:
+        path = str(tmp_path / name)
+        getattr(v, save)(path)
+        loaded = getattr(Vertex, load)(path)
+        inner = loaded["build"].get_subgraph()
+        assert sorted(inner.keys()) == ["compile", "link"]
+        assert sorted(inner["compile"].get_subgraph().keys()) == ["codegen", "parse"]
+        assert loaded["deploy"].get_subgraph() is None
+        assert sorted(loaded.flatten().keys()) == sorted(v.flatten().keys())
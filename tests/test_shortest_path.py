@@ -0,0 +1,419 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex, GraphError, CancelledError
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def bridge_graph():
+    """a -> b -> e is the short route; a -> c -> d -> e is the detour."""
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d", "e"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"type": "road"})
+    v.add_edge("b", "e", {"type": "road"})
+    v.add_edge("a", "c", {"type": "road"})
+    v.add_edge("c", "d", {"type": "road"})
+    v.add_edge("d", "e", {"type": "road"})
+    return v
+
+
+# ---- exclude_nodes / exclude_edges ----
+
+def test_shortest_path_without_exclusions_uses_bridge():
+    v = bridge_graph()
+    result = v.shortest_path_bfs("a", "e")
+    assert result.meta["nodelist"] == ["a", "b", "e"]
+
+
+def test_exclude_node_forces_detour():
+    v = bridge_graph()
+    result = v.shortest_path_bfs("a", "e", exclude_nodes={"b"})
+    assert result.meta["nodelist"] == ["a", "c", "d", "e"]
+    assert not result.has_node("b")
+
+
+def test_exclude_edge_forces_detour():
+    v = bridge_graph()
+    result = v.shortest_path_bfs("a", "e", exclude_edges={("b", "e")})
+    assert result.meta["nodelist"] == ["a", "c", "d", "e"]
+
+
+def test_excluding_every_route_raises():
+    v = bridge_graph()
+    with pytest.raises(ValueError):
+        v.shortest_path_bfs("a", "e", exclude_nodes={"b", "d"})
+
+
+def test_excluded_endpoint_raises():
+    v = bridge_graph()
+    with pytest.raises(ValueError):
+        v.shortest_path_bfs("a", "e", exclude_nodes={"e"})
+
+
+# ---- edge_filter ----
+
+def relation_graph():
+    """cat -related_to-> animal is direct; cat -is_a-> mammal -is_a-> animal."""
+    v = Vertex()
+    for node_id in ["cat", "mammal", "animal"]:
+        v.add_node(node_id, {})
+    v.add_edge("cat", "animal", {"type": "related_to"})
+    v.add_edge("cat", "mammal", {"type": "is_a"})
+    v.add_edge("mammal", "animal", {"type": "is_a"})
+    return v
+
+
+def test_edge_filter_changes_discovered_path():
+    v = relation_graph()
+    assert v.shortest_path_bfs("cat", "animal").meta["nodelist"] == ["cat", "animal"]
+
+    result = v.shortest_path_bfs("cat", "animal", edge_filter={"type": "is_a"})
+    assert result.meta["nodelist"] == ["cat", "mammal", "animal"]
+
+
+def test_edge_filter_without_matching_route_raises():
+    v = relation_graph()
+    with pytest.raises(ValueError):
+        v.shortest_path_bfs("cat", "animal", edge_filter={"type": "part_of"})
+
+
+def test_edge_filter_list_value_matches_any_relation():
+    v = relation_graph()
+    v.add_node("kitten", {})
+    v.add_edge("kitten", "cat", {"type": "part_of"})
+
+    for types in (["is_a", "part_of"], ("is_a", "part_of"), {"is_a", "part_of"}):
+        result = v.shortest_path_bfs("kitten", "animal", edge_filter={"type": types})
+        assert result.meta["nodelist"] == ["kitten", "cat", "mammal", "animal"]
+    assert not v.is_reachable("kitten", "animal", edge_filter={"type": "is_a"})
+    assert v.is_reachable("kitten", "animal", edge_filter={"type": ["is_a", "part_of"]})
+
+    paths = v.batch_shortest_paths(
+        [("kitten", "animal"), ("cat", "animal")], edge_filter={"type": ["is_a", "part_of"]}
+    )
+    assert [n.id for n in paths[0].nodes] == ["kitten", "cat", "mammal", "animal"]
+    assert [n.id for n in paths[1].nodes] == ["cat", "mammal", "animal"]
+
+    view = v.path_neighborhood("cat", "animal", padding=0, edge_filter={"type": "is_a"})
+    assert view.meta["nodelist"] == ["cat", "mammal", "animal"]
+    with pytest.raises(ValueError):
+        v.path_neighborhood("kitten", "animal", edge_filter={"type": "is_a"})
+
+
+# ---- is_reachable ----
+
+def test_is_reachable_across_two_components():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "x", "y"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+    v.add_edge("x", "y", {})
+
+    assert v.is_reachable("a", "c")
+    assert not v.is_reachable("a", "c", max_depth=1)
+    assert not v.is_reachable("a", "y")
+    assert not v.is_reachable("c", "a")
+    assert v.is_reachable("x", "x")
+    with pytest.raises(KeyError):
+        v.is_reachable("a", "missing")
+
+
+# ---- batch_shortest_paths ----
+
+def grid_graph():
+    """Directed chain n0 -> n1 -> ... -> n5 plus a shortcut n0 -> n3."""
+    v = Vertex()
+    for i in range(6):
+        v.add_node(f"n{i}", {})
+    for i in range(5):
+        v.add_edge(f"n{i}", f"n{i+1}", {"weight": 1.0})
+    v.add_edge("n0", "n3", {"weight": 10.0})
+    return v
+
+
+def test_batch_matches_individual_bfs_calls():
+    v = grid_graph()
+    pairs = [("n0", "n5"), ("n0", "n2"), ("n1", "n4"), ("n2", "n2")]
+    paths = v.batch_shortest_paths(pairs)
+    assert len(paths) == len(pairs)
+    for (source, target), path in zip(pairs, paths):
+        expected = v.shortest_path_bfs(source, target).meta["nodelist"]
+        assert [n.id for n in path.nodes] == expected
+
+
+def test_batch_unreachable_pair_is_none():
+    v = grid_graph()
+    paths = v.batch_shortest_paths([("n5", "n0"), ("n0", "n1")])
+    assert paths[0] is None
+    assert [n.id for n in paths[1].nodes] == ["n0", "n1"]
+
+
+def test_batch_weighted_avoids_expensive_shortcut():
+    v = grid_graph()
+    unweighted, weighted = (
+        v.batch_shortest_paths([("n0", "n3")])[0],
+        v.batch_shortest_paths([("n0", "n3")], weight_field="weight")[0],
+    )
+    assert [n.id for n in unweighted.nodes] == ["n0", "n3"]
+    assert [n.id for n in weighted.nodes] == ["n0", "n1", "n2", "n3"]
+
+
+def test_batch_sequential_matches_parallel():
+    v = grid_graph()
+    pairs = [(f"n{i}", f"n{j}") for i in range(6) for j in range(6)]
+    par = v.batch_shortest_paths(pairs, parallel=True)
+    seq = v.batch_shortest_paths(pairs, parallel=False)
+    as_ids = lambda paths: [None if p is None else [n.id for n in p.nodes] for p in paths]
+    assert as_ids(par) == as_ids(seq)
+
+
+def test_batch_hop_limit_cuts_off_path_the_cost_limit_allows():
+    v = grid_graph()
+    ids = lambda path: None if path is None else [n.id for n in path.nodes]
+    pair = [("n0", "n5")]
+    # The chain costs 5 over 5 hops; the shortcut route costs 12 over 3 hops
+    assert ids(v.batch_shortest_paths(pair, weight_field="weight", max_cost=6)[0]) == [
+        "n0", "n1", "n2", "n3", "n4", "n5"
+    ]
+    assert v.batch_shortest_paths(pair, weight_field="weight", max_cost=6, max_depth=3)[0] is None
+    assert ids(v.batch_shortest_paths(pair, weight_field="weight", max_cost=12, max_depth=3)[0]) == [
+        "n0", "n3", "n4", "n5"
+    ]
+
+    # n3 is cheapest over the chain, but n4 within 3 hops needs the shortcut
+    paths = v.batch_shortest_paths([("n0", "n3"), ("n0", "n4")], weight_field="weight", max_depth=3)
+    assert ids(paths[0]) == ["n0", "n1", "n2", "n3"]
+    assert ids(paths[1]) == ["n0", "n3", "n4"]
+
+    # Without weight_field the cost is the hop count
+    assert ids(v.batch_shortest_paths(pair, max_cost=3)[0]) == ["n0", "n3", "n4", "n5"]
+    assert v.batch_shortest_paths(pair, max_cost=2)[0] is None
+    with pytest.raises(ValueError, match="max_cost"):
+        v.batch_shortest_paths(pair, max_cost=-1.0)
+
+
+def test_batch_missing_node_raises():
+    v = grid_graph()
+    with pytest.raises(ValueError):
+        v.batch_shortest_paths([("n0", "missing")])
+
+
+# ---- shortest_path_tree ----
+
+def test_shortest_path_tree_is_acyclic_and_spans_reachable_nodes():
+    v = grid_graph()
+    v.add_edge("n5", "n1", {"weight": 1.0})  # cycle back into the chain
+    tree = v.shortest_path_tree("n1")
+
+    assert sorted(tree.keys()) == ["n1", "n2", "n3", "n4", "n5"]
+    assert tree.edge_count() == tree.node_count() - 1
+    assert tree.is_dag()
+    assert tree.is_weakly_connected()
+    assert [len(tree[n].inverse_edges) for n in ["n2", "n3", "n4", "n5"]] == [1, 1, 1, 1]
+    assert tree["n1"].inverse_edges == []
+    assert tree.meta["distances"]["n5"] == 4.0
+
+
+def test_shortest_path_tree_weighted_uses_dijkstra_predecessors():
+    v = grid_graph()
+    bfs_tree = v.shortest_path_tree("n0")
+    dijkstra_tree = v.shortest_path_tree("n0", weight_field="weight")
+
+    assert bfs_tree["n3"].inverse_edges[0].from_node.id == "n0"
+    assert dijkstra_tree["n3"].inverse_edges[0].from_node.id == "n2"
+    assert dijkstra_tree["n3"].inverse_edges[0].attr == {"weight": 1.0}
+    assert dijkstra_tree.meta["distances"]["n5"] == 5.0
+
+    with pytest.raises(ValueError):
+        v.shortest_path_tree("missing")
+
+
+# ---- bellman_ford ----
+
+def gains_graph():
+    v = Vertex()
+    for node_id in ["s", "a", "b", "t", "z"]:
+        v.add_node(node_id, {})
+    v.add_edge("s", "a", {"weight": 4.0})
+    v.add_edge("s", "b", {"weight": 1.0})
+    v.add_edge("a", "t", {"weight": 1.0})
+    v.add_edge("b", "t", {"weight": 5.0})
+    return v
+
+
+def test_bellman_ford_negative_edge_lowers_path_cost():
+    v = gains_graph()
+    assert v.bellman_ford("s") == {"s": 0.0, "a": 4.0, "b": 1.0, "t": 5.0}
+
+    v.add_edge("b", "a", {"weight": -3.0})
+    assert v.bellman_ford("s") == {"s": 0.0, "a": -2.0, "b": 1.0, "t": -1.0}
+
+
+def test_bellman_ford_reachable_negative_cycle_raises():
+    v = gains_graph()
+    v.add_edge("t", "b", {"weight": -7.0})
+    with pytest.raises(GraphError, match="Negative cycle through node '(b|t)'"):
+        v.bellman_ford("s")
+
+    # The cycle cannot be reached from z
+    assert v.bellman_ford("z") == {"z": 0.0}
+    with pytest.raises(KeyError):
+        v.bellman_ford("missing")
+
+
+# ---- floyd_warshall ----
+
+def test_floyd_warshall_matches_repeated_dijkstra():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d", "e"]:
+        v.add_node(node_id, {})
+    for u, w, weight in [("a", "b", 1.0), ("b", "c", 2.0), ("a", "c", 5.0), ("c", "d", 1.0),
+                         ("d", "a", 3.0), ("b", "d", 4.5), ("a", "b", 0.5)]:
+        v.add_edge(u, w, {"weight": weight})
+
+    ids, dist = v.floyd_warshall()
+    assert ids == ["a", "b", "c", "d", "e"]
+    for i, source in enumerate(ids):
+        reached = v.filter(ids=[source]).expand_weighted(v, float("inf"), annotate_distance=True)
+        expected = {node.id: node.meta["distance"] for node in reached}
+        for j, target in enumerate(ids):
+            assert dist[i][j] == pytest.approx(expected.get(target, float("inf")))
+    assert dist[0][3] == pytest.approx(3.5)
+
+
+def test_floyd_warshall_negative_weights_and_cycles():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 4.0})
+    v.add_edge("a", "c", {"weight": 2.0})
+    v.add_edge("c", "b", {"weight": -1.5})
+    ids, dist = v.floyd_warshall()
+    assert dist[0][1] == pytest.approx(0.5)
+
+    v.add_edge("b", "c", {"weight": 1.0})
+    with pytest.raises(GraphError, match="Negative cycle through node 'b'"):
+        v.floyd_warshall()
+
+
+def test_floyd_warshall_reports_progress_and_cancels():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 1.0})
+    v.add_edge("b", "c", {"weight": 1.0})
+
+    calls = []
+    ids, dist = v.floyd_warshall(progress=lambda done, total: calls.append((done, total)))
+    assert calls == [(1, 4), (2, 4), (3, 4), (4, 4)]
+    assert dist[0][2] == 2.0
+
+    polls = []
+    def cancel_on_third_poll():
+        polls.append(None)
+        return len(polls) == 3
+    with pytest.raises(CancelledError, match="after 2 of 4"):
+        v.floyd_warshall(should_cancel=cancel_on_third_poll)
+
+
+# ---- steiner_tree ----
+
+def hub_graph():
+    """Terminals t1, t2, t3 meet at hub h (weight 1 each); direct links between
+    terminals cost 5 and the spare node s hangs off the hub."""
+    v = Vertex()
+    for node_id in ["t1", "t2", "t3", "h", "s"]:
+        v.add_node(node_id, {"name": node_id})
+    v.add_edge("t1", "h", {"weight": 1.0})
+    v.add_edge("h", "t2", {"weight": 1.0})
+    v.add_edge("t3", "h", {"weight": 1.0})
+    v.add_edge("t1", "t2", {"weight": 5.0})
+    v.add_edge("t2", "t3", {"weight": 5.0})
+    v.add_edge("h", "s", {"weight": 0.5})
+    return v
+
+
+def test_steiner_tree_connects_terminals_through_hub():
+    tree = hub_graph().steiner_tree(["t1", "t2", "t3"])
+
+    assert sorted(tree.keys()) == ["h", "t1", "t2", "t3"]
+    assert tree.edge_count() == 3
+    assert tree.meta["total_weight"] == 3.0
+    assert tree.is_weakly_connected()
+    # Edges keep their original direction and attributes
+    assert [e.to_node.id for e in tree["t3"].edges] == ["h"]
+    assert tree["h"].attr == {"name": "h"}
+
+
+def test_steiner_tree_edge_cases():
+    v = hub_graph()
+    single = v.steiner_tree(["t1"])
+    assert single.keys() == ["t1"] and single.edge_count() == 0
+
+    v.add_node("island", {})
+    with pytest.raises(GraphError, match="not connected"):
+        v.steiner_tree(["t1", "island"])
+    with pytest.raises(KeyError):
+        v.steiner_tree(["t1", "missing"])
+
+
+# ---- path_neighborhood ----
+
+def decorated_chain():
+    """Path a -> b -> c with side nodes: a -> x, b -> y -> z, and w -> c (incoming only)."""
+    v = Vertex()
+    for node_id in ["a", "b", "c", "x", "y", "z", "w"]:
+        v.add_node(node_id, {"name": node_id})
+    v.add_edge("a", "b", {"weight": 1.0})
+    v.add_edge("b", "c", {"weight": 1.0})
+    v.add_edge("a", "x", {})
+    v.add_edge("b", "y", {})
+    v.add_edge("y", "z", {})
+    v.add_edge("w", "c", {})
+    return v
+
+
+def test_path_neighborhood_padding_one_adds_immediate_neighbors():
+    result = decorated_chain().path_neighborhood("a", "c")
+    assert result.meta["nodelist"] == ["a", "b", "c"]
+    assert sorted(result.keys()) == ["a", "b", "c", "x", "y"]
+    assert {e.to_node.id for e in result["b"].edges} == {"c", "y"}
+    assert result["x"].attr == {"name": "x"}
+
+
+def test_path_neighborhood_padding_zero_and_two():
+    v = decorated_chain()
+    assert sorted(v.path_neighborhood("a", "c", padding=0).keys()) == ["a", "b", "c"]
+    assert sorted(v.path_neighborhood("a", "c", padding=2).keys()) == ["a", "b", "c", "x", "y", "z"]
+
+
+def test_path_neighborhood_weighted_and_unreachable():
+    v = decorated_chain()
+    v.add_edge("a", "c", {"weight": 5.0})
+    assert v.path_neighborhood("a", "c", padding=0).meta["nodelist"] == ["a", "c"]
+    weighted = v.path_neighborhood("a", "c", padding=0, weight_field="weight")
+    assert weighted.meta["nodelist"] == ["a", "b", "c"]
+    with pytest.raises(ValueError, match="not reachable"):
+        v.path_neighborhood("c", "a")
+
+
+def test_path_neighborhood_with_cost_and_hop_limits():
+    v = decorated_chain()
+    v.add_edge("a", "c", {"weight": 5.0})
+    # The cheapest path takes 2 hops; one hop forces the dearer direct edge
+    limited = v.path_neighborhood("a", "c", padding=0, weight_field="weight", max_depth=1)
+    assert limited.meta["nodelist"] == ["a", "c"]
+    cheap = v.path_neighborhood("a", "c", padding=0, weight_field="weight", max_cost=3)
+    assert cheap.meta["nodelist"] == ["a", "b", "c"]
+    with pytest.raises(GraphError, match="not reachable"):
+        v.path_neighborhood("a", "c", weight_field="weight", max_cost=3, max_depth=1)
@@ -0,0 +1,115 @@
+import math
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+
+def triangle_with_loop():
+    """a -> b (2.0), b -> c (1.0), c -> a (0.5), plus a self-loop on c."""
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("b", "c", {"weight": 1.0})
+    v.add_edge("c", "a", {"weight": 0.5})
+    v.add_edge("c", "c", {"weight": 9.0})
+    return v
+
+
+# ---- to_laplacian ----
+
+def test_to_laplacian_rows_sum_to_zero():
+    np = pytest.importorskip("numpy")
+    laplacian, ids = triangle_with_loop().to_laplacian(weight_field="weight")
+    assert ids == ["a", "b", "c"]
+    assert np.allclose(laplacian.sum(axis=1), 0.0)
+    assert np.allclose(laplacian, laplacian.T)
+    # Degree of a is 2.0 + 0.5; the self-loop on c is ignored
+    assert laplacian[0, 0] == 2.5
+    assert laplacian[2, 2] == 1.5
+    assert laplacian[0, 1] == -2.0
+
+
+def test_to_laplacian_normalized_has_unit_diagonal():
+    np = pytest.importorskip("numpy")
+    v = triangle_with_loop()
+    v.add_node("isolated", {})
+    laplacian, ids = v.to_laplacian(normalized=True)
+    assert ids[-1] == "isolated"
+    assert np.allclose(np.diag(laplacian), [1.0, 1.0, 1.0, 0.0])
+    assert np.allclose(laplacian[0, 1], -0.5)
+
+
+# ---- fiedler_vector ----
+
+def barbell():
+    """Two directed 4-cliques joined by a single bridge l0 -> r0."""
+    v = Vertex()
+    for side in ["l", "r"]:
+        for i in range(4):
+            v.add_node(f"{side}{i}", {})
+        for i in range(4):
+            for j in range(i + 1, 4):
+                v.add_edge(f"{side}{i}", f"{side}{j}", {})
+    v.add_edge("l0", "r0", {})
+    return v
+
+
+def test_fiedler_vector_separates_barbell_by_sign():
+    fiedler = barbell().fiedler_vector()
+    left = [fiedler[f"l{i}"] for i in range(4)]
+    right = [fiedler[f"r{i}"] for i in range(4)]
+    assert all(x > 0 for x in left) or all(x < 0 for x in left)
+    assert all(x * left[0] < 0 for x in right)
+    assert sum(x * x for x in fiedler.values()) == pytest.approx(1.0)
+    assert sum(fiedler.values()) == pytest.approx(0.0, abs=1e-9)
+    # The bridge endpoints sit closest to the cut
+    assert abs(fiedler["l0"]) < abs(fiedler["l1"])
+
+
+def test_fiedler_vector_small_graphs():
+    v = Vertex()
+    assert v.fiedler_vector() == {}
+    v.add_node("a", {})
+    assert v.fiedler_vector() == {"a": 0.0}
+    v.add_node("b", {})
+    v.add_edge("a", "b", {"weight": 3.0})
+    fiedler = v.fiedler_vector(weight_field="weight")
+    assert sorted(fiedler.values()) == pytest.approx([-(0.5 ** 0.5), 0.5 ** 0.5])
+
+
+# ---- spring_layout ----
+
+def test_spring_layout_triangle_is_equilateral():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {})
+    v.add_edge("b", "c", {})
+    v.add_edge("c", "a", {})
+
+    pos = v.spring_layout(seed=7)
+    assert sorted(pos) == ["a", "b", "c"]
+    assert len(set(pos.values())) == 3
+    sides = [math.dist(pos[x], pos[y]) for x, y in [("a", "b"), ("b", "c"), ("c", "a")]]
+    assert max(sides) == pytest.approx(min(sides), rel=0.05)
+    assert all(abs(c) <= 1.0 + 1e-9 for xy in pos.values() for c in xy)
+    assert v.spring_layout(seed=7) == pos
+
+
+def test_spring_layout_small_graphs():
+    assert Vertex().spring_layout() == {}
+    v = Vertex()
+    v.add_node("only", {})
+    assert v.spring_layout(seed=1) == {"only": (0.0, 0.0)}
@@ -0,0 +1,77 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def small_graph():
+    v = Vertex()
+    v.add_node("a", {"name": "Alice"})
+    v.add_node("b", {"name": "Bob"})
+    v.add_edge("a", "b", {"type": "knows", "since": 2020})
+    return v
+
+
+# ---- Edge.to_dict / Edge.toJSON ----
+
+def test_edge_to_dict_has_endpoints_and_meta():
+    v = small_graph()
+    edge = v["a"].edges[0]
+    edge.meta = {"source": "import"}
+
+    d = edge.to_dict()
+    assert d["from_id"] == "a"
+    assert d["to_id"] == "b"
+    assert d["attr"] == {"type": "knows", "since": 2020}
+    assert d["meta"] == {"source": "import"}
+    assert "id" in d
+
+
+def test_edge_tojson_matches_to_dict():
+    edge = small_graph()["a"].edges[0]
+    assert edge.toJSON() == edge.to_dict()
+
+
+def test_edge_to_dict_is_a_copy():
+    edge = small_graph()["a"].edges[0]
+    d = edge.to_dict()
+    d["attr"]["type"] = "changed"
+    assert edge.attr["type"] == "knows"
+
+
+def test_edge_to_dict_rebuilds_edge():
+    v = small_graph()
+    d = v["a"].edges[0].to_dict()
+
+    rebuilt = Vertex()
+    rebuilt.add_node("a", {})
+    rebuilt.add_node("b", {})
+    rebuilt.add_edge(d["from_id"], d["to_id"], d["attr"])
+    assert rebuilt["a"].edges[0].attr == v["a"].edges[0].attr
+
+
+# ---- Node.to_dict ----
+
+def test_node_to_dict_lists_edges_both_ways():
+    v = small_graph()
+    v["a"].meta = {"seen": True}
+
+    a = v["a"].to_dict()
+    assert a["id"] == "a"
+    assert a["attr"] == {"name": "Alice"}
+    assert a["meta"] == {"seen": True}
+    assert [e["to_id"] for e in a["edges"]] == ["b"]
+    assert a["inverse_edges"] == []
+
+    b = v["b"].to_dict()
+    assert b["edges"] == []
+    assert [e["from_id"] for e in b["inverse_edges"]] == ["a"]
@@ -0,0 +1,383 @@
+import os
+import sys
+
+import pytest
+
+ROOT = os.path.dirname(os.path.dirname(__file__))
+PYTHON_DIR = os.path.join(ROOT, "python")
+sys.path.insert(0, PYTHON_DIR)
+
+try:
+    from ironweaver import Vertex
+except Exception as e:  # pragma: no cover - optional build step
+    pytest.skip(f"ironweaver module unavailable: {e}", allow_module_level=True)
+
+
+def chain(n):
+    v = Vertex()
+    for i in range(n):
+        v.add_node(f"n{i}", {})
+    for i in range(n - 1):
+        v.add_edge(f"n{i}", f"n{i + 1}", {"type": "next"})
+    return v
+
+
+def tree():
+    """r -> a, b; a -> c; b -> c, d (c is reachable at depth 2 twice)."""
+    v = Vertex()
+    for node_id in ["r", "a", "b", "c", "d"]:
+        v.add_node(node_id, {})
+    v.add_edge("r", "a", {"type": "x"})
+    v.add_edge("r", "b", {"type": "y"})
+    v.add_edge("a", "c", {"type": "x"})
+    v.add_edge("b", "c", {"type": "y"})
+    v.add_edge("b", "d", {"type": "y"})
+    return v
+
+
+# ---- bfs_layers ----
+
+def test_bfs_layers_chain_one_node_per_layer():
+    v = chain(4)
+    assert v["n0"].bfs_layers() == [["n0"], ["n1"], ["n2"], ["n3"]]
+
+
+def test_bfs_layers_depth_limit():
+    v = chain(4)
+    assert v["n0"].bfs_layers(depth=2) == [["n0"], ["n1"], ["n2"]]
+    assert v["n0"].bfs_layers(depth=0) == [["n0"]]
+
+
+def test_bfs_layers_groups_by_distance():
+    layers = tree()["r"].bfs_layers()
+    assert layers[0] == ["r"]
+    assert sorted(layers[1]) == ["a", "b"]
+    assert sorted(layers[2]) == ["c", "d"]
+    assert len(layers) == 3
+
+
+def test_bfs_layers_with_filters():
+    v = tree()
+    assert v["r"].bfs_layers(filter={"type": "x"}) == [["r"], ["a"], ["c"]]
+    assert v["r"].bfs_layers(filter=lambda e: e.type == "y") == [["r"], ["b"], ["c", "d"]]
+
+
+# ---- walk ----
+
+def test_walk_bfs_and_dfs_visit_order():
+    v = tree()
+    seen = []
+    v["r"].walk(on_visit=lambda n: seen.append(n.id))
+    assert seen == ["r", "a", "b", "c", "d"]
+
+    seen = []
+    v["r"].walk("dfs", on_visit=lambda n: seen.append(n.id))
+    assert seen == ["r", "a", "c", "b", "d"]
+
+
+def test_walk_prunes_and_reports_edges():
+    v = tree()
+    seen, edges = [], []
+
+    def on_visit(node):
+        seen.append(node.id)
+        return node.id != "b"
+
+    v["r"].walk(on_visit=on_visit, on_edge=lambda e: edges.append((e.from_node.id, e.to_node.id)))
+    assert seen == ["r", "a", "b", "c"]
+    assert edges == [("r", "a"), ("r", "b"), ("a", "c")]
+
+
+def test_walk_depth_and_unknown_order():
+    v = chain(4)
+    seen = []
+    v["n0"].walk("dfs", on_visit=lambda n: seen.append(n.id), depth=2)
+    assert seen == ["n0", "n1", "n2"]
+    with pytest.raises(ValueError):
+        v["n0"].walk("sideways")
+
+
+# ---- path_to ----
+
+def test_path_to_chain_returns_ordered_path():
+    v = chain(5)
+    path = v["n0"].path_to("n4")
+    assert path.toJSON() == ["n0", "n1", "n2", "n3", "n4"]
+    assert path.nodes[-1].id == "n4"
+
+
+def test_path_to_self_and_unreachable():
+    v = chain(3)
+    assert v["n1"].path_to("n1").toJSON() == ["n1"]
+    assert v["n2"].path_to("n0") is None
+    assert v["n0"].path_to("n2", depth=1) is None
+
+
+def test_path_to_with_filter():
+    v = tree()
+    assert v["r"].path_to("c", filter={"type": "y"}).toJSON() == ["r", "b", "c"]
+    assert v["r"].path_to("d", filter=lambda e: e.type == "x") is None
+
+
+# ---- common_neighbors ----
+
+def test_common_neighbors_returns_shared_successor():
+    v = tree()
+    assert v["a"].common_neighbors(v["b"]) == ["c"]
+    assert v["b"].common_neighbors(v["a"]) == ["c"]
+
+
+def test_common_neighbors_without_overlap_is_empty():
+    v = tree()
+    assert v["r"].common_neighbors(v["b"]) == []
+    assert v["d"].common_neighbors(v["r"]) == []
+
+
+# ---- all_edges / is_connected_to ----
+
+def test_all_edges_combines_both_directions():
+    v = tree()
+    b = v["b"]
+    assert [(e.from_node.id, e.to_node.id) for e in b.all_edges()] == [
+        ("b", "c"), ("b", "d"), ("r", "b"),
+    ]
+    assert b.is_connected_to("r")
+    assert b.is_connected_to("d")
+    assert not b.is_connected_to("a")
+
+
+def test_all_edges_lists_self_loop_once():
+    v = Vertex()
+    v.add_node("a", {})
+    v.add_edge("a", "a", {})
+    assert len(v["a"].all_edges()) == 1
+    assert v["a"].is_connected_to("a")
+
+
+# ---- parallel_bfs ----
+
+def test_parallel_bfs_matches_node_bfs_order():
+    v = tree()
+    result = v.parallel_bfs("r")
+    assert result.meta["nodelist"] == v["r"].bfs().meta["nodelist"]
+    assert sorted(result.keys()) == ["a", "b", "c", "d", "r"]
+
+
+def test_parallel_bfs_respects_depth_and_direction():
+    v = chain(5)
+    assert v.parallel_bfs("n1", depth=2).meta["nodelist"] == ["n1", "n2", "n3"]
+    assert v.parallel_bfs("n4").meta["nodelist"] == ["n4"]
+
+
+def test_parallel_bfs_unknown_start_raises():
+    with pytest.raises(ValueError, match="not found"):
+        chain(2).parallel_bfs("missing")
+
+
+# ---- aggregate_neighbors ----
+
+def valued_star():
+    """hub -> a (1), b (4), c ("x"), d (no value); e (10) -> hub."""
+    v = Vertex()
+    v.add_node("hub", {"value": 100})
+    for node_id, attr in [("a", {"value": 1}), ("b", {"value": 4.0}), ("c", {"value": "x"}), ("d", {}), ("e", {"value": 10})]:
+        v.add_node(node_id, attr)
+    for node_id in ["a", "b", "c", "d"]:
+        v.add_edge("hub", node_id, {})
+    v.add_edge("e", "hub", {})
+    return v
+
+
+def test_aggregate_neighbors_mean_skips_missing_and_non_numeric():
+    hub = valued_star()["hub"]
+    assert hub.aggregate_neighbors("value", op="mean") == pytest.approx(2.5)
+    assert hub.aggregate_neighbors("value") == pytest.approx(5.0)
+    assert hub.aggregate_neighbors("value", op="count") == 2
+    assert hub.aggregate_neighbors("value", op="max", direction="both") == pytest.approx(10.0)
+    assert hub.aggregate_neighbors("value", op="min", direction="in") == pytest.approx(10.0)
+
+
+def test_aggregate_neighbors_empty_and_invalid():
+    v = valued_star()
+    assert v["a"].aggregate_neighbors("value", op="mean") is None
+    assert v["a"].aggregate_neighbors("value") == 0.0
+    with pytest.raises(ValueError, match="Unknown op"):
+        v["hub"].aggregate_neighbors("value", op="median")
+    with pytest.raises(ValueError, match="Unknown direction"):
+        v["hub"].aggregate_neighbors("value", direction="sideways")
+
+
+# ---- top_neighbors ----
+
+def test_top_neighbors_orders_by_weight_then_id():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d", "e"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("a", "c", {"weight": 5.0})
+    v.add_edge("a", "b", {"weight": 1.5})   # parallel edges add up
+    v.add_edge("a", "d", {})                # missing weight counts as 1.0
+    v.add_edge("a", "a", {"weight": 99.0})  # self-loop ignored
+    v.add_edge("e", "a", {"weight": 3.5})
+
+    a = v["a"]
+    assert a.top_neighbors(2) == [("c", 5.0), ("b", 3.5)]
+    assert a.top_neighbors(10, direction="both") == [("c", 5.0), ("b", 3.5), ("e", 3.5), ("d", 1.0)]
+    assert a.top_neighbors(1, direction="in") == [("e", 3.5)]
+    assert a.top_neighbors(0) == []
+    v.add_edge("a", "e", {"weight": "heavy"})
+    with pytest.raises(TypeError):
+        a.top_neighbors(2)
+
+
+# ---- propagate ----
+
+def test_propagate_one_iteration_matches_aggregate_neighbors():
+    v = valued_star()
+    expected = {
+        node_id: v[node_id].aggregate_neighbors("value", op="mean", direction="both")
+        for node_id in v.keys()
+    }
+    v.propagate("value", "smoothed", direction="both")
+    for node_id, value in expected.items():
+        assert v[node_id].attr.get("smoothed") == value
+    assert v["a"].attr["smoothed"] == 100.0
+    assert v["a"].attr["value"] == 1
+
+
+def test_propagate_reads_a_consistent_snapshot_per_iteration():
+    # n0 -> n1 -> n2 -> n3 with values 0, 1, 2, 3 written back to the same key
+    v = chain(4)
+    for i in range(4):
+        v[f"n{i}"].attr_set("x", float(i))
+    v.propagate("x", "x", op="sum")
+    # Each node takes its successor's old value, not one updated earlier in the
+    # pass; n3 has no successors and sums to 0
+    assert [v[f"n{i}"].attr["x"] for i in range(4)] == [1.0, 2.0, 3.0, 0.0]
+
+    w = chain(4)
+    for i in range(4):
+        w[f"n{i}"].attr_set("x", float(i))
+    w.propagate("x", "y", op="sum", iterations=2)
+    assert [w[f"n{i}"].attr.get("y") for i in range(4)] == [2.0, 3.0, 0.0, 0.0]
+
+    with pytest.raises(ValueError, match="Unknown op"):
+        w.propagate("x", "y", op="median")
+
+
+# ---- normalize_edge_weights ----
+
+def test_normalize_edge_weights_outgoing_sums_to_one():
+    v = Vertex()
+    for node_id in ["a", "b", "c", "d"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 1.0})
+    v.add_edge("a", "c", {"weight": 3.0})
+    v.add_edge("a", "d", {})  # missing weight counts as 1.0
+    v.add_edge("b", "c", {"weight": 4.0})
+    updates = []
+    v.on_edge_update_callbacks.append(lambda vertex, edge, key, new, old: updates.append((edge.to_node.id, old, new)))
+
+    v.normalize_edge_weights()
+    weights = [e.attr["weight"] for e in v["a"].edges]
+    assert weights == [0.2, 0.6, 0.2]
+    assert sum(weights) == pytest.approx(1.0)
+    assert v["b"].edges[0].attr["weight"] == 1.0
+    assert v["d"].edges == []
+    assert ("d", None, 0.2) in updates and len(updates) == 4
+
+
+def test_normalize_edge_weights_global_min_max():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"w": 2.0})
+    v.add_edge("b", "c", {"w": 6.0})
+    v.add_edge("c", "a", {"w": 4.0})
+    v.normalize_edge_weights("w", mode="global")
+    assert [v[n].edges[0].attr["w"] for n in ["a", "b", "c"]] == [0.0, 1.0, 0.5]
+    with pytest.raises(ValueError, match="Unknown mode"):
+        v.normalize_edge_weights(mode="softmax")
+
+
+# ---- derive_edge_weight ----
+
+def test_derive_edge_weight_inverts_weight():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"weight": 2.0})
+    v.add_edge("a", "c", {"weight": 4})
+    v.add_edge("b", "c", {"weight": 0.5})
+    updates = []
+    v.on_edge_update_callbacks.append(lambda vertex, edge, key, new, old: updates.append((key, new)))
+
+    v.derive_edge_weight("cost", lambda edge: 1 / edge.attr["weight"])
+    assert [e.attr["cost"] for e in v["a"].edges] == [0.5, 0.25]
+    assert v["b"].edges[0].attr["cost"] == 2.0
+    assert sorted(updates) == [("cost", 0.25), ("cost", 0.5), ("cost", 2.0)]
+
+    with pytest.raises(TypeError, match="int or float"):
+        v.derive_edge_weight("label", lambda edge: "heavy" if edge.attr["weight"] > 1 else 1)
+    assert all("label" not in e.attr for n in ["a", "b"] for e in v[n].edges)
+
+
+# ---- map_node_attrs / map_edge_attrs ----
+
+def test_map_node_attrs_doubles_a_numeric_attribute():
+    v = Vertex()
+    for i in range(4):
+        v.add_node(f"n{i}", {"score": i, "name": f"N{i}"})
+    updates = []
+    v.on_node_update_callbacks.append(lambda vertex, node, key, new, old: updates.append((node.id, key, new, old)))
+
+    v.map_node_attrs(lambda attr: {**attr, "score": attr["score"] * 2})
+    assert [v[f"n{i}"].attr["score"] for i in range(4)] == [0, 2, 4, 6]
+    assert v["n1"].attr["name"] == "N1"
+    # n0 keeps 0, so only the three changed scores fire
+    assert updates == [("n1", "score", 2, 1), ("n2", "score", 4, 2), ("n3", "score", 6, 3)]
+
+    v.map_node_attrs(lambda attr: {"name": attr["name"].lower()}, fire=False)
+    assert v["n2"].attr == {"name": "n2"}
+    assert len(updates) == 3
+
+    with pytest.raises(TypeError, match="must return a dict"):
+        v.map_node_attrs(lambda attr: None if attr["name"] == "n3" else attr)
+    assert v["n0"].attr == {"name": "n0"}
+
+
+def test_map_edge_attrs_transforms_every_edge():
+    v = Vertex()
+    for node_id in ["a", "b", "c"]:
+        v.add_node(node_id, {})
+    v.add_edge("a", "b", {"type": "KNOWS", "weight": 1.0})
+    v.add_edge("b", "c", {"type": "LIKES"})
+    updates = []
+    v.on_edge_update_callbacks.append(lambda vertex, edge, key, new, old: updates.append((key, new)))
+
+    v.map_edge_attrs(lambda attr: {key: value.lower() if isinstance(value, str) else value for key, value in attr.items()})
+    assert v["a"].edges[0].attr == {"type": "knows", "weight": 1.0}
+    assert v["b"].edges[0].attr == {"type": "likes"}
+    assert updates == [("type", "knows"), ("type", "likes")]
+
+
+# ---- bfs_frontiers ----
+
+def test_bfs_frontiers_yields_levels_lazily():
+    v = chain(2000)
+    frontiers = v.bfs_frontiers("n0")
+    assert iter(frontiers) is frontiers
+    assert next(frontiers) == ["n0"]
+    assert next(frontiers) == ["n1"]
+
+
+def test_bfs_frontiers_matches_bfs_layers():
+    v = tree()
+    assert list(v.bfs_frontiers("r")) == v["r"].bfs_layers()
+    assert list(v.bfs_frontiers("r", max_depth=1)) == v["r"].bfs_layers(depth=1)
+    assert list(v.bfs_frontiers("d")) == [["d"]]
+
+
+def test_bfs_frontiers_unknown_root_raises():
+    with pytest.raises(ValueError, match="not found"):
+        tree().bfs_frontiers("missing")